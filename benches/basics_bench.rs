@@ -1,6 +1,8 @@
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 
 const IMG_SIZE: u32 = 600;
+const IMG_END: i32 = IMG_SIZE as i32;
+const IMG_MID: i32 = IMG_END / 2;
 
 pub fn blank() -> image::RgbaImage {
     image::RgbaImage::from_pixel(IMG_SIZE, IMG_SIZE, image::Rgba([255, 255, 255, 255]))
@@ -13,8 +15,8 @@ fn bench_basic_vertical_line(c: &mut Criterion) {
             |mut image| {
                 freehand::lines::vertical_line(
                     &mut image,
-                    (IMG_SIZE / 2, 0),
-                    IMG_SIZE,
+                    (IMG_MID, 0),
+                    IMG_END,
                     image::Rgba([255, 0, 0, 255]),
                 );
             },
@@ -30,8 +32,8 @@ fn bench_basic_horizontal_line(c: &mut Criterion) {
             |mut image| {
                 freehand::lines::horizontal_line(
                     &mut image,
-                    (0, IMG_SIZE / 2),
-                    IMG_SIZE,
+                    (0, IMG_MID),
+                    IMG_END,
                     image::Rgba([255, 0, 0, 255]),
                 );
             },
@@ -65,7 +67,7 @@ fn bench_basic_horizontal_dashed(c: &mut Criterion) {
             |mut image| {
                 freehand::lines::horizontal_dashed_line(
                     &mut image,
-                    (0, IMG_SIZE / 2),
+                    (0, IMG_MID),
                     10,
                     IMG_SIZE,
                     image::Rgba([255, 0, 0, 255]),
@@ -83,7 +85,7 @@ fn bench_basic_vertical_dashed(c: &mut Criterion) {
             |mut image| {
                 freehand::lines::vertical_dashed_line(
                     &mut image,
-                    (IMG_SIZE / 2, 0),
+                    (IMG_MID, 0),
                     10,
                     IMG_SIZE,
                     image::Rgba([255, 0, 0, 255]),
@@ -137,15 +139,81 @@ fn bench_imageproc_blend(c: &mut Criterion) {
     });
 }
 
+fn bench_aa_line(c: &mut Criterion) {
+    c.bench_function("aa_line_1.5px", |b| {
+        b.iter_batched(
+            blank,
+            |mut image| {
+                let color = image::Rgba([255, 0, 0, 255]);
+                freehand::lines::antialiased_line(&mut image, (0, 0), (399, 250), 1.5, color);
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_aa_line_1px(c: &mut Criterion) {
+    c.bench_function("aa_line_1px", |b| {
+        b.iter_batched(
+            blank,
+            |mut image| {
+                let color = image::Rgba([255, 0, 0, 255]);
+                freehand::lines::antialiased_line_1px(&mut image, (0, 0), (399, 250), color);
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_fill_small_paths(c: &mut Criterion) {
+    use freehand::raster::{fill_path, FillRule, Rasterizer};
+
+    let triangles: Vec<_> = (0..1000)
+        .map(|i| {
+            let (x, y) = (f64::from(i % 40) * 10.0, f64::from(i / 40) * 16.0);
+            [(x, y), (x + 9.0, y + 2.5), (x + 4.0, y + 14.5)]
+        })
+        .collect();
+    let color = image::Rgba([255, 0, 0, 255]);
+
+    c.bench_function("fill_path_1000", |b| {
+        b.iter_batched(
+            blank,
+            |mut image| {
+                for t in &triangles {
+                    fill_path(&mut image, *t, FillRule::NonZero, color);
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("rasterizer_fill_path_1000", |b| {
+        let mut raster = Rasterizer::new();
+        b.iter_batched(
+            blank,
+            |mut image| {
+                for t in &triangles {
+                    raster.fill_path(&mut image, *t, FillRule::NonZero, color);
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
 criterion_group!(
     lines,
     bench_basic_vertical_line,
     bench_basic_horizontal_line,
     bench_basic_rectangle_filled,
     bench_basic_horizontal_dashed,
-    bench_basic_vertical_dashed
+    bench_basic_vertical_dashed,
+    bench_aa_line,
+    bench_aa_line_1px
 );
 
+criterion_group!(shapes, bench_fill_small_paths);
+
 criterion_group!(
     ops,
     bench_blend_safe,
@@ -153,4 +221,4 @@ criterion_group!(
     bench_imageproc_blend
 );
 
-criterion_main!(ops, lines);
+criterion_main!(ops, lines, shapes);
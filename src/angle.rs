@@ -1,10 +1,4 @@
-use crate::RADS;
-
-#[inline]
-/// Find the octant that contains a given angle.
-pub(crate) fn angle_to_octant(angle: f64) -> u8 {
-    (angle / RADS).floor() as u8 + 1
-}
+use crate::{Pt, RADS};
 
 #[inline]
 /// Retrieve the start angle of the specified octant.
@@ -14,9 +8,75 @@ pub(crate) fn octant_start_angle(oct: u8) -> f64 {
 
 #[inline]
 /// Retrieve the end angle of the specified octant.
+///
+/// This is also the start angle of the next octant, so the octant has to be
+/// kept alongside it (see [`end_octant`]).
 pub(crate) fn octant_end_angle(oct: u8) -> f64 {
-    // subtract a *very* tiny amount to prevent moving into the next octant
-    oct as f64 * RADS - crate::TINY
+    oct as f64 * RADS
+}
+
+#[inline]
+/// Splits an angle into the index of the octant it falls in (`0..8`) and how
+/// far into that octant it is (`0.0..1.0`).
+///
+/// The remainder is found in the angle's own units, so whole numbers of
+/// degrees on an octant boundary (0°, 45°, 90°, ...) have an offset of exactly
+/// 0 and angles just past a boundary never round onto it.
+fn split_octants<A: Angle>(angle: A) -> (u8, f64) {
+    let size = angle.octant_size();
+    let value = angle.f64();
+    let mut whole = (value / size).floor();
+    let mut offset = (value - whole * size) / size;
+    // Division can round across a boundary for values very close to it
+    if offset < 0.0 {
+        whole -= 1.0;
+        offset = (offset + 1.0).min(1.0 - f64::EPSILON);
+    } else if offset >= 1.0 {
+        whole += 1.0;
+        offset = 0.0;
+    }
+    (whole.rem_euclid(8.0) as u8, offset)
+}
+
+#[inline]
+/// Finds the octant a start angle falls in, and the angle in radians.
+///
+/// Octants are half-open: an angle exactly on a boundary starts the octant
+/// after the boundary.
+pub(crate) fn start_octant<A: Angle>(angle: A) -> (u8, f64) {
+    let (index, offset) = split_octants(angle);
+    (index + 1, (f64::from(index) + offset) * RADS)
+}
+
+#[inline]
+/// Finds the octant an end angle falls in, and the angle in radians.
+///
+/// Unlike [`start_octant`], an angle exactly on a boundary ends the octant
+/// before the boundary, so an arc ending at 90° stops in the second octant
+/// instead of reaching into the third.
+// An offset of exactly 0 means the angle is on a boundary
+#[allow(clippy::float_cmp)]
+pub(crate) fn end_octant<A: Angle>(angle: A) -> (u8, f64) {
+    let (index, offset) = split_octants(angle);
+    if offset == 0.0 {
+        // Boundaries end the previous octant, with 0° ending the last one
+        let oct = (index + 7) % 8 + 1;
+        (oct, octant_end_angle(oct))
+    } else {
+        (index + 1, (f64::from(index) + offset) * RADS)
+    }
+}
+
+#[inline]
+/// Returns true if sweeping from `start` to `end` covers a full circle.
+///
+/// Like [`is_full_sweep`], but the angles are compared in their own units, so
+/// whole numbers of degrees that are a full turn apart are always caught.
+// The split angles are compared exactly, so equal inputs always match
+#[allow(clippy::float_cmp)]
+pub(crate) fn is_full_turn<A: Angle>(start: A, end: A) -> bool {
+    (end.f64() - start.f64()).abs() >= 8.0 * start.octant_size()
+        || split_octants(start) == split_octants(end)
 }
 
 #[inline]
@@ -26,6 +86,31 @@ pub(crate) fn normalize(angle: f64) -> f64 {
     (angle % PI2 + PI2) % PI2
 }
 
+#[inline]
+/// Returns true if sweeping from `start` to `end` (in radians) covers a full circle.
+///
+/// Equal angles are treated as a full circle, as is any sweep of `2*PI` or more.
+pub(crate) fn is_full_sweep(start: f64, end: f64) -> bool {
+    (end - start).abs() >= crate::PI2 || (normalize(start) - normalize(end)).abs() <= f64::EPSILON
+}
+
+#[inline]
+/// Find the angle from a center point to another point, in the range `0..2*PI`.
+///
+/// Image coordinates are used, so angles increase counter-clockwise with y going down.
+pub(crate) fn point_angle(c: Pt<f64>, p: Pt<f64>) -> f64 {
+    normalize((c.y() - p.y()).atan2(p.x() - c.x()))
+}
+
+#[inline]
+/// Returns true if `angle` lies within the sweep from `start` to `end`, widened
+/// by `tolerance` radians on either side.
+pub(crate) fn in_sweep(angle: f64, start: f64, end: f64, tolerance: f64) -> bool {
+    let sweep = normalize(end - start);
+    let a = normalize(angle - start);
+    a <= sweep + tolerance || a >= crate::PI2 - tolerance
+}
+
 #[inline]
 /// Find the quadrant that contains a given angle.
 pub(crate) fn angle_to_quad(angle: f64) -> u8 {
@@ -43,6 +128,13 @@ pub trait Angle: Copy {
     fn radians(&self) -> f64 {
         self.f64().to_radians()
     }
+
+    /// Return the size of one octant (45°) in the same units as
+    /// [`Angle::f64`], e.g. `45.0` for degrees.
+    ///
+    /// This lets angles on an octant boundary be found exactly, without
+    /// converting to radians first.
+    fn octant_size(&self) -> f64;
 }
 
 /// Treat `f32` values as radians
@@ -53,6 +145,9 @@ impl Angle for f32 {
     fn radians(&self) -> f64 {
         *self as f64
     }
+    fn octant_size(&self) -> f64 {
+        RADS
+    }
 }
 
 /// Treat `f64` values as radians
@@ -63,61 +158,154 @@ impl Angle for f64 {
     fn radians(&self) -> f64 {
         *self
     }
+    fn octant_size(&self) -> f64 {
+        RADS
+    }
 }
 
 /// Treat `u16` values as degrees
 impl Angle for u16 {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        f64::from(*self)
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `u32` values as degrees
 impl Angle for u32 {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        f64::from(*self)
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `usize` values as degrees
 impl Angle for usize {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        *self as f64
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `u64` values as degrees
 impl Angle for u64 {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        *self as f64
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `i16` values as degrees
 impl Angle for i16 {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        f64::from(*self)
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `i32` values as degrees
 impl Angle for i32 {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        f64::from(*self)
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `isize` values as degrees
 impl Angle for isize {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        *self as f64
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
     }
 }
 
 /// Treat `i64` values as degrees
 impl Angle for i64 {
     fn f64(&self) -> f64 {
-        (self % 360) as f64
+        *self as f64
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
+    }
+}
+
+/// An angle in degrees.
+///
+/// ```
+/// use freehand::{Angle, Degrees};
+/// assert!((Degrees(180.0).radians() - std::f64::consts::PI).abs() < f64::EPSILON);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// An angle in radians.
+///
+/// ```
+/// use freehand::{Angle, Radians};
+/// assert!((Radians(std::f64::consts::PI).radians() - std::f64::consts::PI).abs() < f64::EPSILON);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+/// An angle in turns, where `1.0` is a full circle.
+///
+/// ```
+/// use freehand::{Angle, Turns};
+/// assert!((Turns(0.5).radians() - std::f64::consts::PI).abs() < f64::EPSILON);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Turns(pub f64);
+
+/// Treat the value as degrees
+impl Angle for Degrees {
+    fn f64(&self) -> f64 {
+        self.0
+    }
+    fn octant_size(&self) -> f64 {
+        45.0
+    }
+}
+
+/// Treat the value as radians
+impl Angle for Radians {
+    fn f64(&self) -> f64 {
+        self.0
+    }
+    fn radians(&self) -> f64 {
+        self.0
+    }
+    fn octant_size(&self) -> f64 {
+        RADS
+    }
+}
+
+/// Treat the value as a fraction of a full circle
+impl Angle for Turns {
+    fn f64(&self) -> f64 {
+        self.0
+    }
+    fn radians(&self) -> f64 {
+        self.0 * crate::PI2
+    }
+    fn octant_size(&self) -> f64 {
+        0.125
     }
 }
 
@@ -131,6 +319,59 @@ mod tests {
         assert!((180u32.radians() - 4.0 * RADS).abs() <= crate::TINY);
         assert!((180u64.radians() - 4.0 * RADS).abs() <= crate::TINY);
         assert!((180usize.radians() - 4.0 * RADS).abs() <= crate::TINY);
-        assert!(((4.0f32 * RADS_F32).radians() - 4.0 * RADS) <= std::f32::EPSILON as f64);
+        assert!(((4.0f32 * RADS_F32).radians() - 4.0 * RADS) <= f32::EPSILON as f64);
+    }
+
+    #[test]
+    fn angle_newtypes() {
+        assert!((Degrees(180.0).radians() - 4.0 * RADS).abs() <= crate::TINY);
+        assert!((Radians(4.0 * RADS).radians() - 4.0 * RADS).abs() <= crate::TINY);
+        assert!((Turns(0.5).radians() - 4.0 * RADS).abs() <= crate::TINY);
+        // Unlike integer degrees, fractional degrees are kept
+        assert!((Degrees(22.5).radians() - RADS / 2.0).abs() <= crate::TINY);
+    }
+
+    #[test]
+    fn octant_boundaries() {
+        // Boundaries start the next octant and end the previous one
+        assert_eq!(start_octant(135u32).0, 4);
+        assert_eq!(end_octant(135u32).0, 3);
+        assert_eq!(start_octant(0u32).0, 1);
+        assert_eq!(end_octant(360u32).0, 8);
+        assert_eq!(start_octant(-45i32).0, 8);
+        assert_eq!(start_octant(Turns(0.375)).0, 4);
+        assert_eq!(start_octant(3.0 * RADS).0, 4);
+
+        // Just past a boundary stays in the same octant either way
+        assert_eq!(start_octant(Degrees(135.001)).0, 4);
+        assert_eq!(end_octant(Degrees(135.001)).0, 4);
+        assert_eq!(end_octant(Degrees(134.999)).0, 3);
+
+        assert!(is_full_turn(10u32, 370));
+        assert!(is_full_turn(100i32, -260));
+        assert!(is_full_turn(45u32, 45));
+        assert!(!is_full_turn(10u32, 369));
+    }
+
+    #[test]
+    fn octant_sizes() {
+        // One octant in each type's own units is 45°
+        fn check<A: Angle>(a: A) {
+            let octant = a.octant_size() * a.radians() / a.f64();
+            assert!((octant - RADS).abs() <= crate::TINY, "{octant}");
+        }
+        check(90u16);
+        check(90u32);
+        check(90usize);
+        check(90u64);
+        check(-90i16);
+        check(-90i32);
+        check(-90isize);
+        check(-90i64);
+        check(1.5f32);
+        check(1.5f64);
+        check(Degrees(90.0));
+        check(Radians(1.5));
+        check(Turns(0.25));
     }
 }
@@ -35,6 +35,16 @@ where
         }
     }
 
+    /// Used to adjust the opacity of both pixels.
+    pub(crate) fn mult_opac(self, i: f64) -> Self {
+        Self {
+            a: self.a,
+            b: self.b,
+            oa: self.oa * i,
+            ob: self.ob * i,
+        }
+    }
+
     /// Used to adjust the opacity of the first pixel.
     pub(crate) fn mult_opac_a(self, i: f64) -> Self {
         Self {
@@ -48,12 +58,19 @@ where
 
 impl AAPt<i32> {
     /// Draw an antialiased point by blending the two pixels into an image.
-    pub(crate) fn draw(&self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+    pub(crate) fn draw(
+        &self,
+        image: &mut image::RgbaImage,
+        color: image::Rgba<u8>,
+    ) -> crate::DrawStats {
         let (width, height) = image.dimensions();
         let a = self.a.u32();
         let b = self.b.u32();
+        let mut stats = crate::DrawStats::default();
+        stats.record((a.x < width) && (a.y < height));
+        stats.record((b.x < width) && (b.y < height));
 
-        if (a.x < width) & (a.y < height) {
+        if (a.x < width) && (a.y < height) {
             // This is safe because the coordinates have already been checked against the image bounds
             // Invalid opacity values are safe, but may produce weird blending
             unsafe {
@@ -61,12 +78,13 @@ impl AAPt<i32> {
             }
         }
 
-        if (b.x < width) & (b.y < height) {
+        if (b.x < width) && (b.y < height) {
             // This is safe because the coordinates have already been checked against the image bounds
             // Invalid opacity values are safe, but may produce weird blending
             unsafe {
                 blend_at_unchecked(image, b.x(), b.y(), self.ob as f32, color);
             }
         }
+        stats
     }
 }
@@ -0,0 +1,16 @@
+//! Simple charts drawn with the existing shape functions
+//!
+//! These are meant for quick visualizations; they only draw the data itself,
+//! leaving axes, labels, and backgrounds up to the caller.
+
+mod bars;
+mod heatmap;
+mod histogram;
+mod polar;
+mod scatter;
+
+pub use bars::{bars, BarStyle};
+pub use heatmap::{heatmap, HeatmapStyle};
+pub use histogram::{bin, histogram};
+pub use polar::{polar_grid, GridStyle};
+pub use scatter::{scatter, scatter_colored, Marker};
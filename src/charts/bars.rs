@@ -0,0 +1,142 @@
+use crate::shapes::{self, Rect};
+use image::GenericImage;
+
+/// Colors and spacing used to draw bars.  See [`bars`].
+///
+/// With the `serde` feature a style can be serialized whenever its pixel type
+/// can be.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BarStyle<P> {
+    /// The color the bars are filled with
+    pub fill: P,
+    /// An optional color for the outline of each bar
+    pub outline: Option<P>,
+    /// The number of pixels between bars
+    pub gap: u32,
+}
+
+impl<P> BarStyle<P> {
+    /// Creates a style with filled bars, no outline, and a 1 pixel gap.
+    pub fn new(fill: P) -> Self {
+        Self {
+            fill,
+            outline: None,
+            gap: 1,
+        }
+    }
+}
+
+/// Draws a bar chart of `data` inside `rect`, and returns the area of each bar.
+///
+/// The bars share the width of `rect` evenly, and their heights are scaled so
+/// the range of the data (always including 0) fills the height of `rect`.
+/// Positive values extend up from the zero line and negative values extend
+/// down from it.  Non-finite values are skipped, but still take up a slot.
+///
+/// The returned rectangles (one per value) can be used for hit testing with
+/// [`Rect::contains`].  Bars with a height of 0 have an empty rectangle.
+///
+/// # Example
+///
+/// ```
+/// use freehand::charts::{bars, BarStyle};
+/// use freehand::shapes::Rect;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 200);
+/// let style = BarStyle {
+///     outline: Some(Rgba([0, 0, 0, 255])),
+///     ..BarStyle::new(Rgba([0, 0, 255, 255]))
+/// };
+///
+/// let areas = bars(&mut image, Rect::new((0, 0), 200, 400), &[1.0, 4.0, 2.0, 3.0], style);
+/// assert_eq!(areas[1], Rect::new((100, 0), 200, 99));
+/// assert_eq!(areas[2], Rect::new((200, 100), 100, 99));
+/// ```
+#[allow(clippy::cast_possible_wrap)]
+pub fn bars<I>(image: &mut I, rect: Rect, data: &[f64], style: BarStyle<I::Pixel>) -> Vec<Rect>
+where
+    I: GenericImage,
+{
+    let finite = || data.iter().copied().filter(|v| v.is_finite());
+    let max = finite().fold(0.0, f64::max);
+    let min = finite().fold(0.0, f64::min);
+    let scale = if max > min {
+        f64::from(rect.height) / (max - min)
+    } else {
+        0.0
+    };
+    // The y coordinate of a value, relative to the top of the rect
+    let y_of = |v: f64| ((max - v) * scale).round() as i64;
+
+    let count = data.len() as i64;
+    let left = |i: i64| i64::from(rect.x) + i * i64::from(rect.width) / count;
+
+    data.iter()
+        .zip(0..)
+        .map(|(&v, i)| {
+            let x = left(i);
+            let width = (left(i + 1) - x - i64::from(style.gap)).max(1);
+            let (top, bottom) = if v.is_finite() {
+                (y_of(v.max(0.0)), y_of(v.min(0.0)))
+            } else {
+                (0, 0)
+            };
+
+            let bar = Rect::new(
+                (x as i32, (i64::from(rect.y) + top) as i32),
+                (bottom - top) as u32,
+                width as u32,
+            );
+            bar.draw_filled(image, style.fill);
+            if let (Some(color), true) = (style.outline, bar.height != 0) {
+                shapes::rectangle(image, (bar.x, bar.y), bar.height, bar.width, color);
+            }
+            bar
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn bars_negative_values() {
+        let mut image = crate::test::img::blank((40, 30));
+        let style = BarStyle::new(Rgba([255, 0, 0, 255]));
+        let areas = bars(
+            &mut image,
+            Rect::new((0, 0), 30, 40),
+            &[2.0, -1.0, f64::NAN, 0.0],
+            style,
+        );
+
+        // 2.0 and -1.0 split the height 2:1 around the zero line at y = 20
+        assert_eq!(areas[0], Rect::new((0, 0), 20, 9));
+        assert_eq!(areas[1], Rect::new((10, 20), 10, 9));
+        assert_eq!(areas[2].height, 0);
+        assert_eq!(areas[3].height, 0);
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let filled = areas.iter().any(|r| r.contains((x as i32, y as i32)));
+            assert_eq!(*px == style.fill, filled, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn bars_empty_data() {
+        let mut image = crate::test::img::blank((10, 10));
+        assert!(bars(
+            &mut image,
+            Rect::new((0, 0), 10, 10),
+            &[],
+            BarStyle::new(Rgba([0, 0, 0, 255]))
+        )
+        .is_empty());
+        assert_eq!(image, crate::test::img::blank((10, 10)));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::color::Colormap;
+use crate::shapes::Rect;
+use image::{GenericImage, Rgba};
+
+/// How the cells of a heatmap are colored and spaced.  See [`heatmap`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HeatmapStyle {
+    /// The colormap each cell's value is looked up in
+    pub colormap: Colormap,
+    /// The number of pixels between cells
+    pub gap: u32,
+    /// The values mapped to the start and end of the colormap, or `None` to
+    /// use the smallest and largest values in the data
+    pub range: Option<(f64, f64)>,
+}
+
+impl HeatmapStyle {
+    /// Creates a style using `colormap` with no gaps between cells, scaled to
+    /// the range of the data.
+    #[must_use]
+    pub fn new(colormap: Colormap) -> Self {
+        Self {
+            colormap,
+            gap: 0,
+            range: None,
+        }
+    }
+}
+
+impl Default for HeatmapStyle {
+    fn default() -> Self {
+        Self::new(Colormap::default())
+    }
+}
+
+/// Draws a heatmap of `values` inside `rect`: a grid of cells, one for each
+/// value, filled with the value's color in a [`Colormap`].
+///
+/// Each item of `values` is a row of the grid, starting at the top.  The rows
+/// share the height of `rect` evenly, and the columns share its width, with
+/// as many columns as the longest row; shorter rows leave the cells at their
+/// end empty.  Non-finite values are skipped, leaving their cells empty.
+///
+/// Values are scaled so [`HeatmapStyle::range`] (or the range of the data)
+/// covers the whole colormap.  If every value is the same they all get the
+/// start of the colormap.
+///
+/// # Example
+///
+/// ```
+/// use freehand::charts::{heatmap, HeatmapStyle};
+/// use freehand::color::Colormap;
+/// use freehand::shapes::Rect;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 300);
+/// let values: Vec<Vec<f64>> = (0..30)
+///     .map(|y| (0..40).map(|x| f64::from(x * y).sqrt()).collect())
+///     .collect();
+///
+/// let style = HeatmapStyle { gap: 1, ..HeatmapStyle::new(Colormap::Plasma) };
+/// heatmap(&mut image, Rect::new((0, 0), 300, 400), &values, style);
+/// // The top left cell has the smallest value
+/// assert_eq!(*image.get_pixel(4, 4), Colormap::Plasma.sample(0.0));
+/// ```
+#[allow(clippy::cast_possible_wrap)]
+pub fn heatmap<I, R>(image: &mut I, rect: Rect, values: &[R], style: HeatmapStyle)
+where
+    I: GenericImage<Pixel = Rgba<u8>>,
+    R: AsRef<[f64]>,
+{
+    let finite = || {
+        values
+            .iter()
+            .flat_map(AsRef::as_ref)
+            .copied()
+            .filter(|v| v.is_finite())
+    };
+    let (min, max) = style.range.unwrap_or_else(|| {
+        (
+            finite().fold(f64::INFINITY, f64::min),
+            finite().fold(f64::NEG_INFINITY, f64::max),
+        )
+    });
+    let rows = values.len() as i64;
+    let columns = values
+        .iter()
+        .map(|row| row.as_ref().len())
+        .max()
+        .unwrap_or(0) as i64;
+    if rows == 0 || columns == 0 {
+        return;
+    }
+
+    // The edges of each cell, sharing the rect evenly like the bars of a chart
+    let left = |i: i64| i64::from(rect.x) + i * i64::from(rect.width) / columns;
+    let top = |i: i64| i64::from(rect.y) + i * i64::from(rect.height) / rows;
+    let size = |a: i64, b: i64| (b - a - i64::from(style.gap)).max(1) as u32;
+
+    for (row, y) in values.iter().zip(0..) {
+        let (y0, y1) = (top(y), top(y + 1));
+        if y0 == y1 {
+            continue;
+        }
+        for (&v, x) in row.as_ref().iter().zip(0..) {
+            let (x0, x1) = (left(x), left(x + 1));
+            if x0 == x1 || !v.is_finite() {
+                continue;
+            }
+            let t = if max > min {
+                (v - min) / (max - min)
+            } else {
+                0.0
+            };
+            Rect::new((x0 as i32, y0 as i32), size(y0, y1), size(x0, x1))
+                .draw_filled(image, style.colormap.sample(t));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    #[test]
+    fn cells_and_gaps() {
+        let mut image = crate::test::img::blank((30, 20));
+        let values = [vec![0.0, 1.0, 2.0], vec![4.0, f64::NAN]];
+        let style = HeatmapStyle {
+            gap: 2,
+            ..HeatmapStyle::new(Colormap::Grayscale)
+        };
+        heatmap(&mut image, Rect::new((0, 0), 20, 30), &values, style);
+
+        // 3 columns of 10 pixels and 2 rows of 10 pixels, minus the gaps
+        assert_eq!(*image.get_pixel(0, 0), Colormap::Grayscale.sample(0.0));
+        assert_eq!(*image.get_pixel(17, 7), Colormap::Grayscale.sample(0.25));
+        assert_eq!(*image.get_pixel(29, 0), WHITE);
+        assert_eq!(*image.get_pixel(8, 3), WHITE);
+        assert_eq!(*image.get_pixel(5, 15), Colormap::Grayscale.sample(1.0));
+        // The missing value and the end of the short row are left empty
+        assert_eq!(*image.get_pixel(15, 15), WHITE);
+        assert_eq!(*image.get_pixel(25, 15), WHITE);
+    }
+
+    #[test]
+    fn fixed_range() {
+        let mut image = crate::test::img::blank((4, 1));
+        let style = HeatmapStyle {
+            range: Some((0.0, 10.0)),
+            ..HeatmapStyle::default()
+        };
+        heatmap(&mut image, Rect::new((0, 0), 1, 4), &[[5.0, 20.0]], style);
+        assert_eq!(*image.get_pixel(0, 0), Colormap::Viridis.sample(0.5));
+        assert_eq!(*image.get_pixel(3, 0), Colormap::Viridis.sample(1.0));
+    }
+}
@@ -0,0 +1,100 @@
+use super::{bars, BarStyle};
+use crate::shapes::Rect;
+use image::GenericImage;
+
+/// Counts how many samples fall into each of `bins` equal width ranges
+/// spanning the smallest to the largest sample.
+///
+/// The largest sample is counted in the last bin, and non-finite samples are
+/// ignored.  If every sample has the same value they are all counted in the
+/// first bin.
+///
+/// ```
+/// let counts = freehand::charts::bin(&[0.0, 1.0, 1.5, 2.0, 4.0], 4);
+/// assert_eq!(counts, vec![1, 2, 1, 1]);
+/// ```
+#[must_use]
+pub fn bin(samples: &[f64], bins: usize) -> Vec<u64> {
+    let mut counts = vec![0; bins];
+    let finite = || samples.iter().copied().filter(|v| v.is_finite());
+    let min = finite().fold(f64::INFINITY, f64::min);
+    let max = finite().fold(f64::NEG_INFINITY, f64::max);
+    if bins == 0 || min > max {
+        return counts;
+    }
+
+    let width = (max - min) / bins as f64;
+    for v in finite() {
+        let i = if width > 0.0 {
+            ((v - min) / width) as usize
+        } else {
+            0
+        };
+        counts[i.min(bins - 1)] += 1;
+    }
+    counts
+}
+
+/// Sorts `samples` into `bins` ranges (see [`bin`]) and draws the count of
+/// each as a bar inside `rect`.  Returns the area of each bar.
+///
+/// Histograms are usually drawn without gaps between the bars, which can be
+/// done by setting [`BarStyle::gap`] to 0.
+///
+/// # Example
+///
+/// ```
+/// use freehand::charts::{histogram, BarStyle};
+/// use freehand::shapes::Rect;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 200);
+/// let samples: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.1).sin()).collect();
+/// let style = BarStyle { gap: 0, ..BarStyle::new(Rgba([0, 0, 255, 255])) };
+///
+/// let areas = histogram(&mut image, Rect::new((0, 0), 200, 400), &samples, 20, style);
+/// assert_eq!(areas.len(), 20);
+/// ```
+pub fn histogram<I>(
+    image: &mut I,
+    rect: Rect,
+    samples: &[f64],
+    bins: usize,
+    style: BarStyle<I::Pixel>,
+) -> Vec<Rect>
+where
+    I: GenericImage,
+{
+    let counts: Vec<f64> = bin(samples, bins).into_iter().map(|c| c as f64).collect();
+    bars(image, rect, &counts, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bin_edges() {
+        assert!(bin(&[1.0, 2.0], 0).is_empty());
+        assert_eq!(bin(&[], 3), vec![0, 0, 0]);
+        assert_eq!(bin(&[5.0, 5.0, f64::NAN], 3), vec![2, 0, 0]);
+        assert_eq!(bin(&[-1.0, 0.0, 0.999, 1.0, f64::INFINITY], 2), vec![1, 3]);
+    }
+
+    #[test]
+    fn histogram_heights() {
+        let mut image = crate::test::img::blank((30, 20));
+        let style = BarStyle::new(image::Rgba([0, 0, 0, 255]));
+        let areas = histogram(
+            &mut image,
+            Rect::new((0, 0), 20, 30),
+            &[0.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 3.0],
+            3,
+            style,
+        );
+
+        // Counts of 1, 2, and 5 scaled to a height of 20
+        let heights: Vec<u32> = areas.iter().map(|r| r.height).collect();
+        assert_eq!(heights, vec![4, 8, 20]);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::pt::Point;
+use crate::{lines, Angle, Pt};
+use image::{Rgba, RgbaImage};
+use std::collections::HashSet;
+
+/// How the lines of a grid are drawn.  See [`polar_grid`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GridStyle {
+    /// The color of the grid lines
+    #[cfg_attr(feature = "serde", serde(with = "crate::rgba_serde"))]
+    pub color: Rgba<u8>,
+    /// The opacity used to blend the grid lines into the image
+    pub opacity: f32,
+    /// The length of each dash and each gap between dashes in pixels, or 0 for
+    /// solid lines
+    pub dash: u16,
+}
+
+impl GridStyle {
+    /// Creates a style for solid, opaque grid lines.
+    #[must_use]
+    pub fn new(color: Rgba<u8>) -> Self {
+        Self {
+            color,
+            opacity: 1.0,
+            dash: 0,
+        }
+    }
+}
+
+/// Draws a polar coordinate grid: a circle for each of `radii` around
+/// `center`, and a spoke from `center` out to the largest radius for each of
+/// `angles`.
+///
+/// Pixels shared by multiple circles are only blended once per circle, and
+/// anything outside of the image is clipped.  Dashes on circles are measured
+/// along the circle starting from an angle of 0.
+///
+/// # Panics
+///
+/// - Panics if opacity is not between 0.0 and 1.0
+/// - Panics if any radius is negative
+///
+/// # Example
+///
+/// ```
+/// use freehand::charts::{polar_grid, GridStyle};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// let style = GridStyle {
+///     opacity: 0.5,
+///     dash: 4,
+///     ..GridStyle::new(Rgba([0, 0, 0, 255]))
+/// };
+///
+/// let angles: Vec<i32> = (0..360).step_by(30).collect();
+/// polar_grid(&mut image, (200, 200), &[50, 100, 150], &angles, style);
+/// ```
+pub fn polar_grid<C, A>(
+    image: &mut RgbaImage,
+    center: C,
+    radii: &[i32],
+    angles: &[A],
+    style: GridStyle,
+) where
+    C: Point<i32>,
+    A: Angle,
+{
+    let opacity = style.opacity;
+    check_opacity!(opacity);
+    let center = center.pt();
+
+    for &radius in radii {
+        assert!(radius >= 0, "Radius must not be negative.  radius={radius}");
+        // Octants share points along their edges, which would be blended twice
+        let mut points = HashSet::new();
+        crate::conics::circle_for_each(radius, center, |pt| {
+            points.insert(pt);
+        });
+
+        for pt in points {
+            if style.dash != 0 {
+                let angle = crate::angle::point_angle(Pt::<f64>::from(center), Pt::from(pt));
+                let dist = (angle * f64::from(radius)) as u64 / u64::from(style.dash);
+                if dist % 2 != 0 {
+                    continue;
+                }
+            }
+            if let Ok(Pt { x, y }) = Pt::<u32>::try_from(pt) {
+                crate::ops::blend_at(image, x, y, opacity, style.color);
+            }
+        }
+    }
+
+    let outer = radii.iter().copied().max().unwrap_or(0);
+    for angle in angles {
+        let end = Pt::from_radian(angle.radians(), outer, center).i32();
+        if style.dash == 0 {
+            lines::line_alpha(image, center, end, opacity, style.color);
+        } else {
+            lines::dashed_line_alpha(image, center, end, style.dash, opacity, style.color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polar_grid_blends_once() {
+        let mut image = crate::test::img::blank((60, 60));
+        let style = GridStyle {
+            opacity: 0.5,
+            ..GridStyle::new(Rgba([0, 0, 0, 255]))
+        };
+        polar_grid(&mut image, (30, 30), &[10, 20, 40], &[0, 90], style);
+
+        let gray = Rgba([127, 127, 127, 255]);
+        let white = Rgba([255, 255, 255, 255]);
+        // Away from the spokes every pixel on a circle is blended exactly once
+        for (x, y, px) in image.enumerate_pixels() {
+            if x != 30 && y != 30 {
+                assert!(*px == white || *px == gray, "({x}, {y}) {px:?}");
+            }
+        }
+        // Where a spoke crosses a circle both are blended
+        assert_eq!(*image.get_pixel(40, 30), Rgba([63, 63, 63, 255]));
+        assert_eq!(*image.get_pixel(30, 15), gray);
+        assert_eq!(*image.get_pixel(30, 45), white);
+        assert_eq!(*image.get_pixel(55, 30), gray);
+    }
+
+    #[test]
+    fn polar_grid_dashed() {
+        let mut image = crate::test::img::blank((60, 60));
+        let style = GridStyle {
+            dash: 3,
+            ..GridStyle::new(Rgba([0, 0, 0, 255]))
+        };
+        polar_grid(&mut image, (30, 30), &[20], &[0.0_f64], style);
+
+        let drawn = image.pixels().filter(|px| px.0 == [0, 0, 0, 255]).count();
+        let mut solid = crate::test::img::blank((60, 60));
+        polar_grid(
+            &mut solid,
+            (30, 30),
+            &[20],
+            &[0.0_f64],
+            GridStyle::new(Rgba([0, 0, 0, 255])),
+        );
+        let total = solid.pixels().filter(|px| px.0 == [0, 0, 0, 255]).count();
+
+        // Roughly half of the pixels are in gaps
+        assert!(
+            drawn * 3 > total && drawn * 3 < total * 2,
+            "{drawn} of {total}"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::pt::Point;
+use crate::Pt;
+use image::GenericImage;
+
+/// The symbols that can be drawn at each point of a scatter plot.  See
+/// [`scatter`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Marker {
+    /// A filled circle
+    Dot,
+    /// The outline of a circle
+    Circle,
+    /// A filled square
+    Square,
+    /// A filled diamond
+    Diamond,
+    /// A `+` shape
+    Plus,
+    /// An `x` shape
+    Cross,
+}
+
+impl Marker {
+    /// Returns the offsets of every pixel in the marker from its center, for a
+    /// marker extending `size` pixels from its center.
+    ///
+    /// ```
+    /// use freehand::charts::Marker;
+    ///
+    /// assert_eq!(Marker::Plus.offsets(1).len(), 5);
+    /// assert_eq!(Marker::Square.offsets(1).len(), 9);
+    /// ```
+    #[must_use]
+    pub fn offsets(self, size: u32) -> Vec<Pt<i32>> {
+        let r = i32::try_from(size).unwrap_or(i32::MAX);
+
+        if self == Self::Circle {
+            let mut points = Vec::new();
+            crate::conics::circle_for_each(r, (0, 0), |pt| points.push(pt));
+            // Octants share points along their edges
+            points.sort_unstable();
+            points.dedup();
+            return points;
+        }
+
+        let mut points = Vec::new();
+        for y in -r..=r {
+            for x in -r..=r {
+                if self.covers(x, y, r) {
+                    points.push(Pt::new(x, y));
+                }
+            }
+        }
+        points
+    }
+
+    /// Returns true if the filled marker of radius `r` covers the offset
+    /// `(x, y)` from its center, which must be within `r` along both axes.
+    fn covers(self, x: i32, y: i32, r: i32) -> bool {
+        let (x, y, r) = (i64::from(x), i64::from(y), i64::from(r));
+        match self {
+            // Adding r rounds the edge out a bit, which looks closer to a circle
+            Self::Dot => x * x + y * y <= r * r + r,
+            Self::Square => true,
+            Self::Diamond => x.abs() + y.abs() <= r,
+            Self::Plus => x == 0 || y == 0,
+            Self::Cross => x.abs() == y.abs(),
+            Self::Circle => unreachable!(),
+        }
+    }
+}
+
+/// Draws the same marker centered on each point.
+///
+/// The marker's pixels are only calculated once, and markers entirely inside
+/// the image skip the per-pixel bounds checks, so this is much faster than
+/// drawing a shape for each point.  Markers partially outside of the image are
+/// clipped.
+///
+/// # Example
+///
+/// ```
+/// use freehand::charts::{scatter, Marker};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let points = (0..1000).map(|i| (i % 400, (i * 7) % 400));
+///
+/// scatter(&mut image, Marker::Dot, 2, points, Rgba([255, 0, 0, 255]));
+/// assert_eq!(*image.get_pixel(7, 49), Rgba([255, 0, 0, 255]));
+/// ```
+pub fn scatter<I, P, It>(image: &mut I, marker: Marker, size: u32, points: It, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    scatter_colored(
+        image,
+        marker,
+        size,
+        points.into_iter().map(|pt| (pt, color)),
+    );
+}
+
+/// Draws the same marker centered on each point, using a separate color for
+/// each point.  See [`scatter`].
+///
+/// # Example
+///
+/// ```
+/// use freehand::charts::{scatter_colored, Marker};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let colors = freehand::color::palette(10, 1.0, 1.0);
+/// let points = (0..10).map(|i| ((i * 40 + 20, 200), colors[i as usize]));
+///
+/// scatter_colored(&mut image, Marker::Diamond, 5, points);
+/// ```
+pub fn scatter_colored<I, P, It>(image: &mut I, marker: Marker, size: u32, points: It)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = (P, I::Pixel)>,
+{
+    let offsets = marker.offsets(size);
+    let r = i64::from(size);
+    let (width, height) = (i64::from(image.width()), i64::from(image.height()));
+
+    for (pt, color) in points {
+        let (x, y) = (i64::from(pt.x()), i64::from(pt.y()));
+        if x + r < 0 || y + r < 0 || x - r >= width || y - r >= height {
+            continue;
+        }
+
+        if x - r >= 0 && y - r >= 0 && x + r < width && y + r < height {
+            for off in &offsets {
+                // This is safe because the whole marker is inside the image
+                unsafe {
+                    image.unsafe_put_pixel(
+                        (x + i64::from(off.x())) as u32,
+                        (y + i64::from(off.y())) as u32,
+                        color,
+                    );
+                }
+            }
+        } else {
+            for off in &offsets {
+                let (px, py) = (x + i64::from(off.x()), y + i64::from(off.y()));
+                if (0..width).contains(&px) && (0..height).contains(&py) {
+                    image.put_pixel(px as u32, py as u32, color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn marker_shapes() {
+        let count = |m: Marker, s| m.offsets(s).len();
+        assert_eq!(count(Marker::Dot, 0), 1);
+        assert_eq!(count(Marker::Square, 2), 25);
+        assert_eq!(count(Marker::Diamond, 2), 13);
+        assert_eq!(count(Marker::Plus, 2), 9);
+        assert_eq!(count(Marker::Cross, 2), 9);
+        assert_eq!(count(Marker::Dot, 2), 21);
+
+        let mut circle = crate::test::img::blank((11, 11));
+        crate::conics::circle(&mut circle, 4, (5, 5), Rgba([0, 0, 0, 255]));
+        let drawn = circle.pixels().filter(|px| px.0 == [0, 0, 0, 255]).count();
+        assert_eq!(count(Marker::Circle, 4), drawn);
+    }
+
+    #[test]
+    fn covers_huge_sizes() {
+        let (min, max) = (-i32::MAX, i32::MAX);
+        assert!(Marker::Dot.covers(max, 0, max));
+        assert!(!Marker::Dot.covers(max, max, max));
+        assert!(Marker::Diamond.covers(0, min, max));
+        assert!(!Marker::Diamond.covers(min, max, max));
+        assert!(Marker::Cross.covers(min, max, max));
+        assert!(Marker::Plus.covers(min, 0, max));
+    }
+
+    #[test]
+    fn scatter_clips() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((10, 10));
+        scatter(
+            &mut image,
+            Marker::Square,
+            1,
+            [(0, 0), (5, 5), (-2, 5), (20, 20), (i32::MAX, 0)],
+            color,
+        );
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let expected = (x <= 1 && y <= 1) || ((4..=6).contains(&x) && (4..=6).contains(&y));
+            assert_eq!(*px == color, expected, "({x}, {y})");
+        }
+    }
+}
@@ -0,0 +1,427 @@
+//! Color conversions and helpers for picking colors.
+//!
+//! Provides conversions between [`Rgba`] and the [`Hsv`] and [`Hsl`] color
+//! spaces, along with functions for mixing colors and generating palettes.
+//!
+//! ```
+//! use freehand::color::{self, Hsv};
+//! use image::Rgba;
+//!
+//! let red = Rgba::from(Hsv::new(0.0, 1.0, 1.0));
+//! assert_eq!(red, Rgba([255, 0, 0, 255]));
+//!
+//! let pink = color::lighten(red, 0.25);
+//! assert_eq!(pink, Rgba([255, 128, 128, 255]));
+//! ```
+#![allow(clippy::many_single_char_names)]
+
+use image::Rgba;
+
+/// A color using hue, saturation, and value.
+///
+/// `h` is in degrees (`0.0..360.0`), while `s` and `v` range from `0.0` to
+/// `1.0`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Hsv {
+    /// Hue in degrees
+    pub h: f32,
+    /// Saturation
+    pub s: f32,
+    /// Value
+    pub v: f32,
+}
+
+/// A color using hue, saturation, and lightness.
+///
+/// `h` is in degrees (`0.0..360.0`), while `s` and `l` range from `0.0` to
+/// `1.0`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Hsl {
+    /// Hue in degrees
+    pub h: f32,
+    /// Saturation
+    pub s: f32,
+    /// Lightness
+    pub l: f32,
+}
+
+impl Hsv {
+    /// Creates a new `Hsv` color.  The hue is wrapped into `0.0..360.0` and
+    /// the other components are clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn new(h: f32, s: f32, v: f32) -> Self {
+        Self {
+            h: wrap_hue(h),
+            s: s.clamp(0.0, 1.0),
+            v: v.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Converts to an [`Rgba`] color with the specified alpha.
+    #[must_use]
+    pub fn to_rgba(self, alpha: u8) -> Rgba<u8> {
+        let c = self.v * self.s;
+        let [r, g, b] = hue_to_rgb(self.h, c, self.v - c);
+        Rgba([r, g, b, alpha])
+    }
+}
+
+impl Hsl {
+    /// Creates a new `Hsl` color.  The hue is wrapped into `0.0..360.0` and
+    /// the other components are clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn new(h: f32, s: f32, l: f32) -> Self {
+        Self {
+            h: wrap_hue(h),
+            s: s.clamp(0.0, 1.0),
+            l: l.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Converts to an [`Rgba`] color with the specified alpha.
+    #[must_use]
+    pub fn to_rgba(self, alpha: u8) -> Rgba<u8> {
+        let c = (1.0 - (2.0 * self.l - 1.0).abs()) * self.s;
+        let [r, g, b] = hue_to_rgb(self.h, c, self.l - c / 2.0);
+        Rgba([r, g, b, alpha])
+    }
+}
+
+impl From<Rgba<u8>> for Hsv {
+    /// Converts from RGB, ignoring the alpha channel.
+    fn from(color: Rgba<u8>) -> Self {
+        let (h, min, max) = hue(color);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Self { h, s, v: max }
+    }
+}
+
+impl From<Rgba<u8>> for Hsl {
+    /// Converts from RGB, ignoring the alpha channel.
+    fn from(color: Rgba<u8>) -> Self {
+        let (h, min, max) = hue(color);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        let s = if d == 0.0 {
+            0.0
+        } else {
+            d / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Self { h, s, l }
+    }
+}
+
+impl From<Hsv> for Rgba<u8> {
+    /// Converts to an opaque [`Rgba`] color.
+    fn from(color: Hsv) -> Self {
+        color.to_rgba(255)
+    }
+}
+
+impl From<Hsl> for Rgba<u8> {
+    /// Converts to an opaque [`Rgba`] color.
+    fn from(color: Hsl) -> Self {
+        color.to_rgba(255)
+    }
+}
+
+/// Parses a color in the `#rrggbb` or `#rrggbbaa` hex format.  The `#` is
+/// optional and the digits are case insensitive.  Colors without an alpha
+/// channel are opaque.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidColor`](crate::Error::InvalidColor) if the string
+/// is not 6 or 8 hex digits long.
+///
+/// ```
+/// use image::Rgba;
+/// use freehand::color::from_hex;
+///
+/// assert_eq!(from_hex("#ff8000"), Ok(Rgba([255, 128, 0, 255])));
+/// assert_eq!(from_hex("FF800080"), Ok(Rgba([255, 128, 0, 128])));
+/// assert!(from_hex("#ff80").is_err());
+/// ```
+pub fn from_hex(hex: &str) -> Result<Rgba<u8>, crate::Error> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(crate::Error::InvalidColor);
+    }
+
+    let mut color = Rgba([0, 0, 0, 255]);
+    for (c, i) in color.0.iter_mut().zip((0..hex.len()).step_by(2)) {
+        *c = u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| crate::Error::InvalidColor)?;
+    }
+    Ok(color)
+}
+
+/// Linearly interpolates between two colors, including their alpha channels.
+///
+/// `t` is clamped to `0.0..=1.0`, where `0.0` returns `a` and `1.0` returns `b`.
+///
+/// ```
+/// use image::Rgba;
+/// let c = freehand::color::lerp(Rgba([0, 0, 0, 255]), Rgba([255, 100, 0, 255]), 0.5);
+/// assert_eq!(c, Rgba([128, 50, 0, 255]));
+/// ```
+#[must_use]
+pub fn lerp(a: Rgba<u8>, b: Rgba<u8>, t: f32) -> Rgba<u8> {
+    let t = t.clamp(0.0, 1.0);
+    let mut out = a;
+    for (c, (a, b)) in out.0.iter_mut().zip(a.0.iter().zip(b.0)) {
+        let (a, b) = (f32::from(*a), f32::from(b));
+        *c = (b - a).mul_add(t, a).round() as u8;
+    }
+    out
+}
+
+/// Increases a color's lightness (in [`Hsl`]) by `amount`, keeping its alpha.
+///
+/// An `amount` of `1.0` always results in white.
+#[must_use]
+pub fn lighten(color: Rgba<u8>, amount: f32) -> Rgba<u8> {
+    let hsl = Hsl::from(color);
+    Hsl::new(hsl.h, hsl.s, hsl.l + amount).to_rgba(color[3])
+}
+
+/// Decreases a color's lightness (in [`Hsl`]) by `amount`, keeping its alpha.
+///
+/// An `amount` of `1.0` always results in black.
+#[must_use]
+pub fn darken(color: Rgba<u8>, amount: f32) -> Rgba<u8> {
+    lighten(color, -amount)
+}
+
+/// Generates `count` opaque colors with evenly spaced hues, starting at red.
+///
+/// Useful for picking distinct colors for the series in a chart.
+///
+/// ```
+/// use image::Rgba;
+/// let colors = freehand::color::palette(3, 1.0, 1.0);
+/// assert_eq!(colors, vec![Rgba([255, 0, 0, 255]), Rgba([0, 255, 0, 255]), Rgba([0, 0, 255, 255])]);
+/// ```
+#[must_use]
+pub fn palette(count: usize, saturation: f32, value: f32) -> Vec<Rgba<u8>> {
+    let step = 360.0 / count as f32;
+    (0..count)
+        .map(|i| Hsv::new(i as f32 * step, saturation, value).into())
+        .collect()
+}
+
+/// A gradient that maps numbers from `0.0` to `1.0` to colors, for coloring
+/// heatmaps, gradients, and other data.
+///
+/// [`Colormap::Viridis`] and [`Colormap::Plasma`] are perceptually uniform:
+/// equal steps in the input look like equal steps in color, and they remain
+/// readable when printed in grayscale.
+///
+/// ```
+/// use freehand::color::Colormap;
+/// use image::Rgba;
+///
+/// assert_eq!(Colormap::Grayscale.sample(0.5), Rgba([128, 128, 128, 255]));
+/// assert_eq!(Colormap::Viridis.sample(1.0), Rgba([253, 231, 37, 255]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Colormap {
+    /// Dark purple through blue and green to yellow.  This is the default.
+    Viridis,
+    /// Dark blue through purple and orange to yellow.
+    Plasma,
+    /// Black to white.
+    Grayscale,
+}
+
+impl Default for Colormap {
+    fn default() -> Self {
+        Self::Viridis
+    }
+}
+
+/// Evenly spaced samples of each colormap, interpolated between by
+/// [`Colormap::sample`].
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 44, 122],
+    [59, 81, 139],
+    [44, 113, 142],
+    [33, 144, 141],
+    [39, 173, 129],
+    [92, 200, 99],
+    [170, 220, 50],
+    [253, 231, 37],
+];
+const PLASMA: [[u8; 3]; 9] = [
+    [13, 8, 135],
+    [76, 2, 161],
+    [126, 3, 168],
+    [169, 35, 149],
+    [204, 71, 120],
+    [229, 107, 93],
+    [248, 149, 64],
+    [253, 197, 39],
+    [240, 249, 33],
+];
+const GRAYSCALE: [[u8; 3]; 2] = [[0, 0, 0], [255, 255, 255]];
+
+impl Colormap {
+    /// Returns the opaque color at `t`, where `0.0` is the start of the
+    /// colormap and `1.0` is its end.  Values outside of that range are
+    /// clamped, and `NaN` is treated as `0.0`.
+    #[must_use]
+    pub fn sample(self, t: f64) -> Rgba<u8> {
+        let stops: &[[u8; 3]] = match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Plasma => &PLASMA,
+            Self::Grayscale => &GRAYSCALE,
+        };
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+
+        let pos = t * (stops.len() - 1) as f64;
+        let i = (pos as usize).min(stops.len() - 2);
+        let [r0, g0, b0] = stops[i];
+        let [r1, g1, b1] = stops[i + 1];
+        lerp(
+            Rgba([r0, g0, b0, 255]),
+            Rgba([r1, g1, b1, 255]),
+            (pos - i as f64) as f32,
+        )
+    }
+}
+
+/// Wraps a hue in degrees into `0.0..360.0`.
+fn wrap_hue(h: f32) -> f32 {
+    let h = h.rem_euclid(360.0);
+    // rem_euclid can round up to 360.0 for tiny negative values
+    if h >= 360.0 {
+        0.0
+    } else {
+        h
+    }
+}
+
+/// Returns the hue (in degrees) of a color along with its min and max
+/// channels as `0.0..=1.0`.
+fn hue(color: Rgba<u8>) -> (f32, f32, f32) {
+    let max = color[0].max(color[1]).max(color[2]);
+    let min = color[0].min(color[1]).min(color[2]);
+    let [r, g, b] = [color[0], color[1], color[2]].map(|c| f32::from(c) / 255.0);
+    let d = f32::from(max - min) / 255.0;
+
+    let h = if max == min {
+        0.0
+    } else if max == color[0] {
+        60.0 * ((g - b) / d)
+    } else if max == color[1] {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    (wrap_hue(h), f32::from(min) / 255.0, f32::from(max) / 255.0)
+}
+
+/// Converts a hue and chroma to RGB, adding `m` to each channel.
+fn hue_to_rgb(h: f32, c: f32, m: f32) -> [u8; 3] {
+    let hp = h / 60.0;
+    let x = c * (1.0 - (hp % 2.0 - 1.0).abs());
+    let (r, g, b) = match hp as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    [r, g, b].map(|v| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(51) {
+                    let c = Rgba([r, g, b, 255]);
+                    assert_eq!(Rgba::from(Hsv::from(c)), c, "hsv {c:?}");
+                    assert_eq!(Rgba::from(Hsl::from(c)), c, "hsl {c:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn known_colors() {
+        let c = Hsv::from(Rgba([0, 128, 255, 255]));
+        assert!((c.h - 209.9).abs() < 0.1, "{c:?}");
+        assert_eq!(
+            Hsl::new(120.0, 1.0, 0.25).to_rgba(10),
+            Rgba([0, 128, 0, 10])
+        );
+        assert!((Hsv::new(-60.0, 1.0, 1.0).h - 300.0).abs() < f32::EPSILON);
+
+        let orange = Rgba([255, 128, 0, 100]);
+        assert_eq!(lighten(orange, 1.0), Rgba([255, 255, 255, 100]));
+        assert_eq!(darken(orange, 1.0), Rgba([0, 0, 0, 100]));
+        assert_eq!(darken(lighten(orange, 0.2), 0.2), orange);
+    }
+
+    #[test]
+    fn hex_colors() {
+        assert_eq!(from_hex("#000000"), Ok(Rgba([0, 0, 0, 255])));
+        assert_eq!(from_hex("#1f77B4"), Ok(Rgba([31, 119, 180, 255])));
+        assert_eq!(from_hex("#ffffff00"), Ok(Rgba([255, 255, 255, 0])));
+        for bad in [
+            "",
+            "#",
+            "#fff",
+            "#ff00zz",
+            "#ff00ff0",
+            "##ff00ff",
+            "+f00ff",
+            "#ff00ff00ff",
+        ] {
+            assert_eq!(from_hex(bad), Err(crate::Error::InvalidColor), "{bad}");
+        }
+    }
+
+    #[test]
+    fn colormaps() {
+        for map in [Colormap::Viridis, Colormap::Plasma, Colormap::Grayscale] {
+            assert_eq!(map.sample(-1.0), map.sample(0.0), "{map:?}");
+            assert_eq!(map.sample(2.0), map.sample(1.0), "{map:?}");
+            assert_eq!(map.sample(f64::NAN), map.sample(0.0), "{map:?}");
+        }
+        assert_eq!(Colormap::Viridis.sample(0.0), Rgba([68, 1, 84, 255]));
+        assert_eq!(Colormap::Plasma.sample(0.5), Rgba([204, 71, 120, 255]));
+        assert_eq!(Colormap::Grayscale.sample(0.25), Rgba([64, 64, 64, 255]));
+
+        // Perceptual maps get lighter from start to end
+        for map in [Colormap::Viridis, Colormap::Plasma] {
+            let luma = |t| {
+                let c = map.sample(t);
+                0.299 * f32::from(c[0]) + 0.587 * f32::from(c[1]) + 0.114 * f32::from(c[2])
+            };
+            for i in 0..10 {
+                let t = f64::from(i) / 10.0;
+                assert!(luma(t) < luma(t + 0.1), "{map:?} {t}");
+            }
+        }
+    }
+
+    #[test]
+    fn palette_hues() {
+        assert!(palette(0, 1.0, 1.0).is_empty());
+        let colors = palette(6, 1.0, 1.0);
+        for (i, c) in colors.into_iter().enumerate() {
+            assert!((Hsv::from(c).h - i as f32 * 60.0).abs() < 0.5, "{i}: {c:?}");
+        }
+    }
+}
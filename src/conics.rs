@@ -1,11 +1,57 @@
-//! Conic/circular functions: arcs, antialiased arcs, and annuli (filled-donut shapes).
+//! Conic/circular functions: arcs, antialiased arcs, and annuli (filled-donut shapes), including elliptical annuli.
 
 mod aa_arc;
+mod aa_disk;
 mod annulus;
 mod arc;
+mod arc_set;
 mod cir;
+mod elliptical;
+mod glow;
+mod stroke;
+mod tangent;
 
-pub use aa_arc::{antialiased_arc, AntialiasedArc};
-pub use annulus::{annulus, pie_slice_filled, thick_arc, thick_circle, Annulus};
-pub use arc::{arc, Arc};
-pub use cir::circle;
+pub use aa_arc::{antialiased_arc, antialiased_arc_for_each, AntialiasedArc, Coverage};
+pub use aa_disk::antialiased_circle_filled;
+pub use annulus::{
+    annulus, annulus_for_each, annulus_outline, pie_slice, pie_slice_filled, thick_arc,
+    thick_circle, Annulus,
+};
+pub use arc::{arc, arc_for_each, Arc};
+pub use arc_set::{arcs, ArcSet};
+pub use cir::{circle, circle_filled, circle_for_each, circle_with_algorithm, CircleAlgorithm};
+pub use elliptical::{elliptical_annulus, EllipticalAnnulus};
+pub use glow::{glow_arc, glow_circle, Glow};
+pub use stroke::{stroke_arc, ArcStroke};
+pub(crate) use tangent::corner_arc;
+
+use crate::shapes::Rect;
+use crate::Pt;
+
+/// How far from a shape, in pixels, a point can be while still being considered
+/// part of it (half the diagonal of a pixel).
+const HIT_TOLERANCE: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// Returns the smallest rectangle covering the pixels of an arc between the
+/// given angles for each of the radii.  With the inner and outer radii, this
+/// is also the smallest rectangle covering an annulus.
+///
+/// Each arc's bounds come from the first and last points of its octants, so
+/// the arcs are not iterated over.
+fn sector_bounds(c: Pt<i32>, start: f64, end: f64, full: bool, radii: &[i32]) -> Rect {
+    // Matching angles are a complete circle
+    let end = if full { start } else { end };
+    radii
+        .iter()
+        .filter_map(|&r| Arc::new(start, end, r, c).extremes())
+        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+        .map_or(Rect::new(c, 0, 0), bounds_rect)
+}
+
+/// Returns a rectangle from the smallest and largest coordinates it covers, as
+/// `(x1, y1, x2, y2)`.
+fn bounds_rect((x1, y1, x2, y2): (i32, i32, i32, i32)) -> Rect {
+    // Huge circles can span more than the range of an i32
+    let span = |a: i32, b: i32| (i64::from(b) - i64::from(a) + 1).min(u32::MAX.into()) as u32;
+    Rect::new((x1, y1), span(y1, y2), span(x1, x2))
+}
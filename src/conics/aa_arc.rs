@@ -2,7 +2,8 @@ mod end;
 
 use crate::angle::angle_to_quad;
 use crate::antialias::AAPt;
-use crate::Pt;
+use crate::fixed;
+use crate::{Error, Pt};
 use end::End;
 
 /// Draws an antialiased circular arc.
@@ -15,7 +16,7 @@ use end::End;
 /// ```
 /// use image::{RgbaImage, Rgba};
 /// use freehand::conics::antialiased_arc;
-
+///
 /// let bg = Rgba([255, 255, 255, 255]); // white
 /// let color = Rgba([255, 0, 0, 255]);
 /// let mut image = RgbaImage::from_pixel(400, 400, bg);
@@ -61,12 +62,48 @@ pub fn antialiased_arc<A, C, T>(
     AntialiasedArc::new(start_angle, end_angle, radius, center).draw(image, color);
 }
 
+/// Calls a closure with every pixel of an antialiased arc and its coverage
+/// instead of drawing to an image.
+///
+/// Coverage is in the range `0.0..=1.0`.  Pixels are not clipped.  Angles are
+/// interpreted the same way as [`antialiased_arc`].
+///
+/// See also: [`AntialiasedArc::coverage`]
+///
+/// ```
+/// use freehand::conics::antialiased_arc_for_each;
+///
+/// let mut total = 0.0;
+/// antialiased_arc_for_each(0, 90, 20.0, (50.0, 50.0), |_pt, coverage| total += coverage);
+/// assert!(total > 0.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if radius is negative.
+pub fn antialiased_arc_for_each<A, C, F, T>(
+    start_angle: A,
+    end_angle: A,
+    radius: T,
+    center: C,
+    mut f: F,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    F: FnMut(Pt<i32>, f64),
+    T: Into<f64> + Copy,
+{
+    AntialiasedArc::new(start_angle, end_angle, radius, center)
+        .coverage()
+        .for_each(|(pt, coverage)| f(pt, coverage));
+}
+
 /// An antialiased arc.  Implements [`Iterator`] and returns coordinates in order from the starting point.
 ///
 /// ```
 /// use image::{RgbaImage, Rgba};
 /// use freehand::conics::AntialiasedArc;
-
+///
 /// let bg = Rgba([255, 255, 255, 255]); // white
 /// let color = Rgba([255, 0, 0, 255]);
 /// let mut image = RgbaImage::from_pixel(400, 400, bg);
@@ -82,6 +119,7 @@ pub fn antialiased_arc<A, C, T>(
 /// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct AntialiasedArc {
     /// Current local x coordinate (not the same as the final pixel coordinates)
     x: f64,
@@ -97,12 +135,19 @@ pub struct AntialiasedArc {
     end_quad: u8,
     /// Whether to increment x (true) or y (false) every iteration.  Only used to make forty-five degree edges look nicer
     fast_x: bool,
-    /// Used when start_angle > end_angle and start_quad == end_quad.  This allows it to  loop back around the circle
+    /// Used when `start_angle > end_angle` and `start_quad == end_quad`.  This allows it to  loop back around the circle
     revisit: bool,
     /// Where to stop
     end: End,
     /// Center coordinates
     c: Pt<f64>,
+    /// The coordinates in fixed-point, for arcs using fixed-point math
+    fixed: Option<Fixed>,
+    /// Whether the arc has distinct end points.  The pixels at the end points of
+    /// partial arcs are only partially covered.
+    partial: bool,
+    /// Coverage of the next pixel along the fast direction.  Only less than 1.0 for the first pixel
+    start_cov: f64,
 }
 impl AntialiasedArc {
     /// Creates a new [`AntialiasedArc`].
@@ -114,6 +159,11 @@ impl AntialiasedArc {
     /// larger than`2*PI` for radians).  Angles will be normalized into a range
     /// of 0..PI*2.
     ///
+    /// If the start and end angles are the same, or the end angle is at least a
+    /// full turn away from the start angle, a complete circle is drawn.
+    ///
+    /// A radius of 0 will draw a single pixel at the center.
+    ///
     /// # Panics
     ///
     /// Will panic if `radius` is negative.
@@ -123,7 +173,7 @@ impl AntialiasedArc {
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # use freehand::conics::AntialiasedArc;
-
+    ///
     /// # let bg = Rgba([255, 255, 255, 255]); // white
     /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
     ///
@@ -135,6 +185,31 @@ impl AntialiasedArc {
     /// let arc = AntialiasedArc::new(start, end, radius, center);
     /// ```
     pub fn new<A, P, T>(start: A, end: A, radius: T, center: P) -> Self
+    where
+        A: crate::Angle,
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        match Self::try_new(start, end, radius, center) {
+            Ok(arc) => arc,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Creates a new [`AntialiasedArc`], returning an error instead of
+    /// panicking if the radius is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRadius`] if `radius` is negative.
+    ///
+    /// ```
+    /// # use freehand::conics::AntialiasedArc;
+    /// assert!(AntialiasedArc::try_new(0, 180, 190.0, (200.0, 200.0)).is_ok());
+    /// assert!(AntialiasedArc::try_new(0, 180, 0.0, (200.0, 200.0)).is_ok());
+    /// assert!(AntialiasedArc::try_new(0, 180, -190.0, (200.0, 200.0)).is_err());
+    /// ```
+    pub fn try_new<A, P, T>(start: A, end: A, radius: T, center: P) -> Result<Self, Error>
     where
         A: crate::Angle,
         P: crate::Point<T>,
@@ -143,41 +218,149 @@ impl AntialiasedArc {
         let radius = radius.into();
         let center = Pt::new(center.x().into(), center.y().into());
 
+        if radius < 0.0 || radius.is_nan() {
+            return Err(Error::InvalidRadius(radius));
+        }
+
+        let partial = !crate::angle::is_full_sweep(start.radians(), end.radians());
+        let start = crate::angle::normalize(start.radians());
+        let mut end = crate::angle::normalize(end.radians());
+        if !partial {
+            end = crate::angle::normalize(start - crate::TINY);
+        }
+
+        Ok(Self::arc(start, end, radius, center, false, partial))
+    }
+
+    /// Creates a new [`AntialiasedArc`] that uses 16.16 fixed-point math to
+    /// calculate pixel coverage.
+    ///
+    /// The results are bit-exact across platforms and avoid floating-point
+    /// square roots, which can be faster on targets with weak FPUs.  Coverage
+    /// values are quantized to 256 levels.
+    ///
+    /// If the angles are floating-point numbers they are interpreted as radians.
+    /// Otherwise the angles are interpreted as degrees.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `radius` is negative or larger than 32,767.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::AntialiasedArc;
+    /// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+    /// let arc = AntialiasedArc::new_fixed(0, 180, 190, (200, 200));
+    /// arc.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn new_fixed<A, P, T>(start: A, end: A, radius: T, center: P) -> Self
+    where
+        A: crate::Angle,
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let radius = fixed::quantize(radius.into());
+        let center = Pt::new(
+            fixed::quantize(center.x().into()),
+            fixed::quantize(center.y().into()),
+        );
+
         assert!(
-            radius > 0.0,
-            "Radius must be larger than 0.  radius={radius:.1}"
+            (0.0..fixed::MAX_RADIUS).contains(&radius),
+            "Radius must not be negative and must be less than {}.  radius={radius:.1}",
+            fixed::MAX_RADIUS
         );
 
+        let partial = !crate::angle::is_full_sweep(start.radians(), end.radians());
         let start = crate::angle::normalize(start.radians());
         let mut end = crate::angle::normalize(end.radians());
-        if (start - end).abs() <= std::f64::EPSILON {
+        if !partial {
             end = crate::angle::normalize(start - crate::TINY);
         }
 
-        Self::arc(start, end, radius, center)
+        Self::arc(start, end, radius, center, true, partial)
     }
 
-    /// Draw an antialiased arc by iterating over all of its pixels
+    /// Draw an antialiased arc.  Parts of the arc outside of the image are
+    /// skipped without calculating their pixels.
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # use freehand::conics::AntialiasedArc;
-
+    ///
     /// # let bg = Rgba([255, 255, 255, 255]); // white
     /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
     ///
     /// let arc = AntialiasedArc::new(0, 180, 190, (200, 200));
     /// arc.draw(&mut image, Rgba([255, 0, 0, 255]))
     /// ```
-    pub fn draw(self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+    pub fn draw(mut self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        // Steps whose pixels are all outside of the image are skipped
+        let size = (image.width(), image.height());
+        loop {
+            self.skip_hidden(size);
+            match self.next() {
+                Some(pt) => {
+                    pt.draw(image, color);
+                }
+                None => return,
+            }
+        }
+    }
+
+    /// Draws the arc and returns how many pixels were blended and clipped.
+    ///
+    /// Each step of the arc blends two pixels, and both are counted.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::conics::AntialiasedArc;
+    /// # let mut image = RgbaImage::new(100, 100);
+    ///
+    /// let arc = AntialiasedArc::new(0, 90, 20.0, (-50.0, -50.0));
+    /// assert!(arc.draw_with_stats(&mut image, Rgba([255, 0, 0, 255])).is_offscreen());
+    /// ```
+    pub fn draw_with_stats(
+        self,
+        image: &mut image::RgbaImage,
+        color: image::Rgba<u8>,
+    ) -> crate::DrawStats {
+        let mut stats = crate::DrawStats::default();
         for pt in self {
-            pt.draw(image, color);
+            stats += pt.draw(image, color);
+        }
+        stats
+    }
+
+    /// Returns an iterator over each pixel of the arc and its coverage.
+    ///
+    /// Coverage is in the range `0.0..=1.0`, where `1.0` means the pixel is
+    /// completely covered by the arc.  Each step of the arc produces two pixels
+    /// whose coverage adds up to the coverage of that step.  Pixels are not
+    /// clipped to any image, so coordinates may be negative.
+    ///
+    /// ```
+    /// use freehand::conics::AntialiasedArc;
+    ///
+    /// let arc = AntialiasedArc::new(0, 90, 20.0, (50.0, 50.0));
+    /// for (pt, coverage) in arc.coverage() {
+    ///     assert!((0.0..=1.0).contains(&coverage));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn coverage(self) -> Coverage {
+        Coverage {
+            arc: self,
+            next: None,
         }
     }
 
     /// An internal function to create a new [`AntialiasedArc`] without normalizing
     /// angles or checking the radius.
-    fn arc<T>(start_angle: T, end_angle: T, r: f64, c: Pt<f64>) -> Self
+    ///
+    /// For partial arcs the first and last pixels are only drawn with the portion
+    /// of the pixel covered by the arc, so the ends match the exact angles.
+    fn arc<T>(start_angle: T, end_angle: T, r: f64, c: Pt<f64>, fixed: bool, partial: bool) -> Self
     where
         T: crate::Angle + std::fmt::Display,
     {
@@ -187,11 +370,20 @@ impl AntialiasedArc {
         let end_quad = angle_to_quad(end_angle);
         let mut start = Pt::from_radian(start_angle, r, c).quad_to_iter(quad, c);
         let end = Pt::from_radian(end_angle, r, c).quad_to_iter(end_quad, c);
-        let inc_x = if start.x() < start.y() {
-            true
+        if fixed {
+            start = Pt::new(fixed::quantize(start.x), fixed::quantize(start.y));
+        }
+        // Rounding a quantized coordinate keeps it on a fixed-point step
+        let (inc_x, start_cov) = if start.x() < start.y() {
+            let cov = (start.x.round() + 0.5 - start.x).clamp(0.0, 1.0);
+            (true, cov)
+        } else if partial {
+            let y = start.y;
+            start.y = start.y.round();
+            (false, (y - (start.y - 0.5)).clamp(0.0, 1.0))
         } else {
             start.y = start.y.ceil();
-            false
+            (false, 1.0)
         };
 
         Self {
@@ -205,43 +397,96 @@ impl AntialiasedArc {
             fast_x: inc_x,
             end: End::new(end),
             c,
+            fixed: fixed.then(|| Fixed {
+                x: fixed::to_fixed(start.x),
+                y: fixed::to_fixed(start.y),
+                r: fixed::to_fixed(r),
+            }),
+            partial,
+            start_cov: if partial { start_cov } else { 1.0 },
         }
     }
 
     /// Advance in the x direction
     fn step_x(&mut self) -> Option<AAPt<i32>> {
-        if (self.end_quad == self.quad) & self.end.match_x(self.x) {
+        let at_end = self.at_end();
+        // Partial arcs continue until the pixel no longer overlaps the end point
+        let edge = if self.partial {
+            self.x.round() - 0.5
+        } else {
+            self.x
+        };
+        if at_end && self.end.match_x(edge) {
             return None;
         }
         let x = self.x;
-        let (ya, yb, da) = Self::calc_fract(self.y);
+        let (ya, yb, da) = self.calc_fract(true);
+        let cov = self.cover(at_end, self.end.cover_x(x));
         let rst = AAPt::new(
             Pt::new(x, ya).iter_to_quad(self.quad, self.c).i32(),
             Pt::new(x, yb).iter_to_quad(self.quad, self.c).i32(),
             da,
-        );
-        self.x += 1.0;
-        self.y = self.calc_slow(self.x);
+        )
+        .mult_opac(cov);
+        if let Some(f) = &mut self.fixed {
+            f.x += fixed::ONE;
+            f.y = fixed::calc_slow(f.r * f.r, f.x);
+            self.sync();
+        } else {
+            self.x += 1.0;
+            self.y = (self.r2 - self.x * self.x).sqrt();
+        }
         Some(rst)
     }
 
     /// Advance in the y direction
     fn step_y(&mut self) -> Option<AAPt<i32>> {
-        if (self.end_quad == self.quad) & self.end.match_y(self.y) {
+        let at_end = self.at_end();
+        // Partial arcs continue until the pixel no longer overlaps the end point
+        let edge = if self.partial {
+            self.y.round() + 0.5
+        } else {
+            self.y
+        };
+        if at_end && self.end.match_y(edge) {
             return None;
         }
         let y = self.y;
-        let (xa, xb, da) = Self::calc_fract(self.x);
+        let (xa, xb, da) = self.calc_fract(false);
+        let cov = self.cover(at_end, self.end.cover_y(y));
         let rst = AAPt::new(
             Pt::new(xa, y).iter_to_quad(self.quad, self.c).i32(),
             Pt::new(xb, y).iter_to_quad(self.quad, self.c).i32(),
             da,
-        );
-        self.y -= 1.0;
-        self.x = self.calc_slow(self.y);
+        )
+        .mult_opac(cov);
+        if let Some(f) = &mut self.fixed {
+            f.y -= fixed::ONE;
+            f.x = fixed::calc_slow(f.r * f.r, f.y);
+            self.sync();
+        } else {
+            self.y -= 1.0;
+            self.x = (self.r2 - self.y * self.y).sqrt();
+        }
         Some(rst)
     }
 
+    /// Whether the end point may be reached in the current quadrant.  When
+    /// revisiting, the first pass through the end quadrant starts after the end point.
+    fn at_end(&self) -> bool {
+        self.end_quad == self.quad && !self.revisit
+    }
+
+    /// Returns the coverage of the current pixel, taking the start and end points into account.
+    fn cover(&mut self, at_end: bool, end_cov: f64) -> f64 {
+        let start_cov = std::mem::replace(&mut self.start_cov, 1.0);
+        if at_end && self.partial {
+            start_cov * end_cov
+        } else {
+            start_cov
+        }
+    }
+
     /// Advance or end iteration
     fn step(&mut self) -> Option<AAPt<i32>> {
         if self.x <= self.y {
@@ -249,7 +494,13 @@ impl AntialiasedArc {
         } else if self.fast_x {
             // This is to handle the forty-five degree edge case
             self.fast_x = false;
-            self.y = self.y.ceil();
+            match &mut self.fixed {
+                Some(f) => {
+                    f.y = (f.y + fixed::ONE - 1) & !(fixed::ONE - 1);
+                    self.sync();
+                }
+                None => self.y = self.y.ceil(),
+            }
             self.step_y().map(|o| o.mult_opac_a(0.5))
         } else {
             self.step_y()
@@ -268,7 +519,7 @@ impl AntialiasedArc {
 
     /// Check if iteration should end
     fn end(&mut self) -> bool {
-        let last = (self.quad == self.end_quad) & (self.y <= 0.0);
+        let last = (self.quad == self.end_quad) && (self.y <= 0.0);
         if self.revisit & last {
             self.revisit = false;
             self.reset();
@@ -282,29 +533,182 @@ impl AntialiasedArc {
     fn reset(&mut self) {
         self.x = 0.0;
         self.y = self.r;
+        if let Some(f) = &mut self.fixed {
+            (f.x, f.y) = (0, f.r);
+        }
         self.fast_x = true;
         self.quad = self.quad % 4 + 1;
     }
 
-    /// Calculate the slow coordinate from the fast coordinate
-    fn calc_slow(&self, fast: f64) -> f64 {
-        (self.r2 - fast * fast).sqrt()
+    /// Copies the fixed-point coordinates into the floating-point ones used to
+    /// find the pixels, which is exact as every 16.16 value fits in an `f64`
+    fn sync(&mut self) {
+        if let Some(f) = self.fixed {
+            self.x = fixed::from_fixed(f.x);
+            self.y = fixed::from_fixed(f.y);
+        }
+    }
+
+    /// Skips ahead over the steps of the current quadrant whose pixels are all
+    /// outside of an image of the given size, without calculating them.
+    ///
+    /// Steps along x stop one step before y takes over, and steps along y
+    /// stop at the end of the quadrant, so the end of the arc and the step at
+    /// the diagonal are still handled the same way as when iterating.
+    fn skip_hidden(&mut self, size: (u32, u32)) {
+        if self.r == 0.0 {
+            return;
+        }
+        let (xs, ys) = self.visible_ranges(size);
+        if self.in_x() {
+            let (first, last) = intersect(xs, across(self.r2, ys));
+            let k = if first > last || self.x > last {
+                i64::MAX
+            } else {
+                (first - self.x).ceil() as i64
+            };
+            if k > 0 {
+                // Never past the last step before the diagonal
+                let k = k.min(self.steps_to_diagonal() - 1);
+                if k > 0 {
+                    *self = self.jumped_x(k);
+                }
+            }
+        } else if !self.fast_x {
+            // `y` is only on whole pixels after the step at the diagonal
+            let (first, last) = intersect(ys, across(self.r2, xs));
+            if first > last || self.y < first {
+                // Skip the rest of the quadrant
+                *self = self.jumped_y((self.y + 1.0) as i64);
+            } else if self.y > last {
+                *self = self.jumped_y((self.y - last).ceil() as i64);
+            }
+        }
+    }
+
+    /// Returns how many steps along x it takes to reach the diagonal, where
+    /// steps along y take over.
+    fn steps_to_diagonal(&self) -> i64 {
+        let mut k = ((self.r * std::f64::consts::FRAC_1_SQRT_2 - self.x) as i64).max(1);
+        while k > 1 && !self.jumped_x(k - 1).in_x() {
+            k -= 1;
+        }
+        while self.jumped_x(k).in_x() {
+            k += 1;
+        }
+        k
+    }
+
+    /// Whether the next step is along x.
+    fn in_x(&self) -> bool {
+        self.x <= self.y
+    }
+
+    /// Returns the arc after `k` more steps along x.
+    fn jumped_x(&self, k: i64) -> Self {
+        let mut arc = self.clone();
+        if let Some(f) = &mut arc.fixed {
+            f.x += k * fixed::ONE;
+            f.y = fixed::calc_slow(f.r * f.r, f.x);
+            arc.sync();
+        } else {
+            arc.x += k as f64;
+            arc.y = (arc.r2 - arc.x * arc.x).sqrt();
+        }
+        arc.start_cov = 1.0;
+        arc
+    }
+
+    /// Returns the arc after `k` more steps along y.
+    fn jumped_y(&self, k: i64) -> Self {
+        let mut arc = self.clone();
+        if let Some(f) = &mut arc.fixed {
+            f.y -= k * fixed::ONE;
+            f.x = fixed::calc_slow(f.r * f.r, f.y);
+            arc.sync();
+        } else {
+            arc.y -= k as f64;
+            arc.x = (arc.r2 - arc.y * arc.y).sqrt();
+        }
+        arc.start_cov = 1.0;
+        arc
+    }
+
+    /// Returns the ranges of x and y in iterator coordinates for the current
+    /// quadrant that can have pixels inside of an image of the given size.
+    /// The ranges have a margin for rounding and the second pixel of each step.
+    fn visible_ranges(&self, (width, height): (u32, u32)) -> ((f64, f64), (f64, f64)) {
+        let (w, h) = (f64::from(width) - 1.0, f64::from(height) - 1.0);
+        let (cx, cy) = (self.c.x(), self.c.y());
+        let (xs, ys) = match self.quad {
+            1 => ((cy - h, cy), (-cx, w - cx)),
+            2 => ((cx - w, cx), (cy - h, cy)),
+            3 => ((-cy, h - cy), (cx - w, cx)),
+            _ => ((-cx, w - cx), (-cy, h - cy)),
+        };
+        let grow = |(a, b): (f64, f64)| (a - 2.0, b + 2.0);
+        (grow(xs), grow(ys))
     }
 
-    /// Returns the two slow coordinates to antialias and the distance between a and the actual arc (to be used for antialiasing)
-    fn calc_fract(slow: f64) -> (f64, f64, f64) {
-        let o = slow.fract();
-        let a = slow.floor().abs();
+    /// Returns the two slow coordinates to antialias and the distance between a
+    /// and the actual arc (to be used for antialiasing).  The slow coordinate is
+    /// y when stepping along x.
+    fn calc_fract(&self, fast_x: bool) -> (f64, f64, f64) {
+        let (a, o) = if let Some(f) = self.fixed {
+            fixed::fract(if fast_x { f.y } else { f.x })
+        } else {
+            let slow = if fast_x { self.y } else { self.x };
+            (slow.floor(), slow.fract())
+        };
+        let a = a.abs();
         let b = a + 1.0;
         (a, b, o)
     }
 }
 
+/// Returns the range of one coordinate of points on a circle (with a squared
+/// radius of `r2`) where the other coordinate is within `range`.  Only
+/// positive coordinates are used.
+fn across(r2: f64, (lo, hi): (f64, f64)) -> (f64, f64) {
+    if hi < 0.0 {
+        return (1.0, 0.0);
+    }
+    let other = |v: f64| (r2 - v * v).max(0.0).sqrt();
+    let first = if hi * hi >= r2 { 0.0 } else { other(hi) };
+    let last = if lo <= 0.0 { other(0.0) } else { other(lo) };
+    (first, last)
+}
+
+/// Returns the overlap of two ranges, which is empty if `first > last`.
+fn intersect(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0.max(b.0), a.1.min(b.1))
+}
+
+/// The current coordinates and radius of an arc created with
+/// [`AntialiasedArc::new_fixed`], in 16.16 fixed-point.  Each step uses an
+/// integer square root, and the coverage comes from the fractional bits.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug)]
+struct Fixed {
+    x: i64,
+    y: i64,
+    r: i64,
+}
+
 impl Iterator for AntialiasedArc {
     type Item = AAPt<i32>;
 
     /// Iterate over points in an arc, returning the two corresponding points and their opacities
     fn next(&mut self) -> Option<Self::Item> {
+        if self.r == 0.0 {
+            // A single fully covered pixel at the center, like `Arc`
+            if self.y < 0.0 {
+                return None;
+            }
+            self.y = -1.0;
+            let c = self.c.i32();
+            return Some(AAPt::new(c, c, 0.0));
+        }
         if self.end() {
             return None;
         }
@@ -315,6 +719,31 @@ impl Iterator for AntialiasedArc {
     }
 }
 
+/// An iterator over the pixels of an [`AntialiasedArc`] and their coverage.
+///
+/// Created with [`AntialiasedArc::coverage`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Coverage {
+    arc: AntialiasedArc,
+    /// Second pixel of the current step
+    next: Option<(Pt<i32>, f64)>,
+}
+
+impl Iterator for Coverage {
+    type Item = (Pt<i32>, f64);
+
+    /// Returns the next pixel and its coverage
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next) = self.next.take() {
+            return Some(next);
+        }
+        let pt = self.arc.next()?;
+        self.next = Some((pt.b, pt.ob));
+        Some((pt.a, pt.oa))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,4 +764,168 @@ mod tests {
 
         image.save("images/arc_aa.png")
     }
+
+    #[test]
+    fn arc_aa_fixed() -> Result<(), image::ImageError> {
+        use crate::RADS;
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let start = RADS * 0.8;
+        let end = RADS * 7.4;
+        let r = crate::RADIUS_F;
+        let c = Pt::new(200.0, 200.0);
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        let float: Vec<_> = AntialiasedArc::new(start, end, r, c).collect();
+        let fixed: Vec<_> = AntialiasedArc::new_fixed(start, end, r, c).collect();
+
+        assert_eq!(float.len(), fixed.len());
+        for (f, x) in float.iter().zip(fixed.iter()) {
+            assert_eq!((f.a, f.b), (x.a, x.b));
+            assert!((f.oa - x.oa).abs() <= 1.0 / 128.0);
+        }
+
+        AntialiasedArc::new_fixed(start, end, r, c).draw(&mut image, color);
+
+        image.save("images/arc_aa_fixed.png")
+    }
+
+    #[test]
+    fn arc_aa_zero_radius() {
+        for arc in [
+            AntialiasedArc::new(0, 90, 0.0, (5.0, 7.0)),
+            AntialiasedArc::new(30, 30, 0, (5, 7)),
+            AntialiasedArc::new_fixed(0, 90, 0.0, (5.0, 7.0)),
+        ] {
+            let covered: Vec<_> = arc.coverage().filter(|&(_, c)| c > 0.0).collect();
+            assert_eq!(covered, [(Pt::new(5, 7), 1.0)]);
+        }
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((10, 10));
+        antialiased_arc(&mut image, 0, 180, 0, (5, 7), color);
+        assert_eq!(*image.get_pixel(5, 7), color);
+        assert_eq!(image.pixels().filter(|&&p| p == color).count(), 1);
+    }
+
+    #[test]
+    fn arc_aa_fixed_state() {
+        let mut arc = AntialiasedArc::new_fixed(10, 300, 57.3, (100.0, 100.0));
+        let mut steps = 0;
+        while arc.next().is_some() {
+            // The coordinates stay on fixed-point steps and on the circle
+            let f = arc.fixed.unwrap();
+            assert_eq!((fixed::to_fixed(arc.x), fixed::to_fixed(arc.y)), (f.x, f.y));
+            if arc.y >= 0.0 && arc.x >= 0.0 {
+                let r2 = i128::from(f.x).pow(2) + i128::from(f.y).pow(2);
+                let err = (r2 - i128::from(f.r).pow(2)).abs();
+                assert!(err <= 2 * i128::from(f.r), "{steps} {f:?}");
+            }
+            steps += 1;
+        }
+        assert!(steps > 100);
+    }
+
+    #[test]
+    fn arc_aa_endpoint_coverage() {
+        let cov = |p: &AAPt<i32>| p.oa + p.ob;
+        let c = Pt::new(50.0, 50.0);
+
+        // Ends that fall on a pixel center cover half of the pixel
+        let quarter: Vec<_> = AntialiasedArc::new(0, 90, 20.0, c).collect();
+        assert!((cov(&quarter[0]) - 0.5).abs() < 1e-9);
+        assert!((cov(quarter.last().unwrap()) - 0.5).abs() < 1e-9);
+
+        // Two arcs meeting at the same angle split the shared pixel between them
+        let a: Vec<_> = AntialiasedArc::new(20, 50, 20.0, c).collect();
+        let b: Vec<_> = AntialiasedArc::new(50, 20, 20.0, c).collect();
+        assert_eq!(a.last().unwrap().a, b[0].a);
+        assert!((cov(a.last().unwrap()) + cov(&b[0]) - 1.0).abs() < 1e-9);
+        assert_eq!(b.last().unwrap().a, a[0].a);
+        assert!((cov(b.last().unwrap()) + cov(&a[0]) - 1.0).abs() < 1e-9);
+
+        // Full circles have no end points
+        let full: Vec<_> = AntialiasedArc::new(0, 360, 20.0, c).collect();
+        assert!((cov(&full[0]) - 1.0).abs() < 1e-9);
+        assert!((cov(full.last().unwrap()) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_aa_revisit() {
+        let c = Pt::new(50.0, 50.0);
+        let short = AntialiasedArc::new(70, 80, 20.0, c).count();
+        let long = AntialiasedArc::new(80, 70, 20.0, c).count();
+        assert!(long > short * 5);
+    }
+
+    #[test]
+    fn arc_aa_full_sweep() {
+        let c = Pt::new(50.0, 50.0);
+        let full: Vec<_> = AntialiasedArc::new(30, 30, 20.0, c).collect();
+        for end in [390, 750, -330] {
+            let arc: Vec<_> = AntialiasedArc::new(30, end, 20.0, c).collect();
+            assert_eq!(full.len(), arc.len(), "end={end}");
+            assert!((arc[0].oa + arc[0].ob - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn clipped_aa_arcs_match_unclipped() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let sweeps = [
+            (0, 360),
+            (30, 270),
+            (300, 45),
+            (100, 100),
+            (44, 46),
+            (181, 359),
+        ];
+        let centers = [
+            (20.0, 15.0),
+            (-15.5, 10.0),
+            (45.0, 40.3),
+            (20.0, -30.0),
+            (-8.0, -8.0),
+        ];
+        for (start, end) in sweeps {
+            for center in centers {
+                for r in [5.0, 7.5, 25.0, 60.0] {
+                    for arc in [
+                        AntialiasedArc::new(start, end, r, center),
+                        AntialiasedArc::new_fixed(start, end, r, center),
+                    ] {
+                        let mut image = crate::test::img::blank((40, 30));
+                        arc.clone().draw(&mut image, color);
+                        let mut expected = crate::test::img::blank((40, 30));
+                        arc.draw_with_stats(&mut expected, color);
+                        assert_eq!(image, expected, "{start} {end} {center:?} r={r}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_partially_visible_aa_arcs() {
+        let (r, color) = (1e9, image::Rgba([255, 0, 0, 255]));
+        let mut image = crate::test::img::blank((40, 30));
+        let bg = *image.get_pixel(0, 0);
+        AntialiasedArc::new(0, 360, r, (20.0, 15.0 + r)).draw(&mut image, color);
+        let drawn: Vec<_> = image.enumerate_pixels().filter(|p| *p.2 != bg).collect();
+        assert!(drawn.len() >= 40);
+        assert!(drawn.iter().all(|p| (14..=16).contains(&p.1)));
+    }
+
+    #[test]
+    fn arc_aa_coverage() {
+        let arc = AntialiasedArc::new(15, 200, 20.0, Pt::new(50.0, 50.0));
+        let steps: Vec<_> = arc.clone().collect();
+        let coverage: Vec<_> = arc.coverage().collect();
+
+        assert_eq!(steps.len() * 2, coverage.len());
+        for (step, pair) in steps.iter().zip(coverage.chunks(2)) {
+            assert_eq!(pair, [(step.a, step.oa), (step.b, step.ob)]);
+        }
+    }
 }
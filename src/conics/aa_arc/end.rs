@@ -34,4 +34,24 @@ impl End {
             Self::X(_) => false,
         }
     }
+
+    /// Returns how much of the pixel at `x` is covered before an X end point is reached.
+    ///
+    /// Pixel centers are at whole coordinates.
+    pub(super) fn cover_x(&self, x: f64) -> f64 {
+        match self {
+            Self::X(end) => (end - (x.round() - 0.5)).clamp(0.0, 1.0),
+            Self::Y(_) => 1.0,
+        }
+    }
+
+    /// Returns how much of the pixel at `y` is covered before a Y end point is reached.
+    ///
+    /// Pixel centers are at whole coordinates.
+    pub(super) fn cover_y(&self, y: f64) -> f64 {
+        match self {
+            Self::Y(end) => ((y.round() + 0.5) - end).clamp(0.0, 1.0),
+            Self::X(_) => 1.0,
+        }
+    }
 }
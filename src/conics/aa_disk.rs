@@ -0,0 +1,140 @@
+use crate::pt::Point;
+use crate::Error;
+use image::{Rgba, RgbaImage};
+
+/// Draws an antialiased filled circle (a disk).
+///
+/// The inside of each row is blended as one solid span, and only the pixels
+/// along the edge are blended by how much of them the disk covers.  The
+/// radius and center do not need to be whole numbers, which is handy for
+/// small dots and nodes placed between pixels.
+///
+/// Only the part of the disk inside the image is drawn.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_circle_filled;
+///
+/// let mut image = RgbaImage::from_pixel(100, 100, Rgba([255, 255, 255, 255]));
+/// let red = Rgba([255, 0, 0, 255]);
+///
+/// antialiased_circle_filled(&mut image, 20.25, (50.0, 50.0), red);
+/// assert_eq!(*image.get_pixel(50, 50), red);
+/// // The edge fades into the background
+/// assert!(image.get_pixel(50, 30)[1] > 0 && image.get_pixel(50, 30)[1] < 255);
+/// ```
+///
+/// See also: [`Draw::antialiased_circle_filled`](crate::Draw::antialiased_circle_filled)
+///
+pub fn antialiased_circle_filled<C, T>(image: &mut RgbaImage, radius: T, center: C, color: Rgba<u8>)
+where
+    C: Point<T>,
+    T: Into<f64> + Copy,
+{
+    let r: f64 = radius.into();
+    assert!(r >= 0.0, "{}", Error::InvalidRadius(r));
+    let (cx, cy): (f64, f64) = (center.x().into(), center.y().into());
+    let alpha = f64::from(color[3]) / 255.0;
+    // Pixels are covered by how far their center is inside of the edge
+    let coverage = |x: f64, y: f64| (r + 0.5 - (x - cx).hypot(y - cy)).clamp(0.0, 1.0);
+
+    let (outer, inner) = (r + 0.5, r - 0.5);
+    let y0 = (cy - outer).floor().max(0.0);
+    let y1 = (cy + outer).ceil().min(f64::from(image.height()) - 1.0);
+    let right = f64::from(image.width()) - 1.0;
+
+    let mut y = y0;
+    while y <= y1 {
+        let dy = y - cy;
+        let xo = (outer * outer - dy * dy).max(0.0).sqrt();
+        // The span of pixels entirely inside of the disk, if there is one
+        let solid = (inner > dy.abs()).then(|| {
+            let xi = (inner * inner - dy * dy).sqrt();
+            ((cx - xi).ceil(), (cx + xi).floor())
+        });
+        let (a, b) = match solid {
+            Some((a, b)) if a <= b => {
+                // safe because the row was clamped to the image above
+                #[allow(clippy::cast_possible_truncation)]
+                crate::ops::blend_row(image, y as i32, a as i32, b as i32, alpha as f32, color);
+                (a, b)
+            }
+            _ => (cx.round() + 1.0, cx.round()),
+        };
+
+        // The edge pixels on either side of the solid span
+        let left = (cx - xo).floor().max(0.0);
+        let edges = [
+            (left, (a - 1.0).min(right)),
+            ((b + 1.0).max(left), (cx + xo).ceil().min(right)),
+        ];
+        for (mut x, end) in edges {
+            while x <= end {
+                let opacity = coverage(x, y) * alpha;
+                if opacity > 0.0 {
+                    // safe because x and y were clamped to the image above
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    crate::ops::blend_at(image, x as u32, y as u32, opacity as f32, color);
+                }
+                x += 1.0;
+            }
+        }
+        y += 1.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    #[test]
+    fn solid_inside_smooth_edge() {
+        let mut image = crate::test::img::blank((40, 40));
+        antialiased_circle_filled(&mut image, 10.0, (20.0, 20.0), RED);
+
+        for (x, y, &p) in image.enumerate_pixels() {
+            let d = (f64::from(x) - 20.0).hypot(f64::from(y) - 20.0);
+            if d <= 9.5 {
+                assert_eq!(p, RED, "({x}, {y})");
+            } else if d >= 10.5 {
+                assert_eq!(p, WHITE, "({x}, {y})");
+            } else {
+                // Partly covered, and each pixel is only blended once
+                let expected = ((10.5 - d) * 255.0) as u8;
+                assert!(
+                    (i32::from(p[1]) - i32::from(255 - expected)).abs() <= 1,
+                    "({x}, {y}) {p:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tiny_and_clipped() {
+        // Smaller than a pixel, between pixels
+        let mut image = crate::test::img::blank((5, 5));
+        antialiased_circle_filled(&mut image, 0.5, (2.5, 2.5), RED);
+        for (x, y) in [(2, 2), (3, 2), (2, 3), (3, 3)] {
+            let p = *image.get_pixel(x, y);
+            assert!(p != WHITE && p != RED, "({x}, {y}) {p:?}");
+        }
+        assert_eq!(*image.get_pixel(1, 1), WHITE);
+
+        // Partly outside of the image
+        let mut image = crate::test::img::blank((20, 20));
+        antialiased_circle_filled(&mut image, 8, (0, 19), RED);
+        assert_eq!(*image.get_pixel(0, 19), RED);
+        assert_eq!(*image.get_pixel(5, 15), RED);
+        assert_eq!(*image.get_pixel(19, 0), WHITE);
+        antialiased_circle_filled(&mut image, 8, (-100, -100), RED);
+    }
+}
@@ -5,7 +5,7 @@ use edges::Edge;
 use pos::Pos;
 
 use crate::translate;
-use crate::{angle, Point, Pt};
+use crate::{angle, Error, Point, Pt};
 
 /// Draws a partial annulus (filled donut).
 ///
@@ -77,6 +77,94 @@ pub fn annulus<A, C, I>(
     .draw(image, color);
 }
 
+/// Draws the outline of a partial annulus: the outer arc, the inner arc, and
+/// the two straight edges joining them.
+///
+/// Angles are interpreted the same way as [`annulus`].  A complete ring has
+/// no straight edges, so only the two circles are drawn.  Drawing the outline
+/// over a filled annulus with the same angles and radii traces its edge.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::{annulus, annulus_outline};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// annulus(&mut image, 0, 90, 150, 190, (200, 200), Rgba([255, 200, 200, 255]));
+/// annulus_outline(&mut image, 0, 90, 150, 190, (200, 200), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if either of the radii are negative.
+///
+/// See also: [`Draw::annulus_outline`](crate::Draw::annulus_outline)
+///
+pub fn annulus_outline<A, C, I>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    inner_radius: i32,
+    outer_radius: i32,
+    center: C,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+    I: image::GenericImage,
+{
+    Annulus::new(
+        start_angle,
+        end_angle,
+        inner_radius,
+        outer_radius,
+        center.pt(),
+    )
+    .draw_outline(image, color);
+}
+
+/// Calls a closure for every run of pixels in a partial annulus instead of
+/// drawing to an image.
+///
+/// Each call receives both (inclusive) ends of a horizontal or vertical run in
+/// image coordinates.  Runs are not clipped.  Angles are interpreted the same
+/// way as [`annulus`].
+///
+/// ```
+/// use freehand::conics::annulus_for_each;
+///
+/// let mut pixels = 0;
+/// annulus_for_each(0, 90, 5, 10, (20, 20), |a, b| {
+///     pixels += (b.x() - a.x() + b.y() - a.y()).abs() + 1;
+/// });
+/// assert!(pixels > 0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either of the radii are negative.
+///
+pub fn annulus_for_each<A, C, F>(
+    start_angle: A,
+    end_angle: A,
+    inner_radius: i32,
+    outer_radius: i32,
+    center: C,
+    mut f: F,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+    F: FnMut(Pt<i32>, Pt<i32>),
+{
+    Annulus::new(
+        start_angle,
+        end_angle,
+        inner_radius,
+        outer_radius,
+        center.pt(),
+    )
+    .for_each(|(a, b)| f(a, b));
+}
+
 /// Draws an arc with a specified thickness.
 ///
 /// This is just a wrapper around [`Annulus`] for convenience.
@@ -106,8 +194,9 @@ pub fn thick_arc<A, C, I>(
     let inr = thickness / 2;
     let otr = thickness - inr;
 
-    let outer_radius = radius + otr;
-    let inner_radius = if (radius - inr).is_negative() {
+    // Radii past the end of the i32 range are far outside of any image anyway
+    let outer_radius = radius.saturating_add(otr);
+    let inner_radius = if radius.saturating_sub(inr).is_negative() {
         1
     } else {
         radius - inr
@@ -123,11 +212,61 @@ pub fn thick_arc<A, C, I>(
     .draw(image, color);
 }
 
+/// Draws the outline of a pie slice: the arc and the two straight edges
+/// joining its ends to the center.
+///
+/// A complete sweep has no straight edges, so it draws a circle.  Use
+/// [`pie_slice_filled`] to fill the slice instead.  Only the parts of the arc
+/// and edges crossing the image are calculated.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::pie_slice;
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// pie_slice(&mut image, 0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::pie_slice`](crate::Draw::pie_slice)
+///
+pub fn pie_slice<A, C, I>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    radius: i32,
+    center: C,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+    I: image::GenericImage,
+{
+    let slice = Annulus::new(start_angle, end_angle, 0, radius, center.pt());
+    if slice.is_full() {
+        // The inner circle of a complete sweep is a single pixel at the center
+        super::circle(image, radius, center.pt(), color);
+        return;
+    }
+    slice.draw_outline(image, color);
+}
+
 /// Draws a pie slice.
 ///
 /// Pie slice is drawn from the circle center to a given radius.
 ///
-/// This is just a wrapper around [`Annulus`] for convenience.
+/// This is just a wrapper around [`Annulus`] for convenience.  To test whether
+/// a point falls inside a pie slice use an annulus with an inner radius of 0:
+///
+/// ```
+/// use freehand::conics::Annulus;
+///
+/// let slice = Annulus::new(0, 90, 0, 50, (100, 100));
+/// assert!(slice.contains((120, 80)));
+/// ```
 ///
 /// See also: [`Draw::pie_slice_filled`](crate::Draw::pie_slice_filled)
 ///
@@ -178,20 +317,15 @@ where
     let inr = thickness / 2;
     let otr = thickness - inr;
 
-    let outer_radius = radius + otr;
-    let inner_radius = if (radius - inr).is_negative() {
+    // Radii past the end of the i32 range are far outside of any image anyway
+    let outer_radius = radius.saturating_add(otr);
+    let inner_radius = if radius.saturating_sub(inr).is_negative() {
         1
     } else {
         radius - inr
     };
 
-    let mut octant = Annulus::new(
-        0.0,
-        angle::octant_end_angle(1),
-        inner_radius,
-        outer_radius,
-        center.pt(),
-    );
+    let mut octant = Annulus::new(0, 45, inner_radius, outer_radius, center.pt());
 
     loop {
         if octant.end() {
@@ -210,6 +344,19 @@ where
 
 /// Represents an annulus (part of a filled donut shape) from a start angle to an end angle.
 ///
+/// Implements [`Iterator`], returning the runs of pixels that fill the annulus.
+/// Each item is a pair of points marking both (inclusive) ends of a horizontal
+/// or vertical run, in image coordinates.  Runs are not clipped to any image,
+/// so coordinates may be negative.
+///
+/// ```
+/// use freehand::conics::Annulus;
+///
+/// for (a, b) in Annulus::new(0, 90, 150, 190, (200, 200)) {
+///     assert!(a.x() == b.x() || a.y() == b.y());
+/// }
+/// ```
+///
 /// ```
 /// use image::{RgbaImage, Rgba};
 /// use freehand::conics::Annulus;
@@ -231,6 +378,10 @@ where
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct Annulus {
+    /// Start angle of the whole annulus
+    start: f64,
+    /// Whether the annulus is a complete ring
+    full: bool,
     end: Edge,
     cur_start: Edge,
     cur_end: Edge,
@@ -251,7 +402,15 @@ impl Annulus {
     /// larger than`2*PI` for radians).  Angles will be normalized into a range
     /// of 0..PI*2.
     ///
-    /// # Panic
+    /// If the start and end angles are the same, or the end angle is at least a
+    /// full turn away from the start angle, a complete ring is drawn.
+    ///
+    /// An inner radius of 0 produces a filled sector that reaches all the way
+    /// to the center, drawing the same pixels as
+    /// [`pie_slice_filled`](super::pie_slice_filled), and if both radii are 0 a
+    /// single pixel is drawn at the center.
+    ///
+    /// # Panics
     ///
     /// Will panic if either of the radii are negative.
     ///
@@ -267,59 +426,93 @@ impl Annulus {
     pub fn new<A, P>(
         start_angle: A,
         end_angle: A,
-        mut inner_radius: i32,
-        mut outer_radius: i32,
+        inner_radius: i32,
+        outer_radius: i32,
         center: P,
     ) -> Self
     where
         A: crate::Angle,
         P: crate::pt::Point<i32>,
     {
-        let start_angle = crate::angle::normalize(start_angle.radians());
-        let mut end_angle = crate::angle::normalize(end_angle.radians());
-        if (start_angle - end_angle).abs() <= std::f64::EPSILON {
-            end_angle = crate::angle::normalize(end_angle - crate::TINY);
+        match Self::try_new(start_angle, end_angle, inner_radius, outer_radius, center) {
+            Ok(annulus) => annulus,
+            Err(e) => panic!("{e}"),
         }
+    }
 
-        Self::validate_radii(&mut inner_radius, &mut outer_radius);
+    /// Creates a new [`Annulus`], returning an error instead of panicking if
+    /// either of the radii are invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRadius`] if either of the radii are negative.
+    ///
+    /// ```
+    /// # use freehand::conics::Annulus;
+    /// assert!(Annulus::try_new(0, 180, 150, 190, (200, 200)).is_ok());
+    /// assert!(Annulus::try_new(0, 180, -150, 190, (200, 200)).is_err());
+    /// ```
+    pub fn try_new<A, P>(
+        start_angle: A,
+        end_angle: A,
+        mut inner_radius: i32,
+        mut outer_radius: i32,
+        center: P,
+    ) -> Result<Self, Error>
+    where
+        A: crate::Angle,
+        P: crate::pt::Point<i32>,
+    {
+        let full = angle::is_full_turn(start_angle, end_angle);
+        let (start_oct, mut start_angle) = angle::start_octant(start_angle);
+        // An end angle on an octant boundary stays in the octant before it
+        let (mut end_oct, mut end_angle) = angle::end_octant(end_angle);
+        if full {
+            // Start on an octant boundary so the seam lines up with the octant edges
+            start_angle = angle::octant_start_angle(start_oct);
+            end_oct = (start_oct + 6) % 8 + 1;
+            end_angle = angle::octant_end_angle(end_oct);
+        }
 
-        let end_oct = angle::angle_to_octant(end_angle);
-        let start_oct = angle::angle_to_octant(start_angle);
+        Self::validate_radii(&mut inner_radius, &mut outer_radius)?;
 
         let cur_end = if start_oct == end_oct && start_angle > end_angle {
-            angle::octant_end_angle(start_oct)
+            (start_oct, angle::octant_end_angle(start_oct))
         } else {
-            end_angle
+            (end_oct, end_angle)
         };
 
         let mut a = Self::annulus(
-            start_angle,
+            (start_oct, start_angle),
             cur_end,
             inner_radius,
             outer_radius,
             center.pt(),
         );
-        a.end = Edge::blank(end_angle);
-        a
+        a.end = Edge::blank(end_angle, end_oct);
+        a.full = full;
+        Ok(a)
     }
 
     // this is not a public function so it's fine
     #[allow(clippy::self_named_constructors)]
     /// An internal function used to create a new [`Annulus`].  The `new()` function
     /// should be used externally, which will also normalize angles and check the radii.
-    fn annulus(start_angle: f64, end_angle: f64, ri: i32, ro: i32, c: Pt<i32>) -> Self {
-        let end_oct = angle::angle_to_octant(end_angle);
-        let start_oct = angle::angle_to_octant(start_angle);
+    ///
+    /// The angles are given along with the octants they are in, as angles on
+    /// an octant boundary could be in either octant.
+    fn annulus(start: (u8, f64), end: (u8, f64), ri: i32, ro: i32, c: Pt<i32>) -> Self {
+        let (start_oct, start_angle) = start;
+        let (end_oct, end_angle) = end;
 
-        let end = Edge::blank(end_angle);
+        let end = Edge::blank(end_angle, end_oct);
 
-        let mut cur_start = Edge::blank(start_angle);
-        let ea = if start_oct == end_oct {
-            end_angle
+        let mut cur_start = Edge::blank(start_angle, start_oct);
+        let mut cur_end = if start_oct == end_oct {
+            Edge::blank(end_angle, end_oct)
         } else {
-            angle::octant_end_angle(start_oct)
+            Edge::blank(angle::octant_end_angle(start_oct), start_oct)
         };
-        let mut cur_end = Edge::blank(ea);
 
         let inr = Pos::new(cur_start.angle, cur_end.angle, cur_start.oct, ri, c);
         let otr = Pos::new(cur_start.angle, cur_end.angle, cur_start.oct, ro, c);
@@ -328,6 +521,8 @@ impl Annulus {
         cur_end.set_slope(inr.ex, inr.ey, otr.ex, otr.ey);
 
         Self {
+            start: start_angle,
+            full: false,
             end,
             x: inr.x.min(otr.x),
             inr,
@@ -364,15 +559,18 @@ impl Annulus {
     }
 
     /// Verify radii are not negative and swap if `inner < outer`.
-    fn validate_radii(inner: &mut i32, outer: &mut i32) {
-        assert!(
-            !(inner.is_negative() | outer.is_negative()),
-            "Radii must be non-negative.  inner={inner} outer={outer}"
-        );
+    fn validate_radii(inner: &mut i32, outer: &mut i32) -> Result<(), Error> {
+        if inner.is_negative() {
+            return Err(Error::InvalidRadius((*inner).into()));
+        }
+        if outer.is_negative() {
+            return Err(Error::InvalidRadius((*outer).into()));
+        }
 
         if inner > outer {
             std::mem::swap(inner, outer);
         }
+        Ok(())
     }
 
     fn stop(&self) -> bool {
@@ -389,16 +587,24 @@ impl Annulus {
 
     fn switch_octant(&mut self) {
         self.oct = self.oct % 8 + 1; // Increment octant.  Wraps around to 1 if oct == 8
-        let start = angle::octant_start_angle(self.oct);
-        *self = Self::annulus(start, self.end.angle, self.inr.r, self.otr.r, self.c);
+        let start = (self.oct, angle::octant_start_angle(self.oct));
+        let end = (self.end.oct, self.end.angle);
+        let (start_angle, full) = (self.start, self.full);
+        *self = Self::annulus(start, end, self.inr.r, self.otr.r, self.c);
+        self.start = start_angle;
+        self.full = full;
     }
 
     /// Switch to the next octant
     fn next_octant(&mut self) -> bool {
         if self.x > self.inr.ex && self.x > self.otr.ex {
             self.oct = self.oct % 8 + 1; // Increment octant.  Wraps around to 1 if oct == 8
-            let start = angle::octant_start_angle(self.oct);
-            *self = Self::annulus(start, self.end.angle, self.inr.r, self.otr.r, self.c);
+            let start = (self.oct, angle::octant_start_angle(self.oct));
+            let end = (self.end.oct, self.end.angle);
+            let (start_angle, full) = (self.start, self.full);
+            *self = Self::annulus(start, end, self.inr.r, self.otr.r, self.c);
+            self.start = start_angle;
+            self.full = full;
             true
         } else {
             false
@@ -470,14 +676,242 @@ impl Annulus {
     /// let annulus = Annulus::new(0, 180, 150, 190, (190, 190));
     /// annulus.draw(&mut image, color);
     /// ```
-    pub fn draw<I>(mut self, image: &mut I, color: I::Pixel)
+    pub fn draw<I>(self, image: &mut I, color: I::Pixel)
+    where
+        I: image::GenericImage,
+    {
+        // Skip annuli that are entirely off screen, only calculate the runs
+        // of pixels that cross the image, and clip each run as a whole
+        // instead of checking every pixel
+        if !self
+            .bounding_box()
+            .is_visible_in(image.width(), image.height())
+        {
+            return;
+        }
+        let size = (image.width(), image.height());
+        let mut annulus = self;
+        while let Some((x, y1, y2)) = annulus.next_line(Some(size)) {
+            let a = translate::iter_to_real(x, y1.min(y2), annulus.oct, annulus.c);
+            let b = translate::iter_to_real(x, y1.max(y2), annulus.oct, annulus.c);
+            if a.x() == b.x() {
+                crate::lines::vertical_line(
+                    image,
+                    (a.x(), a.y().min(b.y())),
+                    a.y().max(b.y()),
+                    color,
+                );
+            } else {
+                crate::lines::horizontal_line(
+                    image,
+                    (a.x().min(b.x()), a.y()),
+                    a.x().max(b.x()),
+                    color,
+                );
+            }
+        }
+    }
+
+    /// Draws the outline of the annulus.  See [`outline_points`](Self::outline_points).
+    ///
+    /// The arcs and edges are drawn separately, so only their parts that
+    /// cross the image are calculated.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::Annulus;
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// let annulus = Annulus::new(0, 180, 150, 190, (200, 200));
+    /// annulus.draw_outline(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw_outline<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: image::GenericImage,
+    {
+        let full = self.is_full();
+        let end = if full { self.start } else { self.end.angle };
+
+        let outer = crate::conics::Arc::new(self.start, end, self.otr.r, self.c);
+        let inner = crate::conics::Arc::new(self.start, end, self.inr.r, self.c);
+
+        if !full {
+            if let (Some((outer_start, outer_end)), Some((inner_start, inner_end))) =
+                (outer.ends(), inner.ends())
+            {
+                // The ends of each edge are also drawn by the arcs
+                crate::lines::line(image, outer_end, inner_end, color);
+                crate::lines::line(image, inner_start, outer_start, color);
+            }
+        }
+        outer.draw(image, color);
+        inner.draw(image, color);
+    }
+
+    /// Draws the annulus and returns how many pixels were drawn and clipped.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::conics::Annulus;
+    /// # let mut image = RgbaImage::new(100, 100);
+    ///
+    /// let stats = Annulus::new(0, 90, 5, 10, (50, 50)).draw_with_stats(&mut image, Rgba([255, 0, 0, 255]));
+    /// assert_eq!(stats.clipped, 0);
+    /// ```
+    pub fn draw_with_stats<I>(mut self, image: &mut I, color: I::Pixel) -> crate::DrawStats
     where
         I: image::GenericImage,
     {
+        let mut stats = crate::DrawStats::default();
+        while let Some((x, y1, y2)) = self.next_line(None) {
+            stats += self.put_line(x, y1, y2, self.oct, image, color);
+        }
+        stats
+    }
+
+    /// Returns every point inside the annulus, in the order they would be drawn.
+    ///
+    /// ```
+    /// use freehand::conics::Annulus;
+    ///
+    /// let annulus = Annulus::new(0, 90, 5, 10, (20, 20));
+    /// let points = annulus.points();
+    ///
+    /// assert!(points.contains(&freehand::Pt::new(27, 20)));
+    /// assert!(!points.contains(&freehand::Pt::new(20, 20)));
+    /// ```
+    #[must_use]
+    pub fn points(&self) -> Vec<Pt<i32>> {
+        let mut annulus = self.clone();
+        let mut points = Vec::new();
+        while let Some((x, y1, y2)) = annulus.next_line(None) {
+            for y in y1.min(y2)..=y1.max(y2) {
+                points.push(translate::iter_to_real(x, y, annulus.oct, annulus.c));
+            }
+        }
+        points
+    }
+
+    /// Returns true if a point lies inside the annulus.
+    ///
+    /// Points within about half a pixel of the curved edges (and a pixel of the
+    /// straight edges) are considered inside.  An
+    /// annulus with an inner radius of 0 (a pie slice) contains its center.
+    ///
+    /// ```
+    /// use freehand::conics::Annulus;
+    ///
+    /// let annulus = Annulus::new(0, 90, 5, 10, (20, 20));
+    /// assert!(annulus.contains((27, 18)));
+    /// assert!(!annulus.contains((20, 20)));
+    /// assert!(!annulus.contains((13, 20)));
+    ///
+    /// // A pie slice
+    /// let slice = Annulus::new(0, 90, 0, 10, (20, 20));
+    /// assert!(slice.contains((20, 20)));
+    /// ```
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: Point<i32>,
+    {
+        let tolerance = super::HIT_TOLERANCE;
+        let pt = Pt::new(f64::from(pt.x()), f64::from(pt.y()));
+        let c = Pt::new(f64::from(self.c.x()), f64::from(self.c.y()));
+        let d = (pt.x() - c.x()).hypot(pt.y() - c.y());
+
+        if d < f64::from(self.inr.r) - tolerance || d > f64::from(self.otr.r) + tolerance {
+            return false;
+        }
+        if self.is_full() || d <= tolerance {
+            return true;
+        }
+
+        // The straight edges are stepped along the slope of each line, so they
+        // can land up to a full pixel away from the ideal angle
+        let a = angle::point_angle(c, pt);
+        angle::in_sweep(a, self.start, self.end.angle, 1.0 / d)
+    }
+
+    /// Returns the smallest rectangle covering every pixel of the annulus.
+    ///
+    /// This is calculated from the ends of the inner and outer arcs in each
+    /// octant without iterating over the annulus.
+    ///
+    /// ```
+    /// use freehand::conics::Annulus;
+    /// use freehand::shapes::Rect;
+    ///
+    /// let annulus = Annulus::new(0, 90, 5, 10, (20, 20));
+    /// assert_eq!(annulus.bounding_box(), Rect::new((20, 10), 11, 11));
+    /// ```
+    #[must_use]
+    pub fn bounding_box(&self) -> crate::shapes::Rect {
+        super::sector_bounds(
+            self.c,
+            self.start,
+            self.end.angle,
+            self.is_full(),
+            &[self.inr.r, self.otr.r],
+        )
+    }
+
+    /// Returns true if the annulus is a complete ring.
+    fn is_full(&self) -> bool {
+        self.full
+    }
+
+    /// Returns the points along the outline of the annulus, in order.
+    ///
+    /// The outline follows the outer arc from the start angle to the end angle,
+    /// then the end edge inwards, the inner arc back to the start angle, and
+    /// finally the start edge outwards.  A complete ring only has the outer and
+    /// inner circles.
+    ///
+    /// ```
+    /// use freehand::conics::Annulus;
+    ///
+    /// let annulus = Annulus::new(0, 90, 5, 10, (20, 20));
+    /// let outline = annulus.outline_points();
+    ///
+    /// assert_eq!(outline.first(), Some(&freehand::Pt::new(30, 20)));
+    /// ```
+    #[must_use]
+    pub fn outline_points(&self) -> Vec<Pt<i32>> {
+        let full = self.is_full();
+        let end = if full { self.start } else { self.end.angle };
+
+        let mut points = crate::conics::Arc::new(self.start, end, self.otr.r, self.c).points();
+        let mut inner = crate::conics::Arc::new(self.start, end, self.inr.r, self.c).points();
+        inner.reverse();
+
+        if full {
+            points.append(&mut inner);
+            return points;
+        }
+
+        let (outer_start, outer_end) = (points[0], points[points.len() - 1]);
+        let (inner_end, inner_start) = (inner[0], inner[inner.len() - 1]);
+
+        points.extend(edge_points(outer_end, inner_end));
+        points.append(&mut inner);
+        points.extend(edge_points(inner_start, outer_start));
+        points
+    }
+
+    /// Returns the next line of the annulus as a local x coordinate and two y
+    /// coordinates (in iterator coordinates for the current octant), or `None`
+    /// once the end has been reached.
+    ///
+    /// If the size of an image is given, lines that are outside of the image
+    /// are skipped without calculating them.
+    fn next_line(&mut self, clip: Option<(u32, u32)>) -> Option<(i32, i32, i32)> {
         loop {
+            if let Some((width, height)) = clip {
+                self.clip_columns(width, height);
+            }
             if self.stop() {
                 if self.is_end() {
-                    return;
+                    return None;
                 }
                 self.switch_octant();
                 continue;
@@ -489,9 +923,37 @@ impl Annulus {
                 continue;
             }
 
-            let (x, y1, y2) = (x, y1.max(x), y2.max(x));
+            return Some((x, y1.max(x), y2.max(x)));
+        }
+    }
+
+    /// Skips ahead to the first column of the current octant that is inside of
+    /// an image, or to the end of the octant if there are none left.
+    ///
+    /// Each column of an octant is a single row or column of the image, so
+    /// only the columns crossing the image need to be calculated.
+    fn clip_columns(&mut self, width: u32, height: u32) {
+        let (c, n, sign) = match self.oct {
+            2 | 7 => (self.c.x(), width, 1),
+            3 | 6 => (self.c.x(), width, -1),
+            5 | 8 => (self.c.y(), height, 1),
+            _ => (self.c.y(), height, -1),
+        };
+        let (c, last) = (i64::from(c), i64::from(n) - 1);
+        let (first, last) = if sign > 0 {
+            (-c, last - c)
+        } else {
+            (c - last, c)
+        };
 
-            self.put_line(x, y1, y2, self.oct, image, color);
+        let x = i64::from(self.x);
+        if x > last {
+            self.x = self.inr.ex.max(self.otr.ex).saturating_add(1);
+        } else if x < first {
+            let x = first.min(i32::MAX.into()) as i32;
+            self.x = x;
+            self.inr.skip_to(x);
+            self.otr.skip_to(x);
         }
     }
 
@@ -504,22 +966,47 @@ impl Annulus {
         oct: u8,
         image: &mut I,
         color: I::Pixel,
-    ) {
+    ) -> crate::DrawStats {
         let width = image.width();
         let height = image.height();
 
         let min = yo.min(yi);
         let max = yo.max(yi);
+        let mut stats = crate::DrawStats::default();
 
         for y in min..=max {
             let Pt { x, y } = translate::iter_to_real(x, y, oct, self.c).u32();
             if x < width && y < height {
                 image.put_pixel(x, y, color);
             }
+            stats.record(x < width && y < height);
         }
+        stats
     }
 }
 
+impl Iterator for Annulus {
+    type Item = (Pt<i32>, Pt<i32>);
+
+    /// Returns the next run of pixels as a pair of image coordinates.
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y1, y2) = self.next_line(None)?;
+        let a = translate::iter_to_real(x, y1.min(y2), self.oct, self.c);
+        let b = translate::iter_to_real(x, y1.max(y2), self.oct, self.c);
+        Some((a, b))
+    }
+}
+
+/// Returns the points on a line from `a` to `b`, excluding both end points.
+fn edge_points(a: Pt<i32>, b: Pt<i32>) -> Vec<Pt<i32>> {
+    let mut points: Vec<_> = crate::lines::LineIter::new(a, b).collect();
+    if points.first() != Some(&a) {
+        points.reverse();
+    }
+    points.retain(|&p| p != a && p != b);
+    points
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,6 +1070,98 @@ mod tests {
         }
     }
 
+    #[test]
+    fn annulus_zero_radii() {
+        let mut image = crate::test::img::blank((5, 5));
+        let color = image::Rgba([255, 0, 0, 255]);
+        super::annulus(&mut image, 0, 90, 0, 0, (2, 2), color);
+
+        for (x, y, p) in image.enumerate_pixels() {
+            assert_eq!(*p == color, (x, y) == (2, 2), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn annulus_full_sweep() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for r in [10, 37, 190] {
+            let mut ring = crate::test::img::blank((400, 400));
+            super::thick_circle(&mut ring, r + 5, 11, (200, 200), color);
+
+            for (start, end) in [(0, 0), (45, 45), (10, 370), (100, -260), (200, 920)] {
+                let mut image = crate::test::img::blank((400, 400));
+                Annulus::new(start, end, r, r + 10, (200, 200)).draw(&mut image, color);
+                assert_eq!(ring, image, "r={r} start={start} end={end}");
+            }
+        }
+    }
+
+    #[test]
+    fn annulus_points() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for (start, end, ri, ro) in [(0, 180, 20, 40), (100, 30, 0, 25), (10, 10, 15, 30)] {
+            let annulus = Annulus::new(start, end, ri, ro, (50, 50));
+
+            let mut expected = crate::test::img::blank((100, 100));
+            annulus.clone().draw(&mut expected, color);
+
+            let mut image = crate::test::img::blank((100, 100));
+            for p in annulus.points() {
+                image.put_pixel(p.x() as u32, p.y() as u32, color);
+            }
+            assert_eq!(expected, image, "start={start} end={end}");
+        }
+    }
+
+    #[test]
+    fn annulus_spans() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let annulus = Annulus::new(30, 250, 10, 30, (50, 50));
+
+        let mut expected = crate::test::img::blank((100, 100));
+        annulus.clone().draw(&mut expected, color);
+
+        let mut image = crate::test::img::blank((100, 100));
+        for (a, b) in annulus {
+            assert!(a.x() == b.x() || a.y() == b.y(), "{a:?} {b:?}");
+            for p in crate::lines::LineIter::new(a, b) {
+                image.put_pixel(p.x() as u32, p.y() as u32, color);
+            }
+        }
+        assert_eq!(expected, image);
+    }
+
+    #[test]
+    fn annulus_outline_points() {
+        let outline = Annulus::new(0, 90, 10, 20, (50, 50)).outline_points();
+
+        assert_eq!(outline[0], Pt::new(70, 50));
+        assert!(outline.contains(&Pt::new(50, 30)));
+        assert!(outline.contains(&Pt::new(50, 40)));
+        assert!(outline.contains(&Pt::new(60, 50)));
+        // Edges between the arcs are included
+        assert!(outline.contains(&Pt::new(50, 35)));
+        assert!(outline.contains(&Pt::new(65, 50)));
+
+        // Consecutive points are close together, including the closing edge.
+        // Arcs can skip the pixel on the diagonal between two octants.
+        let mut closed = outline.clone();
+        closed.push(outline[0]);
+        for w in closed.windows(2) {
+            let d = (w[1].x() - w[0].x()).abs().max((w[1].y() - w[0].y()).abs());
+            assert!(d <= 2, "{:?} -> {:?}", w[0], w[1]);
+        }
+    }
+
+    #[test]
+    fn annulus_try_new() {
+        assert!(Annulus::try_new(0, 90, 10, 20, (20, 20)).is_ok());
+        assert_eq!(
+            Annulus::try_new(0, 90, 10, -20, (20, 20)).unwrap_err(),
+            Error::InvalidRadius(-20.0)
+        );
+    }
+
     #[test]
     fn pie_slice() -> Result<(), image::ImageError> {
         crate::logger(crate::LOG_LEVEL);
@@ -643,4 +1222,300 @@ mod tests {
 
         image.save("images/thick_circle.png")
     }
+
+    #[test]
+    fn annulus_contains() {
+        for (start, end, ri, ro) in [
+            (0, 180, 20, 40),
+            (100, 30, 0, 25),
+            (10, 10, 15, 30),
+            (200, 290, 5, 45),
+        ] {
+            let annulus = Annulus::new(start, end, ri, ro, (50, 50));
+            for pt in annulus.points() {
+                assert!(annulus.contains(pt), "{pt:?} start={start} end={end}");
+            }
+        }
+
+        let annulus = Annulus::new(0, 90, 10, 20, (50, 50));
+        assert!(!annulus.contains((50, 50)));
+        assert!(!annulus.contains((35, 50)));
+        assert!(!annulus.contains((50, 65)));
+        assert!(!annulus.contains((75, 50)));
+    }
+
+    #[test]
+    fn annulus_bounding_box() {
+        for start in (0..360).step_by(15) {
+            for end in (0..360).step_by(20) {
+                for (ri, ro) in [(0, 0), (0, 5), (1, 2), (3, 9), (20, 40), (7, 120)] {
+                    let annulus = Annulus::new(start, end, ri, ro, (50, 50));
+                    let msg = format!("start={start} end={end} ri={ri} ro={ro}");
+                    crate::test::check_bounds(annulus.bounding_box(), &annulus.points(), &msg);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_draw_matches_stats() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for center in [(20, 20), (-15, 10), (45, 45), (20, -100), (200, 20)] {
+            for (start, end) in [(0, 360), (30, 200), (300, 45)] {
+                let annulus = Annulus::new(start, end, 8, 25, center);
+                let mut image = crate::test::img::blank((40, 30));
+                annulus.clone().draw(&mut image, color);
+                let mut expected = crate::test::img::blank((40, 30));
+                annulus.draw_with_stats(&mut expected, color);
+                assert_eq!(image, expected, "{center:?} {start} {end}");
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_draw_skips_columns() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let centers = [
+            (20, 15),
+            (-60, 10),
+            (90, -70),
+            (20, 120),
+            (-45, -45),
+            (0, 0),
+        ];
+        let angles = [(0, 360), (10, 80), (100, 340), (275, 95), (45, 46)];
+        for center in centers {
+            for (start, end) in angles {
+                for (ri, ro) in [(0, 6), (8, 25), (30, 31), (40, 90), (75, 130)] {
+                    let annulus = Annulus::new(start, end, ri, ro, center);
+                    let mut image = crate::test::img::blank((40, 30));
+                    annulus.clone().draw(&mut image, color);
+                    let mut expected = crate::test::img::blank((40, 30));
+                    annulus.draw_with_stats(&mut expected, color);
+                    assert_eq!(image, expected, "{center:?} {start} {end} {ri} {ro}");
+                }
+            }
+        }
+
+        // Huge radii only calculate the columns crossing the image
+        let mut image = crate::test::img::blank((20, 20));
+        Annulus::new(0, 360, i32::MAX - 10, i32::MAX, (10, 10)).draw(&mut image, color);
+        assert!(image.pixels().all(|&p| p != color));
+
+        let r = 1 << 30;
+        Annulus::new(0, 360, r - 5, r, (10, 10 + r)).draw(&mut image, color);
+        assert_eq!(*image.get_pixel(0, 10), color);
+        assert_eq!(*image.get_pixel(19, 15), color);
+        assert_ne!(*image.get_pixel(10, 9), color);
+        assert_ne!(*image.get_pixel(10, 16), color);
+    }
+
+    #[test]
+    fn zero_inner_radius_fills_sector() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for r in [1, 2, 5, 25] {
+            for (start, end) in [
+                (0, 90),
+                (30, 100),
+                (10, 350),
+                (200, 20),
+                (350, 10),
+                (0, 360),
+            ] {
+                let annulus = Annulus::new(start, end, 0, r, (50, 50));
+                let msg = format!("r={r} start={start} end={end}");
+                let points = annulus.points();
+                assert!(points.contains(&Pt::new(50, 50)), "{msg}");
+
+                // Every pixel clearly inside the sector is drawn, so there is
+                // no gap around the center, and nothing outside of it is
+                let sweep = f64::from(i32::rem_euclid(end - start, 360));
+                let sweep = if sweep == 0.0 { 360.0 } else { sweep };
+                for y in 0..100 {
+                    for x in 0..100 {
+                        let (dx, dy) = (f64::from(x - 50), f64::from(50 - y));
+                        let d = dx.hypot(dy);
+                        if d < 1.0 || d > f64::from(r) - 1.0 {
+                            continue;
+                        }
+                        let margin = 1.5 / d * 180.0 / std::f64::consts::PI;
+                        let a = (dy.atan2(dx).to_degrees() - f64::from(start)).rem_euclid(360.0);
+                        if a > margin && a < sweep - margin {
+                            assert!(points.contains(&Pt::new(x, y)), "({x}, {y}) {msg}");
+                        }
+                    }
+                }
+                for pt in &points {
+                    assert!(annulus.contains(*pt), "{pt:?} {msg}");
+                }
+
+                let mut expected = crate::test::img::blank((100, 100));
+                super::pie_slice_filled(&mut expected, start, end, r, (50, 50), color);
+                let mut image = crate::test::img::blank((100, 100));
+                annulus.draw(&mut image, color);
+                assert_eq!(expected, image, "{msg}");
+            }
+        }
+    }
+
+    #[test]
+    fn pie_slice_outline() -> Result<(), image::ImageError> {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((100, 100));
+        super::pie_slice(&mut image, 0, 90, 30, (50, 50), color);
+
+        // The arc, both edges, and the center, but not the inside
+        for (x, y) in [(80, 50), (50, 20), (50, 50), (65, 50), (50, 35), (76, 35)] {
+            assert_eq!(*image.get_pixel(x, y), color, "({x}, {y})");
+        }
+        assert_ne!(*image.get_pixel(60, 40), color);
+        assert_ne!(*image.get_pixel(40, 50), color);
+
+        // Every pixel of the outline is on the edge of the filled slice
+        let mut filled = crate::test::img::blank((100, 100));
+        super::pie_slice_filled(&mut filled, 0, 90, 30, (50, 50), color);
+        for (x, y, px) in image.enumerate_pixels() {
+            if *px == color {
+                assert_eq!(*filled.get_pixel(x, y), color, "({x}, {y})");
+            }
+        }
+
+        // A complete sweep is a circle, without a dot in the center
+        let mut full = crate::test::img::blank((100, 100));
+        super::pie_slice(&mut full, 30, 30, 30, (50, 50), color);
+        let mut circle = crate::test::img::blank((100, 100));
+        crate::conics::circle(&mut circle, 30, (50, 50), color);
+        assert_eq!(full, circle);
+
+        // Clipped without panicking
+        super::pie_slice(&mut image, 200, 340, 60, (90, 10), color);
+        image.save("images/pie_slice_outline.png")
+    }
+
+    #[test]
+    fn annulus_outline_edges() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let fill = image::Rgba([0, 0, 255, 255]);
+        for (start, end, ri, ro) in [(0, 90, 10, 30), (100, 30, 5, 40), (200, 200, 15, 30)] {
+            let mut image = crate::test::img::blank((100, 100));
+            super::annulus(&mut image, start, end, ri, ro, (50, 50), fill);
+            super::annulus_outline(&mut image, start, end, ri, ro, (50, 50), color);
+
+            // The outline stays on the filled area and covers the pixels on
+            // its outer and inner edges
+            let annulus = Annulus::new(start, end, ri, ro, (50, 50));
+            for (x, y, px) in image.enumerate_pixels() {
+                #[allow(clippy::cast_possible_wrap)]
+                let pt = Pt::new(x as i32, y as i32);
+                if *px == color {
+                    assert!(annulus.contains(pt), "{pt:?} start={start} end={end}");
+                }
+            }
+            for pt in crate::conics::Arc::new(start, end, ro, (50, 50)) {
+                let px = *image.get_pixel(pt.x() as u32, pt.y() as u32);
+                assert_eq!(px, color, "{pt:?} start={start} end={end}");
+            }
+            assert!(image.pixels().any(|px| *px == fill));
+        }
+
+        let mut image = crate::test::img::blank((100, 100));
+        super::annulus_outline(&mut image, 0, 90, 10, 30, (50, 50), color);
+        assert_eq!(*image.get_pixel(50, 30), color);
+        assert_eq!(*image.get_pixel(70, 50), color);
+        assert_ne!(*image.get_pixel(70, 30), color);
+
+        // Entirely off screen
+        let mut image = crate::test::img::blank((100, 100));
+        super::annulus_outline(&mut image, 0, 90, 10, 30, (-50, 50), color);
+        assert_eq!(image, crate::test::img::blank((100, 100)));
+    }
+
+    #[test]
+    fn outline_matches_points() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for center in [(20, 15), (-10, 10), (45, -5), (20, 60), (0, 0)] {
+            for (start, end) in [(0, 360), (10, 80), (100, 340), (275, 95), (45, 46)] {
+                for (ri, ro) in [(0, 6), (8, 25), (30, 31)] {
+                    let annulus = Annulus::new(start, end, ri, ro, center);
+                    let mut image = crate::test::img::blank((40, 30));
+                    annulus.draw_outline(&mut image, color);
+
+                    let mut expected = crate::test::img::blank((40, 30));
+                    for pt in annulus.outline_points() {
+                        if let Ok(pt) = Pt::<u32>::try_from(pt) {
+                            if pt.x() < 40 && pt.y() < 30 {
+                                expected.put_pixel(pt.x(), pt.y(), color);
+                            }
+                        }
+                    }
+                    assert_eq!(image, expected, "{center:?} {start} {end} {ri} {ro}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_outlines() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let bg = image::Rgba([255, 255, 255, 255]);
+
+        // Only the parts of the arcs and edges crossing the image are drawn
+        let mut image = crate::test::img::blank((20, 20));
+        super::annulus_outline(&mut image, 0, 90, 2, i32::MAX - 20, (10, 10), color);
+        assert_eq!(*image.get_pixel(15, 10), color);
+        assert_eq!(*image.get_pixel(10, 5), color);
+        assert_eq!(*image.get_pixel(15, 5), bg);
+
+        let mut image = crate::test::img::blank((20, 20));
+        super::annulus_outline(&mut image, 0, 360, i32::MAX - 10, i32::MAX, (10, 10), color);
+        assert!(image.pixels().all(|&p| p == bg));
+    }
+
+    #[test]
+    fn clipped_pie_slices() {
+        use image::GenericImageView;
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        for (cx, cy) in [(50, 50), (25, 40), (90, 60), (40, 95)] {
+            for (start, end) in [(0, 90), (100, 340), (275, 95), (30, 30)] {
+                let mut full = crate::test::img::blank((120, 120));
+                super::pie_slice(&mut full, start, end, 40, (cx, cy), color);
+
+                let mut image = crate::test::img::blank((40, 30));
+                super::pie_slice(&mut image, start, end, 40, (cx - 30, cy - 30), color);
+                let expected = full.view(30, 30, 40, 30).to_image();
+                assert_eq!(image, expected, "({cx}, {cy}) {start} {end}");
+            }
+        }
+    }
+
+    #[test]
+    fn huge_pie_slices() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let bg = image::Rgba([255, 255, 255, 255]);
+
+        // Both edges cross the image, the arc is far outside of it
+        let mut image = crate::test::img::blank((20, 20));
+        super::pie_slice(&mut image, 0, 90, i32::MAX - 20, (10, 10), color);
+        for (x, y) in [(10, 10), (15, 10), (19, 10), (10, 5), (10, 0)] {
+            assert_eq!(*image.get_pixel(x, y), color, "({x}, {y})");
+        }
+        assert_eq!(*image.get_pixel(15, 5), bg);
+
+        let mut image = crate::test::img::blank((20, 20));
+        super::pie_slice(&mut image, 0, 360, i32::MAX - 20, (10, 10), color);
+        assert!(image.pixels().all(|&p| p == bg));
+    }
+
+    #[test]
+    fn huge_thick_arcs() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let bg = image::Rgba([255, 255, 255, 255]);
+        let mut image = crate::test::img::blank((20, 20));
+        super::thick_arc(&mut image, 0, 90, i32::MAX, i16::MAX, (10, 10), color);
+        super::thick_arc(&mut image, 0, 90, i32::MAX - 5, 20, (10, 10), color);
+        let stroke = crate::conics::ArcStroke::new(8.0);
+        crate::conics::stroke_arc(&mut image, 0, 90, i32::MAX, (10, 10), stroke, color);
+        assert!(image.pixels().all(|&p| p == bg));
+    }
 }
@@ -8,10 +8,10 @@ pub(super) struct Edge {
 }
 
 impl Edge {
-    pub(super) fn blank(angle: f64) -> Self {
+    pub(super) fn blank(angle: f64, oct: u8) -> Self {
         Self {
             angle,
-            oct: crate::angle::angle_to_octant(angle),
+            oct,
             slope: 0.0,
             int: 0.0,
         }
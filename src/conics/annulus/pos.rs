@@ -5,7 +5,7 @@ use crate::{calc_error, Pt};
 pub(super) struct Pos {
     pub(super) x: i32,
     pub(super) y: i32,
-    pub(super) d: i32,  // decision parameter
+    pub(super) d: i64,  // decision parameter
     pub(super) ex: i32, // ending x coordinate
     pub(super) ey: i32, // ending y coordinate
     pub(super) r: i32,
@@ -37,6 +37,16 @@ impl Pos {
         }
     }
 
+    /// Jumps ahead to column `x`, or the end of the arc if that comes first,
+    /// without stepping through the columns before it.
+    pub(super) fn skip_to(&mut self, x: i32) {
+        if x <= self.x {
+            return;
+        }
+        self.x = x.min(self.ex);
+        (self.y, self.d) = crate::midpoint_at(self.x, self.r);
+    }
+
     pub(super) fn inc(&mut self) {
         if self.x >= self.ex {
             return;
@@ -44,9 +54,9 @@ impl Pos {
         self.x += 1;
         if self.d > 0 {
             self.y -= 1;
-            self.d += 2 * (self.x - self.y) + 1;
+            self.d += 2 * i64::from(self.x - self.y) + 1;
         } else {
-            self.d += 2 * self.x + 1;
+            self.d += 2 * i64::from(self.x) + 1;
         }
     }
 }
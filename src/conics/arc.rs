@@ -2,11 +2,17 @@ mod bounds;
 mod edge;
 mod pos;
 
-use crate::{angle, translate, Pt};
+use super::CircleAlgorithm;
+use crate::curves::CubicBezier;
+use crate::{angle, translate, Error, Pt};
 use bounds::Bounds;
 use edge::Edge;
 use pos::Pos;
 
+/// Most curves [`Arc::to_beziers`] will split an arc into, only reached when
+/// the allowed error is tiny (or zero).
+const MAX_BEZIERS: usize = 1024;
+
 /// Draws a circular arc from a given start angle to an end angle.
 ///
 /// A floating-point angle will represent an angle in radians.  Integer types
@@ -66,10 +72,49 @@ pub fn arc<A, C, I, T>(
     Arc::new(start_angle, end_angle, radius, center).draw(image, color);
 }
 
+/// Calls a closure for every point of a circular arc instead of drawing to an image.
+///
+/// Points are visited in order from the start angle to the end angle and are
+/// not clipped.  Angles are interpreted the same way as [`arc`].
+///
+/// ```
+/// use freehand::conics::arc_for_each;
+///
+/// let mut points = Vec::new();
+/// arc_for_each(0, 90, 10, (20, 20), |pt| points.push(pt));
+///
+/// assert_eq!(points.first(), Some(&freehand::Pt::new(30, 20)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+pub fn arc_for_each<A, C, F, T>(start_angle: A, end_angle: A, radius: T, center: C, f: F)
+where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    F: FnMut(Pt<i32>),
+    T: Into<i32> + Copy,
+{
+    Arc::new(start_angle, end_angle, radius, center).for_each(f);
+}
+
 /// A structure for iterating over points in a circular arc.
 ///
-/// Does not implement the `Iterator` trait because points for even octants would
-/// be returned in reverse order.
+/// Implements [`Iterator`] and returns coordinates in order from the start angle
+/// to the end angle.  Points in even octants are calculated in reverse, so those
+/// octants are buffered before being returned.
+///
+/// ```
+/// use freehand::conics::Arc;
+///
+/// let arc = Arc::new(0, 90, 10, (20, 20));
+/// let points: Vec<_> = arc.collect();
+///
+/// assert_eq!(points.first(), Some(&freehand::Pt::new(30, 20)));
+/// assert_eq!(points.last(), Some(&freehand::Pt::new(20, 10)));
+/// ```
 ///
 /// ```
 /// use image::{RgbaImage, Rgba};
@@ -108,6 +153,17 @@ pub struct Arc {
     /// If `revisit` is true iteration will not immediately end when the octant is finished.
     /// This is set to true for the first octant when `start.oct == end.oct` and `start.angle > end.angle`
     revisit: bool,
+    /// Whether the arc is a complete circle.  Full circles iterate over whole
+    /// octants and ignore the edge angles so no gap is left at the seam.
+    full: bool,
+    /// Buffered points from an even octant, stored in reverse order
+    #[cfg_attr(feature = "serde", serde(skip))]
+    buf: Vec<Pt<i32>>,
+    /// Set once iteration has finished.  Only needed for a radius of 0.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    done: bool,
+    /// How the pixels of each octant are chosen
+    alg: CircleAlgorithm,
 }
 
 impl Arc {
@@ -120,9 +176,14 @@ impl Arc {
     /// larger than`2*PI` for radians).  Angles will be normalized into a range
     /// of 0..PI*2.
     ///
-    /// # Panic
+    /// If the start and end angles are the same, or the end angle is at least a
+    /// full turn away from the start angle, a complete circle is drawn.
     ///
-    /// Panics if radius is less than or equal to 0
+    /// A radius of 0 will draw a single pixel at the center.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radius is negative
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
@@ -138,17 +199,67 @@ impl Arc {
         T: Into<i32> + Copy,
         C: crate::pt::Point<T>,
     {
-        let start = angle::normalize(start_angle.radians());
-        let end = angle::normalize(end_angle.radians() - crate::TINY);
+        match Self::try_new(start_angle, end_angle, radius, center) {
+            Ok(arc) => arc,
+            Err(e) => panic!("{e}"),
+        }
+    }
 
-        let mut arc = Self::blank(start, end, radius, center);
+    /// Creates a new [`Arc`], returning an error instead of panicking if the
+    /// radius is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRadius`] if radius is negative
+    ///
+    /// ```
+    /// # use freehand::conics::Arc;
+    /// assert!(Arc::try_new(0, 180, 190, (200, 200)).is_ok());
+    /// assert!(Arc::try_new(0, 180, -190, (200, 200)).is_err());
+    /// ```
+    pub fn try_new<A, T, C>(
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+    ) -> Result<Self, Error>
+    where
+        A: crate::Angle,
+        T: Into<i32> + Copy,
+        C: crate::pt::Point<T>,
+    {
+        let (start_oct, start) = angle::start_octant(start_angle);
+
+        if angle::is_full_turn(start_angle, end_angle) {
+            // Start at the beginning of the octant and go all the way around
+            let prev = (start_oct + 6) % 8 + 1;
+            let mut arc = Self::blank(
+                Edge::new(angle::octant_start_angle(start_oct), start_oct),
+                Edge::new(angle::octant_end_angle(prev), prev),
+                radius,
+                center,
+            )?;
+            arc.full = true;
+            arc.pos = Pos::new(start_oct, Bounds::default(), arc.r, arc.c);
+            return Ok(arc);
+        }
+
+        // An end angle on an octant boundary stays in the octant before it
+        let (end_oct, end) = angle::end_octant(end_angle);
+
+        let mut arc = Self::blank(
+            Edge::new(start, start_oct),
+            Edge::new(end, end_oct),
+            radius,
+            center,
+        )?;
         let bounds = Bounds::start_bounds(&arc.start, &arc.end, arc.revisit);
 
         arc.pos = Pos::new(arc.start.oct, bounds, arc.r, arc.c);
-        arc
+        Ok(arc)
     }
 
-    fn blank<T, C>(start_angle: f64, end_angle: f64, r: T, c: C) -> Self
+    fn blank<T, C>(start: Edge, end: Edge, r: T, c: C) -> Result<Self, Error>
     where
         T: Into<i32> + Copy,
         C: crate::pt::Point<T>,
@@ -156,28 +267,50 @@ impl Arc {
         let c = Pt::new(c.x().into(), c.y().into());
         let r = r.into();
 
-        assert!(r > 0, "Radius must be larger than 0.  radius={r}");
-
-        let start_oct = crate::angle::angle_to_octant(start_angle);
-        let end_oct = crate::angle::angle_to_octant(end_angle);
+        if r.is_negative() {
+            return Err(Error::InvalidRadius(r.into()));
+        }
 
-        Self {
-            pos: Pos::start(start_oct, r),
-            start: Edge::new(start_angle, start_oct),
-            end: Edge::new(end_angle, end_oct),
+        Ok(Self {
+            pos: Pos::start(start.oct, r),
+            revisit: start.oct == end.oct && start.angle > end.angle,
+            start,
+            end,
             c,
             r,
-            revisit: start_oct == end_oct && start_angle > end_angle,
-        }
+            full: false,
+            buf: Vec::new(),
+            done: false,
+            alg: CircleAlgorithm::default(),
+        })
     }
 
     /// Create an iterator over a single circular octant
     ///
     /// # Panics
     ///
-    /// Panics if radius is less than or equal to 0
+    /// Panics if radius is negative or the octant is not in the range `1..=8`
     ///
     pub fn octant<T, C>(oct: u8, r: T, c: C) -> Self
+    where
+        C: crate::pt::Point<T>,
+        T: Into<i32> + Copy,
+    {
+        match Self::try_octant(oct, r, c) {
+            Ok(arc) => arc,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Create an iterator over a single circular octant, returning an error
+    /// instead of panicking if the radius or octant are invalid.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::InvalidRadius`] if radius is negative
+    /// - Returns [`Error::InvalidOctant`] if the octant is not in the range `1..=8`
+    ///
+    pub fn try_octant<T, C>(oct: u8, r: T, c: C) -> Result<Self, Error>
     where
         C: crate::pt::Point<T>,
         T: Into<i32> + Copy,
@@ -185,32 +318,69 @@ impl Arc {
         let c = Pt::new(c.x().into(), c.y().into());
         let r = r.into();
 
-        assert!(r > 0, "Radius be must larger than 0");
+        if r.is_negative() {
+            return Err(Error::InvalidRadius(r.into()));
+        }
 
-        assert!(
-            (1..=8).contains(&oct),
-            "Invalid octant. Valid octants are 1 through 8"
-        );
+        if !(1..=8).contains(&oct) {
+            return Err(Error::InvalidOctant(oct));
+        }
 
         let pos = Pos::start(oct, r);
 
         let start = Edge::new(angle::octant_start_angle(oct), oct);
         let end = Edge::new(angle::octant_end_angle(oct), oct);
 
-        Self {
+        Ok(Self {
             pos,
             start,
             end,
             c,
             r,
             revisit: false,
-        }
+            full: false,
+            buf: Vec::new(),
+            done: false,
+            alg: CircleAlgorithm::default(),
+        })
+    }
+
+    /// Sets the algorithm used to choose the arc's pixels.  Arcs use
+    /// [`CircleAlgorithm::Midpoint`] unless this is called before iterating.
+    ///
+    /// ```
+    /// use freehand::conics::{Arc, CircleAlgorithm};
+    ///
+    /// let arc = Arc::new(0, 90, 10, (20, 20)).with_algorithm(CircleAlgorithm::Distance);
+    /// assert_eq!(arc.algorithm(), CircleAlgorithm::Distance);
+    ///
+    /// // Every pixel is within half a pixel of the radius
+    /// for pt in arc {
+    ///     let d = f64::from(pt.x() - 20).hypot(f64::from(pt.y() - 20));
+    ///     assert!((d - 10.0).abs() <= 0.5);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_algorithm(mut self, alg: CircleAlgorithm) -> Self {
+        self.alg = alg;
+        self.pos = self.pos.with_algorithm(alg);
+        self
+    }
+
+    /// Returns the algorithm used to choose the arc's pixels
+    #[must_use]
+    pub fn algorithm(&self) -> CircleAlgorithm {
+        self.alg
     }
 
     pub(super) fn restart(&mut self) {
         let oct = self.pos.oct % 8 + 1;
-        let bounds = Bounds::bounds_from_edges(oct, &self.start, &self.end, self.revisit);
-        self.pos = Pos::new(oct, bounds, self.r, self.c);
+        let bounds = if self.full {
+            Bounds::default()
+        } else {
+            Bounds::bounds_from_edges(oct, &self.start, &self.end, self.revisit)
+        };
+        self.pos = Pos::new(oct, bounds, self.r, self.c).with_algorithm(self.alg);
         self.revisit = false;
     }
 
@@ -218,7 +388,9 @@ impl Arc {
         self.pos.oct == self.end.oct && !self.revisit
     }
 
-    /// Draw the specified arc by iterating over its points.
+    /// Draw the specified arc.  Only the columns of each octant that cross the
+    /// image are calculated.
+    ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # use freehand::conics::Arc;
@@ -230,27 +402,398 @@ impl Arc {
     /// arc.draw(&mut image, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    pub fn draw<I>(mut self, image: &mut I, color: I::Pixel)
+    pub fn draw<I>(self, image: &mut I, color: I::Pixel)
     where
         I: image::GenericImage,
     {
-        loop {
-            if self.pos.stop() {
-                if self.end() {
-                    break;
+        // Only the columns of each octant that cross the image are calculated
+        let size = (image.width(), image.height());
+        self.visit(Some(size), |pt| {
+            let pt: Result<Pt<u32>, Error> = pt.try_into();
+            if let Ok(pt) = pt {
+                if pt.x() < size.0 && pt.y() < size.1 {
+                    image.put_pixel(pt.x(), pt.y(), color);
                 }
-                self.restart();
-                continue;
             }
+        });
+    }
 
-            let pt: Result<Pt<u32>, &'static str> = self.pt().try_into();
-            if let Ok(pt) = pt {
-                if pt.x() < image.width() && pt.y() < image.height() {
+    /// Draws the arc and returns how many pixels were drawn and clipped.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::conics::Arc;
+    /// # let mut image = RgbaImage::new(100, 100);
+    ///
+    /// let stats = Arc::new(0, 360, 10, (0, 50)).draw_with_stats(&mut image, Rgba([255, 0, 0, 255]));
+    /// assert!(stats.drawn > 0 && stats.clipped > 0);
+    /// ```
+    pub fn draw_with_stats<I>(self, image: &mut I, color: I::Pixel) -> crate::DrawStats
+    where
+        I: image::GenericImage,
+    {
+        let mut stats = crate::DrawStats::default();
+        self.visit(None, |pt| {
+            let pt: Result<Pt<u32>, Error> = pt.try_into();
+            match pt {
+                Ok(pt) if pt.x() < image.width() && pt.y() < image.height() => {
                     image.put_pixel(pt.x(), pt.y(), color);
+                    stats.record(true);
                 }
+                _ => stats.record(false),
             }
-            self.pos.inc();
+        });
+        stats
+    }
+
+    /// Calls a closure for the remaining points of the arc one octant at a
+    /// time, without buffering the even octants the way the [`Iterator`] does.
+    /// Points in even octants are visited in reverse.
+    ///
+    /// If the size of an image is given, only the columns of each octant with
+    /// points inside of the image are calculated, skipping directly to them.
+    /// Points in those columns can still be outside of the image.
+    fn visit<F>(mut self, clip: Option<(u32, u32)>, mut f: F)
+    where
+        F: FnMut(Pt<i32>),
+    {
+        self.buf.drain(..).rev().for_each(&mut f);
+        if self.done {
+            return;
+        }
+        if self.r == 0 {
+            f(self.c);
+            return;
         }
+        loop {
+            let (first, last) = clip.map_or((0, i64::MAX), |size| self.visible_columns(size));
+            if first <= last {
+                if i64::from(self.pos.x) < first {
+                    self.pos = self.pos.at(first.min(i32::MAX.into()) as i32);
+                }
+                while !self.pos.stop() && i64::from(self.pos.x) <= last {
+                    f(self.pt());
+                    self.pos.inc();
+                }
+            }
+            if self.end() {
+                return;
+            }
+            self.restart();
+        }
+    }
+
+    /// Returns the range of columns of the current octant (in iterator
+    /// coordinates) that can have points inside of an image of the given
+    /// size.  The range is empty if `first > last`.
+    fn visible_columns(&self, (width, height): (u32, u32)) -> (i64, i64) {
+        // The range of offsets from the center along one of the image's axes
+        // that are inside of the image, for an offset that moves in the
+        // direction of `sign`
+        let span = |horizontal: bool, sign: i8| {
+            let (c, n) = if horizontal {
+                (self.c.x(), width)
+            } else {
+                (self.c.y(), height)
+            };
+            let (c, last) = (i64::from(c), i64::from(n) - 1);
+            if sign > 0 {
+                (-c, last - c)
+            } else {
+                (c - last, c)
+            }
+        };
+        // How the x and y iterator coordinates map onto the image's axes
+        let (cols, rows) = match self.pos.oct {
+            1 => (span(false, -1), span(true, 1)),
+            2 => (span(true, 1), span(false, -1)),
+            3 => (span(true, -1), span(false, -1)),
+            4 => (span(false, -1), span(true, -1)),
+            5 => (span(false, 1), span(true, -1)),
+            6 => (span(true, -1), span(false, 1)),
+            7 => (span(true, 1), span(false, 1)),
+            _ => (span(false, 1), span(true, 1)),
+        };
+        if rows.1 < 0 {
+            return (1, 0);
+        }
+        super::cir::intersect(cols, super::cir::reaching(self.r, (rows.0.max(0), rows.1)))
+    }
+
+    /// Returns true if a point lies on the arc.
+    ///
+    /// A point is on the arc if it is within about half a pixel of the circle and
+    /// between the start and end angles.
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    ///
+    /// let arc = Arc::new(0, 90, 10, (20, 20));
+    /// assert!(arc.contains((30, 20)));
+    /// assert!(!arc.contains((10, 20)));
+    /// assert!(!arc.contains((25, 20)));
+    /// ```
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: crate::pt::Point<i32>,
+    {
+        let pt = Pt::new(f64::from(pt.x()), f64::from(pt.y()));
+        let c = Pt::new(f64::from(self.c.x()), f64::from(self.c.y()));
+        let r = f64::from(self.r);
+        let d = (pt.x() - c.x()).hypot(pt.y() - c.y());
+
+        if (d - r).abs() > super::HIT_TOLERANCE {
+            return false;
+        }
+        if self.full || self.r == 0 {
+            return true;
+        }
+
+        let a = angle::point_angle(c, pt);
+        angle::in_sweep(
+            a,
+            self.start.angle,
+            self.end.angle,
+            super::HIT_TOLERANCE / r,
+        )
+    }
+
+    /// Returns the smallest rectangle covering every pixel the arc has left to
+    /// visit, which is the whole arc unless it has already been iterated over.
+    ///
+    /// This is calculated from the first and last points of each octant
+    /// without iterating over the arc.  An arc too small to cover any pixels
+    /// has an empty rectangle at its center.
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    /// use freehand::shapes::Rect;
+    ///
+    /// let arc = Arc::new(0, 90, 10, (20, 20));
+    /// assert_eq!(arc.bounding_box(), Rect::new((20, 10), 11, 11));
+    /// ```
+    #[must_use]
+    pub fn bounding_box(&self) -> crate::shapes::Rect {
+        self.extremes()
+            .map_or(crate::shapes::Rect::new(self.c, 0, 0), super::bounds_rect)
+    }
+
+    /// Returns the smallest and largest coordinates of the points the arc has
+    /// left to visit, as `(x1, y1, x2, y2)`, or `None` if there are none.
+    ///
+    /// Points move steadily away from one axis and towards the other within an
+    /// octant, so the furthest points of each octant are its first and last.
+    pub(super) fn extremes(&self) -> Option<(i32, i32, i32, i32)> {
+        let mut bounds: Option<(i32, i32, i32, i32)> = None;
+        let mut add = |pt: Pt<i32>| {
+            let (x, y) = (pt.x(), pt.y());
+            bounds = Some(bounds.map_or((x, y, x, y), |(x1, y1, x2, y2)| {
+                (x1.min(x), y1.min(y), x2.max(x), y2.max(y))
+            }));
+        };
+        self.octant_ends(|a, b| {
+            add(a);
+            add(b);
+        });
+        bounds
+    }
+
+    /// Returns the first and last points the arc has left to visit, in the
+    /// order the [`Iterator`] returns them, without iterating over the arc.
+    pub(super) fn ends(&self) -> Option<(Pt<i32>, Pt<i32>)> {
+        let mut ends: Option<(Pt<i32>, Pt<i32>)> = None;
+        self.octant_ends(|a, b| {
+            ends = Some(ends.map_or((a, b), |(first, _)| (first, b)));
+        });
+        ends
+    }
+
+    /// Calls a closure with the first and last points of each octant (and of
+    /// any buffered points) the arc has left to visit, in the order the
+    /// [`Iterator`] returns them.  Only the ends of each octant are calculated.
+    fn octant_ends<F>(&self, mut f: F)
+    where
+        F: FnMut(Pt<i32>, Pt<i32>),
+    {
+        // Buffered points are returned from the end of the buffer
+        if let (Some(&first), Some(&last)) = (self.buf.last(), self.buf.first()) {
+            f(first, last);
+        }
+        if self.done {
+            return;
+        }
+        if self.r == 0 {
+            f(self.c, self.c);
+            return;
+        }
+
+        let mut arc = Self {
+            pos: self.pos.clone(),
+            start: self.start.clone(),
+            end: self.end.clone(),
+            buf: Vec::new(),
+            ..*self
+        };
+        loop {
+            if arc.pos.stop() {
+                if arc.end() {
+                    return;
+                }
+                arc.restart();
+                continue;
+            }
+            let a = arc.pt();
+            let b = arc.pos.last().map_or(a, |(x, y)| {
+                translate::iter_to_real(x, y, arc.pos.oct, arc.c)
+            });
+            // Even octants are returned backwards
+            if arc.pos.oct % 2 == 0 {
+                f(b, a);
+            } else {
+                f(a, b);
+            }
+            // Skip the rest of the octant
+            arc.pos.ex = Some(arc.pos.x - 1);
+        }
+    }
+
+    /// Returns the angle covered by the arc, in radians.
+    pub(crate) fn sweep(&self) -> f64 {
+        if self.full {
+            crate::PI2
+        } else {
+            angle::normalize(self.end.angle - self.start.angle)
+        }
+    }
+
+    /// Returns the length of the arc in pixels.
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    ///
+    /// let arc = Arc::new(0, 180, 10, (20, 20));
+    /// assert!((arc.length() - 10.0 * std::f64::consts::PI).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn length(&self) -> f64 {
+        f64::from(self.r) * self.sweep()
+    }
+
+    /// Returns the point on the arc's circle at the given angle.
+    ///
+    /// The angle is not required to be between the start and end angles.
+    /// Angles are interpreted the same way as [`Arc::new`].
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    /// use freehand::Pt;
+    ///
+    /// let arc = Arc::new(0, 180, 10, (20, 20));
+    /// let pt = arc.point_at_angle(90);
+    /// assert!((pt.x() - 20.0).abs() < 1e-9 && (pt.y() - 10.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn point_at_angle<A>(&self, angle: A) -> Pt<f64>
+    where
+        A: crate::Angle,
+    {
+        Pt::from_radian(angle.radians(), self.r, self.c)
+    }
+
+    /// Returns `n` points spaced evenly along the arc, from the start angle to
+    /// the end angle.
+    ///
+    /// Both the start and end points are included, except for complete circles
+    /// where the end point would repeat the start point.  A single sample
+    /// returns the start point.
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    ///
+    /// let points = Arc::new(0, 90, 10, (20, 20)).sample(3);
+    /// assert_eq!(points.len(), 3);
+    /// assert!((points[1].x() - 27.0710678).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn sample(&self, n: usize) -> Vec<Pt<f64>> {
+        let steps = match (n, self.full) {
+            (0, _) => return Vec::new(),
+            (1, _) => return vec![self.point_at_angle(self.start.angle)],
+            (n, true) => n,
+            (n, false) => n - 1,
+        };
+        let step = self.sweep() / steps as f64;
+
+        (0..n)
+            .map(|i| self.point_at_angle((i as f64).mul_add(step, self.start.angle)))
+            .collect()
+    }
+
+    /// Approximates the arc with cubic Bézier curves, in order from the start
+    /// angle to the end angle.
+    ///
+    /// No curve covers more than 90°, and the sweep is split into more curves
+    /// until none of them stray from the arc's circle by more than `max_error`
+    /// pixels.
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    /// use freehand::curves::svg_path;
+    ///
+    /// let arc = Arc::new(0, 270, 100, (200, 200));
+    /// let curves = arc.to_beziers(0.1);
+    /// assert_eq!(curves.len(), 3);
+    ///
+    /// assert!(svg_path(&curves).starts_with("M 300 200 C "));
+    /// ```
+    #[must_use]
+    pub fn to_beziers(&self, max_error: f64) -> Vec<CubicBezier> {
+        let sweep = self.sweep();
+        let r = f64::from(self.r);
+        // Radial error of a Bézier using the standard control point distance
+        let error = |theta: f64| {
+            let q = theta / 4.0;
+            r * 4.0 / 27.0 * q.sin().powi(6) / q.cos().powi(2)
+        };
+
+        let mut n = (sweep / crate::QUAD).ceil().max(1.0) as usize;
+        while n < MAX_BEZIERS && error(sweep / n as f64) > max_error {
+            n += 1;
+        }
+
+        let theta = sweep / n as f64;
+        let k = r * 4.0 / 3.0 * (theta / 4.0).tan();
+        // Tangent direction of increasing angles, with y increasing downwards
+        let tangent = |a: f64, len: f64| Pt::new(-a.sin() * len, -a.cos() * len);
+
+        (0..n)
+            .map(|i| {
+                let a0 = (i as f64).mul_add(theta, self.start.angle);
+                let a1 = a0 + theta;
+                let (start, end) = (self.point_at_angle(a0), self.point_at_angle(a1));
+                let (t0, t1) = (tangent(a0, k), tangent(a1, k));
+                CubicBezier {
+                    start,
+                    ctrl1: Pt::new(start.x() + t0.x(), start.y() + t0.y()),
+                    ctrl2: Pt::new(end.x() - t1.x(), end.y() - t1.y()),
+                    end,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the remaining points of the arc, in order from the start angle.
+    ///
+    /// ```
+    /// use freehand::conics::Arc;
+    ///
+    /// let points = Arc::new(0, 90, 10, (20, 20)).points();
+    /// assert_eq!(points.first(), Some(&freehand::Pt::new(30, 20)));
+    /// ```
+    #[must_use]
+    pub fn points(&self) -> Vec<Pt<i32>> {
+        self.clone().collect()
     }
 
     /// Helper function to translate the current coordinates into a specified octant
@@ -269,11 +812,33 @@ impl Arc {
         self.pos.stop()
     }
 
+    /// Returns true if the current point lies on an axis, where it is shared
+    /// by two neighboring octants
+    pub(super) fn on_axis(&self) -> bool {
+        self.pos.x == 0
+    }
+
+    /// Returns true if the current point lies exactly on a diagonal, where it
+    /// is shared by two neighboring octants
+    pub(super) fn on_diagonal(&self) -> bool {
+        self.pos.x == self.pos.y
+    }
+
     /// Helper function for other modules
     pub(super) fn inc(&mut self) {
         self.pos.inc();
     }
 
+    /// Helper function for other modules
+    pub(super) fn skip_to(&mut self, x: i32) {
+        self.pos.skip_to(x);
+    }
+
+    /// Returns the current position in iterator coordinates
+    pub(super) fn iter_pos(&self) -> (i32, i32) {
+        (self.pos.x, self.pos.y)
+    }
+
     /// Returns the center coordinates
     #[must_use]
     pub fn center(&self) -> Pt<i32> {
@@ -285,6 +850,57 @@ impl Arc {
     pub fn radius(&self) -> i32 {
         self.r
     }
+
+    /// Returns the start angle in radians
+    #[must_use]
+    pub fn start_angle(&self) -> f64 {
+        self.start.angle
+    }
+
+    /// Returns the end angle in radians
+    #[must_use]
+    pub fn end_angle(&self) -> f64 {
+        self.end.angle
+    }
+}
+
+impl Iterator for Arc {
+    type Item = Pt<i32>;
+
+    /// Returns the next point of the arc in order from the start angle.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pt) = self.buf.pop() {
+                return Some(pt);
+            }
+            if self.done {
+                return None;
+            }
+            if self.r == 0 {
+                self.done = true;
+                return Some(self.c);
+            }
+            if self.pos.stop() {
+                if self.end() {
+                    self.done = true;
+                    return None;
+                }
+                self.restart();
+                continue;
+            }
+            if self.pos.oct % 2 == 0 {
+                // Even octants are calculated backwards, so buffer the whole octant
+                while !self.pos.stop() {
+                    self.buf.push(self.pt());
+                    self.pos.inc();
+                }
+                continue;
+            }
+            let pt = self.pt();
+            self.pos.inc();
+            return Some(pt);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -308,4 +924,405 @@ mod tests {
 
         image.save("images/arc.png")
     }
+
+    #[test]
+    fn arc_zero_radius() {
+        let mut image = crate::test::img::blank((5, 5));
+        let color = image::Rgba([255, 0, 0, 255]);
+        Arc::new(0, 90, 0, (2, 2)).draw(&mut image, color);
+
+        for (x, y, p) in image.enumerate_pixels() {
+            assert_eq!(*p == color, (x, y) == (2, 2), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn arc_try_new() {
+        assert!(Arc::try_new(0, 90, 10, (20, 20)).is_ok());
+        assert_eq!(
+            Arc::try_new(0, 90, -10, (20, 20)).unwrap_err(),
+            Error::InvalidRadius(-10.0)
+        );
+        assert_eq!(
+            Arc::try_octant(9, 10, (20, 20)).unwrap_err(),
+            Error::InvalidOctant(9)
+        );
+    }
+
+    #[test]
+    fn arc_ends() {
+        for (start, end) in [
+            (0, 360),
+            (10, 80),
+            (100, 340),
+            (275, 95),
+            (45, 46),
+            (30, 30),
+        ] {
+            for r in [0, 1, 5, 24] {
+                let mut arc = Arc::new(start, end, r, (50, 50));
+                let points = arc.points();
+                let expected = (points[0], points[points.len() - 1]);
+                assert_eq!(arc.ends(), Some(expected), "{start} {end} {r}");
+
+                // Partially iterated arcs, including buffered points
+                arc.next();
+                arc.next();
+                let points = arc.points();
+                let expected = points.first().map(|&a| (a, points[points.len() - 1]));
+                assert_eq!(arc.ends(), expected, "{start} {end} {r}");
+            }
+        }
+    }
+
+    #[test]
+    fn arc_full_sweep() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for r in [10, 37, 190] {
+            let mut circle = crate::test::img::blank((400, 400));
+            crate::conics::circle(&mut circle, r, (200, 200), color);
+
+            for (start, end) in [(45, 45), (10, 370), (100, -260), (200, 920)] {
+                let mut image = crate::test::img::blank((400, 400));
+                Arc::new(start, end, r, (200, 200)).draw(&mut image, color);
+                assert_eq!(circle, image, "r={r} start={start} end={end}");
+            }
+
+            let mut image = crate::test::img::blank((400, 400));
+            Arc::new(RADS, RADS + crate::PI2 * 3.0, r, (200, 200)).draw(&mut image, color);
+            assert_eq!(circle, image, "r={r} multiple revolutions");
+        }
+    }
+
+    #[test]
+    fn arc_octant_boundaries() {
+        let c = Pt::new(100, 100);
+        for r in [5, 10, 37] {
+            // Each point of a full circle is visited once
+            let mut points: Vec<_> = Arc::new(0, 360, r, c).map(|pt| (pt.x(), pt.y())).collect();
+            let len = points.len();
+            points.sort_unstable();
+            points.dedup();
+            assert_eq!(points.len(), len, "r={r}");
+
+            // Arcs include both of their ends, so arcs splitting the circle
+            // on octant boundaries only share the points on those boundaries
+            let first: Vec<_> = Arc::new(0, 135, r, c).collect();
+            let second: Vec<_> = Arc::new(135, 360, r, c).collect();
+            let shared: Vec<_> = first.iter().filter(|pt| second.contains(pt)).collect();
+            assert!(shared.iter().all(|pt| {
+                let (x, y) = (pt.x() - c.x(), pt.y() - c.y());
+                (x, y) == (r, 0) || (x == y && x < 0)
+            }));
+            assert_eq!(first.len() + second.len() - shared.len(), len, "r={r}");
+        }
+
+        // A short arc across a diagonal keeps the point on it
+        let arc: Vec<_> = Arc::new(44, 46, 10, c).collect();
+        assert_eq!(arc, vec![Pt::new(107, 93)]);
+    }
+
+    #[test]
+    fn arc_iter_in_order() {
+        let c = Pt::new(100, 100);
+        let r = 50;
+        let pts: Vec<_> = Arc::new(10, 300, r, c).collect();
+
+        // Angles should never decrease (allowing for rounding) while sweeping from start to end
+        let angle = |p: &Pt<i32>| {
+            let a = f64::from(c.y() - p.y()).atan2(f64::from(p.x() - c.x()));
+            crate::angle::normalize(a).to_degrees()
+        };
+        for w in pts.windows(2) {
+            assert!(
+                angle(&w[1]) + 1.0 >= angle(&w[0]),
+                "{:?} -> {:?}",
+                w[0],
+                w[1]
+            );
+        }
+        assert!((angle(&pts[0]) - 10.0).abs() < 2.0);
+        assert!((angle(pts.last().unwrap()) - 300.0).abs() < 2.0);
+
+        // Adjacent points should touch
+        for w in pts.windows(2) {
+            let d = (w[1].x() - w[0].x()).abs().max((w[1].y() - w[0].y()).abs());
+            assert!(d <= 1, "{:?} -> {:?}", w[0], w[1]);
+        }
+    }
+
+    #[test]
+    fn draw_matches_iterator() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        for (start, end, r) in [(10, 300, 50), (200, 30, 37), (0, 360, 20), (45, 46, 0)] {
+            let arc = Arc::new(start, end, r, (60, 60));
+            let mut drawn = crate::test::img::blank((120, 120));
+            let stats = arc.clone().draw_with_stats(&mut drawn, red);
+            let mut points = crate::test::img::blank((120, 120));
+            let mut count = 0;
+            for pt in arc {
+                points.put_pixel(pt.x() as u32, pt.y() as u32, red);
+                count += 1;
+            }
+            assert_eq!(drawn, points, "{start}° to {end}° r={r}");
+            assert_eq!(stats.drawn, count);
+        }
+
+        // Points already buffered by the iterator are still drawn
+        let mut arc = Arc::new(60, 170, 30, (60, 60));
+        let first = arc.next();
+        assert!(!arc.buf.is_empty());
+        let mut drawn = crate::test::img::blank((120, 120));
+        let stats = arc.draw_with_stats(&mut drawn, red);
+        assert!(first.is_some());
+        let total = Arc::new(60, 170, 30, (60, 60)).count() as u64;
+        assert_eq!(stats.drawn + 1, total);
+    }
+
+    #[test]
+    fn arc_iter_zero_radius() {
+        let pts: Vec<_> = Arc::new(0, 90, 0, (2, 2)).collect();
+        assert_eq!(pts, vec![Pt::new(2, 2)]);
+    }
+
+    #[test]
+    fn arc_contains() {
+        for (start, end, r) in [(10, 300, 50), (300, 10, 37), (0, 0, 20), (45, 46, 90)] {
+            let arc = Arc::new(start, end, r, (100, 100));
+            for pt in arc.points() {
+                assert!(arc.contains(pt), "{pt:?} start={start} end={end} r={r}");
+            }
+        }
+
+        let arc = Arc::new(0, 90, 50, (100, 100));
+        assert!(!arc.contains((100, 100)));
+        assert!(!arc.contains((50, 100)));
+        assert!(!arc.contains((100, 150)));
+        assert!(!arc.contains((140, 100)));
+    }
+
+    #[test]
+    fn arc_bounding_box() {
+        let algorithms = [CircleAlgorithm::Midpoint, CircleAlgorithm::Distance];
+        for start in (0..360).step_by(15) {
+            for end in (0..360).step_by(20) {
+                for r in [0, 1, 2, 7, 40, 333] {
+                    for alg in algorithms {
+                        let arc = Arc::new(start, end, r, (50, 50)).with_algorithm(alg);
+                        let points = arc.points();
+                        // Very small arcs may not cover any pixels
+                        let msg = format!("start={start} end={end} r={r} {alg:?}");
+                        if points.is_empty() {
+                            assert_eq!(arc.bounding_box().width, 0, "{msg}");
+                        } else {
+                            crate::test::check_bounds(arc.bounding_box(), &points, &msg);
+                        }
+                    }
+                }
+            }
+        }
+
+        for oct in 1..=8 {
+            let arc = Arc::octant(oct, 25, (50, 50));
+            let msg = format!("octant {oct}");
+            crate::test::check_bounds(arc.bounding_box(), &arc.points(), &msg);
+        }
+
+        // Only the points left to visit are covered
+        let mut arc = Arc::new(10, 300, 40, (50, 50));
+        for _ in 0..70 {
+            arc.next();
+        }
+        let rest: Vec<_> = arc.clone().collect();
+        crate::test::check_bounds(arc.bounding_box(), &rest, "after 70 points");
+    }
+
+    #[test]
+    fn last_matches_stepping() {
+        let algorithms = [CircleAlgorithm::Midpoint, CircleAlgorithm::Distance];
+        for r in [1, 2, 5, 17, 100, 1001, 99_999] {
+            for start in (0..360).step_by(7) {
+                for alg in algorithms {
+                    let mut arc = Arc::new(start, start + 200, r, (0, 0)).with_algorithm(alg);
+                    loop {
+                        let expected = {
+                            let mut pos = arc.pos.clone();
+                            let mut last = None;
+                            while !pos.stop() {
+                                last = Some((pos.x, pos.y));
+                                pos.inc();
+                            }
+                            last
+                        };
+                        let msg = format!("r={r} start={start} oct={} {alg:?}", arc.pos.oct);
+                        assert_eq!(arc.pos.last(), expected, "{msg}");
+                        if arc.end() {
+                            break;
+                        }
+                        arc.restart();
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arc_sample() {
+        let arc = Arc::new(350, 10, 20, (50, 50));
+        assert!((arc.length() - 20.0 * 20f64.to_radians()).abs() < 1e-9);
+
+        let points = arc.sample(5);
+        assert_eq!(points.len(), 5);
+        for (pt, deg) in points.iter().zip([350.0, 355.0, 0.0, 5.0, 10.0]) {
+            let expected = arc.point_at_angle(crate::Degrees(deg));
+            assert!((pt.x() - expected.x()).abs() < 1e-9, "{deg}");
+            assert!((pt.y() - expected.y()).abs() < 1e-9, "{deg}");
+        }
+        for pt in arc.sample(9) {
+            assert!(arc.contains(pt.i32()), "{pt:?}");
+        }
+
+        let full = Arc::new(0, 360, 20, (50, 50));
+        assert!((full.length() - 40.0 * std::f64::consts::PI).abs() < 1e-9);
+        let points = full.sample(4);
+        assert!((points[3].y() - 70.0).abs() < 1e-9);
+
+        assert!(arc.sample(0).is_empty());
+        assert_eq!(arc.sample(1).len(), 1);
+    }
+
+    #[test]
+    fn arc_draw_stats() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for c in [(50, 50), (0, 20), (-100, -100)] {
+            let mut image = crate::test::img::blank((40, 40));
+            let arc = Arc::new(30, 300, 25, c);
+            let total = arc.points().len() as u64;
+            let stats = arc.draw_with_stats(&mut image, color);
+
+            assert_eq!(stats.total(), total);
+            assert_eq!(
+                stats.drawn,
+                image.pixels().filter(|p| **p == color).count() as u64
+            );
+        }
+    }
+
+    #[test]
+    fn offscreen_arcs_skipped() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for center in [(20, 20), (-15, 10), (45, 45), (20, -100), (200, 20)] {
+            let arc = Arc::new(30, 270, 25, center);
+            let mut image = crate::test::img::blank((40, 30));
+            arc.clone().draw(&mut image, color);
+            let mut expected = crate::test::img::blank((40, 30));
+            let stats = arc.draw_with_stats(&mut expected, color);
+            assert_eq!(image, expected, "{center:?}");
+            assert_eq!(
+                stats.is_offscreen(),
+                image == crate::test::img::blank((40, 30))
+            );
+
+            let mut image = crate::test::img::blank((40, 30));
+            crate::conics::circle(&mut image, 25, center, color);
+            let mut expected = crate::test::img::blank((40, 30));
+            Arc::new(0, 360, 25, center).draw_with_stats(&mut expected, color);
+            assert_eq!(image, expected, "circle {center:?}");
+        }
+    }
+
+    #[test]
+    fn clipped_arcs_match_unclipped() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let sweeps = [
+            (0, 360),
+            (30, 270),
+            (300, 45),
+            (100, 100),
+            (44, 46),
+            (181, 359),
+        ];
+        let centers = [(20, 15), (-15, 10), (45, 40), (20, -30), (60, 20), (-8, -8)];
+        for alg in [CircleAlgorithm::Midpoint, CircleAlgorithm::Distance] {
+            for (start, end) in sweeps {
+                for center in centers {
+                    for r in [1, 7, 25, 60] {
+                        let arc = Arc::new(start, end, r, center).with_algorithm(alg);
+                        let mut image = crate::test::img::blank((40, 30));
+                        arc.clone().draw(&mut image, color);
+                        let mut expected = crate::test::img::blank((40, 30));
+                        arc.draw_with_stats(&mut expected, color);
+                        assert_eq!(image, expected, "{start} {end} {center:?} r={r} {alg:?}");
+                    }
+                }
+            }
+        }
+
+        // Arcs that have already been partially iterated over
+        let mut arc = Arc::new(100, 300, 30, (45, 10));
+        arc.nth(5);
+        let mut image = crate::test::img::blank((40, 30));
+        arc.clone().draw(&mut image, color);
+        let mut expected = crate::test::img::blank((40, 30));
+        arc.draw_with_stats(&mut expected, color);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn huge_partially_visible_arcs() {
+        // Only the few columns crossing the image are calculated
+        let (r, color) = (i32::MAX / 2, image::Rgba([255, 0, 0, 255]));
+        let mut image = crate::test::img::blank((40, 30));
+        Arc::new(0, 360, r, (20, 15 + r)).draw(&mut image, color);
+        let drawn: Vec<_> = image.enumerate_pixels().filter(|p| *p.2 == color).collect();
+        assert_eq!(drawn.len(), 40);
+        assert!(drawn.iter().all(|p| p.1 == 15));
+    }
+
+    #[test]
+    fn beziers_stay_within_error() {
+        for (start, end, r, max_error) in
+            [(0, 270, 100, 0.1), (-30, 45, 190, 0.001), (10, 10, 50, 0.5)]
+        {
+            let arc = Arc::new(start, end, r, (200, 200));
+            let curves = arc.to_beziers(max_error);
+            assert!(curves.iter().all(|c| c.ctrl1 != c.start));
+            for pair in curves.windows(2) {
+                assert_eq!(pair[0].end, pair[1].start);
+            }
+            let worst = curves
+                .iter()
+                .flat_map(|c| c.sample(50))
+                .map(|p| ((p.x() - 200.0).hypot(p.y() - 200.0) - f64::from(r)).abs())
+                .fold(0.0, f64::max);
+            assert!(worst <= max_error, "{worst} > {max_error}");
+        }
+    }
+
+    #[test]
+    fn huge_radius() {
+        // The top of a circle centered far below the image
+        let r = 100_000;
+        let c = (200, 200 + r);
+        let mut image = image::RgbaImage::new(400, 400);
+        Arc::new(60, 120, r, c).draw(&mut image, image::Rgba([255, 0, 0, 255]));
+
+        let drawn: Vec<_> = image
+            .enumerate_pixels()
+            .filter(|(_, _, p)| p.0[3] != 0)
+            .map(|(x, y, _)| (i64::from(x) - 200, i64::from(y) - 200 - i64::from(r)))
+            .collect();
+        assert_eq!(drawn.len(), 400);
+        for (x, y) in drawn {
+            let d = ((x * x + y * y) as f64).sqrt();
+            assert!((d - f64::from(r)).abs() < 1.0, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn saturates_near_coordinate_limits() {
+        let points = Arc::new(0.0, 1e-6, 2_000_000_000, (i32::MAX - 10, 0)).points();
+        assert!(!points.is_empty());
+        assert!(points.iter().all(|p| p.x() == i32::MAX));
+    }
 }
@@ -1,4 +1,5 @@
 use super::Bounds;
+use crate::conics::CircleAlgorithm;
 use crate::Pt;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6,21 +7,23 @@ use crate::Pt;
 pub(super) struct Pos {
     pub(super) x: i32,
     pub(super) y: i32,
-    pub(super) d: i32,
+    pub(super) d: i64,
     pub(super) oct: u8,
     pub(super) ex: Option<i32>,
+    pub(super) alg: CircleAlgorithm,
+    r: i32,
 }
 
+/// Octants are half-open so each point of a full circle is visited once.
+///
+/// Iteration always starts on the x or y axis (`x == 0`) and moves towards
+/// the diagonal (`x == y`).  Odd octants start on the axis, so they include the
+/// axis and leave the diagonal to the next octant.  Even octants are iterated
+/// backwards from the axis at their end, so they skip the axis (it belongs to
+/// the next octant) and include the diagonal.  Bounds from an arc's start or
+/// end angle replace these rules, so arcs always include both of their ends.
 impl Pos {
     pub(super) fn new(oct: u8, bounds: Bounds, r: i32, c: Pt<i32>) -> Self {
-        let (Pt { x, y }, d) = match bounds.start {
-            None => (Pt::new(0, r), 1 - r),
-            Some(a) => {
-                let pt = Pt::from_radian(a, r, c).real_to_iter(oct, c.into());
-                (pt.i32(), crate::calc_error(pt, r))
-            }
-        };
-
         let ex = bounds.end.map(|a| {
             Pt::from_radian(a, r, c)
                 .real_to_iter(oct, c.into())
@@ -28,30 +31,192 @@ impl Pos {
                 .round() as i32
         });
 
-        Self { x, y, d, oct, ex }
+        match bounds.start {
+            None => Self {
+                ex,
+                ..Self::start(oct, r)
+            },
+            Some(a) => {
+                let pt = Pt::from_radian(a, r, c).real_to_iter(oct, c.into());
+                let Pt { x, y } = pt.i32();
+                let d = crate::calc_error(pt, r);
+                Self {
+                    x,
+                    y,
+                    d,
+                    oct,
+                    ex,
+                    alg: CircleAlgorithm::Midpoint,
+                    r,
+                }
+            }
+        }
     }
 
     pub(super) fn stop(&self) -> bool {
-        self.x >= self.ex.map_or(self.y, |ex| ex + 1)
+        match self.ex {
+            // Rounding an edge near the diagonal could reach past it
+            Some(ex) => self.x > ex || self.x > self.y,
+            None if self.oct % 2 == 0 => self.x > self.y,
+            None => self.x >= self.y,
+        }
     }
 
     pub(super) fn start(oct: u8, r: i32) -> Self {
-        Self {
+        let mut pos = Self {
             x: 0,
             y: r,
-            d: 1 - r,
+            d: 1 - i64::from(r),
             oct,
             ex: None,
+            alg: CircleAlgorithm::Midpoint,
+            r,
+        };
+        if oct % 2 == 0 {
+            pos.inc();
         }
+        pos
+    }
+
+    /// Switches to another algorithm, moving `y` onto that algorithm's pixels
+    /// for the current column.  Only meaningful before iterating.
+    pub(super) fn with_algorithm(mut self, alg: CircleAlgorithm) -> Self {
+        if alg == self.alg {
+            return self;
+        }
+        self.alg = alg;
+        match alg {
+            CircleAlgorithm::Distance => self.y = self.ring_top(),
+            CircleAlgorithm::Midpoint => {
+                let x = f64::from(self.x);
+                let y = (f64::from(self.r).powi(2) - x * x).max(0.0).sqrt();
+                self.y = y.round() as i32;
+                self.d = crate::calc_error(Pt::new(x, y), self.r);
+            }
+        }
+        self
+    }
+
+    /// The inner and outer bounds on `x² + y²` for pixels of the distance
+    /// ring: pixel centers within half a pixel of the radius.
+    fn ring(&self) -> (i64, i64) {
+        let r = i64::from(self.r);
+        (r * r - r + 1, r * r + r)
+    }
+
+    /// The largest `y` in the current column that is inside the ring.
+    fn ring_top(&self) -> i32 {
+        let rem = (self.ring().1 - i64::from(self.x).pow(2)).max(0);
+        let mut y = (rem as f64).sqrt() as i64;
+        // Correct any rounding error from the floating-point square root
+        while y * y > rem {
+            y -= 1;
+        }
+        while (y + 1) * (y + 1) <= rem {
+            y += 1;
+        }
+        y as i32
+    }
+
+    /// Jumps ahead to the first point in column `x` without stepping through
+    /// the columns before it.  Only used for whole octants.
+    pub(super) fn skip_to(&mut self, x: i32) {
+        if x <= self.x {
+            return;
+        }
+        self.x = x;
+        match self.alg {
+            CircleAlgorithm::Distance => self.y = self.ring_top(),
+            CircleAlgorithm::Midpoint => (self.y, self.d) = crate::midpoint_at(x, self.r),
+        }
+    }
+
+    /// Returns the last point before [`Pos::stop`] without stepping through
+    /// every point before it, or `None` if there are none left.
+    ///
+    /// The last column is found by bisection using the first point of each
+    /// column, then the last few columns are stepped through, as close to the
+    /// diagonal a column's first point can be off by one from where stepping
+    /// reaches.
+    pub(super) fn last(&self) -> Option<(i32, i32)> {
+        if self.stop() {
+            return None;
+        }
+        // Every column after the current one has its first point at or below
+        // the current `y`, so the column past it is always stopped
+        let end = self.ex.map_or(self.y, |ex| ex.min(self.y));
+        let (mut lo, mut hi) = (i64::from(self.x), i64::from(end) + 1);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if self.at(mid as i32).stop() {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+
+        let mut pos = self.at((lo as i32).saturating_sub(4).max(self.x));
+        let mut last = (pos.x, pos.y);
+        loop {
+            pos.inc();
+            if pos.stop() {
+                return Some(last);
+            }
+            last = (pos.x, pos.y);
+        }
+    }
+
+    /// Returns the position at the first point of column `x`, which is after
+    /// the current column, without stepping through the columns before it.
+    ///
+    /// The midpoint decision parameter stays `(x + 1)² + y² - y - r²` plus
+    /// whatever offset it started with, so each column's `y` is the largest
+    /// where `x² + y² - y` is within that limit.  Starting from a rounded
+    /// point, `y` can start above or below that, and then moves one row per
+    /// column until it meets it.
+    pub(super) fn at(&self, x: i32) -> Self {
+        let mut pos = self.clone();
+        if x <= self.x {
+            return pos;
+        }
+        pos.x = x;
+        match self.alg {
+            CircleAlgorithm::Distance => pos.y = pos.ring_top(),
+            CircleAlgorithm::Midpoint => {
+                let decision = |x: i128, y: i128| (x + 1) * (x + 1) + y * y - y;
+                let (x0, y0) = (i128::from(self.x), i128::from(self.y));
+                // The most `x² + y² - y` can be while keeping `y` in a column
+                let limit = decision(x0, y0) - i128::from(self.d);
+                let x = i128::from(x);
+                let y = crate::midpoint_row(limit - x * x)
+                    .max(y0 - (x - x0))
+                    .min(y0);
+                pos.y = y as i32;
+                pos.d = (decision(x, y) + i128::from(self.d) - decision(x0, y0)) as i64;
+            }
+        }
+        pos
     }
 
     pub(super) fn inc(&mut self) {
+        if self.alg == CircleAlgorithm::Distance {
+            // Walk down the column while still inside the ring, then move to
+            // the top of the next column
+            let (x, y) = (i64::from(self.x), i64::from(self.y) - 1);
+            if x * x + y * y >= self.ring().0 {
+                self.y -= 1;
+            } else {
+                self.x += 1;
+                self.y = self.ring_top();
+            }
+            return;
+        }
         self.x += 1;
         if self.d > 0 {
             self.y -= 1;
-            self.d += 2 * (self.x - self.y) + 1;
+            self.d += 2 * i64::from(self.x - self.y) + 1;
         } else {
-            self.d += 2 * self.x + 1;
+            self.d += 2 * i64::from(self.x) + 1;
         }
     }
 }
@@ -0,0 +1,209 @@
+use super::Arc;
+use crate::{Error, Pt};
+
+/// Draws many arcs that share the same center and radius.
+///
+/// Each sweep is a start and end angle, interpreted the same way as
+/// [`arc`](super::arc), and is drawn with the same pixels.  This is a
+/// convenient way to draw many short arcs, like tick marks or the segments of
+/// a ring.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::arcs;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// // A dashed ring made of 36 segments
+/// let sweeps = (0..36).map(|i| (i * 10, i * 10 + 5));
+/// arcs(&mut image, sweeps, 190, (200, 200), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// See also: [`ArcSet`], [`Draw::arcs`](crate::Draw::arcs)
+pub fn arcs<A, C, I, It, T>(image: &mut I, sweeps: It, radius: T, center: C, color: I::Pixel)
+where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    It: IntoIterator<Item = (A, A)>,
+    T: Into<i32> + Copy,
+{
+    ArcSet::new(radius, center).draw(image, sweeps, color);
+}
+
+/// A circle for drawing many arcs with the same center and radius.
+///
+/// Each arc finds where its start and end angles fall on the circle directly,
+/// without calculating the points before them, so the same set can be reused
+/// to draw any number of arcs.  Arcs are the same as [`Arc`], and only the
+/// parts of them crossing the image are calculated when drawn.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::ArcSet;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// let ring = ArcSet::new(190, (200, 200));
+/// ring.draw(&mut image, [(0, 80), (90, 170), (180, 260), (270, 350)], Rgba([255, 0, 0, 255]));
+/// ring.draw(&mut image, [(80, 90), (260, 270)], Rgba([0, 0, 255, 255]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ArcSet {
+    /// Center of the circle
+    c: Pt<i32>,
+    /// Radius of the circle
+    r: i32,
+}
+
+impl ArcSet {
+    /// Creates a new [`ArcSet`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if radius is negative
+    pub fn new<C, T>(radius: T, center: C) -> Self
+    where
+        C: crate::pt::Point<T>,
+        T: Into<i32> + Copy,
+    {
+        match Self::try_new(radius, center) {
+            Ok(set) => set,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Creates a new [`ArcSet`], returning an error instead of panicking if the
+    /// radius is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRadius`] if radius is negative
+    ///
+    /// ```
+    /// # use freehand::conics::ArcSet;
+    /// assert!(ArcSet::try_new(190, (200, 200)).is_ok());
+    /// assert!(ArcSet::try_new(-190, (200, 200)).is_err());
+    /// ```
+    pub fn try_new<C, T>(radius: T, center: C) -> Result<Self, Error>
+    where
+        C: crate::pt::Point<T>,
+        T: Into<i32> + Copy,
+    {
+        let r = radius.into();
+        if r < 0 {
+            return Err(Error::InvalidRadius(f64::from(r)));
+        }
+        let c = Pt::new(center.x().into(), center.y().into());
+        Ok(Self { c, r })
+    }
+
+    /// Returns the center coordinates
+    #[must_use]
+    pub fn center(&self) -> Pt<i32> {
+        self.c
+    }
+
+    /// Returns the radius
+    #[must_use]
+    pub fn radius(&self) -> i32 {
+        self.r
+    }
+
+    /// Returns the points of a single arc, in order from the start angle to the
+    /// end angle.
+    ///
+    /// If the start and end angles are the same, or the end angle is at least a
+    /// full turn away from the start angle, the whole circle is returned.
+    ///
+    /// ```
+    /// use freehand::conics::ArcSet;
+    /// use freehand::Pt;
+    ///
+    /// let set = ArcSet::new(10, (20, 20));
+    /// let points: Vec<_> = set.arc_points(0, 90).collect();
+    /// assert_eq!(points.first(), Some(&Pt::new(30, 20)));
+    /// assert_eq!(points.last(), Some(&Pt::new(20, 10)));
+    /// ```
+    pub fn arc_points<A>(&self, start_angle: A, end_angle: A) -> impl Iterator<Item = Pt<i32>> + '_
+    where
+        A: crate::Angle,
+    {
+        self.arc(start_angle, end_angle)
+    }
+
+    /// Draws an arc for each sweep (a start and end angle).
+    pub fn draw<A, I, It>(&self, image: &mut I, sweeps: It, color: I::Pixel)
+    where
+        A: crate::Angle,
+        I: image::GenericImage,
+        It: IntoIterator<Item = (A, A)>,
+    {
+        for (start, end) in sweeps {
+            self.arc(start, end).draw(image, color);
+        }
+    }
+
+    /// Returns the arc between two angles.  The radius was checked when the
+    /// set was created, so this never panics.
+    fn arc<A>(&self, start_angle: A, end_angle: A) -> Arc
+    where
+        A: crate::Angle,
+    {
+        Arc::new(start_angle, end_angle, self.r, self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::angle;
+
+    #[test]
+    fn matches_arcs() {
+        let set = ArcSet::new(50, (60, 60));
+        for (start, end) in [(0, 90), (30, 200), (300, 45), (10, 10), (-20, 20)] {
+            let from_set: Vec<_> = set.arc_points(start, end).collect();
+            let from_arc: Vec<_> = Arc::new(start, end, 50, (60, 60)).collect();
+            assert_eq!(from_set, from_arc, "{start} {end}");
+        }
+    }
+
+    #[test]
+    fn points_in_order() {
+        let set = ArcSet::new(30, (0, 0));
+        let points: Vec<_> = set.arc_points(300, 60).collect();
+        let angles: Vec<_> = points
+            .iter()
+            .map(|p| {
+                angle::normalize(f64::from(-p.y()).atan2(f64::from(p.x())) - 290f64.to_radians())
+            })
+            .collect();
+        assert!(angles.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(points.first(), Some(&Pt::new(15, 26)));
+    }
+
+    #[test]
+    fn huge_radius() {
+        // Only the parts of each arc crossing the image are calculated
+        let (r, color) = (i32::MAX / 2, image::Rgba([255, 0, 0, 255]));
+        let mut image = crate::test::img::blank((40, 30));
+        let sweeps = [(0.0, 1.0), (1.0, 3.0), (3.0, 6.0)];
+        ArcSet::new(r, (20, 15 + r)).draw(&mut image, sweeps, color);
+        let drawn: Vec<_> = image.enumerate_pixels().filter(|p| *p.2 == color).collect();
+        assert_eq!(drawn.len(), 40);
+        assert!(drawn.iter().all(|p| p.1 == 15));
+    }
+
+    #[test]
+    fn zero_radius() {
+        let set = ArcSet::new(0, (5, 5));
+        assert_eq!(
+            set.arc_points(0, 90).collect::<Vec<_>>(),
+            vec![Pt::new(5, 5)]
+        );
+    }
+}
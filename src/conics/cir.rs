@@ -1,5 +1,41 @@
 use crate::conics;
 
+/// How the pixels of a circle or arc are chosen.
+///
+/// The two algorithms usually agree, but differ by a pixel here and there,
+/// so pick one and stick with it when shapes need to line up.
+///
+/// ```
+/// use freehand::conics::{circle_with_algorithm, CircleAlgorithm};
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(100, 100);
+///
+/// // Rings one radius apart leave no gaps between them
+/// for r in 20..25 {
+///     circle_with_algorithm(&mut image, r, (50, 50), CircleAlgorithm::Distance, Rgba([255, 0, 0, 255]));
+/// }
+/// assert_eq!(*image.get_pixel(50 + 15, 50 + 15), Rgba([255, 0, 0, 255]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CircleAlgorithm {
+    /// The integer midpoint (Bresenham) algorithm: one pixel per step, and
+    /// the thinnest line that still looks connected.  Used by [`circle`] and
+    /// [`Arc`](conics::Arc) unless another algorithm is chosen.
+    Midpoint,
+    /// Every pixel whose center is within half a pixel of the radius.  Always
+    /// 8-connected, and the rings for consecutive radii tile the plane without
+    /// gaps or overlaps, so they can be stacked into thick circles.  Corners
+    /// can be a pixel thicker than [`Midpoint`](Self::Midpoint).
+    Distance,
+}
+
+impl Default for CircleAlgorithm {
+    fn default() -> Self {
+        Self::Midpoint
+    }
+}
+
 /// Draws a full circle.
 ///
 /// ```
@@ -13,6 +49,12 @@ use crate::conics;
 /// Uses [`conics::Arc`] to calculate a single octant and draw those pixels
 /// in all octants.
 ///
+/// A radius of 0 will draw a single pixel at the center.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
 /// See also: [`Draw::circle`](crate::Draw::circle)
 ///
 pub fn circle<C, I, T>(image: &mut I, radius: T, center: C, color: I::Pixel)
@@ -21,29 +63,279 @@ where
     I: image::GenericImage,
     T: Into<i32> + Copy,
 {
-    let mut octant = conics::Arc::octant(1, radius, center);
+    circle_with_algorithm(image, radius, center, CircleAlgorithm::Midpoint, color);
+}
 
-    loop {
-        if octant.stop() {
-            if octant.end() {
-                break;
+/// Calls a closure for every point of a circle instead of drawing to an image.
+///
+/// Points are not clipped and are visited one octant step at a time, so they
+/// are not in order around the circle.
+///
+/// ```
+/// use freehand::conics::circle_for_each;
+///
+/// let mut points = Vec::new();
+/// circle_for_each(10, (20, 20), |pt| points.push(pt));
+///
+/// assert!(points.contains(&freehand::Pt::new(30, 20)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+pub fn circle_for_each<C, F, T>(radius: T, center: C, f: F)
+where
+    C: crate::pt::Point<T>,
+    F: FnMut(crate::Pt<i32>),
+    T: Into<i32> + Copy,
+{
+    visit(radius, center, CircleAlgorithm::Midpoint, f);
+}
+
+/// Draws a full circle, choosing its pixels with the given algorithm.
+///
+/// [`circle`] is the same as this with [`CircleAlgorithm::Midpoint`].
+///
+/// ```
+/// use freehand::conics::{circle_with_algorithm, CircleAlgorithm};
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// circle_with_algorithm(&mut image, 180, (200, 200), CircleAlgorithm::Distance, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// See also: [`Draw::circle_with_algorithm`](crate::Draw::circle_with_algorithm)
+///
+pub fn circle_with_algorithm<C, I, T>(
+    image: &mut I,
+    radius: T,
+    center: C,
+    algorithm: CircleAlgorithm,
+    color: I::Pixel,
+) where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    if off_screen(image, radius, &center) {
+        return;
+    }
+
+    // Only the columns of the octant that land inside the image in at least
+    // one of the octants are calculated
+    let r = radius.into();
+    let cols = offsets(center.x().into(), image.width());
+    let rows = offsets(center.y().into(), image.height());
+    let columns = [
+        intersect(cols, reaching(r, rows)),
+        intersect(rows, reaching(r, cols)),
+    ];
+
+    visit_columns(radius, center, algorithm, &merge(columns), |pt| {
+        let pt: Result<crate::Pt<u32>, crate::Error> = pt.try_into();
+        if let Ok(pt) = pt {
+            if pt.x() < image.width() && pt.y() < image.height() {
+                image.put_pixel(pt.x(), pt.y(), color);
             }
-            octant.restart();
-            continue;
         }
+    });
+}
+
+/// Draws a filled circle (a disk).
+///
+/// Each row of the disk is drawn as a single horizontal span reaching out to
+/// the pixels [`circle`] would draw, so the disk covers the circle's outline
+/// exactly.  Only the part of the disk inside the image is drawn.
+///
+/// ```
+/// use freehand::conics::circle_filled;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// circle_filled(&mut image, 180, (200, 200), Rgba([255, 0, 0, 255]));
+/// assert_eq!(*image.get_pixel(200, 200), Rgba([255, 0, 0, 255]));
+/// assert_eq!(*image.get_pixel(20, 200), Rgba([255, 0, 0, 255]));
+/// assert_eq!(*image.get_pixel(20, 20), Rgba([0, 0, 0, 0]));
+/// ```
+///
+/// A radius of 0 will draw a single pixel at the center.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// See also: [`Draw::circle_filled`](crate::Draw::circle_filled)
+///
+pub fn circle_filled<C, I, T>(image: &mut I, radius: T, center: C, color: I::Pixel)
+where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    if off_screen(image, radius, &center) {
+        return;
+    }
+    let (cx, cy) = (center.x().into(), center.y().into());
+    let r = radius.into();
+
+    // The half width of each row `dy` rows above and below the center, for
+    // the distances from the center that are inside the image.  A row's
+    // widest point is either on it or on the column of the same distance in
+    // the octant, so only those columns are calculated.
+    let rows = offsets(cy, image.height());
+    let (near, far) = (rows.0, rows.1.min(i64::from(r)));
+    let mut half = vec![0; (far - near + 1).max(0) as usize];
+    let columns = merge([(near, far), reaching(r, rows)]);
+    visit_columns(r, (cx, cy), CircleAlgorithm::Midpoint, &columns, |pt| {
+        let dy = (i64::from(pt.y()) - i64::from(cy)).abs() - near;
+        if let Some(w) = usize::try_from(dy).ok().and_then(|dy| half.get_mut(dy)) {
+            let dx = (i64::from(pt.x()) - i64::from(cx)).unsigned_abs() as i32;
+            *w = (*w).max(dx);
+        }
+    });
+
+    for (dy, &w) in (near..).zip(&half) {
+        let dy = dy as i32;
+        let (x1, x2) = (cx.saturating_sub(w), cx.saturating_add(w));
+        crate::lines::horizontal_line(image, (x1, cy.saturating_sub(dy)), x2, color);
+        if dy != 0 {
+            crate::lines::horizontal_line(image, (x1, cy.saturating_add(dy)), x2, color);
+        }
+    }
+}
+
+/// Returns true if a circle is entirely outside of the image.  Negative radii
+/// are never off screen, so they still panic when the circle is calculated.
+fn off_screen<C, I, T>(image: &I, radius: T, center: &C) -> bool
+where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    let (r, c) = (radius.into(), (center.x().into(), center.y().into()));
+    if r < 0 {
+        return false;
+    }
+    let size = (2 * i64::from(r) + 1).min(i64::from(u32::MAX)) as u32;
+    let bounds =
+        crate::shapes::Rect::new((c.0.saturating_sub(r), c.1.saturating_sub(r)), size, size);
+    !bounds.is_visible_in(image.width(), image.height())
+}
+
+/// Calculates a single octant and visits its points in all eight octants.
+fn visit<C, F, T>(radius: T, center: C, algorithm: CircleAlgorithm, f: F)
+where
+    C: crate::pt::Point<T>,
+    F: FnMut(crate::Pt<i32>),
+    T: Into<i32> + Copy,
+{
+    visit_columns(radius, center, algorithm, &[(0, i64::MAX)], f);
+}
+
+/// Like [`visit`], but only calculates the octant's points in the given
+/// ranges of columns (in iterator coordinates), skipping directly from one
+/// range to the next.  The ranges must be sorted and must not overlap.
+fn visit_columns<C, F, T>(
+    radius: T,
+    center: C,
+    algorithm: CircleAlgorithm,
+    columns: &[(i64, i64)],
+    mut f: F,
+) where
+    C: crate::pt::Point<T>,
+    F: FnMut(crate::Pt<i32>),
+    T: Into<i32> + Copy,
+{
+    if radius.into() == 0 {
+        f(crate::Pt::new(center.x().into(), center.y().into()));
+        return;
+    }
+
+    let mut octant = conics::Arc::octant(1, radius, center).with_algorithm(algorithm);
 
-        // draw the pixel in all 8 octants
-        for i in 1..=8 {
-            let pt: Result<crate::Pt<u32>, &'static str> = octant.coords_oct(i).try_into();
-            if let Ok(pt) = pt {
-                if pt.x() < image.width() && pt.y() < image.height() {
-                    image.put_pixel(pt.x(), pt.y(), color);
+    for &(first, last) in columns {
+        octant.skip_to(first.clamp(0, i32::MAX.into()) as i32);
+        loop {
+            if octant.stop() {
+                // The first octant stops short of the diagonal; a point exactly
+                // on it is visited once in each quadrant
+                if octant.on_diagonal() {
+                    for i in (2..=8).step_by(2) {
+                        f(octant.coords_oct(i));
+                    }
                 }
+                return;
             }
+            if i64::from(octant.iter_pos().0) > last {
+                break;
+            }
+
+            if octant.on_axis() {
+                // visit the point once on each axis
+                for i in (1..=7).step_by(2) {
+                    f(octant.coords_oct(i));
+                }
+            } else {
+                // visit the point in all 8 octants
+                for i in 1..=8 {
+                    f(octant.coords_oct(i));
+                }
+            }
+
+            octant.inc();
         }
+    }
+}
+
+/// Returns the smallest and largest distances from `c` to a position in
+/// `0..n`.
+fn offsets(c: i32, n: u32) -> (i64, i64) {
+    let (c, last) = (i64::from(c), i64::from(n) - 1);
+    let near = if c < 0 {
+        -c
+    } else if c > last {
+        c - last
+    } else {
+        0
+    };
+    (near, c.abs().max((last - c).abs()))
+}
+
+/// Returns a range of octant columns that includes every column whose y is
+/// within `range`.  The range is a little wider than needed so the result is
+/// never short because of rounding.
+pub(super) fn reaching(r: i32, range: (i64, i64)) -> (i64, i64) {
+    let r = i64::from(r);
+    let column = |y: i64| {
+        let (r, y) = (i128::from(r), i128::from(y.clamp(0, r)));
+        ((r * r - y * y) as f64).sqrt()
+    };
+    let first = column(range.1 + 1).floor() as i64 - 1;
+    let last = column(range.0 - 1).ceil() as i64 + 1;
+    (first.max(0), last)
+}
 
-        octant.inc();
+/// Returns the overlap of two ranges, which is empty if `first > last`.
+pub(super) fn intersect(a: (i64, i64), b: (i64, i64)) -> (i64, i64) {
+    (a.0.max(b.0), a.1.min(b.1))
+}
+
+/// Sorts two ranges and combines them if they overlap, dropping any that are
+/// empty.
+fn merge(ranges: [(i64, i64); 2]) -> Vec<(i64, i64)> {
+    let mut ranges: Vec<_> = ranges.into_iter().filter(|r| r.0 <= r.1).collect();
+    ranges.sort_unstable();
+    if let [a, b] = ranges[..] {
+        if b.0 <= a.1 + 1 {
+            return vec![(a.0, a.1.max(b.1))];
+        }
     }
+    ranges
 }
 
 #[cfg(test)]
@@ -61,4 +353,247 @@ mod tests {
 
         image.save("images/circle.png")
     }
+
+    #[test]
+    fn circle_zero_radius() {
+        let mut image = crate::test::img::blank((5, 5));
+        let color = image::Rgba([255, 0, 0, 255]);
+        super::circle(&mut image, 0, (2, 2), color);
+
+        for (x, y, p) in image.enumerate_pixels() {
+            assert_eq!(*p == color, (x, y) == (2, 2), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn circle_for_each() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut expected = crate::test::img::blank((100, 100));
+        super::circle(&mut expected, 30, (50, 50), color);
+
+        let mut image = crate::test::img::blank((100, 100));
+        super::circle_for_each(30, (50, 50), |pt| {
+            image.put_pixel(pt.x() as u32, pt.y() as u32, color);
+        });
+        assert_eq!(expected, image);
+    }
+
+    #[test]
+    fn distance_rings() {
+        use super::CircleAlgorithm;
+        use std::collections::HashMap;
+
+        // Consecutive rings cover every pixel once, and each pixel is in the
+        // ring nearest to its distance from the center
+        let mut seen = HashMap::new();
+        for r in 0..=40 {
+            super::visit(r, (0, 0), CircleAlgorithm::Distance, |pt| {
+                assert!(seen.insert((pt.x(), pt.y()), r).is_none(), "{pt:?} r={r}");
+            });
+        }
+        for x in -40..=40 {
+            for y in -40..=40 {
+                let d = f64::from(x).hypot(f64::from(y));
+                if d < 39.5 {
+                    assert_eq!(seen.get(&(x, y)), Some(&(d.round() as i32)), "({x}, {y})");
+                }
+            }
+        }
+
+        // Each ring is 8-connected
+        for r in 1..=40 {
+            let mut points = Vec::new();
+            super::visit(r, (0, 0), CircleAlgorithm::Distance, |pt| points.push(pt));
+            for pt in &points {
+                let neighbors = points
+                    .iter()
+                    .filter(|p| *p != pt)
+                    .filter(|p| (p.x() - pt.x()).abs() <= 1 && (p.y() - pt.y()).abs() <= 1)
+                    .count();
+                assert!(neighbors >= 2, "{pt:?} r={r}");
+            }
+        }
+    }
+
+    #[test]
+    fn distance_arc_matches_circle() {
+        use super::CircleAlgorithm;
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut expected = crate::test::img::blank((100, 100));
+        super::circle_with_algorithm(
+            &mut expected,
+            30,
+            (50, 50),
+            CircleAlgorithm::Distance,
+            color,
+        );
+
+        let mut image = crate::test::img::blank((100, 100));
+        crate::conics::Arc::new(0, 360, 30, (50, 50))
+            .with_algorithm(CircleAlgorithm::Distance)
+            .draw(&mut image, color);
+        assert_eq!(expected, image);
+
+        // Partial arcs stay on the same pixels
+        let mut image = crate::test::img::blank((100, 100));
+        crate::conics::Arc::new(30, 250, 30, (50, 50))
+            .with_algorithm(CircleAlgorithm::Distance)
+            .for_each(|pt| {
+                assert_eq!(
+                    *expected.get_pixel(pt.x() as u32, pt.y() as u32),
+                    color,
+                    "{pt:?}"
+                );
+                image.put_pixel(pt.x() as u32, pt.y() as u32, color);
+            });
+        assert_eq!(*image.get_pixel(50, 20), color);
+    }
+
+    #[test]
+    fn circle_filled() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        for r in [0, 1, 2, 7, 30] {
+            let mut outline = crate::test::img::blank((100, 100));
+            super::circle(&mut outline, r, (50, 50), color);
+            let mut image = crate::test::img::blank((100, 100));
+            super::circle_filled(&mut image, r, (50, 50), color);
+
+            // Every pixel of the outline is filled, along with everything
+            // between the outline's ends on each row
+            for y in 0..100 {
+                let row: Vec<u32> = (0..100)
+                    .filter(|&x| *outline.get_pixel(x, y) == color)
+                    .collect();
+                let filled: Vec<u32> = (0..100)
+                    .filter(|&x| *image.get_pixel(x, y) == color)
+                    .collect();
+                match (row.first(), row.last()) {
+                    (Some(&a), Some(&b)) => assert_eq!(filled, (a..=b).collect::<Vec<_>>()),
+                    _ => assert!(filled.is_empty(), "r={r} y={y}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn circle_filled_clips() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((20, 20));
+        super::circle_filled(&mut image, 15, (-5, 25), color);
+        assert_eq!(*image.get_pixel(0, 19), color);
+        assert_eq!(*image.get_pixel(5, 15), color);
+        assert_ne!(*image.get_pixel(19, 0), color);
+
+        // Rows far from the image are skipped
+        let mut image = crate::test::img::blank((20, 20));
+        super::circle_filled(&mut image, 1000, (10, 1010), color);
+        assert_eq!(*image.get_pixel(10, 10), color);
+        assert_eq!(*image.get_pixel(0, 19), color);
+        assert_ne!(*image.get_pixel(10, 9), color);
+
+        // Entirely off screen
+        let mut image = crate::test::img::blank((20, 20));
+        super::circle_filled(&mut image, 5, (-6, 10), color);
+        super::circle_filled(&mut image, 5, (100, 100), color);
+        assert!(image.pixels().all(|&p| p != color));
+    }
+
+    #[test]
+    fn skip_matches_stepping() {
+        use super::CircleAlgorithm;
+        for alg in [CircleAlgorithm::Midpoint, CircleAlgorithm::Distance] {
+            for r in 1..150 {
+                let mut octant = crate::conics::Arc::octant(1, r, (0, 0)).with_algorithm(alg);
+                let mut columns = Vec::new();
+                while !octant.stop() {
+                    let (x, y) = octant.iter_pos();
+                    if columns.last().map_or(true, |&(last, _)| last != x) {
+                        columns.push((x, y));
+                    }
+                    octant.inc();
+                }
+                for (x, y) in columns {
+                    let mut octant = crate::conics::Arc::octant(1, r, (0, 0)).with_algorithm(alg);
+                    octant.skip_to(x);
+                    assert_eq!(octant.iter_pos(), (x, y), "{alg:?} r={r}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_circles_match_unclipped() {
+        use super::CircleAlgorithm;
+        let color = image::Rgba([255, 0, 0, 255]);
+        let centers = [
+            (10, 10),
+            (-40, 5),
+            (25, -60),
+            (70, 70),
+            (-30, -30),
+            (10, 95),
+        ];
+        for alg in [CircleAlgorithm::Midpoint, CircleAlgorithm::Distance] {
+            for r in [3, 17, 40, 64, 101] {
+                for c in centers {
+                    let mut image = crate::test::img::blank((20, 30));
+                    super::circle_with_algorithm(&mut image, r, c, alg, color);
+
+                    let mut expected = crate::test::img::blank((20, 30));
+                    super::visit(r, c, alg, |pt| {
+                        if (0..20).contains(&pt.x()) && (0..30).contains(&pt.y()) {
+                            expected.put_pixel(pt.x() as u32, pt.y() as u32, color);
+                        }
+                    });
+                    assert_eq!(image, expected, "{alg:?} r={r} {c:?}");
+
+                    if alg == CircleAlgorithm::Midpoint {
+                        let mut image = crate::test::img::blank((20, 30));
+                        super::circle_filled(&mut image, r, c, color);
+                        // The same disk drawn entirely inside of a larger image
+                        let size = 2 * r as u32 + 300;
+                        let mut expected = crate::test::img::blank((size, size));
+                        let offset = (r + 100, r + 100);
+                        super::circle_filled(
+                            &mut expected,
+                            r,
+                            (c.0 + offset.0, c.1 + offset.1),
+                            color,
+                        );
+                        let expected = image::imageops::crop_imm(
+                            &expected,
+                            offset.0 as u32,
+                            offset.1 as u32,
+                            20,
+                            30,
+                        )
+                        .to_image();
+                        assert_eq!(image, expected, "filled r={r} {c:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_radius() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let r = i32::MAX;
+        // The image is entirely inside of the circle
+        let mut image = crate::test::img::blank((20, 20));
+        super::circle(&mut image, r, (10, 10), color);
+        assert!(image.pixels().all(|&p| p != color));
+
+        // The top of the circle crosses the image
+        let r = 1 << 30;
+        super::circle(&mut image, r, (10, 10 + r), color);
+        assert_eq!(*image.get_pixel(10, 10), color);
+        assert_ne!(*image.get_pixel(10, 11), color);
+
+        let mut image = crate::test::img::blank((20, 20));
+        super::circle_filled(&mut image, r, (10, 10 + r), color);
+        assert_eq!(*image.get_pixel(10, 10), color);
+        assert_eq!(*image.get_pixel(0, 19), color);
+        assert_ne!(*image.get_pixel(10, 9), color);
+    }
 }
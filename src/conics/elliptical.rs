@@ -0,0 +1,428 @@
+use crate::{angle, Error, Point, Pt};
+
+/// Draws a partial elliptical annulus (the area between two ellipses that
+/// share a center).
+///
+/// Radii are given as `(horizontal, vertical)` pairs.  If the angles are
+/// floating-point numbers they are interpreted as radians, otherwise as
+/// degrees.  Angles are measured around the center, as with [`Annulus`](super::Annulus),
+/// so a 45° edge runs diagonally even when the ellipses are squashed.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::elliptical_annulus;
+///
+/// let mut image = RgbaImage::new(400, 400);
+///
+/// // The top half of a flattened dial
+/// elliptical_annulus(&mut image, 0, 180, (150, 70), (190, 100), (200, 200), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if any of the radii are negative.
+///
+/// See also: [`Draw::elliptical_annulus`](crate::Draw::elliptical_annulus)
+///
+pub fn elliptical_annulus<A, C, I>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    inner_radii: (i32, i32),
+    outer_radii: (i32, i32),
+    center: C,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+    I: image::GenericImage,
+{
+    EllipticalAnnulus::new(start_angle, end_angle, inner_radii, outer_radii, center)
+        .draw(image, color);
+}
+
+/// An elliptical annulus, optionally limited to the angles between a start
+/// and end angle.
+///
+/// Implements [`Iterator`], returning horizontal runs of pixels from top to
+/// bottom as pairs of image coordinates (the left and right ends, inclusive).
+///
+/// A pixel is part of the annulus when its center lies inside the outer
+/// ellipse and outside the inner ellipse, with both ellipses widened by half a
+/// pixel so that, like [`Annulus`](super::Annulus), the pixels on both radii
+/// are drawn.  Equal horizontal and vertical radii produce a circular ring,
+/// and inner radii of 0 produce a filled (elliptical) sector.
+///
+/// ```
+/// use freehand::conics::EllipticalAnnulus;
+///
+/// let ring = EllipticalAnnulus::new(0, 360, (20, 10), (40, 20), (50, 50));
+/// assert!(ring.contains((85, 50)));
+/// assert!(!ring.contains((50, 50)));
+/// assert!(!ring.contains((50, 25)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct EllipticalAnnulus {
+    start: f64,
+    end: f64,
+    full: bool,
+    /// Inner radii, shrunk by half a pixel
+    inr: (f64, f64),
+    /// Outer radii, grown by half a pixel
+    otr: (f64, f64),
+    c: Pt<i32>,
+    /// The next row, relative to the center
+    y: i32,
+    /// Runs on the current row that have not been returned yet, in reverse
+    /// order, as a row and two x coordinates relative to the center
+    runs: Vec<(i32, i32, i32)>,
+}
+
+impl EllipticalAnnulus {
+    /// Creates a new [`EllipticalAnnulus`].
+    ///
+    /// Radii are `(horizontal, vertical)` pairs.  If the start and end angles
+    /// are the same, or the end angle is at least a full turn away from the
+    /// start angle, a complete ring is drawn.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any of the radii are negative.
+    pub fn new<A, P>(
+        start_angle: A,
+        end_angle: A,
+        inner_radii: (i32, i32),
+        outer_radii: (i32, i32),
+        center: P,
+    ) -> Self
+    where
+        A: crate::Angle,
+        P: Point<i32>,
+    {
+        match Self::try_new(start_angle, end_angle, inner_radii, outer_radii, center) {
+            Ok(annulus) => annulus,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Creates a new [`EllipticalAnnulus`], returning an error instead of
+    /// panicking if any of the radii are invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRadius`] if any of the radii are negative.
+    ///
+    /// ```
+    /// # use freehand::conics::EllipticalAnnulus;
+    /// assert!(EllipticalAnnulus::try_new(0, 180, (10, 5), (20, 10), (50, 50)).is_ok());
+    /// assert!(EllipticalAnnulus::try_new(0, 180, (10, -5), (20, 10), (50, 50)).is_err());
+    /// ```
+    pub fn try_new<A, P>(
+        start_angle: A,
+        end_angle: A,
+        inner_radii: (i32, i32),
+        outer_radii: (i32, i32),
+        center: P,
+    ) -> Result<Self, Error>
+    where
+        A: crate::Angle,
+        P: Point<i32>,
+    {
+        for r in [inner_radii.0, inner_radii.1, outer_radii.0, outer_radii.1] {
+            if r.is_negative() {
+                return Err(Error::InvalidRadius(r.into()));
+            }
+        }
+        let shrink = |r: i32| (f64::from(r) - 0.5).max(0.0);
+        let grow = |r: i32| f64::from(r) + 0.5;
+
+        Ok(Self {
+            start: angle::normalize(start_angle.radians()),
+            end: angle::normalize(end_angle.radians()),
+            full: angle::is_full_sweep(start_angle.radians(), end_angle.radians()),
+            inr: (shrink(inner_radii.0), shrink(inner_radii.1)),
+            otr: (grow(outer_radii.0), grow(outer_radii.1)),
+            c: center.pt(),
+            y: -outer_radii.1,
+            runs: Vec::new(),
+        })
+    }
+
+    /// Draws the annulus, skipping the parts outside of the image.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::EllipticalAnnulus;
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// let annulus = EllipticalAnnulus::new(0, 180, (150, 70), (190, 100), (200, 200));
+    /// annulus.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw<I>(mut self, image: &mut I, color: I::Pixel)
+    where
+        I: image::GenericImage,
+    {
+        #[allow(clippy::cast_possible_wrap)]
+        let (width, height) = (image.width() as i32, image.height() as i32);
+        // Only visit the rows and columns that are inside the image
+        self.y = self.y.max(-self.c.y());
+        let last = (height - 1 - self.c.y()).min(self.max_y());
+
+        while self.y <= last {
+            let y = self.y;
+            self.y += 1;
+            for (x1, x2) in self.row_spans(y) {
+                let x1 = x1.max(-self.c.x());
+                let x2 = x2.min(width - 1 - self.c.x());
+                if x1 > x2 {
+                    continue;
+                }
+                for (a, b) in self.split_by_angle(y, x1, x2) {
+                    crate::lines::horizontal_line(
+                        image,
+                        (self.c.x() + a, self.c.y() + y),
+                        self.c.x() + b,
+                        color,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns true if a pixel is part of the annulus, exactly matching the
+    /// pixels that are drawn.
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: Point<i32>,
+    {
+        let (x, y) = (pt.x() - self.c.x(), pt.y() - self.c.y());
+        self.in_ring(x, y) && self.in_sweep(x, y)
+    }
+
+    /// The last row of the annulus, relative to the center.
+    fn max_y(&self) -> i32 {
+        (self.otr.1 - 0.5) as i32
+    }
+
+    /// Returns true if a point relative to the center lies inside the outer
+    /// ellipse and outside the inner ellipse.
+    fn in_ring(&self, x: i32, y: i32) -> bool {
+        let inside = |(a, b): (f64, f64), strict: bool| {
+            if a == 0.0 || b == 0.0 {
+                return false;
+            }
+            let d = (f64::from(x) / a).powi(2) + (f64::from(y) / b).powi(2);
+            d < 1.0 || (!strict && d <= 1.0)
+        };
+        inside(self.otr, false) && !inside(self.inr, true)
+    }
+
+    /// Returns true if a point relative to the center is between the start
+    /// and end angles.  The center itself is included in every sweep.
+    fn in_sweep(&self, x: i32, y: i32) -> bool {
+        if self.full || (x == 0 && y == 0) {
+            return true;
+        }
+        let a = angle::point_angle(Pt::new(0.0, 0.0), Pt::new(f64::from(x), f64::from(y)));
+        angle::in_sweep(a, self.start, self.end, 0.0)
+    }
+
+    /// Returns up to two spans of x coordinates (relative to the center) on
+    /// the given row that lie inside the ring, ignoring the angles.
+    fn row_spans(&self, y: i32) -> Vec<(i32, i32)> {
+        // Half the width of an ellipse on this row
+        let half = |(a, b): (f64, f64)| {
+            let t = 1.0 - (f64::from(y) / b).powi(2);
+            (t >= 0.0).then(|| a * t.sqrt())
+        };
+
+        let outer = match half(self.otr) {
+            Some(w) => w.floor() as i32,
+            None => return Vec::new(),
+        };
+        // Pixels closer to the center than this are inside the hole
+        let inner = match half(self.inr) {
+            Some(w) if self.inr.0 > 0.0 && self.inr.1 > 0.0 && w > 0.0 => w.ceil() as i32,
+            _ => 0,
+        };
+        // Fix up rounding at the edges so the spans agree with `in_ring`
+        let outer = (outer.saturating_sub(1)..=outer.saturating_add(1))
+            .rev()
+            .find(|&x| self.in_ring(x, y))
+            .unwrap_or(outer);
+        let inner = (inner.saturating_sub(1)..=inner.saturating_add(1))
+            .find(|&x| x >= 0 && self.in_ring(x, y))
+            .unwrap_or(inner);
+
+        if inner > outer {
+            Vec::new()
+        } else if inner == 0 {
+            vec![(-outer, outer)]
+        } else {
+            vec![(-outer, -inner), (inner, outer)]
+        }
+    }
+
+    /// Splits a span into the runs that are between the start and end angles.
+    fn split_by_angle(&self, y: i32, x1: i32, x2: i32) -> Vec<(i32, i32)> {
+        if self.full {
+            return vec![(x1, x2)];
+        }
+        let mut runs = Vec::new();
+        let mut run: Option<i32> = None;
+        for x in x1..=x2 {
+            match (run, self.in_sweep(x, y)) {
+                (None, true) => run = Some(x),
+                (Some(a), false) => {
+                    runs.push((a, x - 1));
+                    run = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(a) = run {
+            runs.push((a, x2));
+        }
+        runs
+    }
+}
+
+impl Iterator for EllipticalAnnulus {
+    type Item = (Pt<i32>, Pt<i32>);
+
+    /// Returns the next horizontal run of pixels as a pair of image coordinates.
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.runs.is_empty() {
+            if self.y > self.max_y() {
+                return None;
+            }
+            let y = self.y;
+            self.y += 1;
+            for (x1, x2) in self.row_spans(y) {
+                for (a, b) in self.split_by_angle(y, x1, x2) {
+                    self.runs.push((y, a, b));
+                }
+            }
+            self.runs.reverse();
+        }
+        let (y, a, b) = self.runs.pop()?;
+        Some((
+            Pt::new(self.c.x() + a, self.c.y() + y),
+            Pt::new(self.c.x() + b, self.c.y() + y),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    fn drawn(annulus: &EllipticalAnnulus) -> image::RgbaImage {
+        let mut image = crate::test::img::blank((100, 100));
+        annulus.clone().draw(&mut image, RED);
+        image
+    }
+
+    #[test]
+    fn elliptical_ring() -> Result<(), image::ImageError> {
+        let ring = EllipticalAnnulus::new(0, 0, (20, 10), (40, 20), (50, 50));
+        let image = drawn(&ring);
+
+        // The ends of each radius are drawn
+        for (x, y) in [(90, 50), (10, 50), (50, 30), (50, 70), (70, 50), (50, 40)] {
+            assert_eq!(*image.get_pixel(x, y), RED, "({x}, {y})");
+        }
+        for (x, y) in [(50, 50), (91, 50), (50, 29), (69, 50), (50, 41)] {
+            assert_ne!(*image.get_pixel(x, y), RED, "({x}, {y})");
+        }
+
+        // Mirrored in both directions, and the pixels match `contains`
+        for (x, y, px) in image.enumerate_pixels() {
+            assert_eq!(*px, *image.get_pixel(100 - x.max(1), y), "({x}, {y})");
+            assert_eq!(*px, *image.get_pixel(x, 100 - y.max(1)), "({x}, {y})");
+            #[allow(clippy::cast_possible_wrap)]
+            let pt = (x as i32, y as i32);
+            assert_eq!(*px == RED, ring.contains(pt), "({x}, {y})");
+        }
+
+        // The area is close to the area between the two ellipses
+        let area = image.pixels().filter(|px| **px == RED).count() as f64;
+        let expected = std::f64::consts::PI * (40.5 * 20.5 - 19.5 * 9.5);
+        assert!(
+            (area - expected).abs() / expected < 0.03,
+            "{area} {expected}"
+        );
+        image.save("images/elliptical_ring.png")
+    }
+
+    #[test]
+    fn elliptical_sectors() {
+        // Equal radii give the same ring as a circular annulus, give or take
+        // a pixel of rounding along the edges
+        let circular = EllipticalAnnulus::new(30, 250, (10, 10), (30, 30), (50, 50));
+        let mut expected = crate::test::img::blank((100, 100));
+        crate::conics::annulus(&mut expected, 30, 250, 10, 30, (50, 50), RED);
+        let image = drawn(&circular);
+        let differ = image
+            .pixels()
+            .zip(expected.pixels())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert!(differ < 60, "{differ}");
+
+        // A quarter with no hole includes the center and stays in its quadrant
+        let slice = EllipticalAnnulus::new(0, 90, (0, 0), (30, 15), (50, 50));
+        let image = drawn(&slice);
+        assert_eq!(*image.get_pixel(50, 50), RED);
+        assert_eq!(*image.get_pixel(80, 50), RED);
+        assert_eq!(*image.get_pixel(50, 35), RED);
+        for (x, y, px) in image.enumerate_pixels() {
+            if *px == RED {
+                assert!(x >= 50 && y <= 50, "({x}, {y})");
+            }
+        }
+
+        // The iterator returns the same pixels that are drawn
+        for annulus in [
+            circular,
+            slice,
+            EllipticalAnnulus::new(300, 60, (5, 15), (20, 45), (50, 50)),
+        ] {
+            let mut image = crate::test::img::blank((100, 100));
+            for (a, b) in annulus.clone() {
+                assert_eq!(a.y(), b.y());
+                crate::lines::horizontal_line(&mut image, a, b.x(), RED);
+            }
+            assert_eq!(image, drawn(&annulus));
+        }
+    }
+
+    #[test]
+    fn elliptical_clipped() {
+        let annulus = EllipticalAnnulus::new(0, 360, (10, 5), (80, 40), (-20, 90));
+        let image = drawn(&annulus);
+        for (x, y, px) in image.enumerate_pixels() {
+            #[allow(clippy::cast_possible_wrap)]
+            let pt = (x as i32, y as i32);
+            assert_eq!(*px == RED, annulus.contains(pt), "({x}, {y})");
+        }
+
+        assert_eq!(
+            EllipticalAnnulus::try_new(0, 90, (-1, 5), (10, 10), (0, 0)).unwrap_err(),
+            Error::InvalidRadius(-1.0)
+        );
+    }
+
+    #[test]
+    fn huge_row_spans() {
+        let max = i32::MAX;
+        let annulus = EllipticalAnnulus::new(0, 360, (max - 1, 10), (max, 20), (0, 0));
+        assert_eq!(annulus.row_spans(0), vec![(-max, 1 - max), (max - 1, max)]);
+        let annulus = EllipticalAnnulus::new(0, 360, (0, 0), (max, max), (0, 0));
+        assert_eq!(annulus.row_spans(0), vec![(-max, max)]);
+    }
+}
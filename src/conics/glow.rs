@@ -0,0 +1,246 @@
+use crate::pt::Point;
+use crate::{angle, Pt};
+use image::{Rgba, RgbaImage};
+
+/// How the glow drawn by [`glow_arc`] and [`glow_circle`] looks: a solid core
+/// along the arc and a soft falloff on both sides of it.
+///
+/// ```
+/// use freehand::conics::Glow;
+///
+/// // A 2 pixel wide line fading out over 12 pixels on each side
+/// let glow = Glow::new(2.0, 12.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Glow {
+    /// The width of the solid core in pixels, centered on the arc's radius.
+    /// A width of 0 gives a glow with no solid core.
+    pub width: f32,
+    /// How far the glow reaches past each side of the core, in pixels
+    pub feather: f32,
+}
+
+impl Glow {
+    /// Creates a glow with a solid core of the given width that fades out over
+    /// `feather` pixels on each side.
+    #[must_use]
+    pub fn new(width: f32, feather: f32) -> Self {
+        Self { width, feather }
+    }
+
+    /// The opacity of a pixel `d` pixels away from the arc.
+    ///
+    /// The opacity falls off with the square of the distance past the core, so
+    /// the glow fades quickly near the core and lingers at the edge.  Without
+    /// a feather, the edge of the core is antialiased instead.
+    fn coverage(self, d: f64) -> f64 {
+        let hw = f64::from(self.width.max(0.0)) / 2.0;
+        let feather = f64::from(self.feather.max(0.0));
+        if feather == 0.0 {
+            return (hw + 0.5 - d).clamp(0.0, 1.0);
+        }
+        let t = (1.0 - (d - hw) / feather).clamp(0.0, 1.0);
+        t * t
+    }
+
+    /// How far from the arc the glow reaches.
+    fn reach(self) -> f64 {
+        f64::from(self.width.max(0.0)) / 2.0 + f64::from(self.feather.max(0.0)).max(0.5)
+    }
+}
+
+/// Draws a glowing circular arc: a solid core with a soft falloff around it,
+/// useful for highlighting points of interest.
+///
+/// Angles are interpreted the same way as [`arc`](super::arc).  If the start
+/// and end angles are the same, or the end angle is at least a full turn away
+/// from the start angle, a complete circle is drawn.  The glow wraps around
+/// the ends of the arc.
+///
+/// The opacity of each pixel is found from its exact distance to the arc,
+/// scaled by the color's alpha, and blended into the image.  Only the span of
+/// each row within reach of the arc is checked, so wide glows stay fast.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::{glow_arc, Glow};
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([0, 0, 0, 255]));
+/// glow_arc(&mut image, 30, 150, 150, (200, 200), Glow::new(3.0, 20.0), Rgba([255, 200, 0, 255]));
+///
+/// // The core is solid and the glow fades away from it
+/// assert_eq!(*image.get_pixel(200, 50), Rgba([255, 200, 0, 255]));
+/// assert!(image.get_pixel(200, 60)[0] < 255);
+/// assert!(image.get_pixel(200, 60)[0] > 0);
+/// ```
+///
+/// See also: [`Draw::glow_arc`](crate::Draw::glow_arc)
+///
+pub fn glow_arc<A, C>(
+    image: &mut RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: i32,
+    center: C,
+    glow: Glow,
+    color: Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+{
+    assert!(radius >= 0, "Radius must not be negative.  radius={radius}");
+    let full = angle::is_full_turn(start_angle, end_angle);
+    Glower {
+        c: Pt::new(f64::from(center.x()), f64::from(center.y())),
+        r: f64::from(radius),
+        start: start_angle.radians(),
+        end: end_angle.radians(),
+        full,
+        glow,
+    }
+    .draw(image, color);
+}
+
+/// Draws a glowing circle.  See [`glow_arc`].
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::{glow_circle, Glow};
+///
+/// let mut image = RgbaImage::from_pixel(200, 200, Rgba([255, 255, 255, 255]));
+/// // Circle a point of interest with a soft red halo
+/// glow_circle(&mut image, 30, (100, 100), Glow::new(2.0, 8.0), Rgba([255, 0, 0, 200]));
+/// ```
+///
+/// See also: [`Draw::glow_circle`](crate::Draw::glow_circle)
+///
+pub fn glow_circle<C>(image: &mut RgbaImage, radius: i32, center: C, glow: Glow, color: Rgba<u8>)
+where
+    C: Point<i32>,
+{
+    glow_arc(image, 0, 0, radius, center, glow, color);
+}
+
+/// Draws a [`Glow`] one row span at a time.
+struct Glower {
+    c: Pt<f64>,
+    r: f64,
+    start: f64,
+    end: f64,
+    full: bool,
+    glow: Glow,
+}
+
+impl Glower {
+    fn draw(&self, image: &mut RgbaImage, color: Rgba<u8>) {
+        let reach = self.glow.reach();
+        let outer = self.r + reach;
+        let inner = self.r - reach;
+
+        let (width, height) = (f64::from(image.width()), f64::from(image.height()));
+        let y0 = (self.c.y() - outer).floor().max(0.0);
+        let y1 = (self.c.y() + outer).ceil().min(height - 1.0);
+        let alpha = f64::from(color[3]) / 255.0;
+
+        let mut y = y0;
+        while y <= y1 {
+            let dy = y - self.c.y();
+            // The glow covers a ring, which crosses each row in one span, or
+            // two spans on either side of the hole in the middle
+            let xo = (outer * outer - dy * dy).max(0.0).sqrt();
+            let xi = if inner > 0.0 && dy.abs() < inner {
+                (inner * inner - dy * dy).sqrt()
+            } else {
+                -1.0
+            };
+            for (a, b) in [(-xo, -xi), (xi, xo)] {
+                if a > b {
+                    continue;
+                }
+                let x0 = (self.c.x() + a).floor().max(0.0);
+                let x1 = (self.c.x() + b).ceil().min(width - 1.0);
+                let mut x = x0;
+                while x <= x1 {
+                    let coverage = self.glow.coverage(self.distance(Pt::new(x, y))) * alpha;
+                    if coverage > 0.0 {
+                        // safe because x and y were clamped to the image above
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                        crate::ops::blend_at(image, x as u32, y as u32, coverage as f32, color);
+                    }
+                    x += 1.0;
+                }
+            }
+            y += 1.0;
+        }
+    }
+
+    /// The distance from a point to the closest point on the arc.
+    fn distance(&self, p: Pt<f64>) -> f64 {
+        if self.full || angle::in_sweep(angle::point_angle(self.c, p), self.start, self.end, 0.0) {
+            return (self.c.distance(p) - self.r).abs();
+        }
+        // Outside of the sweep the closest point is one of the ends
+        let end = |a: f64| {
+            Pt::new(
+                self.r.mul_add(a.cos(), self.c.x()),
+                (-self.r).mul_add(a.sin(), self.c.y()),
+            )
+        };
+        end(self.start).distance(p).min(end(self.end).distance(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn fades_with_distance() {
+        let mut image = crate::test::img::blank((100, 100));
+        glow_circle(&mut image, 30, (50, 50), Glow::new(2.0, 10.0), RED);
+
+        // Moving out from the core along a row, the glow gets fainter
+        let green: Vec<u8> = (80..=92).map(|x| image.get_pixel(x, 50)[1]).collect();
+        assert_eq!(green[0], 0);
+        assert!(green.windows(2).all(|w| w[0] <= w[1]), "{green:?}");
+        assert_eq!(green[12], 255);
+        // The same on the inside of the ring
+        assert!(image.get_pixel(72, 50)[1] < image.get_pixel(70, 50)[1]);
+        assert_eq!(*image.get_pixel(50, 50), Rgba([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn wraps_around_arc_ends() {
+        let mut image = crate::test::img::blank((100, 100));
+        glow_arc(&mut image, 0, 90, 30, (50, 50), Glow::new(2.0, 6.0), RED);
+
+        // Past the end at 0° the glow fades around the end of the arc
+        assert!(image.get_pixel(80, 53)[1] < 255);
+        assert_eq!(*image.get_pixel(80, 60), Rgba([255, 255, 255, 255]));
+        // Nothing on the far side of the circle
+        assert_eq!(*image.get_pixel(20, 50), Rgba([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn no_feather_is_antialiased_stroke() {
+        let mut image = crate::test::img::blank((100, 100));
+        glow_circle(&mut image, 30, (50, 50), Glow::new(3.0, 0.0), RED);
+        assert_eq!(*image.get_pixel(80, 50), RED);
+        assert_eq!(*image.get_pixel(83, 50), Rgba([255, 255, 255, 255]));
+    }
+}
@@ -0,0 +1,402 @@
+use crate::geo::segment_distance;
+use crate::lines::LineCap;
+use crate::pt::Point;
+use crate::{angle, Pt};
+use image::{Rgba, RgbaImage};
+
+/// How an arc is stroked by [`stroke_arc`]: its width, dashes, end caps, and
+/// whether it is antialiased.  Any combination of these can be used together.
+///
+/// ```
+/// use freehand::conics::ArcStroke;
+/// use freehand::lines::LineCap;
+///
+/// let stroke = ArcStroke {
+///     dash: 12,
+///     cap: LineCap::Round,
+///     antialiased: true,
+///     ..ArcStroke::new(6.0)
+/// };
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ArcStroke {
+    /// The width of the stroke in pixels, centered on the arc's radius
+    pub width: f32,
+    /// The length of each dash and each gap between dashes in pixels,
+    /// measured along the arc from its start angle, or 0 for a solid arc
+    pub dash: u16,
+    /// How the ends of the arc, and of each dash, are drawn
+    pub cap: LineCap,
+    /// Whether the edges of the stroke are antialiased
+    pub antialiased: bool,
+}
+
+impl ArcStroke {
+    /// Creates a solid stroke of the given width with butt caps and no
+    /// antialiasing.
+    #[must_use]
+    pub fn new(width: f32) -> Self {
+        Self {
+            width,
+            dash: 0,
+            cap: LineCap::Butt,
+            antialiased: false,
+        }
+    }
+}
+
+impl Default for ArcStroke {
+    /// A solid 1 pixel wide stroke without antialiasing, the same as [`arc`](super::arc).
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// Strokes a circular arc using an [`ArcStroke`], which combines a width,
+/// dashes, end caps, and antialiasing.
+///
+/// Angles are interpreted the same way as [`arc`](super::arc).  If the start
+/// and end angles are the same, or the end angle is at least a full turn away
+/// from the start angle, a complete circle is drawn; complete circles have no
+/// ends, so only the dashes get caps.
+///
+/// Strokes that one of the specialized functions can draw are passed to it:
+/// [`arc`](super::arc) for solid 1 pixel strokes,
+/// [`antialiased_arc`](super::antialiased_arc) for the antialiased version of
+/// those, and [`thick_arc`](super::thick_arc) for solid strokes with a whole
+/// number width and butt caps.  Everything else is drawn by checking the
+/// distance from each pixel to the stroke, so dashes and caps line up exactly
+/// with the arc and the antialiasing is the same at every edge.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::{stroke_arc, ArcStroke};
+/// use freehand::lines::LineCap;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// let stroke = ArcStroke {
+///     dash: 20,
+///     cap: LineCap::Round,
+///     antialiased: true,
+///     ..ArcStroke::new(8.0)
+/// };
+/// stroke_arc(&mut image, 30, 270, 150, (200, 200), stroke, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::stroke_arc`](crate::Draw::stroke_arc)
+///
+pub fn stroke_arc<A, C>(
+    image: &mut RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: i32,
+    center: C,
+    stroke: ArcStroke,
+    color: Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+{
+    assert!(radius >= 0, "Radius must not be negative.  radius={radius}");
+    let solid = stroke.dash == 0;
+    let butt = stroke.cap == LineCap::Butt;
+    let width = stroke.width.max(0.0);
+
+    match (solid, stroke.antialiased) {
+        (true, false) if width <= 1.0 && butt => {
+            super::arc(image, start_angle, end_angle, radius, center, color);
+        }
+        (true, true) if width <= 1.0 && butt => {
+            super::antialiased_arc(image, start_angle, end_angle, radius, center, color);
+        }
+        (true, false) if butt && width.fract() == 0.0 && width <= f32::from(i16::MAX) => {
+            super::thick_arc(
+                image,
+                start_angle,
+                end_angle,
+                radius,
+                width as i16,
+                center,
+                color,
+            );
+        }
+        _ => Stroker::new(
+            start_angle.radians(),
+            end_angle.radians(),
+            radius,
+            center.pt(),
+            stroke,
+        )
+        .draw(image, color),
+    }
+}
+
+/// Draws an [`ArcStroke`] by finding the distance from each pixel near the arc
+/// to the closest dash.
+struct Stroker {
+    c: Pt<f64>,
+    r: f64,
+    /// Half of the width
+    hw: f64,
+    /// The start angle, normalized
+    start: f64,
+    /// The angle covered by the arc
+    sweep: f64,
+    full: bool,
+    /// The angle covered by each dash and each gap, or 0 for a solid arc
+    dash: f64,
+    cap: LineCap,
+    antialiased: bool,
+}
+
+impl Stroker {
+    fn new(start: f64, end: f64, radius: i32, center: Pt<i32>, stroke: ArcStroke) -> Self {
+        let full = angle::is_full_sweep(start, end);
+        let start = angle::normalize(start);
+        let sweep = if full {
+            crate::PI2
+        } else {
+            angle::normalize(end - start)
+        };
+        let r = f64::from(radius);
+        Self {
+            c: Pt::new(f64::from(center.x()), f64::from(center.y())),
+            r,
+            hw: f64::from(stroke.width.max(0.0)) / 2.0,
+            start,
+            sweep,
+            full,
+            dash: if r == 0.0 {
+                0.0
+            } else {
+                f64::from(stroke.dash) / r
+            },
+            cap: stroke.cap,
+            antialiased: stroke.antialiased,
+        }
+    }
+
+    fn draw(&self, image: &mut RgbaImage, color: Rgba<u8>) {
+        // The sector covered by the stroke, widened by enough to include the
+        // caps and the antialiased edges
+        let inner = (self.r - self.hw).max(0.0).floor() as i32;
+        let outer = (self.r + self.hw).ceil() as i32;
+        let bounds = super::sector_bounds(
+            self.c.i32(),
+            self.start,
+            self.start + self.sweep,
+            self.full,
+            &[inner, outer],
+        );
+        let pad = self.hw.ceil() as i32 + 1;
+        let bounds = crate::shapes::Rect::new(
+            (bounds.x - pad, bounds.y - pad),
+            bounds.height + pad as u32 * 2,
+            bounds.width + pad as u32 * 2,
+        );
+        let visible = bounds.intersect(crate::shapes::Rect::new(
+            (0, 0),
+            image.height(),
+            image.width(),
+        ));
+        if visible.width == 0 || visible.height == 0 {
+            return;
+        }
+        let (x0, y0) = (visible.x as u32, visible.y as u32);
+
+        for y in y0..y0 + visible.height {
+            for x in x0..x0 + visible.width {
+                let d = self.distance(Pt::new(f64::from(x), f64::from(y)));
+                if self.antialiased {
+                    let coverage = (0.5 - d).clamp(0.0, 1.0);
+                    if coverage > 0.0 {
+                        crate::ops::blend_at(image, x, y, coverage as f32, color);
+                    }
+                } else if d <= 0.0 {
+                    image.put_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// The signed distance from a point to the edge of the stroke, negative
+    /// inside.
+    fn distance(&self, p: Pt<f64>) -> f64 {
+        let r = self.c.distance(p);
+        let across = (r - self.r).abs();
+        if across - self.hw > 1.0 {
+            return across - self.hw;
+        }
+
+        // The angle from the start of the arc, measured so that points just
+        // before the start are negative
+        let theta = angle::point_angle(self.c, p);
+        let mut pos = angle::normalize(theta - self.start);
+        if !self.full && pos > (self.sweep + crate::PI2) / 2.0 {
+            pos -= crate::PI2;
+        }
+
+        if self.dash == 0.0 {
+            if self.full {
+                return across - self.hw;
+            }
+            return self.piece_distance(p, r, across, pos, 0.0, self.sweep);
+        }
+
+        // Only the dashes close to the point can be the closest one
+        let period = self.dash * 2.0;
+        let count = (self.sweep / period).ceil() as i64;
+        let near = ((self.hw + 1.0) / (self.r * period)).ceil() as i64 + 1;
+        let offsets: &[f64] = if self.full {
+            &[0.0, crate::PI2, -crate::PI2]
+        } else {
+            &[0.0]
+        };
+
+        let mut closest = f64::MAX;
+        for &offset in offsets {
+            let pos = pos + offset;
+            let k = (pos / period).floor() as i64;
+            for k in (k - near).max(0)..=(k + near).min(count - 1) {
+                let t0 = k as f64 * period;
+                let t1 = (t0 + self.dash).min(self.sweep);
+                closest = closest.min(self.piece_distance(p, r, across, pos, t0, t1));
+            }
+        }
+        closest
+    }
+
+    /// The signed distance from a point to a single piece of the stroke
+    /// covering the angles from `t0` to `t1`, measured from the start angle.
+    fn piece_distance(&self, p: Pt<f64>, r: f64, across: f64, a: f64, t0: f64, t1: f64) -> f64 {
+        let (t0, t1) = match self.cap {
+            LineCap::Square if self.r > 0.0 => (t0 - self.hw / self.r, t1 + self.hw / self.r),
+            _ => (t0, t1),
+        };
+
+        let end = |t: f64| {
+            let angle = self.start + t;
+            let (sin, cos) = angle.sin_cos();
+            let at = |r: f64| Pt::new(self.c.x() + r * cos, self.c.y() - r * sin);
+            (
+                at(self.r),
+                at((self.r - self.hw).max(0.0)),
+                at(self.r + self.hw),
+            )
+        };
+        let (mid0, in0, out0) = end(t0);
+        let (mid1, in1, out1) = end(t1);
+
+        let flat = if (t0..=t1).contains(&a) {
+            // Inside of the sector: the closest edge is either the inner or
+            // outer edge, or one of the flat ends
+            let to_end = (a - t0).min(t1 - a).min(std::f64::consts::FRAC_PI_2);
+            (across - self.hw).max(-r * to_end.sin())
+        } else {
+            segment_distance(p, in0, out0).min(segment_distance(p, in1, out1))
+        };
+
+        match self.cap {
+            LineCap::Round => flat
+                .min(p.distance(mid0) - self.hw)
+                .min(p.distance(mid1) - self.hw),
+            LineCap::Butt | LineCap::Square => flat,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    fn drawn(image: &RgbaImage) -> usize {
+        image.pixels().filter(|p| p[1] == 0).count()
+    }
+
+    #[test]
+    fn fast_paths_match() {
+        let mut expected = crate::test::img::blank((100, 100));
+        super::super::thick_arc(&mut expected, 10, 200, 40, 5, (50, 50), RED);
+        let mut image = crate::test::img::blank((100, 100));
+        stroke_arc(&mut image, 10, 200, 40, (50, 50), ArcStroke::new(5.0), RED);
+        assert_eq!(image, expected);
+
+        let mut expected = crate::test::img::blank((100, 100));
+        super::super::arc(&mut expected, 10, 200, 40, (50, 50), RED);
+        let mut image = crate::test::img::blank((100, 100));
+        stroke_arc(&mut image, 10, 200, 40, (50, 50), ArcStroke::default(), RED);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn dashes_leave_gaps() {
+        let stroke = ArcStroke {
+            dash: 10,
+            ..ArcStroke::new(3.5)
+        };
+        let mut image = crate::test::img::blank((100, 100));
+        stroke_arc(&mut image, 0, 90, 40, (50, 50), stroke, RED);
+
+        // Walk along the arc: dashes start at 0° and alternate every 10 pixels
+        let at = |s: f64| {
+            let a = s / 40.0;
+            let p = Pt::new(50.0 + 40.0 * a.cos(), 50.0 - 40.0 * a.sin()).i32();
+            image.get_pixel(p.x() as u32, p.y() as u32)[1] == 0
+        };
+        assert!(at(5.0));
+        assert!(!at(15.0));
+        assert!(at(25.0));
+        assert!(!at(35.0));
+
+        let solid = ArcStroke::new(3.5);
+        let mut full = crate::test::img::blank((100, 100));
+        stroke_arc(&mut full, 0, 90, 40, (50, 50), solid, RED);
+        assert!(drawn(&image) < drawn(&full) * 2 / 3);
+        assert!(drawn(&image) > drawn(&full) / 3);
+    }
+
+    #[test]
+    fn caps_extend_the_ends() {
+        let mut counts = Vec::new();
+        for cap in [LineCap::Butt, LineCap::Round, LineCap::Square] {
+            let stroke = ArcStroke {
+                cap,
+                antialiased: true,
+                ..ArcStroke::new(8.0)
+            };
+            let mut image = crate::test::img::blank((100, 100));
+            stroke_arc(&mut image, 0, 90, 40, (50, 50), stroke, RED);
+            // Just past the start of the arc, below the x axis
+            let past = image.get_pixel(90, 52)[1];
+            counts.push((past, drawn(&image)));
+        }
+        let (butt, round, square) = (counts[0], counts[1], counts[2]);
+        assert_eq!(butt.0, 255);
+        assert!(round.0 < 255 && square.0 < 255);
+        assert!(butt.1 < round.1 && round.1 < square.1);
+    }
+
+    #[test]
+    fn full_circle_dashes_wrap() {
+        let stroke = ArcStroke {
+            dash: 7,
+            cap: LineCap::Round,
+            ..ArcStroke::new(2.0)
+        };
+        let mut image = crate::test::img::blank((100, 100));
+        stroke_arc(&mut image, 0, 360, 30, (50, 50), stroke, RED);
+        assert!(drawn(&image) > 0);
+        // Nothing is drawn far from the circle
+        assert_eq!(image.get_pixel(50, 50)[1], 255);
+        assert_eq!(image.get_pixel(50, 15)[1], 255);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::Pt;
+
+/// An arc joining two straight edges at a corner, touching each edge at a
+/// tangent so the outline stays smooth.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct CornerArc {
+    pub(crate) center: Pt<f64>,
+    pub(crate) radius: f64,
+    /// Where the arc meets the edge coming from the previous vertex
+    pub(crate) start: Pt<f64>,
+    /// Where the arc meets the edge going to the next vertex
+    pub(crate) end: Pt<f64>,
+}
+
+/// Finds the arc of the given radius that rounds off `corner`, between the
+/// edges from `prev` and to `next`.
+///
+/// The tangent points are kept within the first half of each edge, so the
+/// radius is reduced where a full sized arc would not fit between the
+/// neighboring corners.  Returns `None` if there is nothing to round: the
+/// radius is 0, an edge has no length, or the edges are parallel.
+pub(crate) fn corner_arc(
+    prev: Pt<f64>,
+    corner: Pt<f64>,
+    next: Pt<f64>,
+    radius: f64,
+) -> Option<CornerArc> {
+    let unit = |p: Pt<f64>| {
+        let (dx, dy) = (p.x() - corner.x(), p.y() - corner.y());
+        let len = dx.hypot(dy);
+        (len > 0.0).then(|| (dx / len, dy / len, len))
+    };
+    let (ax, ay, a_len) = unit(prev)?;
+    let (bx, by, b_len) = unit(next)?;
+
+    // Half of the angle between the two edges
+    let half = (ax * bx + ay * by).clamp(-1.0, 1.0).acos() / 2.0;
+    let (sin, tan) = (half.sin(), half.tan());
+    if radius <= 0.0 || sin < 1e-9 || tan > 1e9 {
+        return None;
+    }
+
+    // Distance from the corner to each tangent point
+    let dist = (radius / tan).min(a_len / 2.0).min(b_len / 2.0);
+    let radius = dist * tan;
+
+    // The center lies on the line halfway between the two edges
+    let (mx, my) = (ax + bx, ay + by);
+    let m_len = mx.hypot(my);
+    let to_center = radius / sin;
+
+    Some(CornerArc {
+        center: Pt::new(
+            corner.x() + mx / m_len * to_center,
+            corner.y() + my / m_len * to_center,
+        ),
+        radius,
+        start: Pt::new(corner.x() + ax * dist, corner.y() + ay * dist),
+        end: Pt::new(corner.x() + bx * dist, corner.y() + by * dist),
+    })
+}
+
+impl CornerArc {
+    /// Returns points along the arc from `start` to `end`, including both,
+    /// close enough together that straight lines between them follow the
+    /// curve to within a fraction of a pixel.
+    pub(crate) fn points(&self) -> Vec<Pt<f64>> {
+        let angle = |p: Pt<f64>| (p.y() - self.center.y()).atan2(p.x() - self.center.x());
+        let start = angle(self.start);
+        let mut sweep = angle(self.end) - start;
+        // Corners are always rounded the short way around
+        if sweep > std::f64::consts::PI {
+            sweep -= crate::PI2;
+        } else if sweep < -std::f64::consts::PI {
+            sweep += crate::PI2;
+        }
+
+        // Roughly 2 pixels per segment
+        let steps = (sweep.abs() * self.radius / 2.0).ceil().max(1.0) as u32;
+        let mut points: Vec<_> = (0..steps)
+            .map(|i| {
+                let a = f64::from(i).mul_add(sweep / f64::from(steps), start);
+                Pt::new(
+                    self.radius.mul_add(a.cos(), self.center.x()),
+                    self.radius.mul_add(a.sin(), self.center.y()),
+                )
+            })
+            .collect();
+        points[0] = self.start;
+        points.push(self.end);
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Pt<f64>, b: Pt<f64>) -> bool {
+        (a.x() - b.x()).abs() < 1e-9 && (a.y() - b.y()).abs() < 1e-9
+    }
+
+    #[test]
+    fn square_corner() {
+        let arc = corner_arc(
+            Pt::new(0.0, 50.0),
+            Pt::new(0.0, 0.0),
+            Pt::new(50.0, 0.0),
+            10.0,
+        )
+        .unwrap();
+        assert!(close(arc.center, Pt::new(10.0, 10.0)), "{arc:?}");
+        assert!(close(arc.start, Pt::new(0.0, 10.0)), "{arc:?}");
+        assert!(close(arc.end, Pt::new(10.0, 0.0)), "{arc:?}");
+        assert!((arc.radius - 10.0).abs() < 1e-9);
+
+        // Every point is on the circle
+        for pt in arc.points() {
+            let d = (pt.x() - 10.0).hypot(pt.y() - 10.0);
+            assert!((d - 10.0).abs() < 1e-9, "{pt:?}");
+        }
+
+        // Limited to half of the shorter edge
+        let arc = corner_arc(
+            Pt::new(0.0, 8.0),
+            Pt::new(0.0, 0.0),
+            Pt::new(50.0, 0.0),
+            10.0,
+        )
+        .unwrap();
+        assert!((arc.radius - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nothing_to_round() {
+        let (a, b) = (Pt::new(0.0, 0.0), Pt::new(10.0, 0.0));
+        assert_eq!(corner_arc(a, b, Pt::new(20.0, 0.0), 5.0), None);
+        assert_eq!(corner_arc(a, b, b, 5.0), None);
+        assert_eq!(corner_arc(a, b, Pt::new(10.0, 10.0), 0.0), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Curves described by control points instead of pixels.
+//!
+//! A [`CubicBezier`] can be flattened into points for the drawing functions
+//! (like [`fill_path`](crate::raster::fill_path)), or written out as SVG path
+//! data with [`svg_path`].  Circular arcs can be converted into Béziers with
+//! [`Arc::to_beziers`](crate::conics::Arc::to_beziers).
+//!
+//! Curves given by a closure can be plotted with [`parametric`] and [`polar`],
+//! and functions of `x` with [`graph`].
+
+mod graph;
+mod plot;
+
+pub use graph::graph;
+pub use plot::{antialiased_parametric, parametric, polar, Samples};
+
+use crate::pt::Point;
+use crate::Pt;
+use image::GenericImage;
+use std::fmt::Write;
+
+/// The flattening tolerance, in pixels, used when drawing curves.
+///
+/// Curves are split into however many segments are needed to stay within this
+/// distance of the true curve, so large curves stay smooth while small ones are
+/// drawn with only a few segments.
+pub const DEFAULT_TOLERANCE: f64 = 0.25;
+
+/// Most segments a single curve will be flattened into, only reached when the
+/// tolerance is tiny (or zero).
+const MAX_SEGMENTS: usize = 4096;
+
+/// A cubic Bézier curve from `start` to `end`, shaped by two control points.
+///
+/// ```
+/// use freehand::curves::CubicBezier;
+/// use freehand::Pt;
+///
+/// let curve = CubicBezier::new((0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0));
+/// assert_eq!(curve.point_at(0.5), Pt::new(5.0, 7.5));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CubicBezier {
+    /// The point the curve starts at
+    pub start: Pt<f64>,
+    /// The control point the curve leaves `start` towards
+    pub ctrl1: Pt<f64>,
+    /// The control point the curve arrives at `end` from
+    pub ctrl2: Pt<f64>,
+    /// The point the curve ends at
+    pub end: Pt<f64>,
+}
+
+impl CubicBezier {
+    /// Creates a new cubic Bézier curve.
+    pub fn new<P>(start: P, ctrl1: P, ctrl2: P, end: P) -> Self
+    where
+        P: Point<f64>,
+    {
+        Self {
+            start: start.pt(),
+            ctrl1: ctrl1.pt(),
+            ctrl2: ctrl2.pt(),
+            end: end.pt(),
+        }
+    }
+
+    /// Returns the point on the curve at `t`, where `0.0` is the start and
+    /// `1.0` is the end.
+    #[must_use]
+    pub fn point_at(&self, t: f64) -> Pt<f64> {
+        let s = 1.0 - t;
+        let weights = [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t];
+        let points = [self.start, self.ctrl1, self.ctrl2, self.end];
+
+        points
+            .iter()
+            .zip(weights)
+            .fold(Pt::new(0.0, 0.0), |acc, (pt, w)| {
+                Pt::new(pt.x().mul_add(w, acc.x()), pt.y().mul_add(w, acc.y()))
+            })
+    }
+
+    /// Returns `n` points spaced evenly by `t` along the curve, including both
+    /// the start and end points.  A single sample returns the start point.
+    ///
+    /// ```
+    /// use freehand::curves::CubicBezier;
+    /// use freehand::Pt;
+    ///
+    /// let curve = CubicBezier::new((0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0));
+    /// let points = curve.sample(3);
+    /// assert_eq!(points, vec![Pt::new(0.0, 0.0), Pt::new(5.0, 7.5), Pt::new(10.0, 0.0)]);
+    /// ```
+    #[must_use]
+    pub fn sample(&self, n: usize) -> Vec<Pt<f64>> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.start],
+            n => {
+                let steps = (n - 1) as f64;
+                (0..n).map(|i| self.point_at(i as f64 / steps)).collect()
+            }
+        }
+    }
+
+    /// Returns how many straight segments are needed for a polyline through
+    /// the curve to stay within `tolerance` pixels of it.
+    ///
+    /// ```
+    /// use freehand::curves::CubicBezier;
+    ///
+    /// let small = CubicBezier::new((0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0));
+    /// let large = CubicBezier::new((0.0, 0.0), (0.0, 400.0), (400.0, 400.0), (400.0, 0.0));
+    /// assert_eq!(small.segments(0.25), 7);
+    /// assert_eq!(large.segments(0.25), 42);
+    /// ```
+    #[must_use]
+    pub fn segments(&self, tolerance: f64) -> usize {
+        // Wang's formula, using the largest second difference of the control points
+        let second = |a: Pt<f64>, b: Pt<f64>, c: Pt<f64>| {
+            (a.x() - 2.0 * b.x() + c.x()).hypot(a.y() - 2.0 * b.y() + c.y())
+        };
+        let dd = second(self.start, self.ctrl1, self.ctrl2)
+            .max(second(self.ctrl1, self.ctrl2, self.end));
+
+        let n = (0.75 * dd / tolerance).sqrt().ceil();
+        if n.is_nan() || n >= MAX_SEGMENTS as f64 {
+            // A zero tolerance on a straight curve gives NaN
+            if dd == 0.0 {
+                1
+            } else {
+                MAX_SEGMENTS
+            }
+        } else {
+            (n as usize).max(1)
+        }
+    }
+
+    /// Returns points along the curve, including both end points, such that
+    /// straight lines between them never stray more than `tolerance` pixels
+    /// from the curve.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::curves::CubicBezier;
+    /// use freehand::raster::{fill_path, FillRule};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let curve = CubicBezier::new((20.0, 380.0), (20.0, 20.0), (380.0, 20.0), (380.0, 380.0));
+    /// let points = curve.flatten(0.1);
+    /// fill_path(&mut image, points, FillRule::NonZero, Rgba([255, 0, 0, 255]));
+    /// ```
+    #[must_use]
+    pub fn flatten(&self, tolerance: f64) -> Vec<Pt<f64>> {
+        self.sample(self.segments(tolerance) + 1)
+    }
+
+    /// Draws the curve, flattened with [`DEFAULT_TOLERANCE`].
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::curves::CubicBezier;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let curve = CubicBezier::new((20.0, 380.0), (20.0, 20.0), (380.0, 20.0), (380.0, 380.0));
+    /// curve.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let points = self
+            .flatten(DEFAULT_TOLERANCE)
+            .into_iter()
+            .map(Pt::<i32>::from);
+        crate::lines::path(image, points, color);
+    }
+}
+
+/// Returns SVG path data (the `d` attribute of a `<path>` element) for a
+/// sequence of curves.
+///
+/// A new subpath is started (with a move command) whenever a curve does not
+/// start where the previous one ended.
+///
+/// ```
+/// use freehand::conics::Arc;
+/// use freehand::curves::svg_path;
+///
+/// let curves = Arc::new(0, 90, 10, (20, 20)).to_beziers(0.01);
+/// assert!(svg_path(&curves).starts_with("M 30 20 C "));
+/// ```
+#[must_use]
+pub fn svg_path(curves: &[CubicBezier]) -> String {
+    let mut path = String::new();
+    let mut last = None;
+
+    for curve in curves {
+        if last != Some(curve.start) {
+            if !path.is_empty() {
+                path.push(' ');
+            }
+            let _ = write!(path, "M {} {}", curve.start.x(), curve.start.y());
+        }
+        let _ = write!(
+            path,
+            " C {} {} {} {} {} {}",
+            curve.ctrl1.x(),
+            curve.ctrl1.y(),
+            curve.ctrl2.x(),
+            curve.ctrl2.y(),
+            curve.end.x(),
+            curve.end.y()
+        );
+        last = Some(curve.end);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_path_joins_connected_curves() {
+        let a = CubicBezier::new((0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0));
+        let b = CubicBezier::new((3.0, 0.0), (4.0, 0.0), (5.0, 0.0), (6.0, 0.5));
+        let c = CubicBezier::new((9.0, 9.0), (9.0, 9.0), (9.0, 9.0), (9.0, 9.0));
+        assert_eq!(
+            svg_path(&[a, b, c]),
+            "M 0 0 C 1 0 2 0 3 0 C 4 0 5 0 6 0.5 M 9 9 C 9 9 9 9 9 9"
+        );
+    }
+
+    #[test]
+    fn flatten_within_tolerance() {
+        let curves = [
+            CubicBezier::new((0.0, 0.0), (0.0, 400.0), (400.0, 400.0), (400.0, 0.0)),
+            CubicBezier::new((10.0, 10.0), (300.0, 20.0), (-50.0, 90.0), (200.0, 200.0)),
+            CubicBezier::new((5.0, 5.0), (6.0, 6.0), (7.0, 7.0), (8.0, 8.0)),
+        ];
+        for curve in curves {
+            for tolerance in [1.0, 0.25, 0.01] {
+                let points = curve.flatten(tolerance);
+                assert_eq!(points.first(), Some(&curve.start));
+                assert_eq!(points.last(), Some(&curve.end));
+
+                let segments = (points.len() - 1) as f64;
+                let worst = (0..=1000)
+                    .map(|i| {
+                        let t = f64::from(i) / 1000.0;
+                        let pos = t * segments;
+                        let seg = (pos.floor() as usize).min(points.len() - 2);
+                        let chord = points[seg].lerp(points[seg + 1], pos - seg as f64);
+                        curve.point_at(t).distance(chord)
+                    })
+                    .fold(0.0, f64::max);
+                assert!(worst <= tolerance, "{worst} > {tolerance}");
+            }
+        }
+        assert_eq!(curves[2].segments(0.0), 1);
+        assert_eq!(curves[0].segments(0.0), MAX_SEGMENTS);
+    }
+}
@@ -0,0 +1,160 @@
+use image::GenericImage;
+use std::ops::Range;
+
+/// Draws the graph of a function `y = f(x)`, sampling it once for each column
+/// of pixels in `columns`.
+///
+/// The closure is given the column's x coordinate and returns the row the
+/// graph passes through in that column, so any scaling from the function's
+/// units into pixels happens inside of it.  Only the columns inside of the
+/// image are sampled (along with one just outside each edge, so lines leaving
+/// the image are drawn up to its edge).
+///
+/// Where the function jumps between neighboring columns, each column is
+/// extended halfway towards its neighbor with a vertical span, so steep parts
+/// of the graph stay connected.  Jumps that are not steep slopes but breaks in
+/// the function, like the asymptotes of `tan(x)`, are left open: a jump is only
+/// connected if the function halfway between the columns lands strictly
+/// between the two values.  Columns where the closure returns NaN or an
+/// infinite value are skipped.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::curves::graph;
+///
+/// let mut image = RgbaImage::from_pixel(400, 200, Rgba([255, 255, 255, 255]));
+///
+/// // y = tan(x) from -2π to 2π, 50 pixels per unit in y
+/// let scale = 4.0 * std::f64::consts::PI / 400.0;
+/// let tan = |px: f64| 100.0 - 50.0 * ((px - 200.0) * scale).tan();
+/// graph(&mut image, 0..400, tan, Rgba([0, 0, 255, 255]));
+/// ```
+///
+/// See also: [`Draw::graph`](crate::Draw::graph)
+///
+pub fn graph<I, F>(image: &mut I, columns: Range<i32>, mut f: F, color: I::Pixel)
+where
+    I: GenericImage,
+    F: FnMut(f64) -> f64,
+{
+    check_img_i32!(image);
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+    let height = f64::from(image.height());
+
+    let x0 = columns.start.max(-1);
+    let x1 = columns.end.min(width.saturating_add(1));
+    if x0 >= x1 {
+        return;
+    }
+
+    let mut sample = |x: f64| Some(f(x)).filter(|y| y.is_finite());
+    let rows: Vec<Option<f64>> = (x0..x1).map(|x| sample(f64::from(x))).collect();
+    // Whether each column is connected to the next one
+    let joined: Vec<bool> = rows
+        .windows(2)
+        .zip(x0..)
+        .map(|(pair, x)| match (pair[0], pair[1]) {
+            (Some(a), Some(b)) if (a - b).abs() <= 1.0 => true,
+            (Some(a), Some(b)) => {
+                sample(f64::from(x) + 0.5).map_or(false, |mid| a.min(b) < mid && mid < a.max(b))
+            }
+            _ => false,
+        })
+        .collect();
+
+    for (i, x) in (x0..x1).enumerate() {
+        let y = match rows[i] {
+            Some(y) => y.round(),
+            None => continue,
+        };
+        let (mut top, mut bottom) = (y, y);
+        let before = i.checked_sub(1).filter(|&j| joined[j]);
+        let after = Some(i)
+            .filter(|&j| j < joined.len() && joined[j])
+            .map(|j| j + 1);
+        for n in [before, after]
+            .into_iter()
+            .flatten()
+            .filter_map(|j| rows[j])
+        {
+            // Half of the jump belongs to this column, the rest to its neighbor
+            let reach = y + ((n.round() - y) / 2.0).trunc();
+            top = top.min(reach);
+            bottom = bottom.max(reach);
+        }
+        if bottom < 0.0 || top >= height {
+            continue;
+        }
+        // safe because the rows are clamped to just outside of the image first
+        #[allow(clippy::cast_possible_truncation)]
+        let (top, bottom) = (top.max(-1.0) as i32, bottom.min(height) as i32);
+        crate::lines::vertical_line(image, (x, top), bottom, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    fn column(image: &image::RgbaImage, x: u32) -> Vec<u32> {
+        (0..image.height())
+            .filter(|&y| *image.get_pixel(x, y) == RED)
+            .collect()
+    }
+
+    #[test]
+    fn steep_jumps_are_connected() {
+        let mut image = crate::test::img::blank((20, 40));
+        graph(&mut image, 0..20, |x| 3.0 * x, RED);
+        // Each column covers half of the jump to each neighbor
+        assert_eq!(column(&image, 0), [0, 1]);
+        assert_eq!(column(&image, 5), [14, 15, 16]);
+        assert_eq!(column(&image, 6), [17, 18, 19]);
+        assert_eq!(column(&image, 13), [38, 39]);
+        assert!(column(&image, 14).is_empty());
+
+        // Gentle slopes have one pixel per column
+        let mut image = crate::test::img::blank((20, 20));
+        graph(&mut image, 5..15, |x| x / 2.0, RED);
+        assert_eq!(image.pixels().filter(|&&px| px == RED).count(), 10);
+        assert!(column(&image, 4).is_empty());
+    }
+
+    #[test]
+    fn breaks_are_left_open() {
+        // A step down between columns 9 and 10
+        let step = |x: f64| if x < 9.5 { 5.0 } else { 30.0 };
+        let mut image = crate::test::img::blank((20, 40));
+        graph(&mut image, 0..20, step, RED);
+        assert_eq!(column(&image, 9), [5]);
+        assert_eq!(column(&image, 10), [30]);
+
+        // Values that are not finite are skipped
+        let mut image = crate::test::img::blank((20, 40));
+        graph(&mut image, 0..20, |x| 10.0 / (x - 10.0) + 20.0, RED);
+        assert!(column(&image, 10).is_empty());
+        assert_eq!(column(&image, 11), [28, 29, 30]);
+        assert_eq!(*image.get_pixel(9, 0), WHITE);
+    }
+
+    #[test]
+    fn clipped_to_image() {
+        let mut image = crate::test::img::blank((10, 10));
+        graph(&mut image, i32::MIN..i32::MAX, |x| 1e12 * (x - 4.5), RED);
+        // Only the steep middle crosses the image
+        assert_eq!(column(&image, 4), [0]);
+        assert_eq!(column(&image, 5), (0..10).collect::<Vec<_>>());
+        assert!(column(&image, 3).is_empty());
+    }
+}
@@ -0,0 +1,443 @@
+use super::DEFAULT_TOLERANCE;
+use crate::pt::Point;
+use crate::{Angle, Pt};
+use image::{GenericImage, Rgba, RgbaImage};
+use std::ops::Range;
+
+/// How many evenly spaced samples a curve starts with before any are split.
+/// Starting with several keeps small loops from being stepped over entirely.
+const INITIAL_SAMPLES: usize = 64;
+
+/// How many times each initial sample may be split in half.
+const MAX_DEPTH: u32 = 10;
+
+/// How a curve given by a closure is sampled before being drawn as straight
+/// lines between the samples.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Samples {
+    /// A fixed number of evenly spaced samples, including both ends of the
+    /// range.  Fewer than 2 samples draws nothing.
+    Count(usize),
+    /// As many samples as needed for the lines between them to stay within
+    /// this many pixels of the curve.  More samples are taken where the curve
+    /// bends sharply.  `Tolerance(DEFAULT_TOLERANCE)` is the default.
+    Tolerance(f64),
+}
+
+impl Default for Samples {
+    fn default() -> Self {
+        Self::Tolerance(DEFAULT_TOLERANCE)
+    }
+}
+
+/// Draws a parametric curve: the closure maps each `t` in `range` to a point
+/// in the image, and the samples are joined by straight lines.
+///
+/// With [`Samples::Tolerance`] the curve is refined wherever it bends more
+/// than the tolerance allows.  Values of `t` where the closure returns a point
+/// that is NaN or infinite are skipped, leaving a gap in the curve.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::curves::{parametric, Samples};
+/// use std::f64::consts::TAU;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // A Lissajous figure
+/// let lissajous = |t: f64| (200.0 + 180.0 * (3.0 * t).sin(), 200.0 + 180.0 * (2.0 * t).sin());
+/// parametric(&mut image, 0.0..TAU, Samples::default(), lissajous, Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`antialiased_parametric`], [`Draw::parametric`](crate::Draw::parametric)
+///
+pub fn parametric<I, P, F>(
+    image: &mut I,
+    range: Range<f64>,
+    samples: Samples,
+    mut f: F,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<f64>,
+    F: FnMut(f64) -> P,
+{
+    for piece in sample(range.start, range.end, samples, |t| f(t).pt()) {
+        crate::lines::path(image, piece.iter().map(Pt::<f64>::i32), color);
+    }
+}
+
+/// Draws an antialiased parametric curve.  See [`parametric`].
+///
+/// Pixels where the curve crosses itself are only blended once.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::curves::{antialiased_parametric, Samples};
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // A sine wave made of 50 straight lines
+/// let wave = |t: f64| (t, 200.0 - 100.0 * (t / 40.0).sin());
+/// antialiased_parametric(&mut image, 0.0..400.0, Samples::Count(51), wave, Rgba([0, 0, 255, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_parametric`](crate::Draw::antialiased_parametric)
+///
+pub fn antialiased_parametric<P, F>(
+    image: &mut RgbaImage,
+    range: Range<f64>,
+    samples: Samples,
+    mut f: F,
+    color: Rgba<u8>,
+) where
+    P: Point<f64>,
+    F: FnMut(f64) -> P,
+{
+    let pieces = sample(range.start, range.end, samples, |t| f(t).pt());
+    draw_pieces(image, &pieces, color);
+}
+
+/// Draws an antialiased polar curve: each angle `theta` in `range` is mapped to
+/// the point `f(theta) * scale` pixels from `center`.  Good for drawing rose
+/// curves, cardioids, and spirals.
+///
+/// The closure is given angles in radians, though `range` may use any of the
+/// [angle conventions](crate#note-on-angles), so `0..360` goes once around the
+/// center.  Negative values of `f` point in the opposite direction.
+///
+/// The curve is sampled adaptively: more samples are taken where it bends
+/// sharply, so that straight lines between them stay within
+/// [`DEFAULT_TOLERANCE`] of the curve.  Angles where `f` returns NaN or an
+/// infinite value are skipped, leaving a gap in the curve.  Pixels where the
+/// curve crosses itself are only blended once.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::curves::polar;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // A rose with 8 petals
+/// polar(&mut image, (200, 200), 180.0, 0..360, |t| (4.0 * t).cos(), Rgba([200, 0, 100, 255]));
+/// // A cardioid
+/// polar(&mut image, (200, 200), 60.0, 0..360, |t| 1.0 - t.sin(), Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::polar`](crate::Draw::polar)
+///
+pub fn polar<A, C, T, F>(
+    image: &mut RgbaImage,
+    center: C,
+    scale: f64,
+    range: Range<A>,
+    mut f: F,
+    color: Rgba<u8>,
+) where
+    A: Angle,
+    C: Point<T>,
+    T: Into<f64> + Copy,
+    F: FnMut(f64) -> f64,
+{
+    let center = Pt::new(center.x().into(), center.y().into());
+    let pieces = sample(
+        range.start.radians(),
+        range.end.radians(),
+        Samples::default(),
+        |theta| Pt::from_radian(theta, scale * f(theta), center),
+    );
+    draw_pieces(image, &pieces, color);
+}
+
+/// Draws antialiased lines through each piece of a curve.
+fn draw_pieces(image: &mut RgbaImage, pieces: &[Vec<Pt<f64>>], color: Rgba<u8>) {
+    let mut pen = Pen::new(image.width(), image.height());
+    for piece in pieces {
+        pen.polyline(piece);
+    }
+    pen.draw(image, color);
+}
+
+/// Samples a curve from `t0` to `t1`, returning the connected pieces of it.
+///
+/// A sample that is not a finite point ends the current piece.
+fn sample<F>(t0: f64, t1: f64, samples: Samples, mut f: F) -> Vec<Vec<Pt<f64>>>
+where
+    F: FnMut(f64) -> Pt<f64>,
+{
+    let (count, depth, tolerance) = match samples {
+        Samples::Count(n) => (n.saturating_sub(1), 0, 0.0),
+        Samples::Tolerance(tolerance) => (INITIAL_SAMPLES, MAX_DEPTH, tolerance),
+    };
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let mut curve = Sampler {
+        f: &mut f,
+        tolerance,
+        pieces: Vec::new(),
+        piece: Vec::new(),
+    };
+    let step = (t1 - t0) / count as f64;
+    let mut prev = (t0, curve.eval(t0));
+    if let Some(p) = prev.1 {
+        curve.piece.push(p);
+    }
+    for i in 1..=count {
+        let t = if i == count {
+            t1
+        } else {
+            step.mul_add(i as f64, t0)
+        };
+        let next = (t, curve.eval(t));
+        match (prev.1, next.1) {
+            (Some(a), Some(b)) => curve.refine((prev.0, a), (t, b), depth),
+            (_, Some(b)) => curve.piece.push(b),
+            (_, None) => curve.split(),
+        }
+        prev = next;
+    }
+    curve.split();
+    curve.pieces
+}
+
+/// Collects the pieces of a curve for [`sample`].
+struct Sampler<'f, F> {
+    f: &'f mut F,
+    tolerance: f64,
+    pieces: Vec<Vec<Pt<f64>>>,
+    /// The piece being added to
+    piece: Vec<Pt<f64>>,
+}
+
+impl<F> Sampler<'_, F>
+where
+    F: FnMut(f64) -> Pt<f64>,
+{
+    fn eval(&mut self, t: f64) -> Option<Pt<f64>> {
+        let p = (self.f)(t);
+        (p.x().is_finite() && p.y().is_finite()).then(|| p)
+    }
+
+    /// Adds the points after `a` up to and including `b`, splitting the
+    /// samples in half until the point between them is within `tolerance` of
+    /// a straight line.
+    fn refine(&mut self, a: (f64, Pt<f64>), b: (f64, Pt<f64>), depth: u32) {
+        if depth == 0 {
+            self.piece.push(b.1);
+            return;
+        }
+        let t = (a.0 + b.0) / 2.0;
+        match self.eval(t) {
+            Some(m) if distance_to_segment(m, a.1, b.1) <= self.tolerance => {
+                self.piece.push(b.1);
+            }
+            Some(m) => {
+                self.refine(a, (t, m), depth - 1);
+                self.refine((t, m), b, depth - 1);
+            }
+            None => {
+                self.split();
+                self.piece.push(b.1);
+            }
+        }
+    }
+
+    /// Ends the current piece.  Single points are dropped.
+    fn split(&mut self) {
+        let piece = std::mem::take(&mut self.piece);
+        if piece.len() > 1 {
+            self.pieces.push(piece);
+        }
+    }
+}
+
+/// The distance from `p` to the line segment between `a` and `b`.
+fn distance_to_segment(p: Pt<f64>, a: Pt<f64>, b: Pt<f64>) -> f64 {
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len2 = dx.mul_add(dx, dy * dy);
+    if len2 == 0.0 {
+        return p.distance(a);
+    }
+    let t = ((p.x() - a.x()).mul_add(dx, (p.y() - a.y()) * dy) / len2).clamp(0.0, 1.0);
+    p.distance(a.lerp(b, t))
+}
+
+/// Draws single pixel wide antialiased lines between points that need not be
+/// on pixel centers, keeping the highest coverage of each pixel so that joints
+/// and crossings are not blended more than once.
+struct Pen {
+    width: u32,
+    height: u32,
+    /// The row, column, and coverage of each pixel plotted
+    coverage: Vec<(u32, u32, f32)>,
+}
+
+impl Pen {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            coverage: Vec::new(),
+        }
+    }
+
+    fn polyline(&mut self, points: &[Pt<f64>]) {
+        for pair in points.windows(2) {
+            self.line(pair[0], pair[1]);
+        }
+    }
+
+    /// Plots a line using Xiaolin Wu's algorithm.
+    fn line(&mut self, a: Pt<f64>, b: Pt<f64>) {
+        let steep = (b.y() - a.y()).abs() > (b.x() - a.x()).abs();
+        // Step along the longer axis, swapping x and y for steep lines
+        let (mut a, mut b) = if steep {
+            (Pt::new(a.y(), a.x()), Pt::new(b.y(), b.x()))
+        } else {
+            (a, b)
+        };
+        if a.x() > b.x() {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let dx = b.x() - a.x();
+        let gradient = if dx == 0.0 { 0.0 } else { (b.y() - a.y()) / dx };
+
+        // Only the columns inside of the image are visited
+        let limit = f64::from(if steep { self.height } else { self.width });
+        let x0 = a.x().round().max(0.0);
+        let x1 = b.x().round().min(limit - 1.0);
+        let mut x = x0;
+        while x <= x1 {
+            let y = gradient.mul_add(x.clamp(a.x(), b.x()) - a.x(), a.y());
+            let fy = y.floor();
+            let fract = (y - fy) as f32;
+            self.plot(steep, x, fy, 1.0 - fract);
+            self.plot(steep, x, fy + 1.0, fract);
+            x += 1.0;
+        }
+    }
+
+    fn plot(&mut self, steep: bool, x: f64, y: f64, coverage: f32) {
+        let (x, y) = if steep { (y, x) } else { (x, y) };
+        if coverage > 0.0
+            && x >= 0.0
+            && y >= 0.0
+            && x < f64::from(self.width)
+            && y < f64::from(self.height)
+        {
+            self.coverage.push((y as u32, x as u32, coverage));
+        }
+    }
+
+    fn draw(mut self, image: &mut RgbaImage, color: Rgba<u8>) {
+        self.coverage.sort_by_key(|&(y, x, _)| (y, x));
+        let mut pixels = self.coverage.into_iter().peekable();
+        while let Some((y, x, mut coverage)) = pixels.next() {
+            while let Some(&(_, _, c)) = pixels.peek().filter(|p| (p.0, p.1) == (y, x)) {
+                coverage = coverage.max(c);
+                pixels.next();
+            }
+            crate::ops::blend_at(image, x, y, coverage, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    #[test]
+    fn circle_and_gaps() {
+        let mut image = crate::test::img::blank((100, 100));
+        polar(&mut image, (50, 50), 30.0, 0..360, |_| 1.0, RED);
+        for angle in (0..360).step_by(15) {
+            let pt = Pt::from_angle(angle, 30.0, (50.0, 50.0)).i32();
+            let px = image.get_pixel(pt.x() as u32, pt.y() as u32);
+            assert!(px[1] < 128, "{angle}: {px:?}");
+        }
+        assert_eq!(*image.get_pixel(50, 50), WHITE);
+
+        // Only the left half, where the closure gives a finite value
+        let mut image = crate::test::img::blank((100, 100));
+        let half = |t: f64| if t.cos() < 0.0 { 1.0 } else { f64::NAN };
+        polar(
+            &mut image,
+            (50.0, 50.0),
+            30.0,
+            0.0..std::f64::consts::TAU,
+            half,
+            RED,
+        );
+        assert_eq!(*image.get_pixel(20, 50), RED);
+        assert_eq!(*image.get_pixel(80, 50), WHITE);
+        assert_eq!(*image.get_pixel(50, 50), WHITE);
+    }
+
+    #[test]
+    fn samples_within_tolerance() {
+        let f = |t: f64| Pt::from_radian(t, 80.0 * (3.0 * t).sin(), (100.0, 100.0));
+        let pieces = sample(0.0, std::f64::consts::PI, Samples::Tolerance(0.25), f);
+        assert_eq!(pieces.len(), 1);
+        let points = &pieces[0];
+        assert!(points.len() > INITIAL_SAMPLES);
+        assert!(points[0].distance(f(0.0)) < 1e-9);
+        assert!(points[points.len() - 1].distance(f(std::f64::consts::PI)) < 1e-9);
+    }
+
+    #[test]
+    fn parametric_counts_and_gaps() {
+        // Evenly spaced samples are joined the same as a path
+        let f = |t: f64| (10.0 * t, 50.0 + 40.0 * (t / 2.0).sin());
+        let mut image = crate::test::img::blank((100, 100));
+        parametric(&mut image, 0.0..9.0, Samples::Count(10), f, RED);
+        let mut expected = crate::test::img::blank((100, 100));
+        let points = (0..10).map(|i| Pt::from(f(f64::from(i))).i32());
+        crate::lines::path(&mut expected, points, RED);
+        assert_eq!(image, expected);
+
+        // Nothing is drawn across the values that are not finite
+        let mut image = crate::test::img::blank((100, 100));
+        let f = |t: f64| {
+            (
+                t,
+                if (40.0..60.0).contains(&t) {
+                    f64::INFINITY
+                } else {
+                    50.0
+                },
+            )
+        };
+        parametric(&mut image, 0.0..99.0, Samples::default(), f, RED);
+        assert_eq!(*image.get_pixel(30, 50), RED);
+        assert_eq!(*image.get_pixel(50, 50), WHITE);
+        assert_eq!(*image.get_pixel(70, 50), RED);
+
+        let mut image = crate::test::img::blank((10, 10));
+        antialiased_parametric(&mut image, 0.0..1.0, Samples::Count(1), |t| (t, t), RED);
+        assert!(image.pixels().all(|&px| px == WHITE));
+    }
+
+    #[test]
+    fn crossings_blend_once() {
+        let color = Rgba([255, 0, 0, 128]);
+        let mut image = crate::test::img::blank((100, 100));
+        // A circle traced twice
+        polar(&mut image, (50, 50), 30.0, 0..720, |_| 1.0, color);
+        let mut once = crate::test::img::blank((100, 100));
+        polar(&mut once, (50, 50), 30.0, 0..360, |_| 1.0, color);
+        assert_eq!(image.get_pixel(80, 50), once.get_pixel(80, 50));
+        assert_eq!(image.get_pixel(50, 20), once.get_pixel(50, 20));
+    }
+}
@@ -0,0 +1,132 @@
+//! Helpers for checking drawing code by eye.
+//!
+//! [`guidelines`] draws reference lines through a point (the axes, the
+//! diagonals between octants, and a circle) to compare a shape against.  This
+//! is how the images saved by this crate's own tests are set up.
+//!
+//! ```
+//! use freehand::debug::{guidelines, GuidelineOptions};
+//! use image::{RgbaImage, Rgba};
+//!
+//! let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+//! guidelines(&mut image, (200, 200), 190, GuidelineOptions::default());
+//!
+//! // The arc should line up with the circle and end on the octant boundaries
+//! freehand::new(&mut image).arc(45, 135, 190, (200, 200), Rgba([255, 0, 0, 255]));
+//! ```
+
+use crate::pt::Point;
+use crate::{lines, Pt, RADS};
+use image::{Rgba, RgbaImage};
+
+/// Which guidelines are drawn by [`guidelines`] and their colors.  Parts set
+/// to `None` are not drawn.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GuidelineOptions {
+    /// Horizontal and vertical lines through the center
+    #[cfg_attr(feature = "serde", serde(with = "crate::rgba_serde"))]
+    pub axes: Option<Rgba<u8>>,
+    /// Diagonal lines through the center, along the boundaries between octants
+    /// 1 and 2, 3 and 4, and so on
+    #[cfg_attr(feature = "serde", serde(with = "crate::rgba_serde"))]
+    pub diagonals: Option<Rgba<u8>>,
+    /// A circle around the center with the given radius
+    #[cfg_attr(feature = "serde", serde(with = "crate::rgba_serde"))]
+    pub circle: Option<Rgba<u8>>,
+    /// Single pixel markers on the circle at every tenth of each octant
+    #[cfg_attr(feature = "serde", serde(with = "crate::rgba_serde"))]
+    pub markers: Option<Rgba<u8>>,
+}
+
+impl Default for GuidelineOptions {
+    /// Draws the axes, diagonals, and circle, without markers.
+    fn default() -> Self {
+        Self {
+            axes: Some(Rgba([252, 190, 3, 255])),
+            diagonals: Some(Rgba([255, 242, 206, 255])),
+            circle: Some(Rgba([0, 0, 255, 255])),
+            markers: None,
+        }
+    }
+}
+
+/// Draws guidelines centered on `center`: lines across the whole image
+/// along the axes and diagonals, and a circle with the given `radius`.
+///
+/// The diagonals are drawn first so the axes and circle stay visible where
+/// they cross.  Anything outside of the image is clipped.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// See the [module documentation](self) for an example.
+///
+pub fn guidelines<C>(image: &mut RgbaImage, center: C, radius: i32, options: GuidelineOptions)
+where
+    C: Point<i32>,
+{
+    assert!(radius >= 0, "Radius must not be negative.  radius={radius}");
+    let center = center.pt();
+    #[allow(clippy::cast_possible_wrap)]
+    let (width, height) = (image.width() as i32, image.height() as i32);
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    if let Some(color) = options.diagonals {
+        // Long enough to cross the image from any center inside of it
+        let n = width.max(height);
+        let (x, y) = (center.x(), center.y());
+        lines::diagonal_line(image, (x - n, y - n), (x + n, y + n), color);
+        lines::diagonal_line(image, (x - n, y + n), (x + n, y - n), color);
+    }
+    if let Some(color) = options.axes {
+        lines::horizontal_line(image, (0, center.y()), width - 1, color);
+        lines::vertical_line(image, (center.x(), 0), height - 1, color);
+    }
+    if let Some(color) = options.circle {
+        crate::conics::circle(image, radius, center, color);
+    }
+    if let Some(color) = options.markers {
+        for i in 0..80 {
+            let angle = f64::from(i) / 10.0 * RADS;
+            let pt = Pt::from_radian(angle, radius, center).i32();
+            if (0..width).contains(&pt.x()) && (0..height).contains(&pt.y()) {
+                image.put_pixel(pt.x() as u32, pt.y() as u32, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guidelines_parts() {
+        let options = GuidelineOptions {
+            markers: Some(Rgba([0, 255, 0, 255])),
+            ..GuidelineOptions::default()
+        };
+        let mut image = crate::test::img::blank((50, 40));
+        guidelines(&mut image, (20, 15), 10, options);
+
+        assert_eq!(Some(*image.get_pixel(0, 15)), options.axes);
+        assert_eq!(Some(*image.get_pixel(20, 39)), options.axes);
+        assert_eq!(Some(*image.get_pixel(5, 0)), options.diagonals);
+        assert_eq!(Some(*image.get_pixel(44, 39)), options.diagonals);
+        assert_eq!(Some(*image.get_pixel(27, 8)), options.markers);
+
+        let mut blank = crate::test::img::blank((50, 40));
+        let none = GuidelineOptions {
+            axes: None,
+            diagonals: None,
+            circle: None,
+            markers: None,
+        };
+        guidelines(&mut blank, (20, 15), 10, none);
+        assert_eq!(blank, crate::test::img::blank((50, 40)));
+    }
+}
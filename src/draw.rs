@@ -13,8 +13,9 @@ use crate::conics;
 use crate::lines;
 use crate::ops;
 use crate::shapes;
+use crate::text;
 use crate::{Angle, Point, Pt};
-use image::{GenericImage, Rgba, RgbaImage};
+use image::{GenericImage, GrayImage, Rgba, RgbaImage};
 
 /// Allows drawing functions to be called using method chaining.
 ///
@@ -39,6 +40,7 @@ where
     I: image::GenericImage,
 {
     image: &'i mut I,
+    mode: ops::Blend,
 }
 
 /// Methods for working with [`image::GenericImage`]s
@@ -61,7 +63,189 @@ where
     /// let draw = freehand::Draw::new(&mut image);
     /// ```
     pub fn new(image: &'i mut I) -> Self {
-        Self { image }
+        Self {
+            image,
+            mode: ops::Blend::default(),
+        }
+    }
+
+    /// Sets the [`BlendMode`](ops::BlendMode) used by the `blend_*` methods
+    /// (e.g. [`Draw::blend_at`]).  Defaults to [`ops::BlendMode::SourceOver`].
+    ///
+    /// The other alpha drawing methods always draw using source-over.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// use freehand::ops::BlendMode;
+    /// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 0, 0, 255]));
+    ///
+    /// // Erase a square out of the image
+    /// freehand::new(&mut image)
+    ///     .blend_mode(BlendMode::DestOut)
+    ///     .blend_rect(freehand::shapes::Rect::new((10, 10), 50, 50), 1.0, Rgba([0, 0, 0, 255]));
+    ///
+    /// assert_eq!(image.get_pixel(20, 20)[3], 0);
+    /// ```
+    pub fn blend_mode(mut self, mode: ops::BlendMode) -> Self {
+        self.mode.mode = mode;
+        self
+    }
+
+    /// Sets whether the `blend_*` methods treat the image and colors as
+    /// straight or premultiplied alpha.  Defaults to
+    /// [`ops::AlphaMode::Straight`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// use freehand::ops::AlphaMode;
+    /// let mut image = RgbaImage::new(400, 400);
+    ///
+    /// freehand::new(&mut image)
+    ///     .alpha_mode(AlphaMode::Premultiplied)
+    ///     .blend_at(10, 10, 0.5, Rgba([255, 0, 0, 255]));
+    ///
+    /// assert_eq!(*image.get_pixel(10, 10), Rgba([128, 0, 0, 128]));
+    /// ```
+    pub fn alpha_mode(mut self, alpha: ops::AlphaMode) -> Self {
+        self.mode.alpha = alpha;
+        self
+    }
+
+    /// Sets what the `blend_*` methods write to the alpha channel.  Defaults
+    /// to [`ops::AlphaPolicy::BlendAlpha`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// use freehand::ops::AlphaPolicy;
+    /// let mut image = RgbaImage::new(400, 400);
+    ///
+    /// // Draw a half transparent square that replaces the transparent background
+    /// freehand::new(&mut image)
+    ///     .alpha_policy(AlphaPolicy::ReplaceWithSource)
+    ///     .blend_rect(freehand::shapes::Rect::new((10, 10), 50, 50), 1.0, Rgba([255, 0, 0, 128]));
+    ///
+    /// assert_eq!(*image.get_pixel(20, 20), Rgba([255, 0, 0, 128]));
+    /// ```
+    pub fn alpha_policy(mut self, policy: ops::AlphaPolicy) -> Self {
+        self.mode.alpha_policy = policy;
+        self
+    }
+
+    /// Draws using a closure to combine each pixel with the existing pixel,
+    /// for custom per-pixel effects.
+    ///
+    /// The closure `f` takes `(existing, incoming)` pixels and returns the pixel
+    /// to store.  Everything drawn with the [`Draw`] passed to `scope` goes
+    /// through `f`.  See [`ops::BlendFn`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// let mut image = RgbaImage::from_pixel(400, 400, Rgba([0, 0, 0, 255]));
+    /// let brightest = |a: Rgba<u8>, b: Rgba<u8>| if b[0] > a[0] { b } else { a };
+    ///
+    /// freehand::new(&mut image).with_blend(brightest, |draw| {
+    ///     draw.line((0, 0), (399, 399), Rgba([200, 0, 0, 255]))
+    ///         .line((399, 0), (0, 399), Rgba([100, 0, 0, 255]));
+    /// });
+    ///
+    /// assert_eq!(*image.get_pixel(200, 200), Rgba([200, 0, 0, 255]));
+    /// ```
+    pub fn with_blend<F, S>(self, f: F, scope: S) -> Self
+    where
+        F: FnMut(I::Pixel, I::Pixel) -> I::Pixel,
+        S: FnOnce(Draw<'_, ops::BlendFn<'_, I, F>>),
+    {
+        let Self { image, mode } = self;
+        let mut blended = ops::BlendFn::new(&mut *image, f);
+        scope(Draw {
+            image: &mut blended,
+            mode,
+        });
+        Self { image, mode }
+    }
+
+    /// Draws only where the values in a [`ops::Stencil`] pass `test`.
+    ///
+    /// Everything drawn with the [`Draw`] passed to `scope` is clipped to the
+    /// pixels whose stencil value passes the test.  See [`ops::Stenciled`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Luma, Rgba, RgbaImage};
+    /// use freehand::ops::{Stencil, StencilOp, StencilTest};
+    ///
+    /// let mut image = RgbaImage::new(400, 400);
+    /// let mut stencil = Stencil::new(400, 400);
+    /// freehand::new(&mut stencil.writer(StencilOp::Set(1))).circle(100, (200, 200), Luma([0]));
+    ///
+    /// // The rectangle is only drawn on the outline of the circle
+    /// freehand::new(&mut image).with_stencil(&stencil, StencilTest::Equal(1), |draw| {
+    ///     draw.rectangle_filled((0, 0), 400, 400, Rgba([255, 0, 0, 255]));
+    /// });
+    ///
+    /// assert_eq!(*image.get_pixel(300, 200), Rgba([255, 0, 0, 255]));
+    /// assert_eq!(*image.get_pixel(200, 200), Rgba([0, 0, 0, 0]));
+    /// ```
+    pub fn with_stencil<S>(self, stencil: &ops::Stencil, test: ops::StencilTest, scope: S) -> Self
+    where
+        S: FnOnce(Draw<'_, ops::Stenciled<'_, '_, I>>),
+    {
+        let Self { image, mode } = self;
+        let mut stenciled = stencil.test(&mut *image, test);
+        scope(Draw {
+            image: &mut stenciled,
+            mode,
+        });
+        Self { image, mode }
+    }
+
+    /// Records the previous value of every pixel drawn inside `scope`, and
+    /// pushes the resulting [`ops::Patch`] onto `history` so the drawing can
+    /// be undone.  See [`ops::Recorder`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+    /// let original = image.clone();
+    /// let mut history = Vec::new();
+    ///
+    /// freehand::new(&mut image)
+    ///     .record(&mut history, |draw| {
+    ///         draw.line((10, 10), (200, 50), Rgba([255, 0, 0, 255]));
+    ///     })
+    ///     .record(&mut history, |draw| {
+    ///         draw.circle(50, (200, 200), Rgba([0, 0, 255, 255]));
+    ///     });
+    ///
+    /// // Undo both strokes, most recent first
+    /// while let Some(patch) = history.pop() {
+    ///     patch.revert(&mut image);
+    /// }
+    /// assert_eq!(image, original);
+    /// ```
+    pub fn record<S>(self, history: &mut Vec<ops::Patch<I::Pixel>>, scope: S) -> Self
+    where
+        S: FnOnce(Draw<'_, ops::Recorder<'_, I>>),
+    {
+        let Self { image, mode } = self;
+        let mut recorder = ops::Recorder::new(&mut *image);
+        scope(Draw {
+            image: &mut recorder,
+            mode,
+        });
+        history.push(recorder.finish());
+        Self { image, mode }
     }
 
     /// Draws a straight line.
@@ -91,36 +275,74 @@ where
         self
     }
 
-    /// Draws a dashed line between two points.
+    /// Draws a straight line using a closure to pick the color of each pixel.
+    ///
+    /// The closure is given each point and how far along the line it is, from
+    /// `0.0` to `1.0`.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::color::lerp;
     ///
+    /// let (red, blue) = (Rgba([255, 0, 0, 255]), Rgba([0, 0, 255, 255]));
     /// let draw = freehand::new(&mut image);
-    /// // Draws a 3px dashed line between the two points
-    /// draw.dashed_line((10, 10), (120, 180), 3, Rgba([255, 0, 0, 255]));
+    /// // Draws a line that fades from red to blue
+    /// draw.line_with((10, 10), (120, 180), |_, t| lerp(red, blue, t as f32));
     /// ```
     ///
-    /// See [`lines::dashed_line`]
+    /// See [`lines::line_with`]
     ///
-    pub fn dashed_line<P, T>(self, a: P, b: P, dash_width: u16, color: I::Pixel) -> Self
+    pub fn line_with<P, T, F>(self, a: P, b: P, f: F) -> Self
     where
         P: Point<T>,
         T: Into<i32> + Copy,
+        F: FnMut(Pt<i32>, f64) -> I::Pixel,
     {
         let a = Pt::new(a.x().into(), a.y().into());
         let b = Pt::new(b.x().into(), b.y().into());
 
-        lines::dashed_line(self.image, a, b, dash_width, color);
+        lines::line_with(self.image, a, b, f);
         self
     }
 
-    /// Draws a line from each point to the next.
+    /// Draws a zigzag line between two points.
     ///
-    /// Does not connect the start and end points.
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a zigzag that swings 3 pixels to each side every 8 pixels
+    /// draw.zigzag_line((10, 100), (200, 100), 3.0, 8.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::zigzag_line`]
+    ///
+    pub fn zigzag_line<P, T>(
+        self,
+        a: P,
+        b: P,
+        amplitude: f64,
+        wavelength: f64,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        let a = Pt::new(a.x().into(), a.y().into());
+        let b = Pt::new(b.x().into(), b.y().into());
+
+        lines::zigzag_line(self.image, a, b, amplitude, wavelength, color);
+        self
+    }
+
+    /// Draws a wavy (sine wave) line between two points.
     ///
     /// # Example
     ///
@@ -129,67 +351,1710 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a line between each of the points
-    /// let points = [(10, 10), (120, 180)];
-    /// draw.path(points, Rgba([255, 0, 0, 255]));
+    /// // Draws a wave that swings 4 pixels to each side every 20 pixels
+    /// draw.wavy_line((10, 100), (200, 100), 4.0, 20.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::wavy_line`]
+    ///
+    pub fn wavy_line<P, T>(
+        self,
+        a: P,
+        b: P,
+        amplitude: f64,
+        wavelength: f64,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        let a = Pt::new(a.x().into(), a.y().into());
+        let b = Pt::new(b.x().into(), b.y().into());
+
+        lines::wavy_line(self.image, a, b, amplitude, wavelength, color);
+        self
+    }
+
+    /// Draws a spoke pointing away from the center at each of the angles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws the four main points of a compass, starting 20 pixels from the center
+    /// draw.spokes(&[0, 90, 180, 270], 20, 190, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::spokes`]
+    ///
+    pub fn spokes<A, C>(
+        self,
+        angles: &[A],
+        inner_radius: i32,
+        outer_radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        lines::spokes(
+            self.image,
+            angles,
+            inner_radius,
+            outer_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws evenly spaced spokes around the center.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws the hour marks of a clock face, starting at 12 o'clock
+    /// draw.even_spokes(12, 90, 170, 190, (200, 200), Rgba([0, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::even_spokes`]
+    ///
+    pub fn even_spokes<A, C>(
+        self,
+        count: u32,
+        start_angle: A,
+        inner_radius: i32,
+        outer_radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        lines::even_spokes(
+            self.image,
+            count,
+            start_angle,
+            inner_radius,
+            outer_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws tick marks spread evenly over a range of angles, with major and
+    /// minor lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::lines::Ticks;
+    ///
+    /// let ticks = Ticks {
+    ///     major_every: 5,
+    ///     major_length: 20,
+    ///     ..Ticks::new(60, 8)
+    /// };
+    /// let draw = freehand::new(&mut image);
+    /// // Draws the minute marks of a clock face, starting at 12 o'clock
+    /// draw.radial_ticks(90, 90, 190, (200, 200), ticks, Rgba([0, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::radial_ticks`]
+    ///
+    pub fn radial_ticks<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        ticks: lines::Ticks,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        lines::radial_ticks(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            ticks,
+            color,
+        );
+        self
+    }
+
+    /// Draws a tick mark at each of the given angles, with major and minor
+    /// lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::lines::Ticks;
+    ///
+    /// let ticks = Ticks {
+    ///     major_every: 2,
+    ///     major_length: 30,
+    ///     ..Ticks::new(0, 10)
+    /// };
+    /// let draw = freehand::new(&mut image);
+    /// draw.radial_ticks_at(&[0, 45, 90, 135], 190, (200, 200), ticks, Rgba([0, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::radial_ticks_at`]
+    ///
+    pub fn radial_ticks_at<A, C>(
+        self,
+        angles: &[A],
+        radius: i32,
+        center: C,
+        ticks: lines::Ticks,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        lines::radial_ticks_at(self.image, angles, radius, center, ticks, color);
+        self
+    }
+
+    /// Draws a dashed line between two points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a 3px dashed line between the two points
+    /// draw.dashed_line((10, 10), (120, 180), 3, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::dashed_line`]
+    ///
+    pub fn dashed_line<P, T>(self, a: P, b: P, dash_width: u16, color: I::Pixel) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        let a = Pt::new(a.x().into(), a.y().into());
+        let b = Pt::new(b.x().into(), b.y().into());
+
+        lines::dashed_line(self.image, a, b, dash_width, color);
+        self
+    }
+
+    /// Draws a dashed line between two points that alternates between two
+    /// colors instead of leaving gaps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Black and white 4px dashes, visible on any background
+    /// draw.two_color_dashed_line(
+    ///     (10, 10),
+    ///     (120, 180),
+    ///     4,
+    ///     Rgba([0, 0, 0, 255]),
+    ///     Rgba([255, 255, 255, 255]),
+    /// );
+    /// ```
+    ///
+    /// See [`lines::two_color_dashed_line`]
+    ///
+    pub fn two_color_dashed_line<P, T>(
+        self,
+        a: P,
+        b: P,
+        dash_width: u16,
+        color: I::Pixel,
+        alt_color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        let a = Pt::new(a.x().into(), a.y().into());
+        let b = Pt::new(b.x().into(), b.y().into());
+
+        lines::two_color_dashed_line(self.image, a, b, dash_width, color, alt_color);
+        self
+    }
+
+    /// Draws a line from each point to the next.
+    ///
+    /// Does not connect the start and end points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a line between each of the points
+    /// let points = [(10, 10), (120, 180)];
+    /// draw.path(points, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::path`]
+    ///
+    pub fn path<P, It>(self, points: It, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        lines::path(self.image, points, color);
+        self
+    }
+
+    /// Draws a parametric curve, joining the points the closure gives for
+    /// each `t` in `range` with straight lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::curves::Samples;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // a parabola
+    /// let parabola = |t: f64| (200.0 + t, 350.0 - t * t / 60.0);
+    /// draw.parametric(-150.0..150.0, Samples::default(), parabola, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`curves::parametric`](crate::curves::parametric)
+    ///
+    pub fn parametric<P, F>(
+        self,
+        range: std::ops::Range<f64>,
+        samples: crate::curves::Samples,
+        f: F,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<f64>,
+        F: FnMut(f64) -> P,
+    {
+        crate::curves::parametric(self.image, range, samples, f, color);
+        self
+    }
+
+    /// Draws the graph of a function, sampling it once for each column of
+    /// pixels in `columns`.  The closure maps a column to the row the graph
+    /// passes through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// let draw = freehand::new(&mut image);
+    /// // a sine wave across the whole image
+    /// draw.graph(0..400, |x| 200.0 - 150.0 * (x / 40.0).sin(), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`curves::graph`](crate::curves::graph)
+    ///
+    pub fn graph<F>(self, columns: std::ops::Range<i32>, f: F, color: I::Pixel) -> Self
+    where
+        F: FnMut(f64) -> f64,
+    {
+        crate::curves::graph(self.image, columns, f, color);
+        self
+    }
+
+    /// Draws a dashed line from each point to the next, continuing the dash
+    /// pattern through each corner.
+    ///
+    /// Does not connect the start and end points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let points = [(10, 10), (120, 180), (300, 20)];
+    /// draw.dashed_path(points, 4, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::dashed_path`]
+    ///
+    pub fn dashed_path<P, It>(self, points: It, dash_width: u16, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        lines::dashed_path(self.image, points, dash_width, 0, color);
+        self
+    }
+
+    /// Draws two parallel lines, `gap` pixels apart, centered on the line
+    /// between two points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.double_line((10, 10), (300, 180), 3.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::double_line`]
+    ///
+    pub fn double_line<P>(self, a: P, b: P, gap: f64, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        lines::double_line(self.image, a, b, gap, color);
+        self
+    }
+
+    /// Draws two parallel paths, `gap` pixels apart, centered on a path
+    /// through the points.
+    ///
+    /// Does not connect the start and end points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let points = [(10, 10), (120, 180), (300, 20)];
+    /// draw.double_path(points, 4.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::double_path`]
+    ///
+    pub fn double_path<P, It>(self, points: It, gap: f64, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        lines::double_path(self.image, points, gap, color);
+        self
+    }
+
+    /// Draws a line with short ticks across it every `spacing` pixels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::lines::TickSide;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a railroad track
+    /// draw.ticked_line((10, 10), (300, 180), 8.0, 6.0, TickSide::Both, Rgba([0, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::ticked_line`]
+    ///
+    pub fn ticked_line<P>(
+        self,
+        a: P,
+        b: P,
+        spacing: f64,
+        length: f64,
+        side: lines::TickSide,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<i32>,
+    {
+        lines::ticked_line(self.image, a, b, spacing, length, side, color);
+        self
+    }
+
+    /// Draws a path with short ticks across it every `spacing` pixels.
+    ///
+    /// Does not connect the start and end points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::lines::TickSide;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let points = [(10, 10), (120, 180), (300, 20)];
+    /// draw.ticked_path(points, 10.0, 5.0, TickSide::Left, Rgba([0, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::ticked_path`]
+    ///
+    pub fn ticked_path<P, It>(
+        self,
+        points: It,
+        spacing: f64,
+        length: f64,
+        side: lines::TickSide,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        lines::ticked_path(self.image, points, spacing, length, side, color);
+        self
+    }
+
+    /// Draws a rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.rectangle((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rectangle`]
+    ///
+    pub fn rectangle<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::rectangle(self.image, pt, height, width, color);
+        self
+    }
+
+    /// Draws a filled rectangle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.rectangle_filled((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rectangle_filled`]
+    ///
+    pub fn rectangle_filled<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::rectangle_filled(self.image, pt, height, width, color);
+        self
+    }
+
+    /// Draws a thick border with rounded corners, `thickness` pixels wide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.rounded_frame((10, 10), 50, 80, 4, 12, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rounded_frame`]
+    ///
+    pub fn rounded_frame<P>(
+        self,
+        pt: P,
+        height: u32,
+        width: u32,
+        thickness: u32,
+        radius: u32,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::rounded_frame(self.image, pt, height, width, thickness, radius, color);
+        self
+    }
+
+    /// Draws the outline of a diamond with `(horizontal, vertical)` radii.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.diamond((200, 200), (32, 16), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::diamond`]
+    ///
+    pub fn diamond<P>(self, center: P, radii: (u32, u32), color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::diamond(self.image, center, radii, color);
+        self
+    }
+
+    /// Draws a filled diamond with `(horizontal, vertical)` radii.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.diamond_filled((200, 200), (32, 16), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::diamond_filled`]
+    ///
+    pub fn diamond_filled<P>(self, center: P, radii: (u32, u32), color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::diamond_filled(self.image, center, radii, color);
+        self
+    }
+
+    /// Draws the outline of an axis-aligned ellipse with horizontal and
+    /// vertical semi-axes `a` and `b`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.ellipse((200, 200), 180, 90, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::ellipse`]
+    ///
+    pub fn ellipse<P>(self, center: P, a: u32, b: u32, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::ellipse(self.image, center, a, b, color);
+        self
+    }
+
+    /// Draws the outline of an ellipse with semi-axes `a` and `b`, rotated
+    /// counter-clockwise by `angle` around its center.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.rotated_ellipse((200, 200), 180, 90, 30, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rotated_ellipse`]
+    ///
+    pub fn rotated_ellipse<A, P>(self, center: P, a: u32, b: u32, angle: A, color: I::Pixel) -> Self
+    where
+        A: Angle,
+        P: Point<i32>,
+    {
+        shapes::rotated_ellipse(self.image, center, a, b, angle, color);
+        self
+    }
+
+    /// Draws the lines of an isometric grid across a rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::{IsoGrid, Rect};
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let grid = IsoGrid::new((200, 0), 32, 16);
+    /// draw.iso_grid(Rect::new((0, 0), 400, 400), grid, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::iso_grid`]
+    ///
+    pub fn iso_grid(self, rect: shapes::Rect, grid: shapes::IsoGrid, color: I::Pixel) -> Self {
+        shapes::iso_grid(self.image, rect, grid, color);
+        self
+    }
+
+    /// Draws a circular arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red arc from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::arc`]
+    ///
+    pub fn arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::arc(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws many arcs that share the same center and radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red ring split into 12 segments.
+    /// draw.arcs((0..12).map(|i| (i * 30, i * 30 + 20)), 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::arcs`]
+    ///
+    pub fn arcs<A, C, It, T>(self, sweeps: It, radius: T, center: C, color: I::Pixel) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        It: IntoIterator<Item = (A, A)>,
+        T: Into<i32> + Copy,
+    {
+        conics::arcs(self.image, sweeps, radius, center, color);
+        self
+    }
+
+    /// Draws a circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red circle with a radius of 180 pixels from the image center.
+    /// draw.circle(180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::circle`]
+    ///
+    pub fn circle<C, T>(self, radius: T, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::circle(self.image, radius, center, color);
+        self
+    }
+
+    /// Draws a circle, choosing its pixels with the given algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::conics::CircleAlgorithm;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // An 8-connected circle that rings of other radii fit around without gaps
+    /// draw.circle_with_algorithm(180, (200, 200), CircleAlgorithm::Distance, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::circle_with_algorithm`]
+    ///
+    pub fn circle_with_algorithm<C, T>(
+        self,
+        radius: T,
+        center: C,
+        algorithm: conics::CircleAlgorithm,
+        color: I::Pixel,
+    ) -> Self
+    where
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::circle_with_algorithm(self.image, radius, center, algorithm, color);
+        self
+    }
+
+    /// Draws a filled circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red disk with a radius of 50 pixels
+    /// draw.circle_filled(50, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::circle_filled`]
+    ///
+    pub fn circle_filled<C, T>(self, radius: T, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::circle_filled(self.image, radius, center, color);
+        self
+    }
+
+    /// Draws the outline of a pie slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Outlines a pie slice from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.pie_slice(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::pie_slice`]
+    ///
+    pub fn pie_slice<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+        I: GenericImage,
+    {
+        conics::pie_slice(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws a filled pie slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a pie slice from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.pie_slice_filled(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::pie_slice_filled`]
+    ///
+    pub fn pie_slice_filled<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+        I: GenericImage,
+    {
+        conics::pie_slice_filled(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws a thick arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws an arc, with a thickness of 3, from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.thick_arc(0, 55, 180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::thick_arc`]
+    ///
+    pub fn thick_arc<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        thickness: i16,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::thick_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            thickness,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws a thick circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a circle with a thickness of 3 and a radius of 180 pixels from the image center.
+    /// draw.thick_circle(180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::thick_circle`]
+    ///
+    pub fn thick_circle<C>(self, radius: i32, thickness: i16, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<i32>,
+    {
+        conics::thick_circle(self.image, radius, thickness, center, color);
+        self
+    }
+
+    /// Draws an annulus (a filled donut)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws an annulus from 0° to 55°, with an inner radius of 120 and outer radius of 180 pixels from the image center.
+    /// draw.annulus(0, 55, 120, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::annulus`]
+    ///
+    pub fn annulus<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        inner_radius: i32,
+        outer_radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::annulus(
+            self.image,
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws an elliptical annulus (a filled, squashed donut)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws the top half of a ring twice as wide as it is tall.
+    /// draw.elliptical_annulus(0, 180, (140, 70), (180, 90), (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::elliptical_annulus`]
+    ///
+    pub fn elliptical_annulus<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        inner_radii: (i32, i32),
+        outer_radii: (i32, i32),
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::elliptical_annulus(
+            self.image,
+            start_angle,
+            end_angle,
+            inner_radii,
+            outer_radii,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws the outline of an annulus
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Fills an annulus from 0° to 55° and outlines it in a darker color.
+    /// draw.annulus(0, 55, 120, 180, (200, 200), Rgba([255, 200, 200, 255]))
+    ///     .annulus_outline(0, 55, 120, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::annulus_outline`]
+    ///
+    pub fn annulus_outline<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        inner_radius: i32,
+        outer_radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::annulus_outline(
+            self.image,
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws text using the built-in bitmap font, with its upper left corner
+    /// at `pt`.  Each pixel of the font is drawn as a `scale` by `scale` square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.text((10, 10), "42%", 3, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`text::draw_text`]
+    ///
+    pub fn text<P>(self, pt: P, text: &str, scale: u32, color: I::Pixel) -> Self
+    where
+        P: Point<i32>,
+    {
+        text::draw_text(self.image, pt, text, scale, color);
+        self
+    }
+
+    /// Draws the outline of a polygon with rounded corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Polygon;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// // Each corner is replaced by an arc with a radius of 30 pixels
+    /// draw.rounded_polygon(&triangle, 30.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::Polygon::draw_rounded`]
+    ///
+    pub fn rounded_polygon(self, polygon: &shapes::Polygon, radius: f64, color: I::Pixel) -> Self {
+        polygon.draw_rounded(self.image, radius, color);
+        self
+    }
+}
+
+/// Methods for working with [`image::RgbaImage`]s.
+///
+/// [`image::RgbaImage`]: https://docs.rs/image/latest/image/type.RgbaImage.html
+impl Draw<'_, RgbaImage> {
+    /// Erases everything drawn inside `scope`, leaving fully transparent pixels
+    /// where the shapes would have been drawn.  The colors given to the drawing
+    /// methods are ignored.
+    ///
+    /// See [`ops::eraser`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 0, 0, 255]));
+    ///
+    /// freehand::new(&mut image).erase(|draw| {
+    ///     draw.rectangle_filled((10, 10), 50, 50, Rgba([0, 0, 0, 255]));
+    /// });
+    ///
+    /// assert_eq!(*image.get_pixel(20, 20), Rgba([0, 0, 0, 0]));
+    /// ```
+    pub fn erase<S>(self, scope: S) -> Self
+    where
+        S: FnOnce(Draw<'_, ops::Eraser<'_>>),
+    {
+        let Self { image, mode } = self;
+        scope(Draw::new(&mut ops::eraser(&mut *image)));
+        Self { image, mode }
+    }
+
+    /// Antialiases everything drawn inside `scope` by drawing it onto a larger
+    /// image and scaling it down, then blends the result using the current
+    /// [`Draw::blend_mode`].
+    ///
+    /// Coordinates and sizes used inside `scope` must be multiplied by
+    /// [`Supersample::factor`](ops::Supersample::factor).
+    ///
+    /// See [`ops::supersample`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{Rgba, RgbaImage};
+    /// use freehand::ops::Supersample;
+    /// let mut image = RgbaImage::new(400, 400);
+    ///
+    /// // An antialiased thick arc with a radius of 100 pixels
+    /// freehand::new(&mut image).supersample(Supersample::X4, |draw| {
+    ///     draw.thick_arc(0, 120, 400, 16, (800, 800), Rgba([255, 0, 0, 255]));
+    /// });
+    /// ```
+    pub fn supersample<S>(self, samples: ops::Supersample, scope: S) -> Self
+    where
+        S: FnOnce(Draw<'_, RgbaImage>),
+    {
+        ops::supersample(self.image, samples, self.mode, |big| scope(Draw::new(big)));
+        self
+    }
+
+    /// Draws an antialiased arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased arc from 0° to 55° with a radius of 180 pixels from the image center.
+    /// draw.antialiased_arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_arc`]
+    ///
+    pub fn antialiased_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_arc(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws an antialiased filled circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // A smooth dot with a radius of 6.5 pixels
+    /// draw.antialiased_circle_filled(6.5, (200.0, 200.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_circle_filled`]
+    ///
+    pub fn antialiased_circle_filled<C, T>(self, radius: T, center: C, color: Rgba<u8>) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_circle_filled(self.image, radius, center, color);
+        self
+    }
+
+    /// Strokes an arc with any combination of width, dashes, end caps, and
+    /// antialiasing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::conics::ArcStroke;
+    /// use freehand::lines::LineCap;
+    ///
+    /// let stroke = ArcStroke {
+    ///     dash: 15,
+    ///     cap: LineCap::Round,
+    ///     antialiased: true,
+    ///     ..ArcStroke::new(6.0)
+    /// };
+    /// let draw = freehand::new(&mut image);
+    /// // draws a dashed arc with rounded dashes from 0° to 270°
+    /// draw.stroke_arc(0, 270, 180, (200, 200), stroke, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::stroke_arc`]
+    ///
+    pub fn stroke_arc<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        stroke: conics::ArcStroke,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::stroke_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            stroke,
+            color,
+        );
+        self
+    }
+
+    /// Draws a glowing arc: a solid core with a soft falloff around it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::conics::Glow;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // a yellow arc from 0° to 180° fading out over 15 pixels
+    /// draw.glow_arc(0, 180, 150, (200, 200), Glow::new(3.0, 15.0), Rgba([255, 220, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::glow_arc`]
+    ///
+    pub fn glow_arc<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        glow: conics::Glow,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::glow_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            glow,
+            color,
+        );
+        self
+    }
+
+    /// Draws a glowing circle: a solid core with a soft falloff around it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::conics::Glow;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // highlight a point with a soft red halo
+    /// draw.glow_circle(20, (120, 80), Glow::new(2.0, 10.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::glow_circle`]
+    ///
+    pub fn glow_circle<C>(self, radius: i32, center: C, glow: conics::Glow, color: Rgba<u8>) -> Self
+    where
+        C: Point<i32>,
+    {
+        conics::glow_circle(self.image, radius, center, glow, color);
+        self
+    }
+
+    /// Draws an antialiased parametric curve, joining the points the closure
+    /// gives for each `t` in `range` with straight lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::curves::Samples;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // an ellipse
+    /// let ellipse = |t: f64| (200.0 + 150.0 * t.cos(), 200.0 + 80.0 * t.sin());
+    /// let tau = std::f64::consts::TAU;
+    /// draw.antialiased_parametric(0.0..tau, Samples::default(), ellipse, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`curves::antialiased_parametric`](crate::curves::antialiased_parametric)
+    ///
+    pub fn antialiased_parametric<P, F>(
+        self,
+        range: std::ops::Range<f64>,
+        samples: crate::curves::Samples,
+        f: F,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<f64>,
+        F: FnMut(f64) -> P,
+    {
+        crate::curves::antialiased_parametric(self.image, range, samples, f, color);
+        self
+    }
+
+    /// Draws an antialiased polar curve, mapping each angle in `range` to the
+    /// point `f(theta) * scale` pixels from `center`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// let draw = freehand::new(&mut image);
+    /// // a spiral winding out from the center three times
+    /// draw.polar((200, 200), 10.0, 0..1080, |t| t, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`curves::polar`](crate::curves::polar)
+    ///
+    pub fn polar<A, C, T, F>(
+        self,
+        center: C,
+        scale: f64,
+        range: std::ops::Range<A>,
+        f: F,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+        F: FnMut(f64) -> f64,
+    {
+        crate::curves::polar(self.image, center, scale, range, f, color);
+        self
+    }
+
+    /// Draws a dashed line with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red line with a 3px dash and 50% opacity.
+    /// draw.dashed_line_alpha((0, 10), (200, 200), 5u8, 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::dashed_line_alpha`]
+    pub fn dashed_line_alpha<P, W>(
+        self,
+        a: P,
+        b: P,
+        dash_width: W,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<i32>,
+        W: Into<u16>,
+    {
+        lines::dashed_line_alpha(self.image, a, b, dash_width, opacity, color);
+        self
+    }
+
+    /// Draws a line with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red line with 50% opacity.
+    /// draw.line_alpha((0, 10), (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::line_alpha`]
+    ///
+    pub fn line_alpha<P>(self, a: P, b: P, opacity: f32, color: Rgba<u8>) -> Self
+    where
+        P: Point<i32>,
+    {
+        lines::line_alpha(self.image, a, b, opacity, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased line with a width of 1.5
+    /// draw.antialiased_line((0, 10), (200, 200), 1.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::antialiased_line`]
+    ///
+    pub fn antialiased_line<P, T>(self, a: P, b: P, width: f32, color: Rgba<u8>) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        lines::antialiased_line(self.image, a, b, width, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased line with anti-aliased ends in the specified
+    /// [`LineCap`](lines::LineCap) style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::lines::LineCap;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.antialiased_line_capped((10, 10), (200, 200), 8.0, LineCap::Round, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::antialiased_line_capped`]
+    ///
+    pub fn antialiased_line_capped<P, T>(
+        self,
+        a: P,
+        b: P,
+        width: f32,
+        cap: lines::LineCap,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        lines::antialiased_line_capped(self.image, a, b, width, cap, color);
+        self
+    }
+
+    /// Draws a filled polygon with anti-aliased edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Polygon;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// draw.polygon_filled_antialiased(&triangle, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::Polygon::draw_filled_antialiased`]
+    ///
+    pub fn polygon_filled_antialiased(self, polygon: &shapes::Polygon, color: Rgba<u8>) -> Self {
+        polygon.draw_filled_antialiased(self.image, color);
+        self
+    }
+
+    /// Draws a filled polygon with rounded corners and anti-aliased edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Polygon;
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// draw.rounded_polygon_filled_antialiased(&triangle, 30.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::Polygon::draw_rounded_filled_antialiased`]
+    ///
+    pub fn rounded_polygon_filled_antialiased(
+        self,
+        polygon: &shapes::Polygon,
+        radius: f64,
+        color: Rgba<u8>,
+    ) -> Self {
+        polygon.draw_rounded_filled_antialiased(self.image, radius, color);
+        self
+    }
+
+    /// Draws the outline of a rectangle with rounded, anti-aliased corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.rounded_rectangle_antialiased((10, 10), 50, 80, 8.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rounded_rectangle_antialiased`]
+    ///
+    pub fn rounded_rectangle_antialiased<P>(
+        self,
+        pt: P,
+        height: u32,
+        width: u32,
+        radius: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::rounded_rectangle_antialiased(self.image, pt, height, width, radius, color);
+        self
+    }
+
+    /// Draws the antialiased needle of a gauge or dial pointing at `angle`
+    /// from `pivot`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Needle;
+    ///
+    /// let pointer = Needle {
+    ///     pivot_radius: 10.0,
+    ///     ..Needle::new(160.0, 8.0)
+    /// };
+    /// let draw = freehand::new(&mut image);
+    /// draw.needle((200, 200), 60, pointer, Rgba([200, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::needle`]
+    ///
+    pub fn needle<A, C, T>(
+        self,
+        pivot: C,
+        angle: A,
+        needle: shapes::Needle,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        shapes::needle(self.image, pivot, angle, needle, color);
+        self
+    }
+
+    /// Draws a filled rectangle with rounded, anti-aliased corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// draw.rounded_rectangle_filled_antialiased((10, 10), 50, 80, 8.0, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`lines::path`]
+    /// See [`shapes::rounded_rectangle_filled_antialiased`]
     ///
-    pub fn path<P, It>(self, points: It, color: I::Pixel) -> Self
+    pub fn rounded_rectangle_filled_antialiased<P>(
+        self,
+        pt: P,
+        height: u32,
+        width: u32,
+        radius: f32,
+        color: Rgba<u8>,
+    ) -> Self
     where
         P: Point<i32>,
-        It: IntoIterator<Item = P>,
     {
-        lines::path(self.image, points, color);
+        shapes::rounded_rectangle_filled_antialiased(self.image, pt, height, width, radius, color);
         self
     }
 
-    /// Draws a rectangle.
+    /// Fills a triangle with pixels from a texture, using the texture
+    /// coordinates given with each vertex.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Sampling;
+    /// let texture = RgbaImage::from_pixel(8, 8, Rgba([255, 0, 0, 255]));
     ///
     /// let draw = freehand::new(&mut image);
-    /// draw.rectangle((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
+    /// let vertices = [((200, 10), (0.5, 0.0)), ((390, 390), (1.0, 1.0)), ((10, 390), (0.0, 1.0))];
+    /// draw.triangle_textured(vertices, &texture, Sampling::Nearest);
     /// ```
     ///
-    /// See [`shapes::rectangle`]
+    /// See [`shapes::triangle_textured`]
     ///
-    pub fn rectangle<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
+    pub fn triangle_textured<P>(
+        self,
+        vertices: [(P, (f32, f32)); 3],
+        texture: &RgbaImage,
+        sampling: shapes::Sampling,
+    ) -> Self
     where
-        P: Point<u32>,
+        P: Point<i32>,
     {
-        shapes::rectangle(self.image, pt, height, width, color);
+        shapes::triangle_textured(self.image, vertices, texture, sampling);
         self
     }
 
-    /// Draws a filled rectangle
+    /// Draws an image stretched onto a quadrilateral, with `corners` giving
+    /// where the upper left, upper right, lower right, and lower left corners
+    /// of `src` are placed.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Sampling;
+    /// let sprite = RgbaImage::from_pixel(32, 32, Rgba([255, 0, 0, 255]));
     ///
     /// let draw = freehand::new(&mut image);
-    /// draw.rectangle_filled((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
+    /// draw.warp_blit(&sprite, [(120, 100), (220, 100), (180, 200), (80, 200)], Sampling::Bilinear);
     /// ```
     ///
-    /// See [`shapes::rectangle_filled`]
+    /// See [`ops::warp_blit`]
     ///
-    pub fn rectangle_filled<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
+    pub fn warp_blit<P>(self, src: &RgbaImage, corners: [P; 4], sampling: shapes::Sampling) -> Self
     where
-        P: Point<u32>,
+        P: Point<i32>,
     {
-        shapes::rectangle_filled(self.image, pt, height, width, color);
+        ops::warp_blit(self.image, src, corners, sampling);
         self
     }
 
-    /// Draws a circular arc.
+    /// Draws a rectangle with the specified opacity.
     ///
     /// # Example
     ///
@@ -198,30 +2063,28 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red arc from 0° to 55°, with a radius of 180 pixels from the image center.
-    /// draw.arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// // Draws a red rectangle with 50% opacity.
+    /// draw.rectangle_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::arc`]
+    /// See [`shapes::rectangle_alpha`]
     ///
-    pub fn arc<A, C, T>(
+    pub fn rectangle_alpha<P>(
         self,
-        start_angle: A,
-        end_angle: A,
-        radius: T,
-        center: C,
-        color: I::Pixel,
+        pt: P,
+        height: u32,
+        width: u32,
+        opacity: f32,
+        color: Rgba<u8>,
     ) -> Self
     where
-        A: Angle,
-        C: Point<T>,
-        T: Into<i32> + Copy,
+        P: Point<i32>,
     {
-        conics::arc(self.image, start_angle, end_angle, radius, center, color);
+        shapes::rectangle_alpha(self.image, pt, height, width, opacity, color);
         self
     }
 
-    /// Draws a circle.
+    /// Draws a filled rectangle with the specified opacity.
     ///
     /// # Example
     ///
@@ -230,22 +2093,32 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red circle with a radius of 180 pixels from the image center.
-    /// draw.circle(180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// // Draws a filled red rectangle with 50% opacity.
+    /// draw.rectangle_filled_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::circle`]
+    /// See [`shapes::rectangle_filled_alpha`]
     ///
-    pub fn circle<C, T>(self, radius: T, center: C, color: I::Pixel) -> Self
+    pub fn rectangle_filled_alpha<P>(
+        self,
+        pt: P,
+        height: u32,
+        width: u32,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
     where
-        C: Point<T>,
-        T: Into<i32> + Copy,
+        P: Point<i32>,
     {
-        conics::circle(self.image, radius, center, color);
+        shapes::rectangle_filled_alpha(self.image, pt, height, width, opacity, color);
         self
     }
 
-    /// Draws a filled pie slice.
+    /// Blends a color into an image using the current [`Draw::blend_mode`].
+    ///
+    /// The resulting color's alpha channel will ignore the specified color's alpha
+    /// value and use `opacity` to blend the colors together.  The final alpha
+    /// channel depends on the current [`Draw::alpha_policy`].
     ///
     /// # Example
     ///
@@ -254,30 +2127,19 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a pie slice from 0° to 55°, with a radius of 180 pixels from the image center.
-    /// draw.pie_slice_filled(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// // Blends a red pixel into the image at (0, 10) with 50% opacity
+    /// draw.blend_at(0, 10, 0.5, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::pie_slice_filled`]
+    /// See [`ops::blend_at`]
     ///
-    pub fn pie_slice_filled<A, C>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: i32,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
-    where
-        A: Angle,
-        C: Point<i32>,
-        I: GenericImage,
-    {
-        conics::pie_slice_filled(self.image, start_angle, end_angle, radius, center, color);
+    pub fn blend_at(self, x: u32, y: u32, opacity: f32, color: Rgba<u8>) -> Self {
+        ops::blend_at_mode(self.image, x, y, opacity, color, self.mode);
         self
     }
 
-    /// Draws a thick arc.
+    /// Blends a color into a horizontal run of pixels on row `y`, from `x0` to
+    /// `x1` (inclusive), using the current [`Draw::blend_mode`].
     ///
     /// # Example
     ///
@@ -286,295 +2148,221 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws an arc, with a thickness of 3, from 0° to 55°, with a radius of 180 pixels from the image center.
-    /// draw.thick_arc(0, 55, 180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.blend_row(10, 0, 399, 0.5, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::thick_arc`]
+    /// See [`ops::blend_row`]
     ///
-    pub fn thick_arc<A, C>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: i32,
-        thickness: i16,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
-    where
-        A: Angle,
-        C: Point<i32>,
-    {
-        conics::thick_arc(
-            self.image,
-            start_angle,
-            end_angle,
-            radius,
-            thickness,
-            center,
-            color,
-        );
+    pub fn blend_row(self, y: i32, x0: i32, x1: i32, opacity: f32, color: Rgba<u8>) -> Self {
+        ops::blend_row_mode(self.image, y, x0, x1, opacity, color, self.mode);
         self
     }
 
-    /// Draws a thick circle.
+    /// Blends a color into every pixel inside a rectangle using the current
+    /// [`Draw::blend_mode`].
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Rect;
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a circle with a thickness of 3 and a radius of 180 pixels from the image center.
-    /// draw.thick_circle(180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.blend_rect(Rect::new((10, 10), 100, 200), 0.5, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::thick_circle`]
+    /// See [`ops::blend_rect`]
     ///
-    pub fn thick_circle<C>(self, radius: i32, thickness: i16, center: C, color: I::Pixel) -> Self
-    where
-        C: Point<i32>,
-    {
-        conics::thick_circle(self.image, radius, thickness, center, color);
+    pub fn blend_rect(self, rect: shapes::Rect, opacity: f32, color: Rgba<u8>) -> Self {
+        ops::blend_rect_mode(self.image, rect, opacity, color, self.mode);
         self
     }
 
-    /// Draws an annulus (a filled donut)
+    /// Blends a color into every pixel inside a rectangle using a separate
+    /// opacity for each channel (red, green, blue, and alpha), and the current
+    /// [`Draw::blend_mode`].
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Rect;
     ///
+    /// // Darken the red channel only
     /// let draw = freehand::new(&mut image);
-    /// // Draws an annulus from 0° to 55°, with an inner radius of 120 and outer radius of 180 pixels from the image center.
-    /// draw.annulus(0, 55, 120, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.blend_rect_channels(Rect::new((10, 10), 100, 200), [0.5, 0.0, 0.0, 0.0], Rgba([0, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::annulus`]
+    /// See [`ops::blend_rect_channels`]
     ///
-    pub fn annulus<A, C>(
+    pub fn blend_rect_channels(
         self,
-        start_angle: A,
-        end_angle: A,
-        inner_radius: i32,
-        outer_radius: i32,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
-    where
-        A: Angle,
-        C: Point<i32>,
-    {
-        conics::annulus(
-            self.image,
-            start_angle,
-            end_angle,
-            inner_radius,
-            outer_radius,
-            center,
-            color,
-        );
+        rect: shapes::Rect,
+        opacity: [f32; 4],
+        color: Rgba<u8>,
+    ) -> Self {
+        ops::blend_rect_channels(self.image, rect, opacity, color, self.mode);
         self
     }
-}
 
-/// Methods for working with [`image::RgbaImage`]s.
-///
-/// [`image::RgbaImage`]: https://docs.rs/image/latest/image/type.RgbaImage.html
-impl<'i> Draw<'i, RgbaImage> {
-    /// Draws an antialiased arc.
+    /// Blends an entire image onto the image, with the upper left corner of
+    /// `src` placed at `pt`, using the current [`Draw::blend_mode`].
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// let watermark = RgbaImage::from_pixel(50, 20, Rgba([255, 255, 255, 255]));
     ///
     /// let draw = freehand::new(&mut image);
-    /// // draws an anti-aliased arc from 0° to 55° with a radius of 180 pixels from the image center.
-    /// draw.antialiased_arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.composite(&watermark, (340, 370), 0.25);
     /// ```
     ///
-    /// See [`conics::antialiased_arc`]
+    /// See [`ops::composite`]
     ///
-    pub fn antialiased_arc<A, C, T>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: T,
-        center: C,
-        color: Rgba<u8>,
-    ) -> Self
+    pub fn composite<P>(self, src: &RgbaImage, pt: P, opacity: f32) -> Self
     where
-        A: Angle,
-        C: Point<T>,
-        T: Into<f64> + Copy,
+        P: Point<i32>,
     {
-        conics::antialiased_arc(self.image, start_angle, end_angle, radius, center, color);
+        ops::composite(self.image, src, pt, opacity, self.mode);
         self
     }
 
-    /// Draws a dashed line with a specified opacity.
+    /// Blends a rotated copy of an image, with its center placed at `center`.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// let sprite = RgbaImage::from_pixel(64, 32, Rgba([255, 0, 0, 255]));
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red line with a 3px dash and 50% opacity.
-    /// draw.dashed_line_alpha((0, 10), (200, 200), 5u8, 0.5, Rgba([255, 0, 0, 255]));
+    /// draw.rotate_blit(&sprite, (200.0, 200.0), 30, 0.75);
     /// ```
     ///
-    /// See [`lines::dashed_line_alpha`]
-    pub fn dashed_line_alpha<P, W>(
-        self,
-        a: P,
-        b: P,
-        dash_width: W,
-        opacity: f32,
-        color: Rgba<u8>,
-    ) -> Self
+    /// See [`ops::rotate_blit`]
+    ///
+    pub fn rotate_blit<C, A>(self, src: &RgbaImage, center: C, angle: A, opacity: f32) -> Self
     where
-        P: Point<i32>,
-        W: Into<u16>,
+        C: Point<f64>,
+        A: Angle,
     {
-        lines::dashed_line_alpha(self.image, a, b, dash_width, opacity, color);
+        ops::rotate_blit(self.image, src, center, angle, opacity);
         self
     }
 
-    /// Draws a line with a specified opacity.
+    /// Tints the pixels inside a rectangle by multiplying them with `color`,
+    /// leaving their alpha channel unchanged.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Rect;
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red line with 50% opacity.
-    /// draw.line_alpha((0, 10), (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// // Highlights an area in yellow
+    /// draw.tint_region(Rect::new((10, 10), 100, 200), Rgba([255, 255, 0, 255]), 0.5);
     /// ```
     ///
-    /// See [`lines::line_alpha`]
+    /// See [`ops::tint_region`]
     ///
-    pub fn line_alpha<P>(self, a: P, b: P, opacity: f32, color: Rgba<u8>) -> Self
-    where
-        P: Point<i32>,
-    {
-        lines::line_alpha(self.image, a, b, opacity, color);
+    pub fn tint_region(self, rect: shapes::Rect, color: Rgba<u8>, strength: f32) -> Self {
+        ops::tint_region(self.image, rect, color, strength);
         self
     }
 
-    /// Draws a thick anti-aliased line.
+    /// Inverts the RGB channels of the pixels inside a rectangle, leaving
+    /// their alpha channel unchanged.
     ///
     /// # Example
     ///
     /// ```
-    /// # use image::{RgbaImage, Rgba};
+    /// # use image::RgbaImage;
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Rect;
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red anti-aliased line with a width of 1.5
-    /// draw.antialiased_line((0, 10), (200, 200), 1.5, Rgba([255, 0, 0, 255]));
+    /// draw.invert_region(Rect::new((10, 10), 100, 200));
     /// ```
     ///
-    /// See [`lines::antialiased_line`]
+    /// See [`ops::invert_region`]
     ///
-    pub fn antialiased_line<P, T>(self, a: P, b: P, width: f32, color: Rgba<u8>) -> Self
-    where
-        P: Point<T>,
-        T: Into<i32> + Copy,
-    {
-        lines::antialiased_line(self.image, a, b, width, color);
+    pub fn invert_region(self, rect: shapes::Rect) -> Self {
+        ops::invert_region(self.image, rect);
         self
     }
 
-    /// Draws a rectangle with the specified opacity.
+    /// Blurs the pixels inside a rectangle using a box blur with the
+    /// specified radius.
     ///
     /// # Example
     ///
     /// ```
-    /// # use image::{RgbaImage, Rgba};
+    /// # use image::RgbaImage;
     /// # let mut image = RgbaImage::new(400, 400);
+    /// use freehand::shapes::Rect;
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red rectangle with 50% opacity.
-    /// draw.rectangle_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
+    /// draw.box_blur_region(Rect::new((10, 10), 100, 200), 3);
     /// ```
     ///
-    /// See [`shapes::rectangle_alpha`]
+    /// See [`ops::box_blur_region`]
     ///
-    pub fn rectangle_alpha<P>(
-        self,
-        pt: P,
-        height: u32,
-        width: u32,
-        opacity: f32,
-        color: Rgba<u8>,
-    ) -> Self
-    where
-        P: Point<u32>,
-    {
-        shapes::rectangle_alpha(self.image, pt, height, width, opacity, color);
+    pub fn box_blur_region(self, rect: shapes::Rect, radius: u32) -> Self {
+        ops::box_blur_region(self.image, rect, radius);
         self
     }
 
-    /// Draws a filled rectangle with the specified opacity.
+    /// Fills the pixels covered by a grayscale mask with a color, where the
+    /// mask's value is above `threshold`.  The color's alpha is multiplied by
+    /// the mask's value.
     ///
     /// # Example
     ///
     /// ```
-    /// # use image::{RgbaImage, Rgba};
+    /// # use image::{GrayImage, Luma, RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// let mut mask = GrayImage::new(400, 400);
+    /// mask.put_pixel(20, 20, Luma([255]));
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a filled red rectangle with 50% opacity.
-    /// draw.rectangle_filled_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
+    /// draw.fill_masked(&mask, 0, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`shapes::rectangle_filled_alpha`]
+    /// See [`ops::fill_masked`]
     ///
-    pub fn rectangle_filled_alpha<P>(
-        self,
-        pt: P,
-        height: u32,
-        width: u32,
-        opacity: f32,
-        color: Rgba<u8>,
-    ) -> Self
-    where
-        P: Point<u32>,
-    {
-        shapes::rectangle_filled_alpha(self.image, pt, height, width, opacity, color);
+    pub fn fill_masked(self, mask: &GrayImage, threshold: u8, color: Rgba<u8>) -> Self {
+        ops::fill_masked(self.image, mask, threshold, color);
         self
     }
 
-    /// Blends a color into an image.
-    ///
-    /// The resulting color's alpha channel will ignore the specified color's alpha
-    /// value and use `opacity` to blend the colors together.  The specified
-    /// color's alpha value will only be used for the final alpha channel value.
+    /// Fills the pixels covered by a grayscale mask with the colors returned
+    /// by a closure, which is called with each pixel's position.
     ///
     /// # Example
     ///
     /// ```
-    /// # use image::{RgbaImage, Rgba};
+    /// # use image::{GrayImage, Luma, RgbaImage, Rgba};
     /// # let mut image = RgbaImage::new(400, 400);
+    /// let mask = GrayImage::from_pixel(200, 200, Luma([255]));
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Blends a red pixel into the image at (0, 10) with 50% opacity
-    /// draw.blend_at(0, 10, 0.5, Rgba([255, 0, 0, 255]));
+    /// draw.fill_masked_with(&mask, 0, |pt| Rgba([pt.x() as u8, 0, pt.y() as u8, 255]));
     /// ```
     ///
-    /// See [`ops::blend_at`]
+    /// See [`ops::fill_masked_with`]
     ///
-    pub fn blend_at(self, x: u32, y: u32, opacity: f32, color: Rgba<u8>) -> Self {
-        ops::blend_at(self.image, x, y, opacity, color);
+    pub fn fill_masked_with<F>(self, mask: &GrayImage, threshold: u8, f: F) -> Self
+    where
+        F: FnMut(Pt<i32>) -> Rgba<u8>,
+    {
+        ops::fill_masked_with(self.image, mask, threshold, f);
         self
     }
 
@@ -582,8 +2370,8 @@ impl<'i> Draw<'i, RgbaImage> {
     /// alpha value and instead uses `opacity` which is a floating point number from 0.0 to 1.0.
     ///
     /// The resulting color's alpha channel will ignore the specified color's alpha
-    /// value and use `opacity` to blend the colors together.  The specified
-    /// color's alpha value will only be used for the final alpha channel value.
+    /// value and use `opacity` to blend the colors together.  The final
+    /// alpha combines the existing alpha with `opacity`.
     ///
     /// A few safety checks are skipped here for performance.
     ///
@@ -623,9 +2411,9 @@ impl<'i> Draw<'i, RgbaImage> {
 ///
 /// let draw = freehand::new(&mut image);
 /// ```
-pub fn new<I>(image: &mut I) -> Draw<I>
+pub fn new<I>(image: &mut I) -> Draw<'_, I>
 where
     I: image::GenericImage,
 {
-    Draw { image }
+    Draw::new(image)
 }
@@ -0,0 +1,52 @@
+//! A crate-level error type.
+
+/// Errors returned by fallible functions and conversions in this crate.
+///
+/// The panicking constructors (e.g. [`Arc::new`](crate::conics::Arc::new))
+/// have `try_` counterparts that return this error instead, which is useful
+/// when working with untrusted input.
+///
+/// ```
+/// use freehand::conics::Arc;
+///
+/// let rst = Arc::try_new(0, 180, -10, (200, 200));
+/// assert_eq!(rst.unwrap_err(), freehand::Error::InvalidRadius(-10.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The specified radius cannot be used
+    InvalidRadius(f64),
+    /// An octant outside of the range `1..=8` was specified
+    InvalidOctant(u8),
+    /// A path did not contain enough points
+    EmptyPath,
+    /// A coordinate could not be represented in the requested type (e.g. a
+    /// negative value converted into an unsigned integer)
+    OutOfBounds,
+    /// Font data could not be parsed.  Contains the line number of the
+    /// problem, or 0 if the data ended unexpectedly.
+    InvalidFont(usize),
+    /// A color string was not in the `#rrggbb` or `#rrggbbaa` format
+    InvalidColor,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRadius(r) => write!(f, "Invalid radius.  radius={r}"),
+            Self::InvalidOctant(oct) => write!(
+                f,
+                "Invalid octant. Valid octants are 1 through 8.  octant={oct}"
+            ),
+            Self::EmptyPath => write!(f, "Path must contain at least two points"),
+            Self::OutOfBounds => write!(f, "Coordinates are out of bounds for the requested type"),
+            Self::InvalidFont(0) => write!(f, "Font data ended unexpectedly"),
+            Self::InvalidFont(line) => write!(f, "Invalid font data.  line={line}"),
+            Self::InvalidColor => write!(f, "Colors must be in the #rrggbb or #rrggbbaa format"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,94 @@
+//! Fixed-point helpers used by the optional fixed-point antialiasing path.
+//!
+//! Values are stored as 16.16 fixed-point numbers inside an `i64` so that
+//! squaring a value (producing a 32.32 number) cannot overflow.
+
+/// Number of fractional bits
+pub(crate) const FRACT_BITS: u32 = 16;
+/// The value `1.0` in 16.16 fixed-point
+pub(crate) const ONE: i64 = 1 << FRACT_BITS;
+/// Largest radius (exclusive) that can be used without overflowing the 32.32 intermediate values
+pub(crate) const MAX_RADIUS: f64 = 32768.0;
+
+#[inline]
+/// Convert a floating-point number to 16.16 fixed-point, rounding to the nearest step.
+pub(crate) fn to_fixed(f: f64) -> i64 {
+    (f * ONE as f64).round() as i64
+}
+
+#[inline]
+/// Convert a 16.16 fixed-point number back into a floating-point number.
+///
+/// This is exact as every 16.16 value can be represented by an `f64`.
+pub(crate) fn from_fixed(v: i64) -> f64 {
+    v as f64 / ONE as f64
+}
+
+#[inline]
+/// Round a floating-point number to the nearest 16.16 fixed-point step.
+pub(crate) fn quantize(f: f64) -> f64 {
+    from_fixed(to_fixed(f))
+}
+
+/// Integer square root (rounded down) using the bit-by-bit method.
+pub(crate) fn isqrt(mut n: u64) -> u64 {
+    let mut rst = 0;
+    let mut bit = 1 << 62;
+
+    while bit > n {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if n >= rst + bit {
+            n -= rst + bit;
+            rst = (rst >> 1) + bit;
+        } else {
+            rst >>= 1;
+        }
+        bit >>= 2;
+    }
+    rst
+}
+
+/// Calculate the slow coordinate `sqrt(r² - fast²)` in 16.16 fixed-point.
+///
+/// `r2` is the squared radius in 32.32 fixed-point.
+pub(crate) fn calc_slow(r2: i64, fast: i64) -> i64 {
+    let v = (r2 - fast * fast).max(0);
+    // sqrt of a 32.32 number is a 16.16 number
+    // safe because the square root of a non-negative i64 always fits in an i64
+    #[allow(clippy::cast_possible_wrap)]
+    let rst = isqrt(v as u64) as i64;
+    rst
+}
+
+/// Split a 16.16 fixed-point number into its integer part and an 8-bit
+/// fractional part scaled to `0.0..1.0`.
+pub(crate) fn fract(v: i64) -> (f64, f64) {
+    let whole = (v >> FRACT_BITS) as f64;
+    let fract = ((v & (ONE - 1)) >> 8) as f64 / 256.0;
+    (whole, fract)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_sqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn slow_coordinate() {
+        let r = to_fixed(190.0);
+        let slow = calc_slow(r * r, to_fixed(100.0));
+        let expected = (190.0f64 * 190.0 - 100.0 * 100.0).sqrt();
+        assert!((from_fixed(slow) - expected).abs() < 1.0 / ONE as f64);
+    }
+}
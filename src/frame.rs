@@ -0,0 +1,144 @@
+//! Working in a segment's own frame: distances along the segment and across
+//! it.  Shared by the styled strokes, thick lines, and offsetting.
+
+use crate::Pt;
+
+/// How far a corner can be extended when offsetting, as a multiple of the
+/// offset distance, before it is cut off.
+pub(crate) const MITER_LIMIT: f64 = 4.0;
+
+/// The direction of a segment and the normal to its left.
+///
+/// With y increasing downwards, the left of a segment going right is above it.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Frame {
+    /// The start of the segment
+    pub(crate) origin: Pt<f64>,
+    /// Length of the segment
+    pub(crate) len: f64,
+    /// Unit vector from the start of the segment towards its end.  Segments
+    /// with no length point right.
+    pub(crate) tangent: Pt<f64>,
+}
+
+impl Frame {
+    pub(crate) fn new(a: Pt<f64>, b: Pt<f64>) -> Self {
+        let len = a.distance(b);
+        let tangent = if len == 0.0 {
+            Pt::new(1.0, 0.0)
+        } else {
+            Pt::new((b.x() - a.x()) / len, (b.y() - a.y()) / len)
+        };
+        Self {
+            origin: a,
+            len,
+            tangent,
+        }
+    }
+
+    /// Unit vector pointing to the left of the segment
+    pub(crate) fn normal(&self) -> Pt<f64> {
+        Pt::new(self.tangent.y(), -self.tangent.x())
+    }
+
+    /// Returns the point `along` pixels from the start towards the end and
+    /// `across` pixels to the left of the segment.
+    pub(crate) fn point(&self, along: f64, across: f64) -> Pt<f64> {
+        let (t, n) = (self.tangent, self.normal());
+        Pt::new(
+            along.mul_add(t.x(), across.mul_add(n.x(), self.origin.x())),
+            along.mul_add(t.y(), across.mul_add(n.y(), self.origin.y())),
+        )
+    }
+
+    /// Returns how far a point is along the segment from its start, and how far
+    /// it is to the left of the segment.  The inverse of [`Frame::point`].
+    pub(crate) fn local(&self, pt: Pt<f64>) -> (f64, f64) {
+        let (dx, dy) = (pt.x() - self.origin.x(), pt.y() - self.origin.y());
+        let (t, n) = (self.tangent, self.normal());
+        (dx.mul_add(t.x(), dy * t.y()), dx.mul_add(n.x(), dy * n.y()))
+    }
+}
+
+/// Offsets the corner at `pt`, between the segment from `prev` and the
+/// segment to `next`, by `distance` to the left of both segments.
+///
+/// Pushes the mitered corner, or two points cutting the corner off if the
+/// miter would reach further than [`MITER_LIMIT`].
+pub(crate) fn offset_corner(
+    prev: Pt<f64>,
+    pt: Pt<f64>,
+    next: Pt<f64>,
+    distance: f64,
+    out: &mut Vec<Pt<f64>>,
+) {
+    let n1 = Frame::new(prev, pt).normal();
+    let n2 = Frame::new(pt, next).normal();
+    let (mx, my) = (n1.x() + n2.x(), n1.y() + n2.y());
+    // Cosine of half of the turn between the two edges
+    let cos = mx.hypot(my) / 2.0;
+
+    if cos * MITER_LIMIT < 1.0 {
+        out.push(Pt::new(
+            pt.x() + n1.x() * distance,
+            pt.y() + n1.y() * distance,
+        ));
+        out.push(Pt::new(
+            pt.x() + n2.x() * distance,
+            pt.y() + n2.y() * distance,
+        ));
+    } else {
+        let scale = 2.0 * distance / (mx * mx + my * my);
+        out.push(Pt::new(pt.x() + mx * scale, pt.y() + my * scale));
+    }
+}
+
+/// Offsets an open path by `distance` to the left of each segment, or to the
+/// right if `distance` is negative.
+///
+/// The ends are moved straight across their segments and the corners are
+/// mitered (see [`offset_corner`]).  Repeated points are skipped.
+pub(crate) fn offset_path(points: &[Pt<f64>], distance: f64) -> Vec<Pt<f64>> {
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() < 2 {
+        return points;
+    }
+
+    let n = points.len();
+    let mut out = Vec::with_capacity(n);
+    out.push(Frame::new(points[0], points[1]).point(0.0, distance));
+    for i in 1..n - 1 {
+        offset_corner(points[i - 1], points[i], points[i + 1], distance, &mut out);
+    }
+    let last = Frame::new(points[n - 2], points[n - 1]);
+    out.push(last.point(last.len, distance));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_round_trip() {
+        let frame = Frame::new(Pt::new(10.0, 20.0), Pt::new(13.0, 16.0));
+        assert!((frame.len - 5.0).abs() < 1e-9);
+        assert_eq!(frame.normal(), Pt::new(-0.8, -0.6));
+
+        let pt = frame.point(2.0, 3.0);
+        let (along, across) = frame.local(pt);
+        assert!((along - 2.0).abs() < 1e-9 && (across - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn offset_path_miters_corners() {
+        let path = [Pt::new(0.0, 0.0), Pt::new(10.0, 0.0), Pt::new(10.0, 10.0)];
+        // Left of a path going right then down is above it and then to its right
+        let offset = offset_path(&path, 2.0);
+        assert_eq!(
+            offset,
+            vec![Pt::new(0.0, -2.0), Pt::new(12.0, -2.0), Pt::new(12.0, 10.0)]
+        );
+    }
+}
@@ -0,0 +1,496 @@
+//! Geometry helpers for working with points before drawing them.
+//!
+//! These functions do not draw anything; their results are meant to be passed
+//! to the drawing functions and shapes, e.g. wrapping a cluster of points with
+//! [`convex_hull`] and then drawing it as a [`Polygon`](crate::shapes::Polygon).
+//!
+//! Segments are given by their two end points and circles by their center and
+//! radius.  Points use floating-point coordinates where whole numbers are pixel
+//! centers, the same as the antialiased drawing functions.
+
+use crate::pt::Point;
+use crate::shapes::Rect;
+use crate::Pt;
+
+/// Returns the convex hull of a set of points: the smallest convex polygon that
+/// contains all of them.
+///
+/// The vertices are returned clockwise on screen (with y increasing downwards),
+/// starting from the leftmost point (the topmost one if there is a tie).
+/// Duplicate points and points that lie on an edge of the hull are left out.
+/// Fewer than three points are returned if all of the points lie on a single
+/// line.
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::geo::convex_hull;
+/// use freehand::shapes::Polygon;
+/// use freehand::Pt;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// let points = [(50, 50), (80, 60), (60, 90), (65, 65), (30, 70), (70, 75)];
+/// let hull = convex_hull(&points);
+/// assert_eq!(
+///     hull,
+///     vec![Pt::new(30, 70), Pt::new(50, 50), Pt::new(80, 60), Pt::new(60, 90)]
+/// );
+///
+/// // Highlight the cluster
+/// Polygon::new(hull).draw(&mut image, Rgba([255, 0, 0, 255]));
+/// ```
+#[must_use]
+pub fn convex_hull<P, T>(points: &[P]) -> Vec<Pt<T>>
+where
+    P: Point<T>,
+    T: Copy + PartialOrd + Into<f64>,
+{
+    let mut points: Vec<Pt<T>> = points.iter().map(Point::pt).collect();
+    points.sort_by(|a, b| {
+        a.x()
+            .partial_cmp(&b.x())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(
+                a.y()
+                    .partial_cmp(&b.y())
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+    });
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    // Andrew's monotone chain: build the top half from left to right and then
+    // the bottom half from right to left, dropping points that do not turn
+    // clockwise
+    let mut hull: Vec<Pt<T>> = Vec::with_capacity(points.len() + 1);
+    for &p in &points {
+        push_turn(&mut hull, 0, p);
+    }
+    let top = hull.len() - 1;
+    for &p in points.iter().rev().skip(1) {
+        push_turn(&mut hull, top, p);
+    }
+    // The last point is the first point again
+    hull.pop();
+
+    if hull.len() < 3 {
+        // All points are on one line; keep the two ends
+        return vec![points[0], points[points.len() - 1]];
+    }
+    hull
+}
+
+/// Returns the point where two segments cross, or `None` if they do not.
+///
+/// Touching end points count as crossing.  Parallel segments return `None`,
+/// even if they overlap.
+///
+/// ```
+/// use freehand::geo::segment_intersection;
+/// use freehand::Pt;
+///
+/// let pt = segment_intersection((0.0, 0.0), (10.0, 10.0), (0.0, 10.0), (10.0, 0.0));
+/// assert_eq!(pt, Some(Pt::new(5.0, 5.0)));
+///
+/// assert_eq!(segment_intersection((0.0, 0.0), (4.0, 4.0), (0.0, 10.0), (10.0, 0.0)), None);
+/// ```
+#[must_use]
+pub fn segment_intersection<P>(a1: P, a2: P, b1: P, b2: P) -> Option<Pt<f64>>
+where
+    P: Point<f64>,
+{
+    let (a1, a2, b1, b2) = (a1.pt(), a2.pt(), b1.pt(), b2.pt());
+    let (dax, day) = (a2.x() - a1.x(), a2.y() - a1.y());
+    let (dbx, dby) = (b2.x() - b1.x(), b2.y() - b1.y());
+    let denom = dax * dby - day * dbx;
+    if denom == 0.0 {
+        return None;
+    }
+
+    // How far along each segment the crossing is, from 0.0 to 1.0
+    let (ox, oy) = (b1.x() - a1.x(), b1.y() - a1.y());
+    let t = (ox * dby - oy * dbx) / denom;
+    let u = (ox * day - oy * dax) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(a1.lerp(a2, t))
+    } else {
+        None
+    }
+}
+
+/// Returns the distance from a point to the closest point on a segment.
+///
+/// ```
+/// use freehand::geo::segment_distance;
+///
+/// assert_eq!(segment_distance((5.0, 3.0), (0.0, 0.0), (10.0, 0.0)), 3.0);
+/// // Past the end of the segment
+/// assert_eq!(segment_distance((13.0, 4.0), (0.0, 0.0), (10.0, 0.0)), 5.0);
+/// ```
+#[must_use]
+pub fn segment_distance<P>(pt: P, a: P, b: P) -> f64
+where
+    P: Point<f64>,
+{
+    let (p, a, b) = (pt.pt(), a.pt(), b.pt());
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len = dx * dx + dy * dy;
+    let t = if len == 0.0 {
+        0.0
+    } else {
+        (((p.x() - a.x()) * dx + (p.y() - a.y()) * dy) / len).clamp(0.0, 1.0)
+    };
+    (p.x() - (a.x() + t * dx)).hypot(p.y() - (a.y() + t * dy))
+}
+
+/// Clips a segment to the part that lies inside of a rectangle, using the
+/// Liang–Barsky algorithm.
+///
+/// The rectangle covers the centers of its pixels, from `(rect.x, rect.y)` to
+/// `(rect.x + rect.width - 1, rect.y + rect.height - 1)`.  The end points are
+/// returned in the same direction as the original segment, or `None` if no part
+/// of the segment is inside the rectangle.
+///
+/// ```
+/// use freehand::geo::clip_segment;
+/// use freehand::shapes::Rect;
+/// use freehand::Pt;
+///
+/// let rect = Rect::new((0, 0), 11, 11);
+/// let clipped = clip_segment((-5.0, 5.0), (20.0, 5.0), rect);
+/// assert_eq!(clipped, Some((Pt::new(0.0, 5.0), Pt::new(10.0, 5.0))));
+///
+/// assert_eq!(clip_segment((-5.0, -1.0), (20.0, -1.0), rect), None);
+/// ```
+#[must_use]
+pub fn clip_segment<P>(a: P, b: P, rect: Rect) -> Option<(Pt<f64>, Pt<f64>)>
+where
+    P: Point<f64>,
+{
+    if rect.width == 0 || rect.height == 0 {
+        return None;
+    }
+    let (a, b) = (a.pt(), b.pt());
+    let (x0, y0) = (f64::from(rect.x), f64::from(rect.y));
+    let x1 = x0 + f64::from(rect.width) - 1.0;
+    let y1 = y0 + f64::from(rect.height) - 1.0;
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+
+    // Each edge limits how far along the segment the visible part starts and
+    // ends.  The edge that set each limit is kept so the clipped point can be
+    // placed exactly on it.
+    let (mut start, mut end) = ((0.0_f64, None), (1.0_f64, None));
+    for (p, q, edge) in [
+        (-dx, a.x() - x0, Edge::X(x0)),
+        (dx, x1 - a.x(), Edge::X(x1)),
+        (-dy, a.y() - y0, Edge::Y(y0)),
+        (dy, y1 - a.y(), Edge::Y(y1)),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            if q / p > start.0 {
+                start = (q / p, Some(edge));
+            }
+        } else if q / p < end.0 {
+            end = (q / p, Some(edge));
+        }
+    }
+
+    if start.0 > end.0 {
+        return None;
+    }
+    let point = |(t, edge): (f64, Option<Edge>)| {
+        let pt = a.lerp(b, t);
+        match edge {
+            Some(Edge::X(x)) => Pt::new(x, pt.y().clamp(y0, y1)),
+            Some(Edge::Y(y)) => Pt::new(pt.x().clamp(x0, x1), y),
+            None => pt,
+        }
+    };
+    Some((point(start), point(end)))
+}
+
+/// An edge of a rectangle that a segment was clipped against.
+#[derive(Clone, Copy)]
+enum Edge {
+    X(f64),
+    Y(f64),
+}
+
+/// Returns the points where a circle crosses the infinite line through `a` and
+/// `b`, in the direction from `a` to `b`.
+///
+/// There are no points if the line misses the circle, and a single point if it
+/// only touches the circle.  If `a` and `b` are the same point there is no line
+/// and nothing is returned.
+///
+/// ```
+/// use freehand::geo::circle_line_intersections;
+/// use freehand::Pt;
+///
+/// let pts = circle_line_intersections((50.0, 50.0), 10.0, (0.0, 50.0), (100.0, 50.0));
+/// assert_eq!(pts, vec![Pt::new(40.0, 50.0), Pt::new(60.0, 50.0)]);
+///
+/// let pts = circle_line_intersections((50.0, 50.0), 10.0, (0.0, 60.0), (100.0, 60.0));
+/// assert_eq!(pts, vec![Pt::new(50.0, 60.0)]);
+/// ```
+#[must_use]
+pub fn circle_line_intersections<P>(center: P, radius: f64, a: P, b: P) -> Vec<Pt<f64>>
+where
+    P: Point<f64>,
+{
+    let (center, a, b) = (center.pt(), a.pt(), b.pt());
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len = dx * dx + dy * dy;
+    if len == 0.0 {
+        return Vec::new();
+    }
+
+    // The point on the line closest to the center, as a distance along the line
+    let (fx, fy) = (a.x() - center.x(), a.y() - center.y());
+    let along = -(fx * dx + fy * dy) / len;
+    let (px, py) = (a.x() + along * dx, a.y() + along * dy);
+    let dist2 = (px - center.x()).powi(2) + (py - center.y()).powi(2);
+    let half2 = radius * radius - dist2;
+    if half2 < 0.0 {
+        return Vec::new();
+    }
+    if half2 == 0.0 {
+        return vec![Pt::new(px, py)];
+    }
+
+    // Half of the chord, measured along the line
+    let half = (half2 / len).sqrt();
+    vec![
+        Pt::new(px - half * dx, py - half * dy),
+        Pt::new(px + half * dx, py + half * dy),
+    ]
+}
+
+/// Returns the points where two circles cross.
+///
+/// Looking from the first center towards the second one, the first point
+/// returned is on the right and the second is on the left.  Circles that only
+/// touch return a single point, and circles that miss each other, are inside
+/// one another, or are the same circle return nothing.
+///
+/// ```
+/// use freehand::geo::circle_intersections;
+/// use freehand::Pt;
+///
+/// let pts = circle_intersections((0.0, 0.0), 5.0, (8.0, 0.0), 5.0);
+/// assert_eq!(pts, vec![Pt::new(4.0, 3.0), Pt::new(4.0, -3.0)]);
+///
+/// assert!(circle_intersections((0.0, 0.0), 5.0, (20.0, 0.0), 5.0).is_empty());
+/// ```
+#[must_use]
+pub fn circle_intersections<P>(c1: P, r1: f64, c2: P, r2: f64) -> Vec<Pt<f64>>
+where
+    P: Point<f64>,
+{
+    let (c1, c2) = (c1.pt(), c2.pt());
+    let (dx, dy) = (c2.x() - c1.x(), c2.y() - c1.y());
+    let d = dx.hypot(dy);
+    if d == 0.0 || d > r1 + r2 || d < (r1 - r2).abs() {
+        return Vec::new();
+    }
+
+    // Distance from the first center to the chord between the two points, and
+    // half the length of the chord
+    let along = (r1 * r1 - r2 * r2 + d * d) / (2.0 * d);
+    let half2 = r1 * r1 - along * along;
+    let (ux, uy) = (dx / d, dy / d);
+    let (px, py) = (c1.x() + along * ux, c1.y() + along * uy);
+    if half2 <= 0.0 {
+        return vec![Pt::new(px, py)];
+    }
+
+    let h = half2.sqrt();
+    vec![
+        Pt::new(px - h * uy, py + h * ux),
+        Pt::new(px + h * uy, py - h * ux),
+    ]
+}
+
+/// Adds a point to a hull, first removing points after `start` that would no
+/// longer make a clockwise turn.
+fn push_turn<T>(hull: &mut Vec<Pt<T>>, start: usize, p: Pt<T>)
+where
+    T: Copy + Into<f64>,
+{
+    while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+/// The z component of the cross product of `a - o` and `b - o`, which is
+/// positive when `o`, `a`, `b` turn clockwise on screen.
+fn cross<T>(o: Pt<T>, a: Pt<T>, b: Pt<T>) -> f64
+where
+    T: Copy + Into<f64>,
+{
+    let (ox, oy): (f64, f64) = (o.x().into(), o.y().into());
+    let (ax, ay): (f64, f64) = (a.x().into() - ox, a.y().into() - oy);
+    let (bx, by): (f64, f64) = (b.x().into() - ox, b.y().into() - oy);
+    ax * by - ay * bx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hull_of_square_with_interior_points() {
+        let points = [
+            (0, 0),
+            (10, 0),
+            (5, 5),
+            (10, 10),
+            (5, 0), // on an edge
+            (0, 10),
+            (2, 7),
+            (0, 0),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                Pt::new(0, 0),
+                Pt::new(10, 0),
+                Pt::new(10, 10),
+                Pt::new(0, 10)
+            ]
+        );
+    }
+
+    #[test]
+    fn hull_is_clockwise() {
+        let points = [(3.5, 1.0), (9.0, 4.0), (7.0, 9.5), (1.0, 6.0), (5.0, 5.0)];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        let n = hull.len();
+        for i in 0..n {
+            assert!(cross(hull[i], hull[(i + 1) % n], hull[(i + 2) % n]) > 0.0);
+        }
+    }
+
+    #[test]
+    fn degenerate_hulls() {
+        assert!(convex_hull::<(i32, i32), i32>(&[]).is_empty());
+        assert_eq!(convex_hull(&[(1, 1), (1, 1)]), vec![Pt::new(1, 1)]);
+        assert_eq!(
+            convex_hull(&[(4, 4), (0, 0), (2, 2), (1, 1)]),
+            vec![Pt::new(0, 0), Pt::new(4, 4)]
+        );
+    }
+
+    #[test]
+    fn intersections() {
+        let cross = |a1, a2, b1, b2| segment_intersection::<(f64, f64)>(a1, a2, b1, b2);
+        assert_eq!(
+            cross((0.0, 0.0), (4.0, 0.0), (2.0, -2.0), (2.0, 2.0)),
+            Some(Pt::new(2.0, 0.0))
+        );
+        // Touching at an end point
+        assert_eq!(
+            cross((0.0, 0.0), (4.0, 0.0), (4.0, 0.0), (6.0, 3.0)),
+            Some(Pt::new(4.0, 0.0))
+        );
+        // The lines cross but the segments do not reach each other
+        assert_eq!(cross((0.0, 0.0), (4.0, 0.0), (6.0, -2.0), (6.0, 2.0)), None);
+        // Parallel and collinear
+        assert_eq!(cross((0.0, 0.0), (4.0, 0.0), (0.0, 1.0), (4.0, 1.0)), None);
+        assert_eq!(cross((0.0, 0.0), (4.0, 0.0), (2.0, 0.0), (6.0, 0.0)), None);
+    }
+
+    #[test]
+    fn distances() {
+        let close = |d: f64, expected: f64| (d - expected).abs() < 1e-9;
+        assert!(close(
+            segment_distance((3.0, 4.0), (0.0, 0.0), (0.0, 0.0)),
+            5.0
+        ));
+        assert!(close(
+            segment_distance((-3.0, -4.0), (0.0, 0.0), (10.0, 10.0)),
+            5.0
+        ));
+        assert!(close(
+            segment_distance((5.0, 5.0), (0.0, 0.0), (10.0, 10.0)),
+            0.0
+        ));
+    }
+
+    #[test]
+    fn clipping() {
+        let rect = Rect::new((10, 20), 11, 21);
+        let clip = |a, b| clip_segment::<(f64, f64)>(a, b, rect);
+
+        // Entirely inside
+        assert_eq!(
+            clip((12.0, 22.0), (15.0, 25.0)),
+            Some((Pt::new(12.0, 22.0), Pt::new(15.0, 25.0)))
+        );
+        // Crossing the whole rectangle diagonally, in reverse
+        assert_eq!(
+            clip((35.0, 35.0), (5.0, 5.0)),
+            Some((Pt::new(30.0, 30.0), Pt::new(20.0, 20.0)))
+        );
+        // Vertical line outside, and a line that misses a corner
+        assert_eq!(clip((5.0, 0.0), (5.0, 50.0)), None);
+        assert_eq!(clip((0.0, 15.0), (15.0, 0.0)), None);
+        assert_eq!(clip_segment((0.0, 0.0), (1.0, 1.0), Rect::default()), None);
+    }
+
+    #[test]
+    fn circle_and_line() {
+        let hits = |a, b| circle_line_intersections::<(f64, f64)>((10.0, 10.0), 5.0, a, b);
+
+        // Vertical line, in the direction of the line
+        assert_eq!(
+            hits((10.0, 40.0), (10.0, 30.0)),
+            vec![Pt::new(10.0, 15.0), Pt::new(10.0, 5.0)]
+        );
+        // The points are on the circle even when the line is diagonal
+        for p in hits((0.0, 3.0), (20.0, 12.0)) {
+            assert!(
+                (p.distance(Pt::new(10.0, 10.0)) - 5.0).abs() < 1e-9,
+                "{p:?}"
+            );
+        }
+        assert_eq!(hits((0.0, 3.0), (20.0, 12.0)).len(), 2);
+        // Tangent, missing, and no line at all
+        assert_eq!(hits((15.0, 0.0), (15.0, 1.0)), vec![Pt::new(15.0, 10.0)]);
+        assert!(hits((16.0, 0.0), (16.0, 1.0)).is_empty());
+        assert!(hits((10.0, 10.0), (10.0, 10.0)).is_empty());
+    }
+
+    #[test]
+    fn two_circles() {
+        // Touching from the outside and from the inside
+        assert_eq!(
+            circle_intersections((0.0, 0.0), 5.0, (0.0, 10.0), 5.0),
+            vec![Pt::new(0.0, 5.0)]
+        );
+        assert_eq!(
+            circle_intersections((0.0, 0.0), 10.0, (5.0, 0.0), 5.0),
+            vec![Pt::new(10.0, 0.0)]
+        );
+        // One inside the other, and the same circle twice
+        assert!(circle_intersections((0.0, 0.0), 10.0, (1.0, 0.0), 2.0).is_empty());
+        assert!(circle_intersections((3.0, 3.0), 4.0, (3.0, 3.0), 4.0).is_empty());
+
+        // Different radii: both points are on both circles
+        let (c1, c2) = (Pt::new(2.0, 7.0), Pt::new(9.0, 1.0));
+        let pts = circle_intersections(c1, 6.0, c2, 4.5);
+        assert_eq!(pts.len(), 2);
+        for p in pts {
+            assert!((p.distance(c1) - 6.0).abs() < 1e-9);
+            assert!((p.distance(c2) - 4.5).abs() < 1e-9);
+        }
+    }
+}
@@ -17,7 +17,17 @@
 //! Current features:
 //! - [line drawing functions](lines)
 //! - [circles, circular arcs, and annuli (filled donut shapes)](conics)
-//! - [shapes (currently only rectangles)][shapes]
+//! - [shapes (rectangles, polygons, and ellipses)][shapes]
+//! - [antialiased filling of polygons and paths](raster)
+//! - [Bézier curves and plotted curves](curves)
+//! - [geometry helpers (convex hulls, intersections, and clipping)](geo)
+//! - [coverage masks](ops::rasterize_mask)
+//! - [color conversions and palettes](color)
+//! - [named colors and palettes](palette)
+//! - [simple charts](charts)
+//! - [text using a built-in bitmap font](text)
+//! - [guidelines for checking drawing code](debug)
+//! - [test patterns](patterns)
 //!
 //! # Cargo.toml
 //!
@@ -70,6 +80,27 @@
 //!
 //! [`Serde`]: https://docs.rs/serde/latest/serde/index.html
 //!
+//! ## BDF fonts
+//!
+//! To draw text with your own bitmap fonts (see [`text`]) enable the bdf
+//! feature, which adds a loader for BDF font files:
+//!
+//! ```toml
+//! [dependencies]
+//! freehand = { version = "0.1.0", features = ["bdf"] }
+//! ```
+//!
+//! ## Testing
+//!
+//! To compare rendered images against stored reference images in your own
+//! tests, enable the testing feature (see `testing`), usually only as a
+//! dev-dependency:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! freehand = { version = "0.1.0", features = ["testing"] }
+//! ```
+//!
 //! # Notes
 //!
 //! #### Note on angles
@@ -84,6 +115,18 @@
 //! are more commonly used to specify well-known degrees such as 45°, 90°,
 //! 180°, etc.
 //!
+//! To be explicit about the unit, wrap the value in [`Degrees`], [`Radians`],
+//! or [`Turns`] (where `1.0` is a full circle):
+//!
+//! ```
+//! use freehand::{Degrees, Turns};
+//! # use image::{RgbaImage, Rgba};
+//! # let mut image = RgbaImage::new(400, 400);
+//!
+//! freehand::conics::arc(&mut image, Degrees(22.5), Degrees(90.0), 190, (200, 200), Rgba([255, 0, 0, 255]));
+//! freehand::conics::arc(&mut image, Turns(0.5), Turns(0.75), 190, (200, 200), Rgba([255, 0, 0, 255]));
+//! ```
+//!
 //! [`to_radians()`]: https://doc.rust-lang.org/std/primitive.f64.html#method.to_radians
 //!
 //! #### Note on opacity
@@ -111,9 +154,9 @@
 macro_rules! check_img_i32 {
     ( $img:ident ) => {
         assert!(
-            $img.height() <= (std::i32::MAX as u32) && $img.width() <= (std::i32::MAX as u32),
+            i32::try_from($img.height()).is_ok() && i32::try_from($img.width()).is_ok(),
             "Image is too large, max size for height/width is {}.  size={:?}",
-            std::i32::MAX,
+            i32::MAX,
             $img.dimensions()
         );
     };
@@ -139,19 +182,38 @@ mod test;
 
 mod angle;
 mod antialias;
+mod error;
+mod fixed;
+mod frame;
 mod pt;
+#[cfg(feature = "serde")]
+mod rgba_serde;
+mod stats;
 
 pub(crate) mod draw;
 pub(crate) mod translate;
 
+pub mod charts;
+pub mod color;
 pub mod conics;
+pub mod curves;
+pub mod debug;
+pub mod geo;
 pub mod lines;
 pub mod ops;
+pub mod palette;
+pub mod patterns;
+pub mod raster;
 pub mod shapes;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod text;
 
-pub use angle::Angle;
+pub use angle::{Angle, Degrees, Radians, Turns};
 pub use draw::{new, Draw};
-pub use pt::{Point, Pt};
+pub use error::Error;
+pub use pt::{Point, PointMut, Pt};
+pub use stats::DrawStats;
 
 #[cfg(test)]
 #[allow(unused_imports)] // allow because it's for testing only
@@ -167,7 +229,7 @@ const PI2: f64 = std::f64::consts::PI * 2.0;
 /// Range of a single quadrant in radians
 const QUAD: f64 = std::f64::consts::PI / 2.0;
 /// Tiny amount to subtract from an angle (in radians) to avoid different angles from appearing the same
-const TINY: f64 = std::f64::EPSILON * 10.0; // multiply by a number greater than 2.0 to avoid floating-point inaccuracy
+const TINY: f64 = f64::EPSILON * 10.0; // multiply by a number greater than 2.0 to avoid floating-point inaccuracy
 
 /// A simple helper function that draws `(x, y)` coordinates returned from an iterator.
 pub fn draw_iter<I, P, It, T>(image: &mut I, iter: It, color: I::Pixel)
@@ -195,9 +257,36 @@ fn rgba_array_index(img_width: u32, x: u32, y: u32) -> usize {
 }
 
 /// Calculate the error for a point in a circle.  Assumes octant 7.
-fn calc_error(pt: Pt<f64>, r: i32) -> i32 {
-    ((pt.x().round() + 1.0).powi(2) + (pt.y().round() - 0.5).powi(2) - r.pow(2) as f64).round()
-        as i32
+///
+/// Squares are taken as floating-point numbers so large radii cannot overflow.
+fn calc_error(pt: Pt<f64>, r: i32) -> i64 {
+    ((pt.x().round() + 1.0).powi(2) + (pt.y().round() - 0.5).powi(2) - f64::from(r).powi(2)).round()
+        as i64
+}
+
+/// Returns the point and decision parameter the midpoint circle algorithm
+/// reaches in column `x`, without stepping through the columns before it.
+/// Assumes octant 7, with `x` between the axis and the diagonal.
+///
+/// Each column's y is the largest where `x² + y² - y <= r²`, and the decision
+/// parameter is then `(x + 1)² + y² - y - r²`.
+fn midpoint_at(x: i32, r: i32) -> (i32, i64) {
+    let (x, r) = (i128::from(x), i128::from(r));
+    let y = midpoint_row(r * r - x * x);
+    (y as i32, ((x + 1) * (x + 1) + y * y - y - r * r) as i64)
+}
+
+/// Returns the largest `y` where `y² - y <= rem`, or 0 if there is none.
+fn midpoint_row(rem: i128) -> i128 {
+    let mut y = ((rem.max(0) as f64 + 0.25).sqrt() + 0.5) as i128;
+    // Correct any rounding error from the floating-point square root
+    while y > 0 && y * y - y > rem {
+        y -= 1;
+    }
+    while (y + 1) * (y + 1) - (y + 1) <= rem {
+        y += 1;
+    }
+    y
 }
 
 /// Calculate the slope of a line
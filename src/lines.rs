@@ -10,15 +10,29 @@
 //! ```
 
 mod bres;
+mod clip;
 mod diagonal;
+mod double;
 mod horizontal;
+mod radial;
+mod runs;
 mod straight;
 mod thick;
+mod ticked;
 // mod thick;
 mod vertical;
+mod wave;
 
 pub use bres::LineIter;
 
+pub(crate) use runs::row_span;
+pub use runs::{LineRuns, Run};
+
+pub use clip::clip_line;
+pub(crate) use clip::clipped_iter;
+
+pub use double::{double_line, double_path};
+
 pub use diagonal::{
     diagonal_dashed_line, diagonal_dashed_line_alpha, diagonal_line, diagonal_line_alpha,
 };
@@ -31,6 +45,15 @@ pub use vertical::{
     vertical_dashed_line, vertical_dashed_line_alpha, vertical_line, vertical_line_alpha,
 };
 
-pub use straight::{dashed_line, dashed_line_alpha, line, line_alpha, path};
+pub use radial::{even_spokes, radial_ticks, radial_ticks_at, spokes, Ticks};
+
+pub use straight::{
+    dashed_line, dashed_line_alpha, dashed_line_from, dashed_path, line, line_alpha, line_for_each,
+    line_with, path, try_path, two_color_dashed_line,
+};
+
+pub use thick::{antialiased_line, antialiased_line_1px, antialiased_line_capped, LineCap};
+
+pub use ticked::{ticked_line, ticked_path, TickSide};
 
-pub use thick::antialiased_line;
+pub use wave::{wavy_line, zigzag_line};
@@ -19,11 +19,11 @@ pub struct LineIter {
     pt: Pt<i32>,
     /// Where to end
     end: Pt<i32>,
-    d: i32,
+    d: i64,
     /// Amount added to decision parameter every step
-    dy: i32,
+    dy: i64,
     /// Amount subtracted from decision parameter on y steps
-    dx: i32,
+    dx: i64,
     /// Amount added to y on y steps
     y_step: i32,
     /// If steep the x, y coordinates are transposed
@@ -48,7 +48,9 @@ impl LineIter {
     {
         let (mut a, mut b) = (a.pt(), b.pt());
 
-        let steep = (a.x() - b.x()).abs() < (a.y() - b.y()).abs();
+        // The differences between two i32 coordinates can overflow an i32
+        let steep = (i64::from(a.x()) - i64::from(b.x())).abs()
+            < (i64::from(a.y()) - i64::from(b.y())).abs();
         if steep {
             a.swap();
             b.swap();
@@ -72,8 +74,8 @@ impl LineIter {
             pt: a,
             end: b,
             d,
-            dy: (b.y() - a.y()).abs() * 2,
-            dx: b.x() - a.x(),
+            dy: (i64::from(b.y()) - i64::from(a.y())).abs() * 2,
+            dx: i64::from(b.x()) - i64::from(a.x()),
             y_step,
             steep,
         }
@@ -103,7 +105,7 @@ impl LineIter {
     ///
     /// If `steep` is `true` this represents whether to change x.
     #[must_use]
-    pub fn d(&self) -> i32 {
+    pub fn d(&self) -> i64 {
         self.d
     }
 
@@ -111,7 +113,7 @@ impl LineIter {
     ///
     /// Does not account for `steep == true` (which switches x and y)
     #[must_use]
-    pub fn dy(&self) -> i32 {
+    pub fn dy(&self) -> i64 {
         self.dy
     }
 
@@ -119,7 +121,7 @@ impl LineIter {
     ///
     /// Does not account for `steep == true` (which switches x and y)
     #[must_use]
-    pub fn dx(&self) -> i32 {
+    pub fn dx(&self) -> i64 {
         self.dx
     }
 
@@ -136,6 +138,47 @@ impl LineIter {
     pub fn steep(&self) -> bool {
         self.steep
     }
+
+    /// Returns how many times y has changed after `steps` steps from the
+    /// start of the line.
+    ///
+    /// Does not account for `steep == true` (which switches x and y)
+    pub(super) fn y_steps(&self, steps: i64) -> i64 {
+        self.y_steps_from(0, steps)
+    }
+
+    /// Skips ahead `steps` points without stepping through each one.
+    pub(super) fn advance(&mut self, steps: i64) {
+        let n = self.y_steps_from(self.d, steps);
+        // The new position is on the line, so it always fits in an i32
+        self.pt.x = (i64::from(self.pt.x()) + steps) as i32;
+        self.pt.y = (i64::from(self.pt.y()) + i64::from(self.y_step) * n) as i32;
+        self.d = (i128::from(self.d) + i128::from(steps) * i128::from(self.dy)
+            - i128::from(n) * 2 * i128::from(self.dx)) as i64;
+    }
+
+    /// Ends the line `steps` points after the current position.
+    pub(super) fn truncate(&mut self, steps: i64) {
+        let mut last = self.clone();
+        last.advance(steps);
+        self.end = last.pt;
+    }
+
+    /// The number of y changes after `steps` more steps, starting with the
+    /// decision parameter `d`.
+    ///
+    /// The decision parameter always stays within `-dx < d <= dx`, so the
+    /// number of changes is the smallest count that brings it back into that
+    /// range.
+    fn y_steps_from(&self, d: i64, steps: i64) -> i64 {
+        if self.dx == 0 {
+            return 0;
+        }
+        // `steps * dy` can be larger than an i64 for lines across the whole i32 range
+        let over = i128::from(d) + i128::from(steps) * i128::from(self.dy) - i128::from(self.dx);
+        // Rounded up
+        -(-over).div_euclid(2 * i128::from(self.dx)) as i64
+    }
 }
 
 impl Iterator for LineIter {
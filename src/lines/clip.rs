@@ -0,0 +1,268 @@
+use super::LineIter;
+use crate::pt::Point;
+use crate::shapes::Rect;
+use crate::Pt;
+
+/// Clips a line to the part that is inside of a rectangle.
+///
+/// Returns the first and last points of the line, as drawn by
+/// [`line`](super::line), that are inside the rectangle, in order from `a` to
+/// `b`.  Returns `None` if no part of the line is inside the rectangle.
+///
+/// The points are found without stepping through the pixels outside of the
+/// rectangle, so this is also a cheap way to check whether a very long line
+/// would be visible at all.
+///
+/// ```
+/// use freehand::lines::clip_line;
+/// use freehand::shapes::Rect;
+/// use freehand::Pt;
+///
+/// let image = Rect::new((0, 0), 100, 100);
+/// assert_eq!(
+///     clip_line((-1_000_000, 50), (1_000_000, 50), image),
+///     Some((Pt::new(0, 50), Pt::new(99, 50)))
+/// );
+/// assert_eq!(clip_line((-50, -50), (150, -10), image), None);
+/// ```
+#[must_use]
+pub fn clip_line<P>(a: P, b: P, rect: Rect) -> Option<(Pt<i32>, Pt<i32>)>
+where
+    P: Point<i32>,
+{
+    let (a, b) = (a.pt(), b.pt());
+    let (_, iter) = clipped_iter(a, b, rect)?;
+    let (first, last) = (iter.pt(), iter.end());
+    // LineIter always starts from the end with the smaller x (or y if steep)
+    if LineIter::new(a, b).pt() == a {
+        Some((first, last))
+    } else {
+        Some((last, first))
+    }
+}
+
+/// Returns an iterator over only the points of a line that are inside of a
+/// rectangle, along with the number of points that were skipped before the
+/// first one.
+///
+/// The visible range is found from the endpoints using 64-bit arithmetic, so
+/// lines with coordinates anywhere in the i32 range can be clipped, and the
+/// returned iterator never steps through points outside of the rectangle.
+/// Like [`LineIter`], the points start from the end with the smaller x (or y
+/// if steep).
+pub(crate) fn clipped_iter(a: Pt<i32>, b: Pt<i32>, rect: Rect) -> Option<(i64, LineIter)> {
+    let mut iter = LineIter::new(a, b);
+    let (first, last) = visible_steps(&iter, rect)?;
+    iter.advance(first);
+    iter.truncate(last - first);
+    Some((first, iter))
+}
+
+/// Returns the range of steps, counted from the start of a new [`LineIter`],
+/// whose points are inside of a rectangle.
+///
+/// The points of a line only ever move in one direction along each axis, so
+/// the visible points are always contiguous.  Where the line crosses the top
+/// and bottom of the rectangle is found with a binary search over the number
+/// of steps.
+fn visible_steps(iter: &LineIter, rect: Rect) -> Option<(i64, i64)> {
+    if rect.width == 0 || rect.height == 0 {
+        return None;
+    }
+    let (mut start, mut end) = (iter.pt(), iter.end());
+    let (mut x0, mut x1) = (
+        i64::from(rect.x),
+        i64::from(rect.x) + i64::from(rect.width) - 1,
+    );
+    let (mut y0, mut y1) = (
+        i64::from(rect.y),
+        i64::from(rect.y) + i64::from(rect.height) - 1,
+    );
+    // Work in the same coordinates as the iterator, where x always increases
+    if iter.steep() {
+        start.swap();
+        end.swap();
+        std::mem::swap(&mut x0, &mut y0);
+        std::mem::swap(&mut x1, &mut y1);
+    }
+    let (sx, sy) = (i64::from(start.x()), i64::from(start.y()));
+
+    let len = i64::from(end.x()) - sx;
+    let mut lo = (x0 - sx).max(0);
+    let mut hi = (x1 - sx).min(len);
+    if lo > hi {
+        return None;
+    }
+
+    let y = |steps: i64| sy + i64::from(iter.y_step()) * iter.y_steps(steps);
+    match i64::from(iter.y_step()) {
+        0 if !(y0..=y1).contains(&sy) => return None,
+        0 => {}
+        step => {
+            // Multiplying by the step makes y increase as the steps increase
+            let (near, far) = if step > 0 { (y0, y1) } else { (-y1, -y0) };
+            lo = first_step(lo, hi, |k| step * y(k) >= near);
+            hi = first_step(lo, hi, |k| step * y(k) > far) - 1;
+        }
+    }
+
+    if lo > hi {
+        None
+    } else {
+        Some((lo, hi))
+    }
+}
+
+/// Returns the first step in `lo..=hi` where `f` is true, or `hi + 1` if it
+/// is never true.  `f` must stay true once it becomes true.
+fn first_step<F>(mut lo: i64, mut hi: i64, f: F) -> i64
+where
+    F: Fn(i64) -> bool,
+{
+    hi += 1;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if f(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+/// Clips the negative part of a span from `a` to `b` (inclusive), where `a <= b`.
+///
+/// Returns the clipped start and end as well as the number of positions that were
+/// removed from the start (used to keep dash patterns aligned), or `None` if the
+/// entire span is negative.
+pub(super) fn clip_span(a: i32, b: i32) -> Option<(u32, u32, u32)> {
+    if b.is_negative() {
+        return None;
+    }
+    let skipped = if a.is_negative() { a.unsigned_abs() } else { 0 };
+    Some((a.max(0) as u32, b as u32, skipped))
+}
+
+/// Clips the negative part of a diagonal line, where `a.x() <= b.x()`.
+///
+/// Returns the clipped start and end points as well as the number of steps that
+/// were removed from the start, or `None` if no part of the line has
+/// non-negative coordinates.
+pub(super) fn clip_diagonal(a: Pt<i32>, b: Pt<i32>) -> Option<(Pt<u32>, Pt<u32>, u32)> {
+    let (ax, ay) = (a.x() as i64, a.y() as i64);
+    let (bx, by) = (b.x() as i64, b.y() as i64);
+    let sy = if ay < by { 1 } else { -1 };
+
+    let mut dist = (bx - ax).min((by - ay).abs());
+    let mut skip = (-ax).max(0);
+    if sy > 0 {
+        skip = skip.max(-ay);
+    } else {
+        // an upwards line leaves the image once y becomes negative
+        dist = dist.min(ay);
+    }
+
+    if skip > dist {
+        return None;
+    }
+
+    let start = Pt::new((ax + skip) as u32, (ay + sy * skip) as u32);
+    let end = Pt::new((ax + dist) as u32, (ay + sy * dist) as u32);
+    Some((start, end, skip as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span() {
+        assert_eq!(clip_span(-5, 10), Some((0, 10, 5)));
+        assert_eq!(clip_span(2, 10), Some((2, 10, 0)));
+        assert_eq!(clip_span(-10, -5), None);
+    }
+
+    #[test]
+    fn diagonal() {
+        assert_eq!(
+            clip_diagonal(Pt::new(-2, 0), Pt::new(5, 7)),
+            Some((Pt::new(0, 2), Pt::new(5, 7), 2))
+        );
+        assert_eq!(
+            clip_diagonal(Pt::new(-2, 4), Pt::new(5, -3)),
+            Some((Pt::new(0, 2), Pt::new(2, 0), 2))
+        );
+        assert_eq!(clip_diagonal(Pt::new(0, -4), Pt::new(3, -7)), None);
+    }
+
+    #[test]
+    fn clipped_line_matches_points() {
+        let rect = Rect::new((3, 2), 10, 15);
+        let lines = [
+            ((-30, -10), (60, 45)),
+            ((10, 10), (90, -40)),
+            ((40, 35), (-20, 5)),
+            ((5, 60), (9, -30)),
+            ((-50, -50), (-10, 100)),
+            ((0, 7), (30, 7)),
+            ((8, 8), (8, 8)),
+            ((0, 0), (1, 40)),
+        ];
+        for (a, b) in lines {
+            let mut expected = Vec::new();
+            crate::lines::line_for_each(a, b, |pt| {
+                if rect.contains(pt) {
+                    expected.push(pt);
+                }
+            });
+            let clipped = clip_line(a, b, rect);
+            let expected = expected
+                .first()
+                .map(|&first| (first, *expected.last().unwrap()));
+            assert_eq!(clipped, expected, "{a:?} {b:?}");
+        }
+        assert_eq!(clip_line((0, 0), (10, 10), Rect::default()), None);
+    }
+
+    #[test]
+    fn clip_extreme_lines() {
+        let rect = Rect::new((0, 0), 100, 100);
+        let (min, max) = (i32::MIN, i32::MAX);
+        assert_eq!(
+            clip_line((min, min), (max, max), rect),
+            Some((Pt::new(0, 0), Pt::new(99, 99)))
+        );
+        assert_eq!(
+            clip_line((max, max), (min, min), rect),
+            Some((Pt::new(99, 99), Pt::new(0, 0)))
+        );
+        assert_eq!(
+            clip_line((min, 50), (max, 50), rect),
+            Some((Pt::new(0, 50), Pt::new(99, 50)))
+        );
+        assert_eq!(clip_line((min, max), (max, max), rect), None);
+
+        // Compare against stepping from the visible part of a shallow line
+        let (a, b) = (Pt::new(min, -1_000_000), Pt::new(max, 1_000_000));
+        let (first, last) = clip_line(a, b, rect).unwrap();
+        let mut iter = LineIter::new(a, b);
+        iter.advance(i64::from(first.x()) - i64::from(min));
+        assert_eq!(iter.pt(), first);
+        assert!(rect.contains(first) && rect.contains(last));
+        assert_eq!(iter.take_while(|&pt| rect.contains(pt)).last(), Some(last));
+    }
+
+    #[test]
+    fn advance_matches_stepping() {
+        for (a, b) in [((0, 0), (17, 5)), ((3, 20), (-4, -11)), ((9, 2), (-30, 13))] {
+            let points: Vec<_> = LineIter::new(a, b).collect();
+            for (k, &pt) in (0..).zip(&points) {
+                let mut iter = LineIter::new(a, b);
+                iter.advance(k as i64);
+                assert_eq!(iter.pt(), pt, "{a:?} {b:?} {k}");
+                assert_eq!(iter.collect::<Vec<_>>(), points[k as usize..]);
+            }
+        }
+    }
+}
@@ -1,13 +1,77 @@
 // These functions are exported publicly in a different module - keep the module prefix
 #![allow(clippy::module_name_repetitions)]
 
+use super::clip::clip_diagonal;
 use crate::ops::blend_at_unchecked;
 use crate::pt::Point;
+use crate::Pt;
 use image::{GenericImage, Rgba, RgbaImage};
 
+/// The visible part of a diagonal line: the starting point, the number of steps
+/// to take, whether the line goes downwards, and how many steps were clipped from
+/// the start of the line.
+struct Visible {
+    x: u32,
+    y: u32,
+    dist: u32,
+    down: bool,
+    skipped: u32,
+}
+
+impl Visible {
+    fn pt(&self, i: u32) -> (u32, u32) {
+        if self.down {
+            (self.x + i, self.y + i)
+        } else {
+            (self.x + i, self.y - i)
+        }
+    }
+}
+
+/// Clips a diagonal line to the image bounds.  Negative coordinates are clipped
+/// along the line; coordinates past the right or bottom edge are clamped.
+fn visible<P: Point<i32>>(width: u32, height: u32, a: P, b: P) -> Option<Visible> {
+    let (mut a, mut b) = (Pt::new(a.x(), a.y()), Pt::new(b.x(), b.y()));
+    if a.x() > b.x() {
+        std::mem::swap(&mut a, &mut b);
+    }
+
+    let (a, b, skipped) = clip_diagonal(a, b)?;
+
+    if a.x() >= width || a.y().min(b.y()) >= height {
+        return None;
+    }
+
+    let x0 = a.x().min(width - 1);
+    let y0 = a.y().min(height - 1);
+    let x1 = b.x().min(width - 1);
+    let y1 = b.y().min(height - 1);
+
+    let down = y0 < y1;
+    let dist = if down {
+        (x1 - x0).min(y1 - y0)
+    } else {
+        (x1 - x0).min(y0 - y1)
+    };
+
+    Some(Visible {
+        x: x0,
+        y: y0,
+        dist,
+        down,
+        skipped,
+    })
+}
+
+/// Returns true if the `i`th step of a dashed line should be drawn.
+fn dash_on(i: u32, skipped: u32, width: u32) -> bool {
+    let dash = u64::from(width);
+    (u64::from(i) + u64::from(skipped)) % (dash * 2) < dash
+}
+
 /// A straight diagonal line.
 ///
-/// Only points within the image are drawn.
+/// Only points within the image are drawn.  Negative coordinates are supported.
 ///
 /// # Example
 ///
@@ -21,32 +85,17 @@ use image::{GenericImage, Rgba, RgbaImage};
 /// /// Upwards diagonal line across the image
 /// diagonal_line(&mut image, (0, 399), (399, 0), Rgba([255, 0, 0, 255]));
 /// ```
-pub fn diagonal_line<I, P>(image: &mut I, mut a: P, mut b: P, color: I::Pixel)
+pub fn diagonal_line<I, P>(image: &mut I, a: P, b: P, color: I::Pixel)
 where
     I: GenericImage,
-    P: Point<u32>,
+    P: Point<i32>,
 {
-    if a.x() > b.x() {
-        std::mem::swap(&mut a, &mut b);
-    }
-
-    if a.x() >= image.width() || a.y().min(b.y()) >= image.height() {
-        return;
-    }
-
-    let x0 = a.x().min(image.width() - 1);
-    let y0 = a.y().min(image.height() - 1);
-    let x1 = b.x().min(image.width() - 1);
-    let y1 = b.y().min(image.height() - 1);
-
-    if y0 < y1 {
-        let dist = (x1 - x0).min(y1 - y0);
-        // This is safe due to the min calls above
-        (0..=dist).for_each(|i| unsafe { image.unsafe_put_pixel(x0 + i, y0 + i, color) });
-    } else {
-        let dist = (x1 - x0).min(y0 - y1);
-        // This is safe due to the min calls above
-        (0..=dist).for_each(|i| unsafe { image.unsafe_put_pixel(x0 + i, y0 - i, color) });
+    if let Some(v) = visible(image.width(), image.height(), a, b) {
+        (0..=v.dist).for_each(|i| {
+            let (x, y) = v.pt(i);
+            // This is safe due to the clamping in visible()
+            unsafe { image.unsafe_put_pixel(x, y, color) }
+        });
     }
 }
 
@@ -54,7 +103,7 @@ where
 ///
 /// A `width` of 0 will draw a solid diagonal line.
 ///
-/// Only points within the image are drawn.
+/// Only points within the image are drawn.  Negative coordinates are supported.
 ///
 /// # Example
 ///
@@ -68,52 +117,24 @@ where
 /// /// Upwards dashed diagonal line across the image with a 2px dash
 /// diagonal_dashed_line(&mut image, (0, 399), (399, 0), 2, Rgba([255, 0, 0, 255]));
 /// ```
-pub fn diagonal_dashed_line<I, P>(image: &mut I, mut a: P, mut b: P, width: u32, color: I::Pixel)
+pub fn diagonal_dashed_line<I, P>(image: &mut I, a: P, b: P, width: u32, color: I::Pixel)
 where
     I: GenericImage,
-    P: Point<u32>,
+    P: Point<i32>,
 {
     if width == 0 {
         crate::lines::diagonal_line(image, a, b, color);
         return;
     }
 
-    if a.x() > b.x() {
-        std::mem::swap(&mut a, &mut b);
-    }
-
-    if a.x() >= image.width() || a.y().min(b.y()) >= image.height() {
-        return;
-    }
-
-    let x0 = a.x().min(image.width() - 1);
-    let y0 = a.y().min(image.height() - 1);
-    let x1 = b.x().min(image.width() - 1);
-    let y1 = b.y().min(image.height() - 1);
-    let mut i = 0;
-
-    if y0 < y1 {
-        let dist = (x1 - x0).min(y1 - y0);
-        while i <= dist {
-            // This is safe due to the min calls above
-            unsafe {
-                image.unsafe_put_pixel(x0 + i, y0 + i, color);
-            }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
-        }
-    } else {
-        let dist = (x1 - x0).min(y0 - y1);
-        while i <= dist {
-            // This is safe due to the min calls above
-            unsafe {
-                image.unsafe_put_pixel(x0 + i, y0 - i, color);
-            }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
-        }
+    if let Some(v) = visible(image.width(), image.height(), a, b) {
+        (0..=v.dist)
+            .filter(|&i| dash_on(i, v.skipped, width))
+            .for_each(|i| {
+                let (x, y) = v.pt(i);
+                // This is safe due to the clamping in visible()
+                unsafe { image.unsafe_put_pixel(x, y, color) }
+            });
     }
 }
 
@@ -121,7 +142,7 @@ where
 ///
 /// Opacity should be in the range `0..=1`.
 ///
-/// Only points within the image are drawn.
+/// Only points within the image are drawn.  Negative coordinates are supported.
 ///
 /// # Panics
 ///
@@ -139,40 +160,18 @@ where
 /// /// Upwards diagonal line across the image with 50% opacity
 /// diagonal_line_alpha(&mut image, (0, 399), (399, 0), 0.5, Rgba([255, 0, 0, 255]));
 /// ```
-pub fn diagonal_line_alpha<P>(
-    image: &mut RgbaImage,
-    mut a: P,
-    mut b: P,
-    opacity: f32,
-    color: Rgba<u8>,
-) where
-    P: Point<u32>,
+pub fn diagonal_line_alpha<P>(image: &mut RgbaImage, a: P, b: P, opacity: f32, color: Rgba<u8>)
+where
+    P: Point<i32>,
 {
     check_opacity!(opacity);
 
-    if a.x() > b.x() {
-        std::mem::swap(&mut a, &mut b);
-    }
-
-    if a.x() >= image.width() || a.y().min(b.y()) >= image.height() {
-        return;
-    }
-
-    let x0 = a.x().min(image.width() - 1);
-    let y0 = a.y().min(image.height() - 1);
-    let x1 = b.x().min(image.width() - 1);
-    let y1 = b.y().min(image.height() - 1);
-
-    if y0 < y1 {
-        let dist = (x1 - x0).min(y1 - y0);
-        // This is safe due to the min calls above
-        (0..=dist)
-            .for_each(|i| unsafe { blend_at_unchecked(image, x0 + i, y0 + i, opacity, color) });
-    } else {
-        let dist = (x1 - x0).min(y0 - y1);
-        // This is safe due to the min calls above
-        (0..=dist)
-            .for_each(|i| unsafe { blend_at_unchecked(image, x0 + i, y0 - i, opacity, color) });
+    if let Some(v) = visible(image.width(), image.height(), a, b) {
+        (0..=v.dist).for_each(|i| {
+            let (x, y) = v.pt(i);
+            // This is safe due to the clamping in visible()
+            unsafe { blend_at_unchecked(image, x, y, opacity, color) }
+        });
     }
 }
 
@@ -182,7 +181,7 @@ pub fn diagonal_line_alpha<P>(
 ///
 /// A `width` of 0 will draw a solid diagonal line.
 ///
-/// Only points within the image are drawn.
+/// Only points within the image are drawn.  Negative coordinates are supported.
 ///
 /// # Panics
 ///
@@ -202,13 +201,13 @@ pub fn diagonal_line_alpha<P>(
 /// ```
 pub fn diagonal_dashed_line_alpha<P>(
     image: &mut RgbaImage,
-    mut a: P,
-    mut b: P,
+    a: P,
+    b: P,
     width: u32,
     opacity: f32,
     color: Rgba<u8>,
 ) where
-    P: Point<u32>,
+    P: Point<i32>,
 {
     check_opacity!(opacity);
 
@@ -217,42 +216,14 @@ pub fn diagonal_dashed_line_alpha<P>(
         return;
     }
 
-    if a.x() > b.x() {
-        std::mem::swap(&mut a, &mut b);
-    }
-
-    if a.x() >= image.width() || a.y().min(b.y()) >= image.height() {
-        return;
-    }
-
-    let x0 = a.x().min(image.width() - 1);
-    let y0 = a.y().min(image.height() - 1);
-    let x1 = b.x().min(image.width() - 1);
-    let y1 = b.y().min(image.height() - 1);
-    let mut i = 0;
-
-    if y0 < y1 {
-        let dist = (x1 - x0).min(y1 - y0);
-        while i <= dist {
-            // This is safe due to the min calls above
-            unsafe {
-                blend_at_unchecked(image, x0 + i, y0 + i, opacity, color);
-            }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
-        }
-    } else {
-        let dist = (x1 - x0).min(y0 - y1);
-        while i <= dist {
-            // This is safe due to the min calls above
-            unsafe {
-                blend_at_unchecked(image, x0 + i, y0 - i, opacity, color);
-            }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
-        }
+    if let Some(v) = visible(image.width(), image.height(), a, b) {
+        (0..=v.dist)
+            .filter(|&i| dash_on(i, v.skipped, width))
+            .for_each(|i| {
+                let (x, y) = v.pt(i);
+                // This is safe due to the clamping in visible()
+                unsafe { blend_at_unchecked(image, x, y, opacity, color) }
+            });
     }
 }
 
@@ -274,6 +245,18 @@ mod tests {
             3,
             &*vec![]
         );
+        test_pixels_changed!(
+            diagonal_line_negative,
+            diagonal_line((-2, -2), (10, 10)),
+            3,
+            &*vec![(0, 0), (1, 1), (2, 2)]
+        );
+        test_pixels_changed!(
+            diagonal_line_negative_upwards,
+            diagonal_line((0, 2), (5, -3)),
+            6,
+            &*vec![(0, 2), (1, 1), (2, 0)]
+        );
     }
 
     mod diagonal_dashed_line {
@@ -308,6 +291,12 @@ mod tests {
             6,
             &*vec![]
         );
+        test_pixels_changed!(
+            diagonal_dashed_line_negative,
+            diagonal_dashed_line((-1, -1), (10, 10), 2),
+            6,
+            &*vec![(0, 0), (3, 3), (4, 4)]
+        );
     }
 
     mod diagonal_line_alpha {
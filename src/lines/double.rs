@@ -0,0 +1,103 @@
+use crate::frame::offset_path;
+use crate::pt::{Point, Pt};
+use image::GenericImage;
+
+/// Draws two parallel lines, `gap` pixels apart, centered on the line between
+/// two points.
+///
+/// The gap is the number of empty pixels between the two lines.  Anything
+/// outside of the image is clipped.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::double_line;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// double_line(&mut image, (20, 20), (380, 200), 3.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`double_path`], [`Draw::double_line`](crate::Draw::double_line)
+///
+pub fn double_line<I, P>(image: &mut I, a: P, b: P, gap: f64, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    double_path(image, [a.pt(), b.pt()], gap, color);
+}
+
+/// Draws two parallel paths, `gap` pixels apart, centered on a path through
+/// the given points, like a road on a map.
+///
+/// The corners of both paths are mitered so they stay the same distance apart
+/// through each turn (very sharp corners are cut off).  The start and end
+/// points are not connected.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::double_path;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// let route = [(20, 380), (120, 200), (300, 220), (380, 20)];
+/// double_path(&mut image, route, 4.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`double_line`], [`Draw::double_path`](crate::Draw::double_path)
+///
+pub fn double_path<I, P, It>(image: &mut I, points: It, gap: f64, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let points: Vec<Pt<f64>> = points.into_iter().map(|p| p.pt().into()).collect();
+    // Each line is centered half of a pixel past the edge of the gap
+    let offset = (gap.max(0.0) + 1.0) / 2.0;
+
+    for side in [offset, -offset] {
+        let line = offset_path(&points, side);
+        super::path(image, line.iter().map(Pt::<f64>::i32), color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn gap_between_lines() {
+        let color = Rgba([255, 0, 0, 255]);
+        for (gap, rows) in [(0.0, [10, 11]), (1.0, [9, 11]), (2.0, [9, 12])] {
+            let mut image = image::RgbaImage::new(30, 20);
+            double_line(&mut image, (5, 10), (25, 10), gap, color);
+            for y in 0..20 {
+                let expected = if rows.contains(&y) {
+                    color
+                } else {
+                    Rgba([0, 0, 0, 0])
+                };
+                assert_eq!(image.get_pixel(15, y), &expected, "gap {gap}, y {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn corners_are_joined() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = image::RgbaImage::new(30, 30);
+        double_path(&mut image, [(5, 10), (20, 10), (20, 25)], 3.0, color);
+
+        // The outer and inner corners of the turn
+        assert_eq!(image.get_pixel(22, 8), &color);
+        assert_eq!(image.get_pixel(18, 12), &color);
+        // Nothing in the gap
+        for pt in [(10, 10), (20, 10), (20, 20), (19, 11)] {
+            assert_ne!(image.get_pixel(pt.0, pt.1), &color, "{pt:?}");
+        }
+    }
+}
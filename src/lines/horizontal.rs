@@ -2,12 +2,16 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::many_single_char_names)]
 
+use super::clip::clip_span;
 use crate::ops::blend_at_unchecked;
 use crate::pt::Point;
 use image::{GenericImage, Rgba, RgbaImage};
 
 /// Draws a straight horizontal line.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Example
 ///
 /// ```
@@ -21,15 +25,22 @@ use image::{GenericImage, Rgba, RgbaImage};
 /// /// Horizontal line across the center of the image
 /// horizontal_line(&mut image, (0, 200), 399, color);
 /// ```
-pub fn horizontal_line<I, P>(image: &mut I, pt: P, x2: u32, color: I::Pixel)
+pub fn horizontal_line<I, P>(image: &mut I, pt: P, x2: i32, color: I::Pixel)
 where
     I: GenericImage,
-    P: Point<u32>,
+    P: Point<i32>,
 {
-    if pt.y() < image.height() {
-        (pt.x().min(image.width() - 1)..=x2.min(image.width() - 1))
-            // This is safe due to the min() calls above
-            .for_each(|x| unsafe { image.unsafe_put_pixel(x, pt.y(), color) });
+    let (x, y) = pt.tuple();
+    if y.is_negative() || y as u32 >= image.height() {
+        return;
+    }
+
+    if let Some((x1, x2, _)) = clip_span(x, x2) {
+        let y = y as u32;
+        // Spans that start past the edge of the image are empty
+        (x1..(x2 + 1).min(image.width()))
+            // This is safe due to the min() call above
+            .for_each(|x| unsafe { image.unsafe_put_pixel(x, y, color) });
     }
 }
 
@@ -37,6 +48,9 @@ where
 ///
 /// A `width` of 0 will draw a solid horizontal line.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Example
 ///
 ///
@@ -51,10 +65,10 @@ where
 /// /// Horizontal dashed line across the center of the image with a 2px dash
 /// horizontal_dashed_line(&mut image, (0, 200), 399, 2, color);
 /// ```
-pub fn horizontal_dashed_line<I, P>(image: &mut I, pt: P, mut x2: u32, width: u32, color: I::Pixel)
+pub fn horizontal_dashed_line<I, P>(image: &mut I, pt: P, mut x2: i32, width: u32, color: I::Pixel)
 where
     I: GenericImage,
-    P: Point<u32>,
+    P: Point<i32>,
 {
     if width == 0 {
         crate::lines::horizontal_line(image, pt, x2, color);
@@ -67,21 +81,26 @@ where
         std::mem::swap(&mut x0, &mut x2);
     }
 
-    if y >= image.height() || x0 >= image.width() {
+    let (x0, x2, skipped) = match clip_span(x0, x2) {
+        Some(span) => span,
+        None => return,
+    };
+
+    if y.is_negative() || y as u32 >= image.height() || x0 >= image.width() {
         return;
     }
 
+    let y = y as u32;
     let x1 = x2.min(image.width() - 1);
-    let mut x = x0.min(image.width() - 1);
-    let mut i = 0;
+    let dash = u64::from(width);
 
-    while x <= x1 {
-        // This is safe due to the min calls above
-        unsafe {
-            image.unsafe_put_pixel(x, y, color);
+    for x in x0..=x1 {
+        if (u64::from(x - x0) + u64::from(skipped)) % (dash * 2) < dash {
+            // This is safe due to the min calls above
+            unsafe {
+                image.unsafe_put_pixel(x, y, color);
+            }
         }
-        x = if i == width - 1 { x + width + 1 } else { x + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -89,6 +108,9 @@ where
 ///
 /// Opacity should be in the range `0..=1`.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Panics
 ///
 /// Panics if opacity is not in the range `0.0..=1.0`
@@ -109,18 +131,25 @@ where
 pub fn horizontal_line_alpha<P>(
     image: &mut RgbaImage,
     pt: P,
-    x2: u32,
+    x2: i32,
     opacity: f32,
     color: Rgba<u8>,
 ) where
-    P: Point<u32>,
+    P: Point<i32>,
 {
     check_opacity!(opacity);
 
-    if pt.y() < image.height() {
-        (pt.x().min(image.width() - 1)..=x2.min(image.width() - 1))
-            // This is safe due to the min() calls above
-            .for_each(|x| unsafe { blend_at_unchecked(image, x, pt.y(), opacity, color) });
+    let (x, y) = pt.tuple();
+    if y.is_negative() || y as u32 >= image.height() {
+        return;
+    }
+
+    if let Some((x1, x2, _)) = clip_span(x, x2) {
+        let y = y as u32;
+        // Spans that start past the edge of the image are empty
+        (x1..(x2 + 1).min(image.width()))
+            // This is safe due to the min() call above
+            .for_each(|x| unsafe { blend_at_unchecked(image, x, y, opacity, color) });
     }
 }
 
@@ -130,6 +159,9 @@ pub fn horizontal_line_alpha<P>(
 ///
 /// A `width` of 0 will draw a solid horizontal line.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Panics
 ///
 /// Panics if opacity is not in the range `0.0..=1.0`
@@ -150,12 +182,12 @@ pub fn horizontal_line_alpha<P>(
 pub fn horizontal_dashed_line_alpha<P>(
     image: &mut RgbaImage,
     pt: P,
-    mut x2: u32,
+    mut x2: i32,
     width: u32,
     opacity: f32,
     color: Rgba<u8>,
 ) where
-    P: Point<u32>,
+    P: Point<i32>,
 {
     check_opacity!(opacity);
 
@@ -169,23 +201,27 @@ pub fn horizontal_dashed_line_alpha<P>(
         std::mem::swap(&mut x1, &mut x2);
     }
 
-    if y >= image.height() || (x1 >= image.width()) {
-        horizontal_line_alpha(image, pt, x2, opacity, color);
+    let (x1, x2, skipped) = match clip_span(x1, x2) {
+        Some(span) => span,
+        None => return,
+    };
+
+    if y.is_negative() || y as u32 >= image.height() || (x1 >= image.width()) {
         return;
     }
 
+    let y = y as u32;
     let x2 = x2.min(image.width() - 1);
-    let mut x = x1.min(image.width() - 1);
-    let mut i = 0;
-
-    while x <= x2 {
-        let (r, g, b) = (color[0], color[1], color[2]);
-        // This is safe due to the min calls above
-        unsafe {
-            blend_at_unchecked(image, x, y, opacity, Rgba([r, g, b, 255]));
+    let dash = u64::from(width);
+    let (r, g, b) = (color[0], color[1], color[2]);
+
+    for x in x1..=x2 {
+        if (u64::from(x - x1) + u64::from(skipped)) % (dash * 2) < dash {
+            // This is safe due to the min calls above
+            unsafe {
+                blend_at_unchecked(image, x, y, opacity, Rgba([r, g, b, 255]));
+            }
         }
-        x = if i == width - 1 { x + width + 1 } else { x + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -207,6 +243,12 @@ mod tests {
             3,
             &*vec![]
         );
+        test_pixels_changed!(
+            horizontal_line_negative,
+            horizontal_line((-5, 1), 1),
+            3,
+            &*vec![(0, 1), (1, 1)]
+        );
     }
 
     mod horizontal_dashed_line {
@@ -241,6 +283,12 @@ mod tests {
             6,
             &*vec![]
         );
+        test_pixels_changed!(
+            horizontal_dashed_line_negative,
+            horizontal_dashed_line((-1, 0), 10, 2),
+            6,
+            &*vec![(0, 0), (3, 0), (4, 0)]
+        );
     }
 
     mod horizontal_line_alpha {
@@ -0,0 +1,353 @@
+use crate::pt::{Point, Pt};
+use crate::Angle;
+use image::GenericImage;
+
+/// Draws a straight line pointing away from `center` at each of the given
+/// angles, starting at `inner_radius` and ending at `outer_radius`.
+///
+/// Useful for the ticks on a clock face or dial, or the lines of a compass
+/// rose.  An inner radius of 0 starts each spoke at the center.  Angles follow
+/// the [angle conventions](crate#note-on-angles) used by the rest of the
+/// crate, and anything outside of the image is clipped.
+///
+/// # Panics
+///
+/// Panics if either of the radii are negative
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::spokes;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// // The four main points of a compass
+/// spokes(&mut image, &[0, 90, 180, 270], 20, 190, (200, 200), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`even_spokes`], [`Draw::spokes`](crate::Draw::spokes)
+///
+pub fn spokes<A, C, I>(
+    image: &mut I,
+    angles: &[A],
+    inner_radius: i32,
+    outer_radius: i32,
+    center: C,
+    color: I::Pixel,
+) where
+    A: Angle,
+    C: Point<i32>,
+    I: GenericImage,
+{
+    for r in [inner_radius, outer_radius] {
+        assert!(r >= 0, "Radius must not be negative.  radius={r}");
+    }
+    let center = center.pt();
+    for angle in angles {
+        let a = Pt::from_radian(angle.radians(), inner_radius, center).i32();
+        let b = Pt::from_radian(angle.radians(), outer_radius, center).i32();
+        super::line(image, a, b, color);
+    }
+}
+
+/// Draws `count` evenly spaced spokes around `center`, the first one at
+/// `start_angle`.  See [`spokes`].
+///
+/// # Panics
+///
+/// Panics if either of the radii are negative
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::even_spokes;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// // The hour marks of a clock face
+/// even_spokes(&mut image, 12, 90, 170, 190, (200, 200), Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::even_spokes`](crate::Draw::even_spokes)
+///
+pub fn even_spokes<A, C, I>(
+    image: &mut I,
+    count: u32,
+    start_angle: A,
+    inner_radius: i32,
+    outer_radius: i32,
+    center: C,
+    color: I::Pixel,
+) where
+    A: Angle,
+    C: Point<i32>,
+    I: GenericImage,
+{
+    let step = crate::PI2 / f64::from(count.max(1));
+    let angles: Vec<f64> = (0..count)
+        .map(|i| f64::from(i).mul_add(step, start_angle.radians()))
+        .collect();
+    spokes(image, &angles, inner_radius, outer_radius, center, color);
+}
+
+/// How many tick marks [`radial_ticks`] draws, how long they are, and which
+/// of them are major ticks.  Any tick can be made a major tick with a
+/// different length, e.g. the hour marks on a clock.
+///
+/// ```
+/// use freehand::lines::Ticks;
+///
+/// // 60 ticks 8 pixels long, with every fifth one 20 pixels long
+/// let ticks = Ticks {
+///     major_every: 5,
+///     major_length: 20,
+///     ..Ticks::new(60, 8)
+/// };
+/// assert_eq!(ticks.length(10), 20);
+/// assert_eq!(ticks.length(11), 8);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Ticks {
+    /// The number of ticks spread over the angle range by [`radial_ticks`].
+    /// Ignored by [`radial_ticks_at`], which draws one tick per angle.
+    pub count: u32,
+    /// The length of the minor ticks in pixels
+    pub length: u32,
+    /// The length of the major ticks in pixels
+    pub major_length: u32,
+    /// Every `major_every`th tick is a major tick, starting with the first
+    /// one.  1 makes every tick major, while 0 makes every tick minor.
+    pub major_every: u32,
+}
+
+impl Ticks {
+    /// Creates `count` ticks that are all `length` pixels long.
+    #[must_use]
+    pub fn new(count: u32, length: u32) -> Self {
+        Self {
+            count,
+            length,
+            major_length: length,
+            major_every: 0,
+        }
+    }
+
+    /// Returns true if the tick at position `index` (counting from 0) is a
+    /// major tick.
+    #[must_use]
+    pub fn is_major(&self, index: u32) -> bool {
+        self.major_every != 0 && index % self.major_every == 0
+    }
+
+    /// Returns the length of the tick at position `index`.
+    #[must_use]
+    pub fn length(&self, index: u32) -> u32 {
+        if self.is_major(index) {
+            self.major_length
+        } else {
+            self.length
+        }
+    }
+}
+
+/// Draws tick marks spread evenly from `start_angle` to `end_angle`, with
+/// major and minor lengths given by a [`Ticks`].
+///
+/// Each tick points in towards `center`, running from `radius` inwards by the
+/// tick's length.  The first and last ticks are at the start and end angles.
+/// If the start and end angles are the same, or the end angle is at least a
+/// full turn away from the start angle, the ticks go all the way around
+/// without doubling up where the ends meet.  Angles are interpreted the same
+/// way as [`arc`](crate::conics::arc), going counter-clockwise from the start
+/// angle.
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{radial_ticks, Ticks};
+/// # let mut image = RgbaImage::new(400, 400);
+/// let black = Rgba([0, 0, 0, 255]);
+///
+/// // A clock face: 60 minute marks with a longer mark every 5 minutes
+/// let minutes = Ticks {
+///     major_every: 5,
+///     major_length: 20,
+///     ..Ticks::new(60, 8)
+/// };
+/// radial_ticks(&mut image, 90, 90, 190, (200, 200), minutes, black);
+///
+/// // A protractor: every degree from 0° to 180°, with longer marks every 10°
+/// let degrees = Ticks {
+///     major_every: 10,
+///     major_length: 12,
+///     ..Ticks::new(181, 4)
+/// };
+/// radial_ticks(&mut image, 0, 180, 150, (200, 200), degrees, black);
+/// ```
+///
+/// See also: [`Draw::radial_ticks`](crate::Draw::radial_ticks)
+///
+pub fn radial_ticks<A, C, I>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    radius: i32,
+    center: C,
+    ticks: Ticks,
+    color: I::Pixel,
+) where
+    A: Angle,
+    C: Point<i32>,
+    I: GenericImage,
+{
+    let start = start_angle.radians();
+    let step = if crate::angle::is_full_turn(start_angle, end_angle) {
+        crate::PI2 / f64::from(ticks.count.max(1))
+    } else {
+        let sweep = crate::angle::normalize(end_angle.radians() - start);
+        sweep / f64::from(ticks.count.saturating_sub(1).max(1))
+    };
+    let angles: Vec<f64> = (0..ticks.count)
+        .map(|i| f64::from(i).mul_add(step, start))
+        .collect();
+    radial_ticks_at(image, &angles, radius, center, ticks, color);
+}
+
+/// Draws a tick mark at each of the given angles, with major and minor
+/// lengths.  The ticks are numbered in the order they are given.  See
+/// [`radial_ticks`].
+///
+/// # Panics
+///
+/// Panics if radius is negative
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{radial_ticks_at, Ticks};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// // Long ticks at the compass points, short ones in between
+/// let angles = [0, 45, 90, 135, 180, 225, 270, 315];
+/// let ticks = Ticks {
+///     major_every: 2,
+///     major_length: 30,
+///     ..Ticks::new(0, 10)
+/// };
+/// radial_ticks_at(&mut image, &angles, 190, (200, 200), ticks, Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::radial_ticks_at`](crate::Draw::radial_ticks_at)
+///
+pub fn radial_ticks_at<A, C, I>(
+    image: &mut I,
+    angles: &[A],
+    radius: i32,
+    center: C,
+    ticks: Ticks,
+    color: I::Pixel,
+) where
+    A: Angle,
+    C: Point<i32>,
+    I: GenericImage,
+{
+    assert!(radius >= 0, "Radius must not be negative.  radius={radius}");
+    let center = center.pt();
+    for (i, angle) in (0..).zip(angles) {
+        // A tick longer than the radius stops at the center
+        let length = i32::try_from(ticks.length(i)).unwrap_or(i32::MAX);
+        let inner = radius.saturating_sub(length).max(0);
+        spokes(image, &[*angle], inner, radius, center, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn spoke_ends() -> Result<(), image::ImageError> {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((100, 100));
+        spokes(&mut image, &[0, 90, 225], 10, 40, (50, 50), color);
+
+        for (x, y) in [(60, 50), (90, 50), (50, 40), (50, 10), (43, 57), (22, 78)] {
+            assert_eq!(*image.get_pixel(x, y), color, "({x}, {y})");
+        }
+        // Nothing between the center and the inner radius
+        for (x, y) in [(50, 50), (59, 50), (50, 41), (44, 56)] {
+            assert_ne!(*image.get_pixel(x, y), color, "({x}, {y})");
+        }
+        image.save("images/spokes.png")
+    }
+
+    #[test]
+    fn even_spokes_match_angles() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut expected = crate::test::img::blank((100, 100));
+        spokes(&mut expected, &[30, 120, 210, 300], 5, 60, (50, 50), color);
+
+        let mut image = crate::test::img::blank((100, 100));
+        even_spokes(&mut image, 4, 30, 5, 60, (50, 50), color);
+        assert_eq!(image, expected);
+
+        let mut image = crate::test::img::blank((100, 100));
+        even_spokes(&mut image, 0, 0, 5, 60, (50, 50), color);
+        assert_eq!(image, crate::test::img::blank((100, 100)));
+    }
+
+    #[test]
+    fn ticks_spread_over_range() {
+        let color = Rgba([255, 0, 0, 255]);
+        let ticks = Ticks {
+            major_every: 2,
+            major_length: 20,
+            ..Ticks::new(5, 10)
+        };
+
+        // The ends of the range both get a tick
+        let mut expected = crate::test::img::blank((100, 100));
+        spokes(&mut expected, &[0, 90, 180], 20, 40, (50, 50), color);
+        spokes(&mut expected, &[45, 135], 30, 40, (50, 50), color);
+        let mut image = crate::test::img::blank((100, 100));
+        radial_ticks(&mut image, 0, 180, 40, (50, 50), ticks, color);
+        assert_eq!(image, expected);
+
+        // A full circle doesn't repeat the first tick
+        let mut expected = crate::test::img::blank((100, 100));
+        spokes(&mut expected, &[90, 270], 20, 40, (50, 50), color);
+        spokes(&mut expected, &[180, 0], 30, 40, (50, 50), color);
+        let mut image = crate::test::img::blank((100, 100));
+        let ticks = Ticks { count: 4, ..ticks };
+        radial_ticks(&mut image, 90, 90, 40, (50, 50), ticks, color);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn tick_lengths() {
+        let ticks = Ticks {
+            major_every: 3,
+            major_length: 20,
+            ..Ticks::new(7, 10)
+        };
+        let lengths: Vec<u32> = (0..7).map(|i| ticks.length(i)).collect();
+        assert_eq!(lengths, [20, 10, 10, 20, 10, 10, 20]);
+        assert!(!Ticks::new(7, 10).is_major(0));
+
+        // Ticks longer than the radius stop at the center
+        let color = Rgba([255, 0, 0, 255]);
+        let mut expected = crate::test::img::blank((30, 30));
+        spokes(&mut expected, &[0], 0, 10, (15, 15), color);
+        let mut image = crate::test::img::blank((30, 30));
+        radial_ticks_at(&mut image, &[0], 10, (15, 15), Ticks::new(1, 50), color);
+        assert_eq!(image, expected);
+    }
+}
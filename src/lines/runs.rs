@@ -0,0 +1,248 @@
+use super::LineIter;
+use crate::pt::{Point, Pt};
+
+/// A straight run of pixels from a line, where every pixel shares the same y
+/// (or x) coordinate.  The start of a run is never greater than its end.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Run {
+    /// A row of pixels from `x0` to `x1` (inclusive)
+    Horizontal {
+        /// The row of the run
+        y: i32,
+        /// The first column
+        x0: i32,
+        /// The last column
+        x1: i32,
+    },
+    /// A column of pixels from `y0` to `y1` (inclusive)
+    Vertical {
+        /// The column of the run
+        x: i32,
+        /// The first row
+        y0: i32,
+        /// The last row
+        y1: i32,
+    },
+}
+
+/// An iterator over the runs of a line, yielding the same pixels as
+/// [`LineIter`] grouped into [`Run`]s.
+///
+/// Lines that are wider than they are tall are made of horizontal runs, one for
+/// each row, and steep lines are made of vertical runs, one for each column.
+/// Runs are returned starting from the end of the line with the smaller x (or
+/// y for steep lines), the same order as [`LineIter`].
+///
+/// The length of each run is calculated directly, so long runs take the same
+/// amount of time as short ones.  This allows drawing a line with span fills:
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{horizontal_line, vertical_line, LineRuns, Run};
+/// # let mut image = RgbaImage::new(400, 400);
+/// let color = Rgba([255, 0, 0, 255]);
+///
+/// for run in LineRuns::new((10, 10), (390, 60)) {
+///     match run {
+///         Run::Horizontal { y, x0, x1 } => horizontal_line(&mut image, (x0, y), x1, color),
+///         Run::Vertical { x, y0, y1 } => vertical_line(&mut image, (x, y0), y1, color),
+///     }
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct LineRuns {
+    /// A new iterator for the line, used for its setup
+    iter: LineIter,
+    /// The step that the next run starts on
+    step: i64,
+}
+
+impl LineRuns {
+    /// Creates an iterator over the runs of a line between two points.
+    ///
+    /// ```
+    /// use freehand::lines::{LineRuns, Run};
+    ///
+    /// let runs: Vec<_> = LineRuns::new((0, 0), (5, 2)).collect();
+    /// assert_eq!(
+    ///     runs,
+    ///     vec![
+    ///         Run::Horizontal { y: 0, x0: 0, x1: 1 },
+    ///         Run::Horizontal { y: 1, x0: 2, x1: 3 },
+    ///         Run::Horizontal { y: 2, x0: 4, x1: 5 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn new<P>(a: P, b: P) -> Self
+    where
+        P: Point<i32>,
+    {
+        Self {
+            iter: LineIter::new(a, b),
+            step: 0,
+        }
+    }
+}
+
+impl Iterator for LineRuns {
+    type Item = Run;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (mut start, mut end) = (self.iter.pt(), self.iter.end());
+        if self.iter.steep() {
+            start.swap();
+            end.swap();
+        }
+        let len = i64::from(end.x()) - i64::from(start.x());
+        if self.step > len {
+            return None;
+        }
+
+        let changes = self.iter.y_steps(self.step);
+        let last = run_end(&self.iter, changes, len);
+
+        // Every point is on the line, so they always fit in an i32
+        let minor = (i64::from(start.y()) + i64::from(self.iter.y_step()) * changes) as i32;
+        let first = (i64::from(start.x()) + self.step) as i32;
+        let last_major = (i64::from(start.x()) + last) as i32;
+        self.step = last + 1;
+
+        Some(if self.iter.steep() {
+            Run::Vertical {
+                x: minor,
+                y0: first,
+                y1: last_major,
+            }
+        } else {
+            Run::Horizontal {
+                y: minor,
+                x0: first,
+                x1: last_major,
+            }
+        })
+    }
+}
+
+/// Returns the last step of the run after `changes` changes in y, for a line
+/// `len` steps long.
+fn run_end(iter: &LineIter, changes: i64, len: i64) -> i64 {
+    // y changes on the first step where `step * dy > dx * (2 * changes + 1)`
+    match iter.dy() {
+        0 => len,
+        dy => (i128::from(iter.dx()) * i128::from(2 * changes + 1) / i128::from(dy))
+            .min(i128::from(len)) as i64,
+    }
+}
+
+/// Returns the first and last x of the points of the line from `a` to `b` on
+/// row `y`, or `None` if the line does not reach that row.
+///
+/// The points are found directly instead of by stepping along the line.
+pub(crate) fn row_span(a: Pt<i32>, b: Pt<i32>, y: i32) -> Option<(i32, i32)> {
+    let iter = LineIter::new(a, b);
+    let (mut start, mut end) = (iter.pt(), iter.end());
+    if iter.steep() {
+        start.swap();
+        end.swap();
+    }
+    let len = i64::from(end.x()) - i64::from(start.x());
+
+    if iter.steep() {
+        // Steep lines have one point on each row
+        let step = i64::from(y) - i64::from(start.x());
+        if !(0..=len).contains(&step) {
+            return None;
+        }
+        let mut iter = iter;
+        iter.advance(step);
+        let x = iter.pt().x();
+        return Some((x, x));
+    }
+
+    let changes = (i64::from(y) - i64::from(start.y())) * i64::from(iter.y_step());
+    if iter.y_step() == 0 && y != start.y() || changes < 0 || changes > iter.y_steps(len) {
+        return None;
+    }
+    let first = if changes == 0 {
+        0
+    } else {
+        run_end(&iter, changes - 1, len) + 1
+    };
+    let last = run_end(&iter, changes, len);
+    // Every point is on the line, so they always fit in an i32
+    Some((
+        (i64::from(start.x()) + first) as i32,
+        (i64::from(start.x()) + last) as i32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pt;
+
+    #[test]
+    fn runs_match_points() {
+        let lines = [
+            ((0, 0), (17, 5)),
+            ((3, 20), (-4, -11)),
+            ((9, 2), (-30, 13)),
+            ((0, 0), (10, 0)),
+            ((4, 9), (4, -3)),
+            ((0, 0), (6, 6)),
+            ((2, 2), (2, 2)),
+        ];
+        for (a, b) in lines {
+            let points: Vec<_> = LineIter::new(a, b).collect();
+            let from_runs: Vec<_> = LineRuns::new(a, b)
+                .flat_map(|run| match run {
+                    Run::Horizontal { y, x0, x1 } => {
+                        assert!(x0 <= x1);
+                        (x0..=x1).map(|x| Pt::new(x, y)).collect::<Vec<_>>()
+                    }
+                    Run::Vertical { x, y0, y1 } => {
+                        assert!(y0 <= y1);
+                        (y0..=y1).map(|y| Pt::new(x, y)).collect()
+                    }
+                })
+                .collect();
+            assert_eq!(from_runs, points, "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn row_spans_match_points() {
+        let lines = [
+            ((0, 0), (17, 5)),
+            ((3, 20), (-4, -11)),
+            ((9, 2), (-30, 13)),
+            ((0, 0), (10, 0)),
+            ((4, 9), (4, -3)),
+            ((2, 2), (2, 2)),
+        ];
+        for (a, b) in lines {
+            let points: Vec<_> = LineIter::new(a, b).collect();
+            for y in -15..25 {
+                let xs = points.iter().filter(|pt| pt.y() == y).map(Pt::x);
+                let expected = xs.clone().min().zip(xs.max());
+                assert_eq!(row_span(a.into(), b.into(), y), expected, "{a:?} {b:?} {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn one_run_per_row() {
+        let runs: Vec<_> = LineRuns::new((0, 0), (1000, 3)).collect();
+        assert_eq!(runs.len(), 4);
+        assert_eq!(
+            runs[3],
+            Run::Horizontal {
+                y: 3,
+                x0: 834,
+                x1: 1000
+            }
+        );
+    }
+}
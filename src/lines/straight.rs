@@ -33,13 +33,111 @@ where
     #[allow(clippy::cast_possible_wrap)]
     let height = image.height() as i32;
 
-    for Pt { x, y } in LineIter::new(a, b) {
-        if (0..width).contains(&x) && (0..height).contains(&y) {
-            // Avoid double checking bounds with unsafe_put_pixel()
-            // This is safe because the bounds have already been checked
-            unsafe {
-                image.unsafe_put_pixel(x as u32, y as u32, color);
-            }
+    for (_, Pt { x, y }) in visible_points(a.pt(), b.pt(), width, height) {
+        // Avoid double checking bounds with unsafe_put_pixel()
+        // This is safe because visible_points() only returns points inside the image
+        unsafe {
+            image.unsafe_put_pixel(x as u32, y as u32, color);
+        }
+    }
+}
+
+/// Calls a closure for every point on a straight line between two points,
+/// instead of drawing to an image.
+///
+/// Points are visited in order from `a` to `b` and are not clipped.
+///
+/// # Example
+///
+/// ```
+/// use freehand::lines::line_for_each;
+///
+/// let mut points = Vec::new();
+/// line_for_each((0, 0), (3, 3), |pt| points.push(pt));
+///
+/// assert_eq!(points.len(), 4);
+/// ```
+pub fn line_for_each<P, F>(a: P, b: P, mut f: F)
+where
+    P: Point<i32>,
+    F: FnMut(Pt<i32>),
+{
+    let (a, b) = (a.pt(), b.pt());
+    let iter = LineIter::new(a, b);
+    if iter.pt() == a {
+        iter.for_each(f);
+        return;
+    }
+
+    // The iterator starts at `b`, so jump to each point from the far end back
+    let dx = (i64::from(b.x()) - i64::from(a.x())).abs();
+    let dy = (i64::from(b.y()) - i64::from(a.y())).abs();
+    for steps in (0..=dx.max(dy)).rev() {
+        let mut pt = iter.clone();
+        pt.advance(steps);
+        f(pt.pt());
+    }
+}
+
+/// Draws a straight line between two points using a closure to pick the color
+/// of each pixel.  Ignores points that are outside of the image bounds.
+///
+/// The closure is given the point and how far along the line it is, from `0.0`
+/// at `a` to `1.0` at `b`, and returns the color to draw.  It is only called
+/// for points inside the image, which may be visited starting from either end.
+/// This can be used for gradients, custom dash patterns, or coloring a line
+/// by data.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::color::{lerp, Colormap};
+/// use freehand::lines::line_with;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // Fade from red to blue
+/// let (red, blue) = (Rgba([255, 0, 0, 255]), Rgba([0, 0, 255, 255]));
+/// line_with(&mut image, (0, 0), (399, 399), |_, t| lerp(red, blue, t as f32));
+///
+/// // Color a line using a colormap
+/// line_with(&mut image, (0, 399), (399, 0), |_, t| Colormap::Viridis.sample(t));
+/// ```
+///
+/// See also: [`Draw::line_with`](crate::Draw::line_with)
+///
+pub fn line_with<I, P, F>(image: &mut I, a: P, b: P, mut f: F)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    F: FnMut(Pt<i32>, f64) -> I::Pixel,
+{
+    check_img_i32!(image);
+
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+    #[allow(clippy::cast_possible_wrap)]
+    let height = image.height() as i32;
+
+    let (a, b) = (a.pt(), b.pt());
+    // The positions from visible_points() count from the iterator's start,
+    // which may be either end of the line
+    let reversed = LineIter::new(a, b).pt() != a;
+    let dx = (i64::from(b.x()) - i64::from(a.x())).abs();
+    let dy = (i64::from(b.y()) - i64::from(a.y())).abs();
+    let steps = dx.max(dy).max(1) as f64;
+
+    for (i, pt) in visible_points(a, b, width, height) {
+        let t = i as f64 / steps;
+        let color = f(pt, if reversed { 1.0 - t } else { t });
+        // Avoid double checking bounds with unsafe_put_pixel()
+        // This is safe because visible_points() only returns points inside the image
+        unsafe {
+            image.unsafe_put_pixel(pt.x() as u32, pt.y() as u32, color);
         }
     }
 }
@@ -73,7 +171,6 @@ where
     check_img_i32!(image);
 
     let dash_width = dash_width as usize;
-    let w = dash_width * 2;
 
     if dash_width == 0 {
         line(image, a, b, color);
@@ -86,15 +183,140 @@ where
     #[allow(clippy::cast_possible_wrap)]
     let width = image.width() as i32;
 
-    for (i, Pt { x, y }) in LineIter::new(a, b).enumerate() {
-        if (0..width).contains(&x) && (0..height).contains(&y) && i % w < dash_width {
+    for (i, Pt { x, y }) in visible_points(a.pt(), b.pt(), width, height) {
+        if dash_index(i, dash_width) == 0 {
+            // Avoid double checking bounds with unsafe_put_pixel()
+            // This is safe because visible_points() only returns points inside the image
+            unsafe {
+                image.unsafe_put_pixel(x as u32, y as u32, color);
+            }
+        }
+    }
+}
+
+/// Draws a dashed straight line that starts part way through the dash pattern,
+/// and returns where in the pattern the line ended.
+///
+/// The phase is the number of pixels into the pattern of a dash followed by a
+/// gap, so `0` starts at the beginning of a dash and `dash_width` starts at the
+/// beginning of a gap.  The returned phase is the phase of the end point `b`,
+/// which can be passed to the next line starting at `b` so the pattern
+/// continues without restarting.
+///
+/// If the width is 0 then a solid line is drawn and the phase is returned
+/// unchanged.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::dashed_line_from;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// let color = Rgba([255, 0, 0, 255]);
+///
+/// let phase = dashed_line_from(&mut image, (10, 10), (200, 10), 4, 0, color);
+/// assert_eq!(phase, 6);
+/// dashed_line_from(&mut image, (200, 10), (200, 200), 4, phase, color);
+/// ```
+///
+/// See also: [`dashed_path`]
+///
+pub fn dashed_line_from<I, P>(
+    image: &mut I,
+    a: P,
+    b: P,
+    dash_width: u16,
+    phase: usize,
+    color: I::Pixel,
+) -> usize
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    check_img_i32!(image);
+
+    let dash_width = dash_width as usize;
+
+    if dash_width == 0 {
+        line(image, a, b, color);
+        return phase;
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    let height = image.height() as i32;
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+
+    let (a, b) = (a.pt(), b.pt());
+    let (steps, pos_from_a) = positions_from(a, b);
+    let phase = phase % (2 * dash_width);
+
+    for (i, Pt { x, y }) in visible_points(a, b, width, height) {
+        if dash_index(phase + pos_from_a(i), dash_width) == 0 {
             // Avoid double checking bounds with unsafe_put_pixel()
-            // This is safe because the bounds have already been checked
+            // This is safe because visible_points() only returns points inside the image
             unsafe {
                 image.unsafe_put_pixel(x as u32, y as u32, color);
             }
         }
     }
+    (phase + steps) % (2 * dash_width)
+}
+
+/// Draws a dashed path from one point to the next, carrying the dash pattern
+/// through each corner instead of restarting it on every line.
+///
+/// The pattern starts at `phase` (see [`dashed_line_from`]), and the phase at
+/// the last point is returned so a later call can continue the same pattern.
+/// The start and end points are not connected.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::dashed_path;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// let color = Rgba([255, 0, 0, 255]);
+///
+/// let phase = dashed_path(&mut image, [(0, 0), (399, 0), (399, 399)], 5, 0, color);
+/// dashed_path(&mut image, [(399, 399), (0, 399), (0, 0)], 5, phase, color);
+/// ```
+///
+/// See also: [`Draw::dashed_path`](crate::Draw::dashed_path)
+///
+pub fn dashed_path<I, P, It>(
+    image: &mut I,
+    points: It,
+    dash_width: u16,
+    phase: usize,
+    color: I::Pixel,
+) -> usize
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let mut points = points.into_iter();
+
+    let mut a = match points.next() {
+        Some(first) => first,
+        None => return phase,
+    };
+
+    let mut phase = phase;
+    for b in points {
+        phase = dashed_line_from(image, a.pt(), b.pt(), dash_width, phase, color);
+        a = b;
+    }
+    phase
 }
 
 /// Draws a straight line between two points using a specified opacity.
@@ -132,13 +354,11 @@ where
     #[allow(clippy::cast_possible_wrap)]
     let height = image.height() as i32;
 
-    for Pt { x, y } in LineIter::new(a, b) {
-        if (0..width).contains(&x) && (0..height).contains(&y) {
-            // Avoid double checking bounds
-            // This is safe because the bounds have already been checked
-            unsafe {
-                blend_at_unchecked(image, x as u32, y as u32, opacity, color);
-            }
+    for (_, Pt { x, y }) in visible_points(a.pt(), b.pt(), width, height) {
+        // Avoid double checking bounds
+        // This is safe because visible_points() only returns points inside the image
+        unsafe {
+            blend_at_unchecked(image, x as u32, y as u32, opacity, color);
         }
     }
 }
@@ -184,7 +404,6 @@ pub fn dashed_line_alpha<P, W>(
     check_opacity!(opacity);
 
     let dash_width = dash_width.into() as usize;
-    let w = dash_width * 2;
 
     if dash_width == 0 {
         line(image, a, b, color);
@@ -196,10 +415,10 @@ pub fn dashed_line_alpha<P, W>(
     #[allow(clippy::cast_possible_wrap)]
     let height = image.height() as i32;
 
-    for (i, Pt { x, y }) in LineIter::new(a, b).enumerate() {
-        if (0..width).contains(&x) && (0..height).contains(&y) && i % w < dash_width {
+    for (i, Pt { x, y }) in visible_points(a.pt(), b.pt(), width, height) {
+        if dash_index(i, dash_width) == 0 {
             // Avoid double checking
-            // This is safe because the bounds have already been checked
+            // This is safe because visible_points() only returns points inside the image
             unsafe {
                 blend_at_unchecked(image, x as u32, y as u32, opacity, color);
             }
@@ -207,6 +426,108 @@ pub fn dashed_line_alpha<P, W>(
     }
 }
 
+/// Draws a dashed straight line between two points that alternates between
+/// two colors instead of leaving gaps, which keeps the line visible on both
+/// light and dark backgrounds.
+/// Points that are outside of the image bounds are ignored.
+///
+/// The line starts with a dash of `color` at `a`, followed by a dash of
+/// `alt_color`, matching where [`dashed_line_from`] starts its pattern.  If the
+/// width is 0 then a solid line is drawn using `color`.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::two_color_dashed_line;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let black = Rgba([0, 0, 0, 255]);
+/// let white = Rgba([255, 255, 255, 255]);
+/// two_color_dashed_line(&mut image, (0, 0), (399, 399), 4, black, white);
+/// ```
+///
+/// See also: [`Draw::two_color_dashed_line`](crate::Draw::two_color_dashed_line)
+///
+pub fn two_color_dashed_line<I, P>(
+    image: &mut I,
+    a: P,
+    b: P,
+    dash_width: u16,
+    color: I::Pixel,
+    alt_color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    check_img_i32!(image);
+
+    let dash_width = dash_width as usize;
+
+    if dash_width == 0 {
+        line(image, a, b, color);
+        return;
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    let height = image.height() as i32;
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+
+    let (a, b) = (a.pt(), b.pt());
+    let (_, pos_from_a) = positions_from(a, b);
+    let colors = [color, alt_color];
+    for (i, Pt { x, y }) in visible_points(a, b, width, height) {
+        let color = colors[dash_index(pos_from_a(i), dash_width)];
+        // Avoid double checking bounds with unsafe_put_pixel()
+        // This is safe because visible_points() only returns points inside the image
+        unsafe {
+            image.unsafe_put_pixel(x as u32, y as u32, color);
+        }
+    }
+}
+
+/// Returns the number of steps from `a` to `b`, and a function converting the
+/// positions from [`visible_points`], which count from the iterator's start
+/// (either end of the line), into positions counted from `a`.
+fn positions_from(a: Pt<i32>, b: Pt<i32>) -> (usize, impl Fn(usize) -> usize) {
+    let reversed = LineIter::new(a, b).pt() != a;
+    let steps = (i64::from(b.x()) - i64::from(a.x()))
+        .abs()
+        .max((i64::from(b.y()) - i64::from(a.y())).abs()) as usize;
+    (steps, move |i| if reversed { steps - i } else { i })
+}
+
+/// Returns which dash the point at position `i` along a line belongs to: `0`
+/// for the dashes (drawn with the line's color) and `1` for the gaps between
+/// them.
+fn dash_index(i: usize, dash_width: usize) -> usize {
+    (i / dash_width) % 2
+}
+
+/// Returns the points of a line that are inside an image of the given size,
+/// along with their position along the line.
+///
+/// The parts of the line outside of the image are skipped without stepping
+/// through them (see [`clip_line`](super::clip_line)).
+fn visible_points(
+    a: Pt<i32>,
+    b: Pt<i32>,
+    width: i32,
+    height: i32,
+) -> impl Iterator<Item = (usize, Pt<i32>)> {
+    let image = crate::shapes::Rect::new((0, 0), height as u32, width as u32);
+    let (skip, iter) = match super::clip::clipped_iter(a, b, image) {
+        Some((skip, iter)) => (skip as usize, Some(iter)),
+        None => (0, None),
+    };
+    (skip..).zip(iter.into_iter().flatten())
+}
+
 /// Draws a path using straight solid lines from one point to the next.
 /// The start and end points are not connected.
 ///
@@ -241,6 +562,49 @@ where
     }
 }
 
+/// Draws a path using straight solid lines from one point to the next,
+/// returning an error if there are not enough points to draw a line.
+///
+/// The start and end points are not connected.
+///
+/// # Errors
+///
+/// Returns [`Error::EmptyPath`](crate::Error::EmptyPath) if fewer than two
+/// points were given.  Nothing is drawn in this case.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::try_path;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let lines = [(0, 0), (399, 0), (399, 399), (0, 399)];
+/// assert!(try_path(&mut image, lines, Rgba([255, 0, 0, 255])).is_ok());
+///
+/// let empty: [(i32, i32); 0] = [];
+/// assert!(try_path(&mut image, empty, Rgba([255, 0, 0, 255])).is_err());
+/// ```
+pub fn try_path<I, P, It>(image: &mut I, points: It, color: I::Pixel) -> Result<(), crate::Error>
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let mut points = points.into_iter();
+
+    let (mut a, b) = match (points.next(), points.next()) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return Err(crate::Error::EmptyPath),
+    };
+
+    for b in std::iter::once(b).chain(points) {
+        line(image, a.pt(), b.pt(), color);
+        a = b;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,8 +693,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn line_with_position() {
+        let mut image = crate::test::img::blank((12, 5));
+        let mut seen = Vec::new();
+        // Drawn from right to left, partly outside of the image
+        line_with(&mut image, (10, 2), (-10, 2), |pt, t| {
+            seen.push((pt.x(), t));
+            image::Rgba([(t * 200.0) as u8, 0, 0, 255])
+        });
+
+        seen.sort_by_key(|&(x, _)| x);
+        assert_eq!(seen.len(), 11);
+        assert_eq!(seen[10], (10, 0.0));
+        assert_eq!(seen[0], (0, 0.5));
+        assert_eq!(image.get_pixel(5, 2).0, [50, 0, 0, 255]);
+        assert_eq!(image.get_pixel(11, 2).0, [255, 255, 255, 255]);
+    }
+
+    mod two_color_dashed_line {
+        #[test]
+        fn alternates_colors() {
+            let (red, blue) = (image::Rgba([255, 0, 0, 255]), image::Rgba([0, 0, 255, 255]));
+            let mut image = crate::test::img::blank((10, 3));
+            super::super::two_color_dashed_line(&mut image, (9, 1), (-2, 1), 3, red, blue);
+
+            // The dashes start at `a`, even though the line is drawn from x = -2
+            let expected = [blue, red, red, red, blue, blue, blue, red, red, red];
+            for (x, color) in (0..10).zip(expected) {
+                assert_eq!(image.get_pixel(x, 1), &color, "{x}");
+            }
+        }
+    }
+
     mod path {
 
+        #[test]
+        fn dashed_path_continues_through_corners() {
+            let red = image::Rgba([255, 0, 0, 255]);
+            let white = image::Rgba([255, 255, 255, 255]);
+            let mut image = crate::test::img::blank((10, 10));
+            // Drawn right to left, then down
+            let phase = super::super::dashed_path(&mut image, [(6, 0), (0, 0), (0, 7)], 2, 1, red);
+            assert_eq!(phase, 2);
+
+            // The pattern starts one pixel into a dash at (6, 0) and carries on
+            // through the corner at (0, 0), which is in a gap
+            let top = [white, white, red, red, white, white, red];
+            for (x, color) in (0..7).zip(top) {
+                assert_eq!(image.get_pixel(x, 0), &color, "({x}, 0)");
+            }
+            let side = [red, red, white, white, red, red, white];
+            for (y, color) in (1..8).zip(side) {
+                assert_eq!(image.get_pixel(0, y), &color, "(0, {y})");
+            }
+        }
+
         #[test]
         fn path() -> Result<(), image::ImageError> {
             crate::logger(crate::LOG_LEVEL);
@@ -345,4 +763,90 @@ mod tests {
             image.save("images/path.png")
         }
     }
+
+    #[test]
+    fn line_for_each_order() {
+        let mut points = Vec::new();
+        line_for_each((5, 3), (0, 0), |pt| points.push(pt));
+
+        assert_eq!(points.first(), Some(&Pt::new(5, 3)));
+        assert_eq!(points.last(), Some(&Pt::new(0, 0)));
+        assert_eq!(points.len(), LineIter::new((5, 3), (0, 0)).count());
+
+        for (a, b) in [
+            ((5, 3), (0, 0)),
+            ((0, 0), (5, 3)),
+            ((2, 9), (-4, -1)),
+            ((7, 7), (7, 7)),
+        ] {
+            let mut points = Vec::new();
+            line_for_each(a, b, |pt| points.push(pt));
+
+            let mut expected: Vec<_> = LineIter::new(a, b).collect();
+            if expected.first() != Some(&Pt::from(a)) {
+                expected.reverse();
+            }
+            assert_eq!(points, expected, "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn clipped_lines_match_unclipped() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        // Lines that enter and leave the image, start inside, or miss it entirely
+        let lines = [
+            ((-30, -10), (60, 45)),
+            ((10, 10), (90, -40)),
+            ((-20, 35), (70, 5)),
+            ((5, 60), (45, -30)),
+            ((-50, -50), (-10, 100)),
+        ];
+        for (a, b) in lines {
+            let mut image = crate::test::img::blank((40, 30));
+            line(&mut image, a, b, color);
+            dashed_line_alpha(&mut image, b, a, 3_u16, 0.5, color);
+
+            let mut expected = crate::test::img::blank((40, 30));
+            line_for_each(a, b, |pt| {
+                if let Ok(pt) = crate::Pt::<u32>::try_from(pt) {
+                    if pt.x() < 40 && pt.y() < 30 {
+                        expected.put_pixel(pt.x(), pt.y(), color);
+                    }
+                }
+            });
+            let points: Vec<_> = LineIter::new(b, a).collect();
+            for (i, pt) in points.into_iter().enumerate() {
+                if (0..40).contains(&pt.x()) && (0..30).contains(&pt.y()) && i % 6 < 3 {
+                    crate::ops::blend_at(&mut expected, pt.x() as u32, pt.y() as u32, 0.5, color);
+                }
+            }
+            assert_eq!(image, expected, "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn extreme_coordinates() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (min, max) = (i32::MIN, i32::MAX);
+        let mut image = crate::test::img::blank((20, 20));
+        line(&mut image, (min, min), (max, max), color);
+        dashed_line(&mut image, (0, 19), (max, 19 - max), 2_u16, color);
+        line_alpha(&mut image, (min, 5), (max, 5), 0.5, color);
+
+        let mut expected = crate::test::img::blank((20, 20));
+        for i in 0..20 {
+            expected.put_pixel(i, i, color);
+        }
+        for x in (0..20).step_by(4) {
+            expected.put_pixel(x, 19 - x, color);
+            expected.put_pixel(x + 1, 18 - x, color);
+        }
+        for x in 0..20 {
+            crate::ops::blend_at(&mut expected, x, 5, 0.5, color);
+        }
+        assert_eq!(image, expected);
+
+        let runs = crate::lines::LineRuns::new((min, min), (max, min));
+        assert_eq!(runs.count(), 1);
+    }
 }
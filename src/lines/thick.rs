@@ -1,11 +1,16 @@
 // These functions are exported publicly in a different module - keep the module prefix
 #![allow(clippy::module_name_repetitions)]
 
+use crate::frame::Frame;
 use crate::ops::blend_at;
 use crate::{Point, Pt};
 
 /// Draws an antialiased line of the specified thickness
 ///
+/// Widths of 1.0 or less use the faster [`antialiased_line_1px`].  Only the
+/// part of the line that can reach the image is calculated, so the end points
+/// can be anywhere in the i32 range.
+///
 /// # Example
 ///
 /// ```
@@ -30,47 +35,105 @@ pub fn antialiased_line<P, T>(
     P: Point<T>,
     T: Into<i32> + Copy,
 {
-    let Pt {
-        x: mut x0,
-        y: mut y0,
-    } = Pt::new(a.x().into(), a.y().into());
+    if wd <= 1.0 {
+        antialiased_line_1px(image, a, b, color);
+        return;
+    }
 
-    let Pt { x: x1, y: y1 } = Pt::new(b.x().into(), b.y().into());
+    let a = Pt::new(a.x().into(), a.y().into());
+    let b = Pt::new(b.x().into(), b.y().into());
 
-    let dx = (x1 - x0).abs(); // x difference
-    let dy = (y1 - y0).abs(); // y difference
+    // The slope comes from the whole line so clipping doesn't change it
+    let (xa, ya) = (i64::from(a.x()), i64::from(a.y()));
+    let dx = (i64::from(b.x()) - xa).abs(); // x difference
+    let dy = (i64::from(b.y()) - ya).abs(); // y difference
 
     // amount to added to x
-    let sx = if x0 < x1 { 1 } else { -1 };
+    let sx = if a.x() < b.x() { 1 } else { -1 };
     // amount added to y
-    let sy = if y0 < y1 { 1 } else { -1 };
+    let sy = if a.y() < b.y() { 1 } else { -1 };
 
-    let mut err = dx - dy;
     let ed = if dx + dy == 0 {
         1.0
     } else {
-        ((dx as f32 * dx as f32) + (dy as f32 * dy as f32)).sqrt()
+        (dx as f64).hypot(dy as f64)
     };
+    let wd = f64::from((wd + 1.0) / 2.0);
+
+    let margin = (wd * 1.5 + 2.0).min(f64::from(1 << 30)) as i64;
+    let rect = reach_rect((dx, dy), ed * wd, margin, image.dimensions());
+    let (start, end) = match super::clip_line(a, b, rect) {
+        Some(ends) => ends,
+        None => return,
+    };
+
+    let (mut x0, mut y0) = (i64::from(start.x()), i64::from(start.y()));
+    let (x1, y1) = (i64::from(end.x()), i64::from(end.y()));
 
-    let wd = (wd + 1.0) / 2.0;
+    // The error of the first visible point, as if the line had been followed
+    // from `a`.  Points on the line keep the error small, so it fits in an i64.
+    let err = i128::from(dx - dy) - i128::from((x0 - xa).abs()) * i128::from(dy)
+        + i128::from((y0 - ya).abs()) * i128::from(dx);
+    let mut err = err as i64;
+
+    let (width, height) = image.dimensions();
+    // Coordinates outside of these ranges only draw outside of the image
+    let near_x = (-margin, i64::from(width) - 1 + margin);
+    let near_y = (-margin, i64::from(height) - 1 + margin);
+
+    let blend = |image: &mut image::RgbaImage, x: i64, y: i64, e: i64| {
+        if let (Ok(x), Ok(y)) = (u32::try_from(x), u32::try_from(y)) {
+            let o = (e.abs() as f64 / ed - wd + 1.0).max(0.0);
+            blend_at(image, x, y, 1.0 - o as f32, color);
+        }
+    };
+    // Draws up to `last` pixels from `(x, y)` in the direction of `step`
+    // while the error `e` (growing by `inc` for each pixel) is small enough,
+    // skipping straight to the pixels inside of the image
+    let sweep = |image: &mut image::RgbaImage,
+                 (x, y): (i64, i64),
+                 step: (i64, i64),
+                 last: i64,
+                 e: i64,
+                 inc: i64| {
+        let (lo_x, hi_x) = sweep_inside(x, step.0, width);
+        let (lo_y, hi_y) = sweep_inside(y, step.1, height);
+        let (first, last) = (lo_x.max(lo_y), last.min(hi_x).min(hi_y));
+        let mut e = e.saturating_add((first - 1).saturating_mul(inc));
+        for j in first..=last {
+            if (e as f64) >= ed * wd {
+                break;
+            }
+            blend(image, x + step.0 * j, y + step.1 * j, e);
+            e = e.saturating_add(inc);
+        }
+    };
 
     loop {
-        {
-            let o = (((err - dx + dy).abs() as f32) / ed - wd + 1.0).max(0.0);
-            blend_at(image, x0 as u32, y0 as u32, 1.0 - o, color);
+        // Skip steps along the major axis that can't draw inside of the image
+        let run = major_run(err, dx, dy);
+        let skip = if dx > dy {
+            hidden_steps(run, (x0, x1, sx), y0, near_x, near_y)
+        } else {
+            hidden_steps(run, (y0, y1, sy), x0, near_y, near_x)
+        };
+        if skip > 0 {
+            if dx > dy {
+                x0 += sx * skip;
+                err -= dy * skip;
+            } else {
+                y0 += sy * skip;
+                err += dx * skip;
+            }
         }
+
+        blend(image, x0, y0, err - dx + dy);
         let mut e2 = err;
-        let mut x2 = x0;
+        let x2 = x0;
         if 2 * e2 >= -dx {
             // x step
-            e2 += dy;
-            let mut y2 = y0;
-            while (e2 as f32) < ed * wd && (y1 != y2 || dx > dy) {
-                y2 += sy;
-                let o = (e2.abs() as f32 / ed - wd + 1.0).max(0.0);
-                blend_at(image, x0 as u32, y2 as u32, 1.0 - o, color);
-                e2 += dx;
-            }
+            let last = if dx > dy { i64::MAX } else { (y1 - y0).abs() };
+            sweep(image, (x0, y0), (0, sy), last, e2 + dy, dx);
             if x0 == x1 {
                 break;
             }
@@ -80,13 +143,8 @@ pub fn antialiased_line<P, T>(
         }
         if 2 * e2 <= dy {
             // y step
-            e2 = dx - e2;
-            while (e2 as f32) < (ed * wd) && (x1 != x2 || dx < dy) {
-                x2 += sx;
-                let o = (e2.abs() as f32 / ed - wd + 1.0).max(0.0);
-                blend_at(image, x2 as u32, y0 as u32, 1.0 - o, color);
-                e2 += dy;
-            }
+            let last = if dx < dy { i64::MAX } else { (x1 - x2).abs() };
+            sweep(image, (x2, y0), (sx, 0), last, dx - e2, dy);
             if y0 == y1 {
                 break;
             }
@@ -96,6 +154,273 @@ pub fn antialiased_line<P, T>(
     }
 }
 
+/// Returns the area around an image that a thick line has to be followed
+/// through to draw everything inside of the image.
+///
+/// Steps along the major axis draw a few pixels across the line, within
+/// `margin`.  Steps along the minor axis draw a long sweep ahead along the
+/// major axis, which is shorter the more the line moves along the minor axis.
+fn reach_rect(
+    (dx, dy): (i64, i64),
+    limit: f64,
+    margin: i64,
+    (width, height): (u32, u32),
+) -> crate::shapes::Rect {
+    let minor = dx.min(dy);
+    let reach = if minor == 0 {
+        0
+    } else {
+        (limit / minor as f64 + 2.0).min(f64::from(1 << 30)) as i64
+    };
+    let (mx, my) = if dx > dy {
+        (margin + reach, margin)
+    } else {
+        (margin, margin + reach)
+    };
+    let grow = |n: u32, m: i64| (i64::from(n) + 2 * m).min(u32::MAX.into()) as u32;
+    crate::shapes::Rect::new((-mx as i32, -my as i32), grow(height, my), grow(width, mx))
+}
+
+/// Returns the range of pixels `j` (counting from 1) where `pos + step * j`
+/// is inside of `0..len`.  The range is empty if `first > last`.
+fn sweep_inside(pos: i64, step: i64, len: u32) -> (i64, i64) {
+    let last = i64::from(len) - 1;
+    let (first, last) = match step.cmp(&0) {
+        std::cmp::Ordering::Greater => (-pos, last - pos),
+        std::cmp::Ordering::Less => (pos - last, pos),
+        std::cmp::Ordering::Equal if (0..=last).contains(&pos) => (1, i64::MAX),
+        std::cmp::Ordering::Equal => (1, 0),
+    };
+    (first.max(1), last)
+}
+
+/// Returns how many of the next steps of a thick line with the given error
+/// only move along its major axis.
+fn major_run(err: i64, dx: i64, dy: i64) -> i64 {
+    if dx > dy {
+        match dy {
+            0 => i64::MAX,
+            _ if 2 * err > dy => (2 * err + dy - 1) / (2 * dy),
+            _ => 0,
+        }
+    } else {
+        match dx {
+            0 => i64::MAX,
+            _ if 2 * err < -dx => (dx - 2 * err - 1) / (2 * dx),
+            _ => 0,
+        }
+    }
+}
+
+/// Returns how many of the next `run` steps along the major axis of a thick
+/// line can be skipped because they only draw outside of the image.
+///
+/// `major` is the current position, the end of the line, and the direction
+/// of each step.  `near_major` and `near_minor` are the coordinates that can
+/// draw inside of the image.
+fn hidden_steps(
+    run: i64,
+    (pos, end, step): (i64, i64, i64),
+    minor: i64,
+    near_major: (i64, i64),
+    near_minor: (i64, i64),
+) -> i64 {
+    let run = run.min((end - pos).abs());
+    if minor < near_minor.0 || minor > near_minor.1 {
+        return run;
+    }
+    let (before, past) = if step > 0 {
+        (near_major.0 - pos, pos > near_major.1)
+    } else {
+        (pos - near_major.1, pos < near_major.0)
+    };
+    if past {
+        run
+    } else {
+        run.min(before.max(0))
+    }
+}
+
+/// Draws a single pixel wide antialiased line using Xiaolin Wu's algorithm.
+///
+/// Each step along the line splits the color between the two pixels closest
+/// to the line, which is much faster than the general [`antialiased_line`].
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::antialiased_line_1px;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// antialiased_line_1px(&mut image, (0, 0), (399, 200), Rgba([255, 0, 0, 255]));
+/// ```
+// https://en.wikipedia.org/wiki/Xiaolin_Wu%27s_line_algorithm
+pub fn antialiased_line_1px<P, T>(image: &mut image::RgbaImage, a: P, b: P, color: image::Rgba<u8>)
+where
+    P: Point<T>,
+    T: Into<i32> + Copy,
+{
+    let (mut x0, mut y0) = (a.x().into(), a.y().into());
+    let (mut x1, mut y1) = (b.x().into(), b.y().into());
+
+    // Step along the longer axis, swapping x and y for steep lines
+    let steep = (i64::from(y1) - i64::from(y0)).abs() > (i64::from(x1) - i64::from(x0)).abs();
+    if steep {
+        std::mem::swap(&mut x0, &mut y0);
+        std::mem::swap(&mut x1, &mut y1);
+    }
+    if x0 > x1 {
+        std::mem::swap(&mut x0, &mut x1);
+        std::mem::swap(&mut y0, &mut y1);
+    }
+
+    let (columns, rows) = if steep {
+        (image.height(), image.width())
+    } else {
+        (image.width(), image.height())
+    };
+
+    let mut plot = |x: i32, y: i64, coverage: f32| {
+        let (x, y) = if steep {
+            (y, i64::from(x))
+        } else {
+            (i64::from(x), y)
+        };
+        if let (Ok(x), Ok(y)) = (u32::try_from(x), u32::try_from(y)) {
+            if coverage > 0.0 {
+                blend_at(image, x, y, coverage, color);
+            }
+        }
+    };
+
+    let dx = f64::from(x1) - f64::from(x0);
+    let gradient = if dx == 0.0 {
+        0.0
+    } else {
+        (f64::from(y1) - f64::from(y0)) / dx
+    };
+
+    // The end points are on pixel centers and are fully covered
+    plot(x0, y0.into(), 1.0);
+    if x1 != x0 {
+        plot(x1, y1.into(), 1.0);
+    }
+
+    // Only the columns between the end points that can reach the image are
+    // stepped through
+    let mut first = (i64::from(x0) + 1).max(0);
+    let mut last = (i64::from(x1) - 1).min(i64::from(columns) - 1);
+    if gradient != 0.0 {
+        let column = |y: f64| f64::from(x0) + (y - f64::from(y0)) / gradient;
+        let (a, b) = (column(-1.0), column(f64::from(rows)));
+        first = first.max(a.min(b).floor() as i64 - 1);
+        last = last.min(a.max(b).ceil() as i64 + 1);
+    }
+
+    for x in first..=last {
+        let intery = f64::from(y0) + gradient * (x - i64::from(x0)) as f64;
+        let y = intery.floor();
+        let fract = (intery - y) as f32;
+        plot(x as i32, y as i64, 1.0 - fract);
+        plot(x as i32, y as i64 + 1, fract);
+    }
+}
+
+/// How the ends of a thick line are drawn.  See [`antialiased_line_capped`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LineCap {
+    /// The line ends flat at its end points
+    Butt,
+    /// The line ends flat, extended past its end points by half of its width
+    Square,
+    /// The line ends with a half circle centered on each end point
+    Round,
+}
+
+impl Default for LineCap {
+    fn default() -> Self {
+        Self::Butt
+    }
+}
+
+/// Draws an antialiased line of the specified thickness, with antialiased
+/// ends in the specified [`LineCap`] style.
+///
+/// Unlike [`antialiased_line`], whose ends are cut off without antialiasing,
+/// the coverage of every pixel is calculated from its distance to the edge of
+/// the line (including the caps), so thick lines look finished.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{antialiased_line_capped, LineCap};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// antialiased_line_capped(&mut image, (20, 20), (380, 200), 12.0, LineCap::Round, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_line_capped`](crate::Draw::antialiased_line_capped)
+///
+pub fn antialiased_line_capped<P, T>(
+    image: &mut image::RgbaImage,
+    a: P,
+    b: P,
+    wd: f32,
+    cap: LineCap,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<i32> + Copy,
+{
+    let a = Pt::new(f64::from(a.x().into()), f64::from(a.y().into()));
+    let b = Pt::new(f64::from(b.x().into()), f64::from(b.y().into()));
+    let hw = f64::from(wd.max(0.0)) / 2.0;
+
+    let frame = Frame::new(a, b);
+    // Round caps are added by the distance calculation instead
+    let half_len = match cap {
+        LineCap::Square => frame.len / 2.0 + hw,
+        LineCap::Butt | LineCap::Round => frame.len / 2.0,
+    };
+
+    // Signed distance from a point to the edge of the line, negative inside
+    let distance = |x: f64, y: f64| {
+        let (along, across) = frame.local(Pt::new(x, y));
+        let along = (along - frame.len / 2.0).abs() - half_len;
+        let across = across.abs();
+        match cap {
+            LineCap::Round => along.max(0.0).hypot(across) - hw,
+            LineCap::Butt | LineCap::Square => {
+                let across = across - hw;
+                along.max(0.0).hypot(across.max(0.0)) + along.max(across).min(0.0)
+            }
+        }
+    };
+
+    // Every pixel touched by the line, including the caps and antialiasing
+    let reach = hw + 1.0;
+    let (w, h) = (f64::from(image.width()), f64::from(image.height()));
+    let x0 = (a.x().min(b.x()) - reach).floor().max(0.0);
+    let y0 = (a.y().min(b.y()) - reach).floor().max(0.0);
+    let x1 = (a.x().max(b.x()) + reach).ceil().min(w - 1.0);
+    let y1 = (a.y().max(b.y()) + reach).ceil().min(h - 1.0);
+    if x0 > x1 || y0 > y1 {
+        return;
+    }
+
+    for y in y0 as u32..=y1 as u32 {
+        for x in x0 as u32..=x1 as u32 {
+            let coverage = (0.5 - distance(f64::from(x), f64::from(y))).clamp(0.0, 1.0);
+            if coverage > 0.0 {
+                blend_at(image, x, y, coverage as f32, color);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -110,4 +435,152 @@ mod tests {
         );
         image.save("images/thick_aa_line.png")
     }
+
+    #[test]
+    fn clipped_thick_aa_lines() {
+        use image::GenericImageView;
+
+        let red = image::Rgba([255, 0, 0, 255]);
+        let lines = [
+            ((10, 20), (190, 150)),
+            ((180, 10), (30, 190)),
+            ((100, 10), (110, 190)),
+            ((10, 100), (190, 100)),
+            ((15, 15), (185, 185)),
+            ((80, 90), (95, 100)),
+            ((100, 10), (103, 190)),
+            ((190, 102), (10, 100)),
+            ((120, 185), (117, 12)),
+        ];
+        for (a, b) in lines {
+            for wd in [1.5, 4.0, 9.5] {
+                // The whole line is inside of the larger image
+                let mut full = crate::test::img::blank((200, 200));
+                super::antialiased_line(&mut full, a, b, wd, red);
+
+                for (ox, oy) in [(70, 70), (95, 40), (20, 130)] {
+                    let shift = |(x, y): (i32, i32)| (x - ox, y - oy);
+                    let mut image = crate::test::img::blank((60, 50));
+                    super::antialiased_line(&mut image, shift(a), shift(b), wd, red);
+                    let expected = full.view(ox as u32, oy as u32, 60, 50).to_image();
+                    assert_eq!(image, expected, "{a:?} {b:?} {wd} ({ox}, {oy})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_thick_aa_lines() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let bg = image::Rgba([255, 255, 255, 255]);
+
+        let mut image = crate::test::img::blank((20, 20));
+        super::antialiased_line(&mut image, (i32::MIN, 10), (i32::MAX, 10), 3.0, red);
+        for x in 0..20 {
+            assert_eq!(*image.get_pixel(x, 10), red, "{x}");
+            assert_eq!(*image.get_pixel(x, 0), bg, "{x}");
+        }
+
+        let mut image = crate::test::img::blank((20, 20));
+        super::antialiased_line(
+            &mut image,
+            (i32::MIN, i32::MIN),
+            (i32::MAX, i32::MAX),
+            3.0,
+            red,
+        );
+        assert_eq!(*image.get_pixel(5, 5), red);
+        assert_eq!(*image.get_pixel(15, 2), bg);
+
+        // Nearly horizontal and vertical lines
+        let mut image = crate::test::img::blank((20, 20));
+        super::antialiased_line(&mut image, (i32::MIN, 9), (i32::MAX, 11), 3.0, red);
+        super::antialiased_line(&mut image, (9, i32::MAX), (11, i32::MIN), 3.0, red);
+        assert_eq!(*image.get_pixel(2, 10), red);
+        assert_eq!(*image.get_pixel(10, 17), red);
+        assert_eq!(*image.get_pixel(2, 2), bg);
+
+        // Lines that only pass near the image
+        let mut image = crate::test::img::blank((20, 20));
+        super::antialiased_line(&mut image, (i32::MIN, -50), (i32::MAX, -50), 3.0, red);
+        assert!(image.pixels().all(|&p| p == bg));
+    }
+
+    #[test]
+    fn aa_line_1px() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((20, 20));
+        super::antialiased_line_1px(&mut image, (2, 2), (6, 4), red);
+
+        let px = |x, y| image.get_pixel(x, y).0[1];
+        assert_eq!(px(2, 2), 0);
+        assert_eq!(px(6, 4), 0);
+        // Half way between two rows each pixel gets half of the color
+        assert_eq!(px(3, 2), px(3, 3));
+        assert_eq!(px(4, 3), 0);
+        assert_eq!(px(4, 2), 255);
+
+        // Steep lines and reversed end points are drawn the same way
+        let mut steep = crate::test::img::blank((20, 20));
+        super::antialiased_line_1px(&mut steep, (4, 6), (2, 2), red);
+        for (x, y, p) in steep.enumerate_pixels() {
+            assert_eq!(*p, *image.get_pixel(y, x), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn aa_line_1px_clips() {
+        let mut image = crate::test::img::blank((10, 10));
+        super::antialiased_line_1px(&mut image, (-5, -5), (20, 15), image::Rgba([0, 0, 0, 255]));
+        super::antialiased_line_1px(
+            &mut image,
+            (i32::MAX, 0),
+            (i32::MAX, 3),
+            image::Rgba([0, 0, 0, 255]),
+        );
+        // The line passes through (5, 3)
+        assert!(image.get_pixel(5, 3).0[0] < 5);
+
+        // Lines longer than an i32 can step are clipped to the image first
+        let mut image = crate::test::img::blank((10, 10));
+        let black = image::Rgba([0, 0, 0, 255]);
+        super::antialiased_line_1px(&mut image, (i32::MIN, 4), (i32::MAX, 5), black);
+        super::antialiased_line_1px(&mut image, (7, i32::MAX), (8, i32::MIN), black);
+        assert!(image.get_pixel(2, 4).0[0] < 200 && image.get_pixel(2, 5).0[0] < 200);
+        assert!(image.get_pixel(7, 2).0[0] < 255 && image.get_pixel(8, 2).0[0] < 255);
+    }
+
+    #[test]
+    fn line_caps() {
+        use super::LineCap;
+        let red = image::Rgba([255, 0, 0, 255]);
+        let draw = |cap| {
+            let mut image = crate::test::img::blank((40, 20));
+            super::antialiased_line_capped(&mut image, (10, 10), (30, 10), 6.0, cap, red);
+            image
+        };
+        let (butt, square, round) = (
+            draw(LineCap::Butt),
+            draw(LineCap::Square),
+            draw(LineCap::Round),
+        );
+
+        // The middle of the line is solid for every cap
+        for image in [&butt, &square, &round] {
+            assert_eq!(*image.get_pixel(20, 10), red);
+            assert_eq!(*image.get_pixel(20, 12), red);
+            assert_eq!(*image.get_pixel(20, 14), image::Rgba([255, 255, 255, 255]));
+        }
+
+        // Butt caps end half way through the end points
+        assert_eq!(*butt.get_pixel(9, 10), image::Rgba([255, 255, 255, 255]));
+        assert_eq!(*butt.get_pixel(10, 10), image::Rgba([255, 127, 127, 255]));
+        // Square caps extend by half of the width, with square corners
+        assert_eq!(*square.get_pixel(8, 12), red);
+        assert_eq!(*square.get_pixel(6, 10), image::Rgba([255, 255, 255, 255]));
+        // Round caps reach as far as square caps, but not in the corners
+        assert_eq!(*round.get_pixel(8, 10), red);
+        assert_ne!(*round.get_pixel(8, 12), red);
+        assert_eq!(*round.get_pixel(6, 10), image::Rgba([255, 255, 255, 255]));
+    }
 }
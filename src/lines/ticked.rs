@@ -0,0 +1,247 @@
+use crate::frame::Frame;
+use crate::pt::{Point, Pt};
+use image::GenericImage;
+
+/// Which side of a line its ticks are drawn on.
+///
+/// With y increasing downwards, the left of a line going right is above it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TickSide {
+    /// Ticks cross the line, centered on it (like a railroad track)
+    Both,
+    /// Ticks stick out to the left of the line
+    Left,
+    /// Ticks stick out to the right of the line
+    Right,
+}
+
+impl Default for TickSide {
+    fn default() -> Self {
+        Self::Both
+    }
+}
+
+/// Draws a straight line with short ticks across it at regular intervals,
+/// like a railroad track or a boundary on a map.
+///
+/// A tick is drawn every `spacing` pixels along the line, starting half of the
+/// spacing from `a`, and each tick is `length` pixels long.  Anything outside
+/// of the image is clipped.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{ticked_line, TickSide};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// ticked_line(&mut image, (20, 200), (380, 60), 10.0, 8.0, TickSide::Both, Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`ticked_path`], [`Draw::ticked_line`](crate::Draw::ticked_line)
+///
+pub fn ticked_line<I, P>(
+    image: &mut I,
+    a: P,
+    b: P,
+    spacing: f64,
+    length: f64,
+    side: TickSide,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    ticked_path(image, [a.pt(), b.pt()], spacing, length, side, color);
+}
+
+/// Draws a path from one point to the next with short ticks across it at
+/// regular intervals.  See [`ticked_line`].
+///
+/// The spacing carries on around each corner, so the ticks stay evenly spaced
+/// along the whole path.  A spacing of 0 (or less) draws the path without
+/// ticks, and a spacing under 1 pixel is drawn as 1 pixel.  The start and end
+/// points are not connected.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{ticked_path, TickSide};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// let border = [(20, 20), (380, 40), (360, 380)];
+/// ticked_path(&mut image, border, 12.0, 6.0, TickSide::Right, Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::ticked_path`](crate::Draw::ticked_path)
+///
+pub fn ticked_path<I, P, It>(
+    image: &mut I,
+    points: It,
+    spacing: f64,
+    length: f64,
+    side: TickSide,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let points: Vec<Pt<i32>> = points.into_iter().map(|p| p.pt()).collect();
+    super::path(image, points.iter().copied(), color);
+    if spacing <= 0.0 {
+        return;
+    }
+    let spacing = spacing.max(1.0);
+
+    let (from, to) = match side {
+        TickSide::Both => (-length / 2.0, length / 2.0),
+        TickSide::Left => (0.0, length),
+        TickSide::Right => (-length, 0.0),
+    };
+
+    // Distance along the current segment to the next tick
+    let mut next = spacing / 2.0;
+    for pair in points.windows(2) {
+        let frame = Frame::new(pair[0].into(), pair[1].into());
+        let count = if next <= frame.len {
+            ((frame.len - next) / spacing).floor() + 1.0
+        } else {
+            0.0
+        };
+
+        // Only the ticks that can reach the image are drawn
+        let visible = super::wave::visible_range(image, &frame, pair[0], pair[1], length.abs());
+        if let Some((start, end)) = visible {
+            let first = ((start - next) / spacing).ceil().max(0.0) as i64;
+            let last = ((end - next) / spacing).floor().min(count - 1.0) as i64;
+            for i in first..=last {
+                let along = next + i as f64 * spacing;
+                let a = frame.point(along, from).i32();
+                let b = frame.point(along, to).i32();
+                super::line(image, a, b, color);
+            }
+        }
+        next += count * spacing - frame.len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn tick_positions() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = image::RgbaImage::new(50, 20);
+        ticked_line(
+            &mut image,
+            (0, 10),
+            (40, 10),
+            10.0,
+            6.0,
+            TickSide::Both,
+            color,
+        );
+
+        for x in 0..50 {
+            let tick = [5, 15, 25, 35].contains(&x);
+            assert_eq!(image.get_pixel(x, 7) == &color, tick, "{x}");
+            assert_eq!(image.get_pixel(x, 13) == &color, tick, "{x}");
+            assert_eq!(image.get_pixel(x, 10) == &color, x <= 40, "{x}");
+        }
+    }
+
+    #[test]
+    fn ticks_on_one_side() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut left = image::RgbaImage::new(30, 30);
+        ticked_line(
+            &mut left,
+            (0, 15),
+            (20, 15),
+            10.0,
+            4.0,
+            TickSide::Left,
+            color,
+        );
+        assert_eq!(left.get_pixel(5, 11), &color);
+        assert_ne!(left.get_pixel(5, 16), &color);
+
+        let mut right = image::RgbaImage::new(30, 30);
+        ticked_line(
+            &mut right,
+            (0, 15),
+            (20, 15),
+            10.0,
+            4.0,
+            TickSide::Right,
+            color,
+        );
+        assert_eq!(right.get_pixel(5, 19), &color);
+        assert_ne!(right.get_pixel(5, 14), &color);
+    }
+
+    #[test]
+    fn spacing_carries_around_corners() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = image::RgbaImage::new(40, 40);
+        ticked_path(
+            &mut image,
+            [(0, 5), (12, 5), (12, 35)],
+            10.0,
+            4.0,
+            TickSide::Both,
+            color,
+        );
+
+        // Ticks at 5 along the first segment, then 15 and 25 (3 and 13 down the second)
+        assert_eq!(image.get_pixel(5, 3), &color);
+        assert_eq!(image.get_pixel(10, 8), &color);
+        assert_eq!(image.get_pixel(14, 18), &color);
+        assert_ne!(image.get_pixel(14, 15), &color);
+    }
+
+    #[test]
+    fn clipped_ticks_match_unclipped() {
+        let color = Rgba([255, 0, 0, 255]);
+        let path = [(5, 100), (150, 20), (190, 180), (20, 170)];
+        let mut full = image::RgbaImage::new(200, 200);
+        ticked_path(&mut full, path, 7.0, 9.0, TickSide::Both, color);
+        for (x, y) in [(0, 0), (140, 10), (100, 140), (60, 60)] {
+            let mut image = image::RgbaImage::new(50, 50);
+            let shifted = path.map(|(px, py)| (px - x, py - y));
+            ticked_path(&mut image, shifted, 7.0, 9.0, TickSide::Both, color);
+            let view = image::imageops::crop_imm(&full, x as u32, y as u32, 50, 50);
+            assert_eq!(image, view.to_image(), "viewed from ({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn tiny_spacing_and_long_lines() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut tiny = image::RgbaImage::new(30, 30);
+        ticked_line(
+            &mut tiny,
+            (0, 15),
+            (20, 15),
+            0.001,
+            4.0,
+            TickSide::Left,
+            color,
+        );
+        let mut one = image::RgbaImage::new(30, 30);
+        ticked_line(&mut one, (0, 15), (20, 15), 1.0, 4.0, TickSide::Left, color);
+        assert_eq!(tiny, one);
+
+        // Only the ticks that reach the image are drawn.  They start half a
+        // pixel along, so they round away from column 0.
+        let mut image = image::RgbaImage::new(30, 30);
+        let (a, b) = ((i32::MIN, 15), (i32::MAX, 15));
+        ticked_line(&mut image, a, b, 1.0, 4.0, TickSide::Left, color);
+        assert!((1..30).all(|x| image.get_pixel(x, 12) == &color));
+    }
+}
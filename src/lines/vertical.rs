@@ -2,12 +2,16 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::many_single_char_names)]
 
+use super::clip::clip_span;
 use crate::ops::blend_at_unchecked;
 use crate::pt::Point;
 use image::{GenericImage, Rgba, RgbaImage};
 
 /// Draws a straight vertical line.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Example
 ///
 /// ```
@@ -21,15 +25,22 @@ use image::{GenericImage, Rgba, RgbaImage};
 /// /// Vertical line across the center of the image
 /// vertical_line(&mut image, (200, 0), 399, color);
 /// ```
-pub fn vertical_line<I, P>(image: &mut I, pt: P, y2: u32, color: I::Pixel)
+pub fn vertical_line<I, P>(image: &mut I, pt: P, y2: i32, color: I::Pixel)
 where
     I: GenericImage,
-    P: Point<u32>,
+    P: Point<i32>,
 {
-    if pt.x() < image.width() {
-        (pt.y().min(image.height() - 1)..=y2.min(image.height() - 1))
-            // This is safe due to the min() calls above
-            .for_each(|y| unsafe { image.unsafe_put_pixel(pt.x(), y, color) });
+    let (x, y) = pt.tuple();
+    if x.is_negative() || x as u32 >= image.width() {
+        return;
+    }
+
+    if let Some((y1, y2, _)) = clip_span(y, y2) {
+        let x = x as u32;
+        // Spans that start past the edge of the image are empty
+        (y1..(y2 + 1).min(image.height()))
+            // This is safe due to the min() call above
+            .for_each(|y| unsafe { image.unsafe_put_pixel(x, y, color) });
     }
 }
 
@@ -37,6 +48,9 @@ where
 ///
 /// A `width` of 0 will draw a solid vertical line.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Example
 ///
 ///
@@ -51,10 +65,10 @@ where
 /// /// Vertical dashed line across the center of the image with a 2px dash
 /// vertical_dashed_line(&mut image, (200, 0), 399, 2, color);
 /// ```
-pub fn vertical_dashed_line<I, P>(image: &mut I, pt: P, mut y2: u32, width: u32, color: I::Pixel)
+pub fn vertical_dashed_line<I, P>(image: &mut I, pt: P, mut y2: i32, width: u32, color: I::Pixel)
 where
     I: GenericImage,
-    P: Point<u32>,
+    P: Point<i32>,
 {
     if width == 0 {
         crate::lines::vertical_line(image, pt, y2, color);
@@ -67,21 +81,26 @@ where
         std::mem::swap(&mut y0, &mut y2);
     }
 
-    if x >= image.width() || (y0 >= image.height()) {
+    let (y0, y2, skipped) = match clip_span(y0, y2) {
+        Some(span) => span,
+        None => return,
+    };
+
+    if x.is_negative() || x as u32 >= image.width() || y0 >= image.height() {
         return;
     }
 
+    let x = x as u32;
     let y1 = y2.min(image.height() - 1);
-    let mut y = y0.min(image.height() - 1);
-    let mut i = 0;
-
-    while y <= y1 {
-        // This is safe due to the min calls above
-        unsafe {
-            image.unsafe_put_pixel(x, y, color);
+    let dash = u64::from(width);
+
+    for y in y0..=y1 {
+        if (u64::from(y - y0) + u64::from(skipped)) % (dash * 2) < dash {
+            // This is safe due to the min calls above
+            unsafe {
+                image.unsafe_put_pixel(x, y, color);
+            }
         }
-        y = if i == width - 1 { y + width + 1 } else { y + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -89,6 +108,9 @@ where
 ///
 /// Opacity should be in the range `0..=1`.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Panics
 ///
 /// Panics if opacity is not in the range `0.0..=1.0`
@@ -106,16 +128,23 @@ where
 /// /// Vertical line across the center of the image with 50% opacity
 /// vertical_line_alpha(&mut image, (200, 0), 399, 0.5, color);
 /// ```
-pub fn vertical_line_alpha<P>(image: &mut RgbaImage, pt: P, y2: u32, opacity: f32, color: Rgba<u8>)
+pub fn vertical_line_alpha<P>(image: &mut RgbaImage, pt: P, y2: i32, opacity: f32, color: Rgba<u8>)
 where
-    P: Point<u32>,
+    P: Point<i32>,
 {
     check_opacity!(opacity);
 
-    if pt.x() < image.width() {
-        (pt.y().min(image.height() - 1)..=y2.min(image.height() - 1))
-            // This is safe due to the min() calls above
-            .for_each(|y| unsafe { blend_at_unchecked(image, pt.x(), y, opacity, color) });
+    let (x, y) = pt.tuple();
+    if x.is_negative() || x as u32 >= image.width() {
+        return;
+    }
+
+    if let Some((y1, y2, _)) = clip_span(y, y2) {
+        let x = x as u32;
+        // Spans that start past the edge of the image are empty
+        (y1..(y2 + 1).min(image.height()))
+            // This is safe due to the min() call above
+            .for_each(|y| unsafe { blend_at_unchecked(image, x, y, opacity, color) });
     }
 }
 
@@ -125,6 +154,9 @@ where
 ///
 /// A `width` of 0 will draw a solid vertical line.
 ///
+/// Negative coordinates are supported; only the part of the line inside the
+/// image is drawn.
+///
 /// # Panics
 ///
 /// Panics if opacity is not in the range `0.0..=1.0`
@@ -145,12 +177,12 @@ where
 pub fn vertical_dashed_line_alpha<P>(
     image: &mut RgbaImage,
     pt: P,
-    mut y2: u32,
+    mut y2: i32,
     width: u32,
     opacity: f32,
     color: Rgba<u8>,
 ) where
-    P: Point<u32>,
+    P: Point<i32>,
 {
     check_opacity!(opacity);
 
@@ -165,21 +197,27 @@ pub fn vertical_dashed_line_alpha<P>(
         std::mem::swap(&mut y1, &mut y2);
     }
 
-    if x >= image.width() || (y1 >= image.height()) {
+    let (y1, y2, skipped) = match clip_span(y1, y2) {
+        Some(span) => span,
+        None => return,
+    };
+
+    if x.is_negative() || x as u32 >= image.width() || (y1 >= image.height()) {
         return;
     }
 
+    let x = x as u32;
     let y2 = y2.min(image.height() - 1);
-    let mut y = y1.min(image.height() - 1);
-    let mut i = 0;
-    while y <= y2 {
-        let (r, g, b) = (color[0], color[1], color[2]);
-        // This is safe due to the min calls above
-        unsafe {
-            blend_at_unchecked(image, x, y, opacity, Rgba([r, g, b, 255]));
+    let dash = u64::from(width);
+    let (r, g, b) = (color[0], color[1], color[2]);
+
+    for y in y1..=y2 {
+        if (u64::from(y - y1) + u64::from(skipped)) % (dash * 2) < dash {
+            // This is safe due to the min calls above
+            unsafe {
+                blend_at_unchecked(image, x, y, opacity, Rgba([r, g, b, 255]));
+            }
         }
-        y = if i == width - 1 { y + width + 1 } else { y + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -202,6 +240,12 @@ mod tests {
             3,
             &*vec![]
         );
+        test_pixels_changed!(
+            vertical_line_negative,
+            vertical_line((1, -5), 1),
+            3,
+            &*vec![(1, 0), (1, 1)]
+        );
     }
 
     mod vertical_dashed_line {
@@ -236,6 +280,12 @@ mod tests {
             6,
             &*vec![]
         );
+        test_pixels_changed!(
+            vertical_dashed_line_negative,
+            vertical_dashed_line((0, -1), 10, 2),
+            6,
+            &*vec![(0, 0), (0, 3), (0, 4)]
+        );
     }
 
     mod vertical_line_alpha {
@@ -0,0 +1,310 @@
+use crate::frame::Frame;
+use crate::pt::{Point, Pt};
+use crate::shapes::Rect;
+use image::GenericImage;
+
+/// Draws a zigzag line between two points, like a squiggly underline.
+///
+/// The zigzag starts and ends on the straight line from `a` to `b`, and swings
+/// `amplitude` pixels to either side of it once every `wavelength` pixels.  A
+/// wavelength or amplitude of 0 (or less) draws a straight line, and
+/// wavelengths shorter than a pixel are drawn as one pixel long.  Anything
+/// outside of the image is clipped.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::zigzag_line;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// zigzag_line(&mut image, (20, 100), (380, 100), 3.0, 8.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`wavy_line`], [`Draw::zigzag_line`](crate::Draw::zigzag_line)
+///
+pub fn zigzag_line<I, P>(
+    image: &mut I,
+    a: P,
+    b: P,
+    amplitude: f64,
+    wavelength: f64,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    let (a, b) = (a.pt(), b.pt());
+    let frame = Frame::new(a.into(), b.into());
+    let len = frame.len;
+    if amplitude <= 0.0 || wavelength <= 0.0 {
+        super::line(image, a, b, color);
+        return;
+    }
+
+    let (start, end) = match visible_range(image, &frame, a, b, amplitude) {
+        Some(range) => range,
+        None => return,
+    };
+
+    // Peaks closer together than a pixel can't be told apart
+    let wavelength = wavelength.max(1.0);
+    // The corners are both ends, and the peaks a quarter of a wavelength
+    // after each crossing
+    let (half, quarter) = (wavelength / 2.0, wavelength / 4.0);
+    let peaks = ((len - quarter) / half).ceil().max(0.0) as usize;
+    let corner = |k: usize| match k {
+        0 => 0.0,
+        k if k <= peaks => ((k - 1) as f64).mul_add(half, quarter),
+        _ => len,
+    };
+
+    // Only the corners around the visible part of the line are calculated
+    let first = if start < quarter {
+        0
+    } else {
+        (((start - quarter) / half).floor() as usize + 1).min(peaks)
+    };
+    let last = (((end - quarter) / half).ceil().max(0.0) as usize + 1).min(peaks + 1);
+
+    let points = (first..=last).map(|k| {
+        let s = corner(k);
+        frame.point(s, triangle(s / wavelength) * amplitude).i32()
+    });
+    draw_path(image, points, color);
+}
+
+/// Draws a wavy (sine wave) line between two points, like a squiggly
+/// underline.
+///
+/// The wave starts and ends on the straight line from `a` to `b`, and swings
+/// `amplitude` pixels to either side of it once every `wavelength` pixels.  A
+/// wavelength or amplitude of 0 (or less) draws a straight line.  Anything
+/// outside of the image is clipped.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::wavy_line;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// wavy_line(&mut image, (20, 100), (380, 300), 4.0, 20.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`zigzag_line`], [`Draw::wavy_line`](crate::Draw::wavy_line)
+///
+pub fn wavy_line<I, P>(image: &mut I, a: P, b: P, amplitude: f64, wavelength: f64, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    let (a, b) = (a.pt(), b.pt());
+    let frame = Frame::new(a.into(), b.into());
+    let len = frame.len;
+    if amplitude <= 0.0 || wavelength <= 0.0 {
+        super::line(image, a, b, color);
+        return;
+    }
+
+    let (start, end) = match visible_range(image, &frame, a, b, amplitude) {
+        Some(range) => range,
+        None => return,
+    };
+
+    // Short enough steps that the straight lines between samples follow the curve
+    let step = (wavelength / 16.0).clamp(0.5, 2.0);
+    let steps = (len / step).ceil() as usize;
+
+    // Only the samples around the visible part of the line are calculated
+    let first = (start / step).floor().max(0.0) as usize;
+    let last = ((end / step).ceil().max(0.0) as usize).min(steps);
+
+    let points = (first..=last).map(|i| {
+        let s = (i as f64 * step).min(len);
+        let wave = (s / wavelength * crate::PI2).sin();
+        frame.point(s, wave * amplitude).i32()
+    });
+    draw_path(image, points, color);
+}
+
+/// Returns how far along the line from `a` to `b` its visible part starts and
+/// ends, once the line is widened by `amplitude` on both sides, or `None` if
+/// none of it can reach the image.
+pub(super) fn visible_range<I>(
+    image: &I,
+    frame: &Frame,
+    a: Pt<i32>,
+    b: Pt<i32>,
+    amplitude: f64,
+) -> Option<(f64, f64)>
+where
+    I: GenericImage,
+{
+    let margin = (amplitude.ceil() + 1.0).min(f64::from(i32::MAX / 2)) as i32;
+    let grow = |n: u32| n.saturating_add(2 * margin as u32);
+    let bounds = Rect::new(
+        (-margin, -margin),
+        grow(image.height()),
+        grow(image.width()),
+    );
+
+    let (first, last) = super::clip_line(a, b, bounds)?;
+    // The pixels of the line can be up to a pixel away from the exact line
+    let (s0, s1) = (frame.local(first.into()).0, frame.local(last.into()).0);
+    Some((s0.min(s1) - 1.0, s0.max(s1) + 1.0))
+}
+
+/// A triangle wave with a period of 1 that starts at 0, peaks at 1 a quarter
+/// of the way through, and bottoms out at -1 three quarters of the way through.
+fn triangle(t: f64) -> f64 {
+    let t = t.rem_euclid(1.0);
+    if t < 0.25 {
+        4.0 * t
+    } else if t < 0.75 {
+        2.0 - 4.0 * t
+    } else {
+        4.0 * t - 4.0
+    }
+}
+
+/// Draws straight lines from each point to the next, skipping repeated points
+/// so each pixel is only drawn once per segment.
+fn draw_path<I, It>(image: &mut I, points: It, color: I::Pixel)
+where
+    I: GenericImage,
+    It: Iterator<Item = Pt<i32>>,
+{
+    let mut prev = None;
+    for pt in points {
+        match prev {
+            Some(p) if p == pt => continue,
+            Some(p) => super::line(image, p, pt, color),
+            None => {}
+        }
+        prev = Some(pt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    /// Returns how far each drawn pixel is from the horizontal line at `y`
+    fn offsets(image: &image::RgbaImage, y: i64) -> Vec<i64> {
+        image
+            .enumerate_pixels()
+            .filter(|(_, _, p)| p.0[3] != 0)
+            .map(|(_, py, _)| i64::from(py) - y)
+            .collect()
+    }
+
+    #[test]
+    fn zigzag_peaks() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = image::RgbaImage::new(60, 20);
+        zigzag_line(&mut image, (10, 10), (50, 10), 4.0, 8.0, color);
+
+        let offsets = offsets(&image, 10);
+        assert_eq!(offsets.iter().min(), Some(&-4));
+        assert_eq!(offsets.iter().max(), Some(&4));
+        // Both ends are on the line, and the first peak is above it
+        assert_eq!(image.get_pixel(10, 10), &color);
+        assert_eq!(image.get_pixel(50, 10), &color);
+        assert_eq!(image.get_pixel(12, 6), &color);
+        assert_eq!(image.get_pixel(16, 14), &color);
+    }
+
+    #[test]
+    fn wave_follows_line() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut image = image::RgbaImage::new(60, 60);
+        // Straight down, so the wave swings left and right
+        wavy_line(&mut image, (30, 5), (30, 55), 3.0, 10.0, color);
+
+        for (x, _, p) in image.enumerate_pixels() {
+            if p.0[3] != 0 {
+                assert!((27..=33).contains(&x), "{x}");
+            }
+        }
+        assert_eq!(image.get_pixel(30, 5), &color);
+        assert_eq!(image.get_pixel(30, 55), &color);
+        // A quarter wavelength down the line, the wave is on the line's left
+        assert_eq!(image.get_pixel(33, 8), &color);
+    }
+
+    #[test]
+    fn flat_waves_are_lines() {
+        let color = Rgba([255, 0, 0, 255]);
+        let mut expected = image::RgbaImage::new(40, 40);
+        super::super::line(&mut expected, (2, 3), (35, 30), color);
+
+        let mut image = image::RgbaImage::new(40, 40);
+        wavy_line(&mut image, (2, 3), (35, 30), 0.0, 10.0, color);
+        zigzag_line(&mut image, (2, 3), (35, 30), 5.0, 0.0, color);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn clipped_waves_match_unclipped() {
+        let color = Rgba([255, 0, 0, 255]);
+        let lines = [
+            ((-300, 20), (400, 5)),
+            ((25, -500), (10, 600)),
+            ((-200, -150), (250, 180)),
+            ((10, 10), (35, 15)),
+            ((-100, 60), (100, 60)),
+        ];
+        for (a, b) in lines {
+            let (a, b) = (Pt::new(a.0, a.1), Pt::new(b.0, b.1));
+            let frame = Frame::new(a.into(), b.into());
+            let (amplitude, wavelength) = (6.0, 14.0);
+
+            // Every corner and sample of the whole line
+            let (half, quarter) = (wavelength / 2.0, wavelength / 4.0);
+            let peaks = ((frame.len - quarter) / half).ceil() as usize;
+            let corners = std::iter::once(0.0)
+                .chain((0..peaks).map(|i| (i as f64).mul_add(half, quarter)))
+                .chain(std::iter::once(frame.len))
+                .map(|s| frame.point(s, triangle(s / wavelength) * amplitude).i32());
+            let step = wavelength / 16.0;
+            let samples = (0..=(frame.len / step).ceil() as usize).map(|i| {
+                let s = (i as f64 * step).min(frame.len);
+                let wave = (s / wavelength * crate::PI2).sin();
+                frame.point(s, wave * amplitude).i32()
+            });
+
+            let mut expected = image::RgbaImage::new(40, 30);
+            draw_path(&mut expected, corners, color);
+            let mut image = image::RgbaImage::new(40, 30);
+            zigzag_line(&mut image, a, b, amplitude, wavelength, color);
+            assert_eq!(image, expected, "zigzag {a:?} {b:?}");
+
+            let mut expected = image::RgbaImage::new(40, 30);
+            draw_path(&mut expected, samples, color);
+            let mut image = image::RgbaImage::new(40, 30);
+            wavy_line(&mut image, a, b, amplitude, wavelength, color);
+            assert_eq!(image, expected, "wavy {a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn long_lines_are_clipped() {
+        let color = Rgba([255, 0, 0, 255]);
+        let (min, max) = (i32::MIN, i32::MAX);
+        let mut image = image::RgbaImage::new(40, 30);
+        wavy_line(&mut image, (min, 15), (max, 15), 4.0, 10.0, color);
+        zigzag_line(&mut image, (20, min), (20, max), 4.0, 10.0, color);
+        assert!(image.pixels().any(|p| *p == color));
+
+        let mut image = image::RgbaImage::new(40, 30);
+        wavy_line(&mut image, (min, -10), (max, -10), 4.0, 10.0, color);
+        zigzag_line(&mut image, (min, min), (max, min), 4.0, 1e-3, color);
+        assert!(image.pixels().all(|p| *p != color));
+
+        // Tiny wavelengths are limited to a pixel
+        zigzag_line(&mut image, (min, 15), (max, 15), 4.0, 1e-300, color);
+        assert_eq!(image.get_pixel(20, 11), &color);
+    }
+}
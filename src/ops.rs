@@ -1,5 +1,36 @@
 //! Helper functions for image operations
 
 mod blend;
+mod blend_fn;
+mod composite;
+mod diff;
+mod erase;
+mod mask;
+mod masked;
+mod mode;
+mod outline;
+mod record;
+mod region;
+mod rotate;
+mod stencil;
+mod supersample;
+mod warp;
 
-pub use blend::{blend_at, blend_at_unchecked};
+pub use blend::{
+    blend_at, blend_at_channels, blend_at_mode, blend_at_unchecked, blend_rect,
+    blend_rect_channels, blend_rect_mode, blend_row, blend_row_mode,
+};
+pub use blend_fn::BlendFn;
+pub use composite::composite;
+pub use diff::{diff, DiffStats};
+pub use erase::{eraser, Eraser};
+pub use mask::{rasterize_mask, rasterize_mask_into};
+pub use masked::{fill_masked, fill_masked_with};
+pub use mode::{AlphaMode, AlphaPolicy, Blend, BlendMode};
+pub use outline::trace_outline;
+pub use record::{Patch, Recorder};
+pub use region::{box_blur_region, invert_region, tint_region};
+pub use rotate::rotate_blit;
+pub use stencil::{Stencil, StencilOp, StencilTest, StencilWriter, Stenciled};
+pub use supersample::{supersample, Supersample};
+pub use warp::warp_blit;
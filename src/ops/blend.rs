@@ -1,12 +1,15 @@
 // These functions are exported publicly in a different module - keep the module prefix
 #![allow(clippy::module_name_repetitions)]
 
+use super::{Blend, BlendMode};
+
 /// Blend a specified color into an existing image coordinate.  This ignores `color`'s
 /// alpha value and instead uses `opacity` which is a floating point number from 0.0 to 1.0.
 ///
 /// The resulting color's alpha channel will ignore the specified color's alpha
-/// value and use `opacity` to blend the colors together.  The specified
-/// color's alpha value will only be used for the final alpha channel value.
+/// value and use `opacity` to blend the colors together.  The final
+/// alpha combines the existing alpha with `opacity`.  Use [`blend_at_mode`]
+/// with an [`AlphaPolicy`](super::AlphaPolicy) to write a different alpha.
 ///
 /// A few safety checks are skipped here for performance.
 ///
@@ -35,26 +38,221 @@ pub unsafe fn blend_at_unchecked(
     opacity: f32,
     color: image::Rgba<u8>,
 ) {
+    let i = crate::rgba_array_index(image.width(), x, y);
+    let bg = image.get_unchecked_mut(i..i + std::mem::size_of::<image::Rgba<u8>>());
+    blend_pixel(bg, source(color, opacity));
+}
+
+/// Blend a color into a horizontal run of pixels from `x0` to `x1` (inclusive)
+/// on row `y`, using `opacity` instead of `color`'s alpha value.
+///
+/// This works directly on the image's underlying buffer, which is much faster
+/// than calling [`blend_at`] for each pixel.  Coordinates may be negative or
+/// outside of the image; only the visible part of the row is blended.
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::blend_row;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::from_pixel(10, 10, Rgba([255, 255, 255, 255]));
+/// blend_row(&mut image, 2, -5, 5, 0.5, Rgba([255, 0, 0, 255]));
+/// assert_eq!(*image.get_pixel(5, 2), Rgba([255, 127, 127, 255]));
+/// ```
+pub fn blend_row(
+    image: &mut image::RgbaImage,
+    y: i32,
+    x0: i32,
+    x1: i32,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) {
+    blend_row_mode(image, y, x0, x1, opacity, color, BlendMode::SourceOver);
+}
+
+/// Blend a color into a horizontal run of pixels using the specified
+/// [`BlendMode`], or a [`Blend`] for premultiplied pixels.  See [`blend_row`].
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+pub fn blend_row_mode(
+    image: &mut image::RgbaImage,
+    y: i32,
+    x0: i32,
+    x1: i32,
+    opacity: f32,
+    color: image::Rgba<u8>,
+    mode: impl Into<Blend>,
+) {
+    check_opacity!(opacity);
+
+    let (x0, x1) = (x0.min(x1), x0.max(x1));
+    let mode = mode.into();
+    for_each_in_span(image, y, x0, x1, |bg| mode.blend_slice(bg, color, opacity));
+}
+
+/// Blend a color into every pixel inside a rectangle, using `opacity` instead
+/// of `color`'s alpha value.
+///
+/// Each row is blended directly on the image's underlying buffer.  Only the
+/// part of the rectangle inside the image is blended.
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::blend_rect;
+/// use freehand::shapes::Rect;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::from_pixel(10, 10, Rgba([255, 255, 255, 255]));
+/// blend_rect(&mut image, Rect::new((-2, -2), 5, 5), 0.5, Rgba([255, 0, 0, 255]));
+/// assert_eq!(*image.get_pixel(2, 2), Rgba([255, 127, 127, 255]));
+/// assert_eq!(*image.get_pixel(3, 3), Rgba([255, 255, 255, 255]));
+/// ```
+pub fn blend_rect(
+    image: &mut image::RgbaImage,
+    rect: crate::shapes::Rect,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) {
+    blend_rect_mode(image, rect, opacity, color, BlendMode::SourceOver);
+}
+
+/// Blend a color into every pixel inside a rectangle using the specified
+/// [`BlendMode`].  See [`blend_rect`].
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+pub fn blend_rect_mode(
+    image: &mut image::RgbaImage,
+    rect: crate::shapes::Rect,
+    opacity: f32,
+    color: image::Rgba<u8>,
+    mode: impl Into<Blend>,
+) {
+    check_opacity!(opacity);
+
+    let mode = mode.into();
+    for_each_in_rect(image, rect, |bg| mode.blend_slice(bg, color, opacity));
+}
+
+/// Blend a color into every pixel inside a rectangle using a separate opacity
+/// for each channel (red, green, blue, and alpha).  See [`Blend::blend_channels`].
+///
+/// # Panics
+///
+/// Panics if any opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::{blend_rect_channels, BlendMode};
+/// use freehand::shapes::Rect;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::from_pixel(10, 10, Rgba([200, 100, 50, 255]));
+/// // Tint only the blue channel
+/// let opacity = [0.0, 0.0, 0.5, 0.0];
+/// blend_rect_channels(&mut image, Rect::new((0, 0), 5, 5), opacity, Rgba([0, 0, 250, 255]), BlendMode::SourceOver);
+/// assert_eq!(*image.get_pixel(2, 2), Rgba([200, 100, 150, 255]));
+/// ```
+///
+/// See also: [`crate::Draw::blend_rect_channels`](crate::Draw::blend_rect_channels)
+///
+pub fn blend_rect_channels(
+    image: &mut image::RgbaImage,
+    rect: crate::shapes::Rect,
+    opacity: [f32; 4],
+    color: image::Rgba<u8>,
+    mode: impl Into<Blend>,
+) {
+    for opacity in opacity {
+        check_opacity!(opacity);
+    }
+
+    let mode = mode.into();
+    for_each_in_rect(image, rect, |bg| {
+        mode.blend_slice_channels(bg, color, opacity);
+    });
+}
+
+/// Calls `f` with each pixel inside a rectangle, as a slice of the image's
+/// underlying buffer.  Only the part of the rectangle inside the image is visited.
+pub(super) fn for_each_in_rect<F>(image: &mut image::RgbaImage, rect: crate::shapes::Rect, mut f: F)
+where
+    F: FnMut(&mut [u8]),
+{
+    if rect.width == 0 || rect.height == 0 {
+        return;
+    }
+    let x1 = crate::shapes::far_edge(rect.x, rect.width);
+    let y1 = crate::shapes::far_edge(rect.y, rect.height);
+    #[allow(clippy::cast_possible_wrap)]
+    let max_y = image.height() as i32 - 1;
+
+    for y in rect.y.max(0)..=y1.min(max_y) {
+        for_each_in_span(image, y, rect.x, x1, &mut f);
+    }
+}
+
+/// Calls `f` with each pixel of a clipped run of pixels, as a slice of the
+/// image's underlying buffer.  Expects `x0 <= x1`.
+pub(super) fn for_each_in_span<F>(image: &mut image::RgbaImage, y: i32, x0: i32, x1: i32, mut f: F)
+where
+    F: FnMut(&mut [u8]),
+{
+    let (width, height) = image.dimensions();
+    if y < 0 || y as u32 >= height || x1 < 0 || x0 >= 0 && x0 as u32 >= width {
+        return;
+    }
+    let x0 = x0.max(0) as u32;
+    let x1 = (x1 as u32).min(width - 1);
+
+    let start = crate::rgba_array_index(width, x0, y as u32);
+    let end = crate::rgba_array_index(width, x1, y as u32) + std::mem::size_of::<image::Rgba<u8>>();
+
+    let buf: &mut [u8] = image;
+    for bg in buf[start..end].chunks_exact_mut(std::mem::size_of::<image::Rgba<u8>>()) {
+        f(bg);
+    }
+}
+
+/// Returns the premultiplied source color used by [`blend_pixel`].
+#[inline]
+pub(super) fn source(color: image::Rgba<u8>, opacity: f32) -> [f32; 4] {
     use image::Pixel;
+    mult_alpha(rgb_float(color.channels(), opacity))
+}
+
+/// Blends a premultiplied color into a single RGBA pixel.
+#[inline]
+pub(super) fn blend_pixel(bg: &mut [u8], fg: [f32; 4]) {
     // https://stackoverflow.com/questions/7438263/alpha-compositing-algorithm-blend-modes#answer-11163848
     // https://docs.rs/image/latest/src/image/color.rs.html#358-370
-    let i = crate::rgba_array_index(image.width(), x, y);
-    let bg = image.get_unchecked_mut(i..i + std::mem::size_of::<image::Rgba<u8>>());
     let [r1, g1, b1, a1] = mult_alpha(rgba_float(bg));
-    let [r2, g2, b2, a2] = mult_alpha(rgb_float(color.channels(), opacity));
-    let o = 1.0 - opacity;
-    bg[0] = (r1.mul_add(o, r2) * 255.0).to_int_unchecked(); // ((r2 + r1 * (1.0 - a2)) * 255.0);
-    bg[1] = (g1.mul_add(o, g2) * 255.0).to_int_unchecked(); // ((g2 + g1 * (1.0 - a2)) * 255.0);
-    bg[2] = (b1.mul_add(o, b2) * 255.0).to_int_unchecked(); // ((b2 + b1 * (1.0 - a2)) * 255.0);
-    bg[3] = ((a1 + a2 - a1 * a2) * 255.0).to_int_unchecked();
+    let [r2, g2, b2, a2] = fg;
+    let o = 1.0 - a2;
+    bg[0] = (r1.mul_add(o, r2) * 255.0) as u8; // ((r2 + r1 * (1.0 - a2)) * 255.0);
+    bg[1] = (g1.mul_add(o, g2) * 255.0) as u8; // ((g2 + g1 * (1.0 - a2)) * 255.0);
+    bg[2] = (b1.mul_add(o, b2) * 255.0) as u8; // ((b2 + b1 * (1.0 - a2)) * 255.0);
+    bg[3] = ((a1 + a2 - a1 * a2) * 255.0) as u8;
 }
 
 /// Blend a specified color into an existing image coordinate.  This ignores `color`'s
 /// alpha value and instead uses `opacity` which expects a floating point number from 0.0 to 1.0.
 ///
 /// The resulting color's alpha channel will ignore the specified color's alpha
-/// value and use `opacity` to blend the colors together.  The specified
-/// color's alpha value will only be used for the final alpha channel value.
+/// value and use `opacity` to blend the colors together.  The final
+/// alpha combines the existing alpha with `opacity`.  Use [`blend_at_mode`]
+/// with an [`AlphaPolicy`](super::AlphaPolicy) to write a different alpha.
 ///
 /// # Panics
 ///
@@ -88,6 +286,88 @@ pub fn blend_at(
     }
 }
 
+/// Blend a color into an existing image coordinate using the specified
+/// [`BlendMode`], or a [`Blend`] for premultiplied pixels.  Coordinates outside of the image are ignored.
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::{blend_at_mode, BlendMode};
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::from_pixel(10, 10, Rgba([128, 128, 128, 255]));
+/// blend_at_mode(&mut image, 0, 0, 1.0, Rgba([255, 0, 0, 255]), BlendMode::Multiply);
+/// assert_eq!(*image.get_pixel(0, 0), Rgba([128, 0, 0, 255]));
+/// ```
+///
+/// See also: [`crate::Draw::blend_mode`](crate::Draw::blend_mode)
+///
+pub fn blend_at_mode(
+    image: &mut image::RgbaImage,
+    x: u32,
+    y: u32,
+    opacity: f32,
+    color: image::Rgba<u8>,
+    mode: impl Into<Blend>,
+) {
+    check_opacity!(opacity);
+
+    if x < image.width() && y < image.height() {
+        let i = crate::rgba_array_index(image.width(), x, y);
+        let buf: &mut [u8] = image;
+        mode.into().blend_slice(
+            &mut buf[i..i + std::mem::size_of::<image::Rgba<u8>>()],
+            color,
+            opacity,
+        );
+    }
+}
+
+/// Blend a color into an existing image coordinate using a separate opacity
+/// for each channel (red, green, blue, and alpha).  Coordinates outside of the
+/// image are ignored.  See [`Blend::blend_channels`].
+///
+/// # Panics
+///
+/// Panics if any opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::{blend_at_channels, BlendMode};
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::from_pixel(10, 10, Rgba([200, 100, 50, 255]));
+/// // Only the green channel is blended
+/// blend_at_channels(&mut image, 0, 0, [0.0, 1.0, 0.0, 0.0], Rgba([0, 0, 0, 255]), BlendMode::SourceOver);
+/// assert_eq!(*image.get_pixel(0, 0), Rgba([200, 0, 50, 255]));
+/// ```
+///
+pub fn blend_at_channels(
+    image: &mut image::RgbaImage,
+    x: u32,
+    y: u32,
+    opacity: [f32; 4],
+    color: image::Rgba<u8>,
+    mode: impl Into<Blend>,
+) {
+    for opacity in opacity {
+        check_opacity!(opacity);
+    }
+
+    if x < image.width() && y < image.height() {
+        let i = crate::rgba_array_index(image.width(), x, y);
+        let buf: &mut [u8] = image;
+        mode.into().blend_slice_channels(
+            &mut buf[i..i + std::mem::size_of::<image::Rgba<u8>>()],
+            color,
+            opacity,
+        );
+    }
+}
+
 #[inline]
 fn rgba_float(c: &[u8]) -> [f32; 4] {
     [
@@ -143,7 +423,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Opacity must be between")]
     fn safe_blend_invalids() {
         let color = image::Rgba([255, 0, 0, 255]);
         let mut image = image::RgbaImage::from_pixel(1, 1, image::Rgba([255, 255, 255, 255]));
@@ -155,4 +435,46 @@ mod tests {
         blend_at(&mut image, 0, 0, -1.1, color);
         assert_eq!(*image.get_pixel(0, 0), image::Rgba([255, 255, 255, 255]));
     }
+
+    #[test]
+    fn blend_row_matches_blend_at() {
+        let color = image::Rgba([40, 200, 90, 255]);
+        let mut expected = crate::test::img::blank((10, 4));
+        let mut image = expected.clone();
+
+        for x in 0..10 {
+            blend_at(&mut expected, x, 1, 0.3, color);
+        }
+        blend_row(&mut image, 1, 20, -20, 0.3, color);
+        assert_eq!(image, expected);
+
+        // Rows outside of the image are ignored
+        blend_row(&mut image, -1, 0, 9, 0.3, color);
+        blend_row(&mut image, 4, 0, 9, 0.3, color);
+        blend_row(&mut image, 2, 10, 20, 0.3, color);
+        blend_row(&mut image, 2, -20, -1, 0.3, color);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn blend_rect_clips() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((6, 6));
+        blend_rect(
+            &mut image,
+            crate::shapes::Rect::new((4, -3), 5, 10),
+            0.5,
+            color,
+        );
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let inside = x >= 4 && y <= 1;
+            let expected = if inside {
+                [255, 127, 127, 255]
+            } else {
+                [255, 255, 255, 255]
+            };
+            assert_eq!(px.0, expected, "({x}, {y})");
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use image::{GenericImage, GenericImageView};
+
+/// Wraps a mutable image so every pixel written to it is combined with the
+/// existing pixel using a closure.
+///
+/// The closure is called with the existing pixel and the incoming pixel, and
+/// returns the pixel to store.  Because this implements
+/// [`image::GenericImage`], it can be passed to any drawing function that
+/// accepts a generic image, which allows custom per-pixel effects (e.g. max
+/// blending for heat maps, or additive glows) without a dedicated function.
+///
+/// See also: [`Draw::with_blend`](crate::Draw::with_blend)
+///
+/// # Example
+///
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use freehand::ops::BlendFn;
+///
+/// let mut image = RgbaImage::from_pixel(100, 100, Rgba([0, 0, 0, 255]));
+/// let add = |a: Rgba<u8>, b: Rgba<u8>| {
+///     Rgba([a[0].saturating_add(b[0]), a[1].saturating_add(b[1]), a[2].saturating_add(b[2]), 255])
+/// };
+///
+/// let mut glow = BlendFn::new(&mut image, add);
+/// freehand::lines::line(&mut glow, (0, 50), (99, 50), Rgba([100, 0, 0, 255]));
+/// freehand::lines::line(&mut glow, (50, 0), (50, 99), Rgba([100, 0, 0, 255]));
+///
+/// assert_eq!(*image.get_pixel(50, 50), Rgba([200, 0, 0, 255]));
+/// ```
+pub struct BlendFn<'i, I, F>
+where
+    I: GenericImage,
+    F: FnMut(I::Pixel, I::Pixel) -> I::Pixel,
+{
+    image: &'i mut I,
+    f: F,
+}
+
+impl<'i, I, F> BlendFn<'i, I, F>
+where
+    I: GenericImage,
+    F: FnMut(I::Pixel, I::Pixel) -> I::Pixel,
+{
+    /// Wraps an image with a closure taking `(existing, incoming)` pixels.
+    pub fn new(image: &'i mut I, f: F) -> Self {
+        Self { image, f }
+    }
+}
+
+impl<I, F> GenericImageView for BlendFn<'_, I, F>
+where
+    I: GenericImage,
+    F: FnMut(I::Pixel, I::Pixel) -> I::Pixel,
+{
+    type Pixel = I::Pixel;
+
+    fn dimensions(&self) -> (u32, u32) {
+        self.image.dimensions()
+    }
+
+    #[allow(deprecated)]
+    fn bounds(&self) -> (u32, u32, u32, u32) {
+        self.image.bounds()
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Self::Pixel {
+        self.image.get_pixel(x, y)
+    }
+}
+
+impl<I, F> GenericImage for BlendFn<'_, I, F>
+where
+    I: GenericImage,
+    F: FnMut(I::Pixel, I::Pixel) -> I::Pixel,
+{
+    /// Returns the underlying pixel.  Writing through the reference bypasses
+    /// the closure.
+    #[allow(deprecated)]
+    fn get_pixel_mut(&mut self, x: u32, y: u32) -> &mut Self::Pixel {
+        self.image.get_pixel_mut(x, y)
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        let existing = self.image.get_pixel(x, y);
+        self.image.put_pixel(x, y, (self.f)(existing, pixel));
+    }
+
+    /// Same as [`put_pixel`](Self::put_pixel), as the closure does the blending.
+    fn blend_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        self.put_pixel(x, y, pixel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn blend_fn_max() {
+        let mut image = crate::test::img::blank((20, 20));
+        image.put_pixel(5, 5, Rgba([0, 0, 0, 255]));
+        let max = |a: Rgba<u8>, b: Rgba<u8>| if b[0] > a[0] { b } else { a };
+
+        let mut wrapped = BlendFn::new(&mut image, max);
+        crate::conics::circle(&mut wrapped, 5, (10, 10), Rgba([0, 0, 0, 255]));
+        crate::shapes::rectangle_filled(&mut wrapped, (4, 4), 3, 3, Rgba([128, 0, 0, 255]));
+
+        // White always wins over black and red
+        assert_eq!(*image.get_pixel(10, 5), Rgba([255, 255, 255, 255]));
+        assert_eq!(*image.get_pixel(4, 4), Rgba([255, 255, 255, 255]));
+        // Red wins over the black pixel
+        assert_eq!(*image.get_pixel(5, 5), Rgba([128, 0, 0, 255]));
+    }
+}
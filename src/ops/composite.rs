@@ -0,0 +1,133 @@
+use super::{AlphaMode, Blend};
+use crate::pt::Point;
+
+/// Bytes per pixel
+const PX: usize = std::mem::size_of::<image::Rgba<u8>>();
+
+/// Blends an entire image onto another image, with the upper left corner of
+/// `src` placed at `pt` in `dst`.
+///
+/// Each pixel of `src` is blended using `opacity` multiplied by its own alpha
+/// channel, so transparent areas of `src` leave `dst` unchanged.  The parts of
+/// `src` that fall outside of `dst` are skipped.  Rows are blended directly on
+/// the images' underlying buffers, making this much faster than calling
+/// [`blend_at`](super::blend_at) for each pixel.  Useful for watermarks, stamps,
+/// and other overlays.
+///
+/// With [`AlphaMode::Premultiplied`] both images are expected to be
+/// premultiplied, and `opacity` scales every channel of `src`.
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::{composite, BlendMode};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(100, 100, Rgba([255, 255, 255, 255]));
+/// let stamp = RgbaImage::from_pixel(20, 20, Rgba([255, 0, 0, 255]));
+///
+/// composite(&mut image, &stamp, (90, -10), 0.5, BlendMode::SourceOver);
+/// assert_eq!(*image.get_pixel(95, 5), Rgba([255, 127, 127, 255]));
+/// assert_eq!(*image.get_pixel(95, 15), Rgba([255, 255, 255, 255]));
+/// ```
+///
+/// See also: [`crate::Draw::composite`](crate::Draw::composite)
+pub fn composite<P>(
+    dst: &mut image::RgbaImage,
+    src: &image::RgbaImage,
+    pt: P,
+    opacity: f32,
+    mode: impl Into<Blend>,
+) where
+    P: Point<i32>,
+{
+    check_opacity!(opacity);
+    let mode = mode.into();
+
+    let (x, y) = (i64::from(pt.x()), i64::from(pt.y()));
+    // The visible area in dst coordinates
+    let x0 = x.max(0);
+    let y0 = y.max(0);
+    let x1 = (x + i64::from(src.width())).min(i64::from(dst.width()));
+    let y1 = (y + i64::from(src.height())).min(i64::from(dst.height()));
+    if x0 >= x1 || y0 >= y1 {
+        return;
+    }
+
+    let len = (x1 - x0) as usize * PX;
+    let (dst_width, src_width) = (dst.width(), src.width());
+    let dst_buf: &mut [u8] = dst;
+    let src_buf: &[u8] = src;
+
+    for dy in y0..y1 {
+        let d = crate::rgba_array_index(dst_width, x0 as u32, dy as u32);
+        let s = crate::rgba_array_index(src_width, (x0 - x) as u32, (dy - y) as u32);
+        let rows = dst_buf[d..d + len]
+            .chunks_exact_mut(PX)
+            .zip(src_buf[s..s + len].chunks_exact(PX));
+
+        for (bg, fg) in rows {
+            let color = image::Rgba([fg[0], fg[1], fg[2], fg[3]]);
+            let opacity = match mode.alpha {
+                AlphaMode::Straight => opacity * f32::from(fg[3]) / 255.0,
+                AlphaMode::Premultiplied => opacity,
+            };
+            mode.blend_slice(bg, color, opacity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::BlendMode;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn composite_matches_blend_at() {
+        let mut src = RgbaImage::new(8, 6);
+        for (x, y, px) in src.enumerate_pixels_mut() {
+            *px = Rgba([(x * 30) as u8, (y * 40) as u8, 100, (x * y * 7) as u8]);
+        }
+
+        for pt in [(0, 0), (-3, 2), (7, -4), (9, 9), (-20, 0)] {
+            let mut expected = crate::test::img::blank((10, 10));
+            let mut image = expected.clone();
+            composite(&mut image, &src, pt, 0.75, BlendMode::Multiply);
+
+            for (x, y, px) in src.enumerate_pixels() {
+                let (dx, dy) = (x as i32 + pt.0, y as i32 + pt.1);
+                if (0..10).contains(&dx) && (0..10).contains(&dy) {
+                    let opacity = 0.75 * f32::from(px[3]) / 255.0;
+                    crate::ops::blend_at_mode(
+                        &mut expected,
+                        dx as u32,
+                        dy as u32,
+                        opacity,
+                        *px,
+                        BlendMode::Multiply,
+                    );
+                }
+            }
+            assert_eq!(image, expected, "{pt:?}");
+        }
+    }
+
+    #[test]
+    fn composite_transparent_source() {
+        let mut image = crate::test::img::blank((10, 10));
+        composite(
+            &mut image,
+            &RgbaImage::new(5, 5),
+            (2, 2),
+            1.0,
+            BlendMode::SourceOver,
+        );
+        assert_eq!(image, crate::test::img::blank((10, 10)));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::shapes::Rect;
+use image::{GrayImage, Luma, RgbaImage};
+
+/// A summary of the differences between two images, returned by [`diff`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DiffStats {
+    /// The number of pixels that differ in any channel
+    pub changed: u64,
+    /// The largest difference found in any single channel
+    pub max: u8,
+    /// The smallest rectangle containing every changed pixel, or `None` if the
+    /// images are the same
+    pub bounds: Option<Rect>,
+}
+
+impl DiffStats {
+    /// Returns true if no pixels differ.
+    #[must_use]
+    pub const fn is_identical(&self) -> bool {
+        self.changed == 0
+    }
+}
+
+/// Compares two images pixel by pixel, returning a grayscale image of how much
+/// each pixel differs along with a summary of the differences.
+///
+/// Each pixel of the difference image is the largest difference between the
+/// two images in any one channel (including alpha), so unchanged pixels are
+/// black.  Images with different sizes are compared where they overlap; the
+/// difference image covers both, and pixels that are only in one image count
+/// as changed by 255.
+///
+/// This is useful for checking which parts of an image a drawing function
+/// touched, e.g. when tracking damaged regions that need to be redrawn.
+/// With the `testing` feature, `testing::compare` offers a tolerance and a
+/// color diff image for comparing against reference images.
+///
+/// # Panics
+///
+/// Panics if either image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::diff;
+/// use freehand::shapes::Rect;
+/// use image::{Rgba, RgbaImage};
+///
+/// let before = RgbaImage::from_pixel(100, 100, Rgba([255, 255, 255, 255]));
+/// let mut after = before.clone();
+/// freehand::lines::line(&mut after, (10, 20), (40, 20), Rgba([255, 0, 0, 255]));
+///
+/// let (image, stats) = diff(&before, &after);
+/// assert_eq!(stats.changed, 31);
+/// assert_eq!(stats.max, 255);
+/// assert_eq!(stats.bounds, Some(Rect::new((10, 20), 1, 31)));
+/// assert_eq!(image.get_pixel(25, 20)[0], 255);
+/// ```
+#[must_use]
+pub fn diff(a: &RgbaImage, b: &RgbaImage) -> (GrayImage, DiffStats) {
+    check_img_i32!(a);
+    check_img_i32!(b);
+
+    let width = a.width().max(b.width());
+    let height = a.height().max(b.height());
+    let overlap = (a.width().min(b.width()), a.height().min(b.height()));
+
+    let mut image = GrayImage::new(width, height);
+    let mut stats = DiffStats::default();
+    // Corners of the changed area: (x0, y0, x1, y1)
+    let mut area: Option<(u32, u32, u32, u32)> = None;
+
+    for (x, y, px) in image.enumerate_pixels_mut() {
+        let difference = if x < overlap.0 && y < overlap.1 {
+            let (a, b) = (a.get_pixel(x, y), b.get_pixel(x, y));
+            a.0.iter()
+                .zip(b.0)
+                .map(|(a, b)| a.abs_diff(b))
+                .max()
+                .unwrap_or(0)
+        } else {
+            u8::MAX
+        };
+        if difference == 0 {
+            continue;
+        }
+
+        *px = Luma([difference]);
+        stats.changed += 1;
+        stats.max = stats.max.max(difference);
+        area = Some(match area {
+            Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+            None => (x, y, x, y),
+        });
+    }
+
+    // safe because of earlier check on image bounds (check_img_i32)
+    #[allow(clippy::cast_possible_wrap)]
+    {
+        stats.bounds = area
+            .map(|(x0, y0, x1, y1)| Rect::new((x0 as i32, y0 as i32), y1 - y0 + 1, x1 - x0 + 1));
+    }
+    (image, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn identical_images() {
+        let image = RgbaImage::from_pixel(10, 10, Rgba([10, 20, 30, 255]));
+        let (difference, stats) = diff(&image, &image.clone());
+        assert!(stats.is_identical());
+        assert_eq!(stats.bounds, None);
+        assert!(difference.pixels().all(|px| px[0] == 0));
+    }
+
+    #[test]
+    fn magnitudes_and_sizes() {
+        let a = RgbaImage::from_pixel(4, 3, Rgba([100, 100, 100, 255]));
+        let mut b = RgbaImage::from_pixel(3, 3, Rgba([100, 100, 100, 255]));
+        b.put_pixel(1, 1, Rgba([90, 105, 100, 255]));
+        b.put_pixel(2, 2, Rgba([100, 100, 100, 200]));
+
+        let (difference, stats) = diff(&a, &b);
+        assert_eq!(difference.dimensions(), (4, 3));
+        assert_eq!(difference.get_pixel(1, 1)[0], 10);
+        assert_eq!(difference.get_pixel(2, 2)[0], 55);
+        // The column only in the first image
+        assert_eq!(difference.get_pixel(3, 0)[0], 255);
+        assert_eq!(stats.changed, 2 + 3);
+        assert_eq!(stats.max, 255);
+        assert_eq!(stats.bounds, Some(Rect::new((1, 0), 3, 3)));
+    }
+}
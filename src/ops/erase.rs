@@ -0,0 +1,57 @@
+use super::BlendFn;
+use image::{Rgba, RgbaImage};
+
+/// A fully transparent pixel
+const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+/// An image wrapper that replaces every pixel drawn to it with a fully
+/// transparent pixel.  Created with [`eraser`].
+pub type Eraser<'i> = BlendFn<'i, RgbaImage, fn(Rgba<u8>, Rgba<u8>) -> Rgba<u8>>;
+
+/// Wraps an image so that drawing any shape to it erases the pixels under the
+/// shape instead, leaving them fully transparent.
+///
+/// The color passed to the drawing functions is ignored.  To partially erase
+/// pixels use [`BlendMode::DestOut`](super::BlendMode::DestOut) with the blend
+/// functions instead.
+///
+/// See also: [`Draw::erase`](crate::Draw::erase)
+///
+/// # Example
+///
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use freehand::ops::eraser;
+///
+/// let mut image = RgbaImage::from_pixel(100, 100, Rgba([255, 0, 0, 255]));
+///
+/// freehand::conics::pie_slice_filled(&mut eraser(&mut image), 0, 90, 40, (50, 50), Rgba([0, 0, 0, 255]));
+///
+/// assert_eq!(*image.get_pixel(60, 40), Rgba([0, 0, 0, 0]));
+/// assert_eq!(*image.get_pixel(40, 60), Rgba([255, 0, 0, 255]));
+/// ```
+pub fn eraser(image: &mut RgbaImage) -> Eraser<'_> {
+    BlendFn::new(image, |_, _| TRANSPARENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erase_rectangle() {
+        let mut image = crate::test::img::blank((10, 10));
+        crate::shapes::rectangle_filled(
+            &mut eraser(&mut image),
+            (-2, 2),
+            3,
+            5,
+            Rgba([255, 0, 0, 255]),
+        );
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let erased = x < 3 && (2..5).contains(&y);
+            assert_eq!(*px == TRANSPARENT, erased, "({x}, {y})");
+        }
+    }
+}
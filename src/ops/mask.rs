@@ -0,0 +1,114 @@
+// These functions are exported publicly in a different module - keep the module prefix
+#![allow(clippy::module_name_repetitions)]
+
+use crate::Pt;
+use image::{GrayImage, Luma};
+
+/// Renders pixel coverage into a new grayscale mask.
+///
+/// Coverage values are in the range `0.0..=1.0` and are stored as `0..=255`.
+/// When a pixel is covered more than once the largest value is kept.  Pixels
+/// outside of the mask are ignored.
+///
+/// Any iterator of points and their coverage can be used, such as
+/// [`AntialiasedArc::coverage`](crate::conics::AntialiasedArc::coverage).
+/// Shapes without antialiasing can be mapped to a coverage of `1.0`.
+///
+/// # Example
+///
+/// ```
+/// use freehand::conics::{AntialiasedArc, Arc};
+/// use freehand::ops::rasterize_mask;
+///
+/// let arc = AntialiasedArc::new(0, 180, 190.0, (200.0, 200.0));
+/// let mask = rasterize_mask(400, 400, arc.coverage());
+///
+/// // Solid shapes can be used by giving every point full coverage
+/// let arc = Arc::new(0, 180, 150, (200, 200));
+/// let mask = rasterize_mask(400, 400, arc.map(|pt| (pt, 1.0)));
+/// ```
+///
+/// See also: [`rasterize_mask_into`]
+///
+pub fn rasterize_mask<It>(width: u32, height: u32, coverage: It) -> GrayImage
+where
+    It: IntoIterator<Item = (Pt<i32>, f64)>,
+{
+    let mut mask = GrayImage::new(width, height);
+    rasterize_mask_into(&mut mask, coverage);
+    mask
+}
+
+/// Renders pixel coverage into an existing grayscale mask.
+///
+/// Coverage values are in the range `0.0..=1.0` and are stored as `0..=255`.
+/// Existing values in the mask are only replaced by larger values, so several
+/// shapes can be combined into one mask.  Pixels outside of the mask are ignored.
+///
+/// # Example
+///
+/// ```
+/// use freehand::conics::AntialiasedArc;
+/// use freehand::ops::rasterize_mask_into;
+///
+/// let mut mask = image::GrayImage::new(400, 400);
+/// rasterize_mask_into(&mut mask, AntialiasedArc::new(0, 180, 190.0, (200.0, 200.0)).coverage());
+/// rasterize_mask_into(&mut mask, AntialiasedArc::new(180, 0, 150.0, (200.0, 200.0)).coverage());
+/// ```
+///
+/// See also: [`rasterize_mask`]
+///
+pub fn rasterize_mask_into<It>(mask: &mut GrayImage, coverage: It)
+where
+    It: IntoIterator<Item = (Pt<i32>, f64)>,
+{
+    for (pt, cov) in coverage {
+        let (x, y) = match Pt::<u32>::try_from(pt) {
+            Ok(pt) if pt.x() < mask.width() && pt.y() < mask.height() => (pt.x(), pt.y()),
+            _ => continue,
+        };
+
+        // safe because the value is clamped to 0.0..=255.0
+        let v = (cov.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let px = mask.get_pixel_mut(x, y);
+        if v > px[0] {
+            *px = Luma([v]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_keeps_max() {
+        let coverage = vec![
+            (Pt::new(1, 1), 0.25),
+            (Pt::new(1, 1), 1.0),
+            (Pt::new(1, 1), 0.5),
+            (Pt::new(2, 0), 0.5),
+            (Pt::new(-1, 0), 1.0),
+            (Pt::new(5, 5), 1.0),
+        ];
+        let mask = rasterize_mask(3, 3, coverage);
+
+        assert_eq!(mask.get_pixel(1, 1)[0], 255);
+        assert_eq!(mask.get_pixel(2, 0)[0], 128);
+        assert_eq!(mask.pixels().filter(|p| p[0] != 0).count(), 2);
+    }
+
+    #[test]
+    fn mask_arc() -> Result<(), image::ImageError> {
+        let arc = crate::conics::AntialiasedArc::new(30, 300, 150.0, (200.0, 200.0));
+        let mask = rasterize_mask(400, 400, arc.clone().coverage());
+
+        for (pt, cov) in arc.coverage() {
+            assert!(
+                f64::from(mask.get_pixel(pt.x() as u32, pt.y() as u32)[0]) >= cov * 255.0 - 0.5
+            );
+        }
+
+        mask.save("images/mask_arc.png")
+    }
+}
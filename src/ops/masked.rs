@@ -0,0 +1,131 @@
+use super::blend::{blend_pixel, source};
+use crate::Pt;
+use image::{GrayImage, Rgba, RgbaImage};
+
+/// Fills the pixels of an image covered by a grayscale mask with a color.
+///
+/// Only pixels where the mask's value is above `threshold` are changed (a
+/// threshold of 0 fills every nonzero pixel).  The color's alpha is multiplied
+/// by the mask's value before blending, so antialiased edges in the mask stay
+/// smooth.  The mask lines up with the top left corner of the image, and any
+/// part of either one that does not overlap the other is ignored.
+///
+/// Masks can come from [`rasterize_mask`](super::rasterize_mask) or from
+/// anywhere else, such as a loaded image converted to grayscale.
+///
+/// # Example
+///
+/// ```
+/// use freehand::conics::AntialiasedArc;
+/// use freehand::ops::{fill_masked, rasterize_mask};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// let mask = rasterize_mask(400, 400, AntialiasedArc::new(0, 180, 190.0, (200.0, 200.0)).coverage());
+///
+/// fill_masked(&mut image, &mask, 0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`fill_masked_with`], [`crate::Draw::fill_masked`](crate::Draw::fill_masked)
+///
+pub fn fill_masked(image: &mut RgbaImage, mask: &GrayImage, threshold: u8, color: Rgba<u8>) {
+    fill_masked_with(image, mask, threshold, |_| color);
+}
+
+/// Fills the pixels of an image covered by a grayscale mask with the colors
+/// returned by a closure, which is called with the position of each pixel.
+/// This confines gradients and patterns to any shape.  See [`fill_masked`].
+///
+/// # Example
+///
+/// ```
+/// use freehand::color::Colormap;
+/// use freehand::conics::AntialiasedArc;
+/// use freehand::ops::{fill_masked_with, rasterize_mask};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let mask = rasterize_mask(400, 400, AntialiasedArc::new(0, 180, 190.0, (200.0, 200.0)).coverage());
+///
+/// // A horizontal gradient that only shows where the mask is
+/// fill_masked_with(&mut image, &mask, 0, |pt| Colormap::Viridis.sample(f64::from(pt.x()) / 400.0));
+/// ```
+///
+/// See also: [`crate::Draw::fill_masked_with`](crate::Draw::fill_masked_with)
+///
+pub fn fill_masked_with<F>(image: &mut RgbaImage, mask: &GrayImage, threshold: u8, mut f: F)
+where
+    F: FnMut(Pt<i32>) -> Rgba<u8>,
+{
+    check_img_i32!(image);
+
+    let width = image.width().min(mask.width());
+    let height = image.height().min(mask.height());
+    let image_width = image.width();
+    let buf: &mut [u8] = image;
+
+    for y in 0..height {
+        for x in 0..width {
+            let m = mask.get_pixel(x, y)[0];
+            if m <= threshold {
+                continue;
+            }
+
+            // safe because of earlier check on image bounds (check_img_i32)
+            #[allow(clippy::cast_possible_wrap)]
+            let color = f(Pt::new(x as i32, y as i32));
+            let opacity = f32::from(color[3]) / 255.0 * f32::from(m) / 255.0;
+            let i = crate::rgba_array_index(image_width, x, y);
+            blend_pixel(&mut buf[i..i + 4], source(color, opacity));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Luma;
+
+    #[test]
+    fn only_inside_mask() {
+        let mut mask = GrayImage::new(4, 2);
+        mask.put_pixel(0, 0, Luma([255]));
+        mask.put_pixel(1, 0, Luma([128]));
+        mask.put_pixel(2, 0, Luma([10]));
+
+        let white = Rgba([255, 255, 255, 255]);
+        let mut image = RgbaImage::from_pixel(3, 3, white);
+        fill_masked(&mut image, &mask, 20, Rgba([0, 0, 0, 255]));
+
+        assert_eq!(*image.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+        // Half of the mask's value blends halfway to black
+        let half = image.get_pixel(1, 0);
+        assert!(half.0.iter().take(3).all(|&c| (126..=128).contains(&c)));
+        assert!(half[3] >= 254);
+        // Below the threshold
+        assert_eq!(*image.get_pixel(2, 0), white);
+        // Outside of the mask
+        assert!(image
+            .enumerate_pixels()
+            .all(|(_, y, px)| y == 0 || *px == white));
+    }
+
+    #[test]
+    fn closure_gets_positions() {
+        let mask = GrayImage::from_pixel(3, 3, Luma([255]));
+        let mut image = RgbaImage::new(2, 4);
+        let mut seen = Vec::new();
+        fill_masked_with(&mut image, &mask, 0, |pt| {
+            seen.push(pt);
+            Rgba([0, 0, 255, 128])
+        });
+
+        assert_eq!(
+            seen,
+            [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)].map(Pt::from)
+        );
+        // The color's own alpha is kept
+        assert_eq!(image.get_pixel(1, 1)[3], 128);
+        assert_eq!(image.get_pixel(1, 3)[3], 0);
+    }
+}
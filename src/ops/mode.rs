@@ -0,0 +1,547 @@
+// These are exported publicly in a different module - keep the module prefix
+#![allow(clippy::module_name_repetitions)]
+
+use image::Rgba;
+
+/// Compositing operators used to combine a color with the existing pixels of
+/// an image.
+///
+/// The Porter-Duff operators (e.g. [`BlendMode::SourceOver`],
+/// [`BlendMode::DestOut`]) describe which parts of the source and destination
+/// are kept, while [`BlendMode::Multiply`], [`BlendMode::Screen`], and
+/// [`BlendMode::Add`] combine the colors themselves.
+///
+/// The `opacity` given to the blend functions is used as the source color's
+/// alpha, matching [`blend_at`](crate::ops::blend_at).
+///
+/// ```
+/// use freehand::ops::{blend_at_mode, BlendMode};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(1, 1, Rgba([255, 0, 0, 255]));
+///
+/// // Erase the pixel completely
+/// blend_at_mode(&mut image, 0, 0, 1.0, Rgba([0, 0, 0, 255]), BlendMode::DestOut);
+/// assert_eq!(image.get_pixel(0, 0)[3], 0);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum BlendMode {
+    /// Draws the source over the destination.  This is the default, and is
+    /// what all of the alpha drawing functions use.
+    SourceOver,
+    /// Draws the source behind the destination
+    DestOver,
+    /// Keeps the source only where the destination is opaque
+    SourceIn,
+    /// Keeps the source only where the destination is transparent
+    SourceOut,
+    /// Draws the source over the destination only where the destination is opaque
+    SourceAtop,
+    /// Keeps the destination only where the source is opaque
+    DestIn,
+    /// Removes the destination where the source is opaque (erasing)
+    DestOut,
+    /// Keeps the destination and draws the source behind it only where the source is opaque
+    DestAtop,
+    /// Keeps the source and destination only where they do not overlap
+    Xor,
+    /// Clears the destination, regardless of the source color and opacity
+    Clear,
+    /// Multiplies the source and destination colors, darkening the image
+    Multiply,
+    /// Inverts, multiplies, and inverts the colors again, lightening the image
+    Screen,
+    /// Adds the source and destination together (also known as lighter or plus)
+    Add,
+}
+
+impl Default for BlendMode {
+    fn default() -> Self {
+        Self::SourceOver
+    }
+}
+
+/// How the color channels of pixels relate to their alpha channel.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AlphaMode {
+    /// Color channels are independent of the alpha channel.  This is the
+    /// default, and is what the [`image`] crate assumes.
+    Straight,
+    /// Color channels have already been multiplied by the alpha channel, as
+    /// used by many compositors and GPU APIs.
+    ///
+    /// The incoming color is treated as premultiplied as well, and `opacity`
+    /// scales the whole color (including its alpha) instead of replacing its
+    /// alpha.
+    Premultiplied,
+}
+
+impl Default for AlphaMode {
+    fn default() -> Self {
+        Self::Straight
+    }
+}
+
+/// What is written to the alpha channel of a blended pixel.
+///
+/// Blending onto an opaque background normally leaves it opaque, but when
+/// compositing into a transparent canvas it is often useful to control the
+/// final alpha separately from how the colors are mixed.
+///
+/// ```
+/// use freehand::ops::{AlphaPolicy, BlendMode};
+/// use image::Rgba;
+///
+/// let bg = Rgba([0, 0, 255, 0]);
+/// let mode = BlendMode::SourceOver.with_alpha_policy(AlphaPolicy::KeepDestinationAlpha);
+///
+/// // The color is mixed in, but the pixel stays transparent
+/// let px = mode.blend(bg, Rgba([255, 0, 0, 255]), 0.5);
+/// assert_eq!(px, Rgba([255, 0, 0, 0]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AlphaPolicy {
+    /// Combines the destination's alpha with the source's, as described by the
+    /// [`BlendMode`].  This is the default.
+    BlendAlpha,
+    /// Leaves the destination's alpha unchanged, only changing its colors.
+    KeepDestinationAlpha,
+    /// Uses the alpha channel of the color being drawn, ignoring `opacity`.
+    ReplaceWithSource,
+}
+
+impl Default for AlphaPolicy {
+    fn default() -> Self {
+        Self::BlendAlpha
+    }
+}
+
+/// A [`BlendMode`] together with the [`AlphaMode`] of the pixels being blended
+/// and the [`AlphaPolicy`] used for the resulting alpha.
+///
+/// Functions taking a `Blend` accept a plain [`BlendMode`] as well, which uses
+/// straight alpha.  Use [`BlendMode::premultiplied`] for premultiplied pixels.
+///
+/// ```
+/// use freehand::ops::{blend_at_mode, BlendMode};
+/// use image::{RgbaImage, Rgba};
+///
+/// // A premultiplied, half transparent red
+/// let mut image = RgbaImage::from_pixel(1, 1, Rgba([128, 0, 0, 128]));
+///
+/// blend_at_mode(&mut image, 0, 0, 1.0, Rgba([0, 0, 128, 128]), BlendMode::SourceOver.premultiplied());
+/// assert_eq!(*image.get_pixel(0, 0), Rgba([64, 0, 128, 192]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Blend {
+    /// The compositing operator
+    pub mode: BlendMode,
+    /// Whether pixels use straight or premultiplied alpha
+    pub alpha: AlphaMode,
+    /// What is written to the alpha channel
+    pub alpha_policy: AlphaPolicy,
+}
+
+impl From<BlendMode> for Blend {
+    fn from(mode: BlendMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+}
+
+impl Blend {
+    /// Uses `policy` for the alpha channel of blended pixels.
+    #[must_use]
+    pub fn with_alpha_policy(self, policy: AlphaPolicy) -> Self {
+        Self {
+            alpha_policy: policy,
+            ..self
+        }
+    }
+
+    /// Blends `color` into `bg`.  See [`BlendMode::blend`].
+    #[must_use]
+    pub fn blend(self, bg: Rgba<u8>, color: Rgba<u8>, opacity: f32) -> Rgba<u8> {
+        let mut px = bg;
+        self.blend_slice(&mut px.0, color, opacity);
+        px
+    }
+
+    /// Blends a color into a single pixel of an image's underlying buffer.
+    pub(crate) fn blend_slice(self, bg: &mut [u8], color: Rgba<u8>, opacity: f32) {
+        self.blend_slice_channels(bg, color, [opacity; 4]);
+    }
+
+    /// Blends `color` into `bg` using a separate opacity for each channel
+    /// (red, green, blue, and alpha).
+    ///
+    /// Each color channel is mixed as if the color had the opacity given for
+    /// that channel, which allows overlays that only affect some channels.
+    /// Using the same opacity for every channel is the same as
+    /// [`Blend::blend`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any opacity is not between 0.0 and 1.0
+    ///
+    /// ```
+    /// use freehand::ops::BlendMode;
+    /// use image::Rgba;
+    ///
+    /// // Remove the red channel, leaving the others unchanged
+    /// let px = BlendMode::SourceOver.blend_channels(Rgba([200, 100, 50, 255]), Rgba([0, 0, 0, 255]), [1.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(px, Rgba([0, 100, 50, 255]));
+    /// ```
+    #[must_use]
+    pub fn blend_channels(self, bg: Rgba<u8>, color: Rgba<u8>, opacity: [f32; 4]) -> Rgba<u8> {
+        for opacity in opacity {
+            check_opacity!(opacity);
+        }
+        let mut px = bg;
+        self.blend_slice_channels(&mut px.0, color, opacity);
+        px
+    }
+
+    /// Blends a color into a single pixel of an image's underlying buffer,
+    /// using a separate opacity for each channel.
+    pub(crate) fn blend_slice_channels(self, bg: &mut [u8], color: Rgba<u8>, opacity: [f32; 4]) {
+        let dst_alpha = bg[3];
+        self.composite_slice(bg, color, opacity);
+
+        let alpha = match self.alpha_policy {
+            AlphaPolicy::BlendAlpha => return,
+            AlphaPolicy::KeepDestinationAlpha => dst_alpha,
+            AlphaPolicy::ReplaceWithSource => color[3],
+        };
+        if self.alpha == AlphaMode::Premultiplied {
+            // Premultiplied colors have to be rescaled to the new alpha
+            let scale = if bg[3] == 0 {
+                0.0
+            } else {
+                f32::from(alpha) / f32::from(bg[3])
+            };
+            for c in &mut bg[..3] {
+                *c = (f32::from(*c) * scale).round().min(f32::from(alpha)) as u8;
+            }
+        }
+        bg[3] = alpha;
+    }
+
+    /// Blends the colors of a single pixel, combining the alpha channels as
+    /// described by the [`BlendMode`].
+    fn composite_slice(self, bg: &mut [u8], color: Rgba<u8>, opacity: [f32; 4]) {
+        if opacity
+            .iter()
+            .all(|o| (o - opacity[0]).abs() <= f32::EPSILON)
+            && self.alpha == AlphaMode::Straight
+            && self.mode == BlendMode::SourceOver
+            && self.alpha_policy == AlphaPolicy::BlendAlpha
+        {
+            // Keeps the original source-over math used by every other alpha function
+            super::blend::blend_pixel(bg, super::blend::source(color, opacity[0]));
+            return;
+        }
+
+        match self.alpha {
+            AlphaMode::Straight => {
+                let dst = premultiply([bg[0], bg[1], bg[2], bg[3]], f32::from(bg[3]) / 255.0);
+                let mut src = opacity;
+                for (s, c) in src.iter_mut().zip(&color.0[..3]) {
+                    *s *= f32::from(*c) / 255.0;
+                }
+                let [r, g, b, alpha] = self.mode.composite(src, opacity, dst);
+                let unmult = |c: f32| {
+                    if alpha > 0.0 {
+                        (c / alpha).min(1.0)
+                    } else {
+                        0.0
+                    }
+                };
+                write(bg, [unmult(r), unmult(g), unmult(b), alpha]);
+            }
+            AlphaMode::Premultiplied => {
+                let d = [bg[0], bg[1], bg[2], bg[3]].map(|c| f32::from(c) / 255.0);
+                let mut s = [0.0; 4];
+                for ((s, c), o) in s.iter_mut().zip(color.0).zip(opacity) {
+                    *s = f32::from(c) / 255.0 * o;
+                }
+                let sa = opacity.map(|o| f32::from(color[3]) / 255.0 * o);
+                write(bg, self.mode.composite(s, sa, d));
+            }
+        }
+    }
+}
+
+impl BlendMode {
+    /// Blends `color` into `bg` using `opacity` as the color's alpha.
+    ///
+    /// ```
+    /// use freehand::ops::BlendMode;
+    /// use image::Rgba;
+    ///
+    /// let bg = Rgba([200, 100, 50, 255]);
+    /// let px = BlendMode::Multiply.blend(bg, Rgba([255, 0, 255, 255]), 1.0);
+    /// assert_eq!(px, Rgba([200, 0, 50, 255]));
+    /// ```
+    #[must_use]
+    pub fn blend(self, bg: Rgba<u8>, color: Rgba<u8>, opacity: f32) -> Rgba<u8> {
+        Blend::from(self).blend(bg, color, opacity)
+    }
+
+    /// Uses this mode with `policy` for the alpha channel of blended pixels.
+    /// See [`AlphaPolicy`].
+    #[must_use]
+    pub fn with_alpha_policy(self, policy: AlphaPolicy) -> Blend {
+        Blend::from(self).with_alpha_policy(policy)
+    }
+
+    /// Blends `color` into `bg` using a separate opacity for each channel.
+    /// See [`Blend::blend_channels`].
+    #[must_use]
+    pub fn blend_channels(self, bg: Rgba<u8>, color: Rgba<u8>, opacity: [f32; 4]) -> Rgba<u8> {
+        Blend::from(self).blend_channels(bg, color, opacity)
+    }
+
+    /// Uses this mode with premultiplied pixels.  See [`AlphaMode::Premultiplied`].
+    #[must_use]
+    pub fn premultiplied(self) -> Blend {
+        Blend {
+            mode: self,
+            alpha: AlphaMode::Premultiplied,
+            alpha_policy: AlphaPolicy::BlendAlpha,
+        }
+    }
+
+    /// Composites a premultiplied source color over a premultiplied destination
+    /// color, returning the premultiplied result.
+    ///
+    /// `sa` is the source alpha used for each channel, which is normally the
+    /// source's alpha channel repeated, but differs per channel when blending
+    /// with a separate opacity for each channel.
+    fn composite(self, s: [f32; 4], sa: [f32; 4], d: [f32; 4]) -> [f32; 4] {
+        let da = d[3];
+        let fractions = |sa: f32| match self {
+            Self::DestOver => (1.0 - da, 1.0),
+            Self::SourceIn => (da, 0.0),
+            Self::SourceOut => (1.0 - da, 0.0),
+            Self::SourceAtop => (da, 1.0 - sa),
+            Self::DestIn => (0.0, sa),
+            Self::DestOut => (0.0, 1.0 - sa),
+            Self::DestAtop => (1.0 - da, sa),
+            Self::Xor => (1.0 - da, 1.0 - sa),
+            Self::Clear => (0.0, 0.0),
+            // The separable modes composite their mixed color with source-over
+            Self::SourceOver | Self::Multiply | Self::Screen | Self::Add => (1.0, 1.0 - sa),
+        };
+
+        let mut out = [0.0; 4];
+        for i in 0..3 {
+            let (s, d, sa) = (s[i], d[i], sa[i]);
+            let (fa, fb) = fractions(sa);
+            out[i] = match self {
+                Self::Add => (s + d).min(1.0),
+                Self::Multiply => s.mul_add(1.0 - da, d.mul_add(1.0 - sa, s * d)),
+                Self::Screen => s + d - s * d,
+                _ => s.mul_add(fa, d * fb),
+            };
+        }
+        let (fa, fb) = fractions(sa[3]);
+        out[3] = match self {
+            Self::Add => (s[3] + da).min(1.0),
+            _ => s[3].mul_add(fa, da * fb),
+        };
+        out
+    }
+}
+
+/// Converts a straight color to premultiplied floats, using `alpha` as its alpha.
+fn premultiply(c: [u8; 4], alpha: f32) -> [f32; 4] {
+    [
+        f32::from(c[0]) / 255.0 * alpha,
+        f32::from(c[1]) / 255.0 * alpha,
+        f32::from(c[2]) / 255.0 * alpha,
+        alpha,
+    ]
+}
+
+/// Writes floating-point channels back into a pixel.
+fn write(bg: &mut [u8], c: [f32; 4]) {
+    for (bg, c) in bg.iter_mut().zip(c) {
+        *bg = (c * 255.0).round() as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+    const CLEAR: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+    #[test]
+    fn porter_duff() {
+        assert_eq!(BlendMode::SourceOver.blend(RED, BLUE, 1.0), BLUE);
+        assert_eq!(BlendMode::DestOver.blend(RED, BLUE, 1.0), RED);
+        assert_eq!(BlendMode::DestOver.blend(CLEAR, BLUE, 1.0), BLUE);
+        assert_eq!(BlendMode::SourceIn.blend(RED, BLUE, 1.0), BLUE);
+        assert_eq!(BlendMode::SourceIn.blend(CLEAR, BLUE, 1.0)[3], 0);
+        assert_eq!(BlendMode::SourceOut.blend(RED, BLUE, 1.0)[3], 0);
+        assert_eq!(BlendMode::SourceOut.blend(CLEAR, BLUE, 1.0), BLUE);
+        assert_eq!(BlendMode::SourceAtop.blend(RED, BLUE, 1.0), BLUE);
+        assert_eq!(BlendMode::DestIn.blend(RED, BLUE, 0.0)[3], 0);
+        assert_eq!(BlendMode::DestAtop.blend(RED, BLUE, 1.0), RED);
+        assert_eq!(BlendMode::Xor.blend(RED, BLUE, 1.0)[3], 0);
+        assert_eq!(BlendMode::Xor.blend(CLEAR, BLUE, 1.0), BLUE);
+        assert_eq!(BlendMode::Clear.blend(RED, BLUE, 1.0)[3], 0);
+    }
+
+    #[test]
+    fn dest_out_keeps_color() {
+        let px = BlendMode::DestOut.blend(RED, BLUE, 0.5);
+        assert_eq!(px, Rgba([255, 0, 0, 128]));
+        assert_eq!(BlendMode::DestOut.blend(RED, BLUE, 1.0)[3], 0);
+    }
+
+    #[test]
+    fn separable() {
+        let gray = Rgba([128, 128, 128, 255]);
+        assert_eq!(
+            BlendMode::Multiply.blend(gray, RED, 1.0),
+            Rgba([128, 0, 0, 255])
+        );
+        assert_eq!(
+            BlendMode::Screen.blend(gray, RED, 1.0),
+            Rgba([255, 128, 128, 255])
+        );
+        assert_eq!(
+            BlendMode::Add.blend(gray, RED, 1.0),
+            Rgba([255, 128, 128, 255])
+        );
+        assert_eq!(BlendMode::Multiply.blend(gray, RED, 0.0), gray);
+    }
+
+    #[test]
+    fn premultiplied_matches_straight() {
+        // Opaque pixels are the same in both representations
+        let bg = Rgba([200, 100, 50, 255]);
+        for mode in [
+            BlendMode::Multiply,
+            BlendMode::Screen,
+            BlendMode::Add,
+            BlendMode::DestOver,
+        ] {
+            assert_eq!(
+                mode.premultiplied().blend(bg, RED, 1.0),
+                mode.blend(bg, RED, 1.0),
+                "{mode:?}"
+            );
+        }
+
+        // Erasing half of a premultiplied pixel halves every channel
+        let px = BlendMode::DestOut
+            .premultiplied()
+            .blend(Rgba([200, 100, 50, 200]), BLUE, 0.5);
+        assert_eq!(px, Rgba([100, 50, 25, 100]));
+    }
+
+    #[test]
+    fn per_channel_opacity() {
+        let bg = Rgba([200, 100, 50, 255]);
+        let color = Rgba([20, 40, 60, 255]);
+        for mode in [
+            BlendMode::SourceOver.into(),
+            BlendMode::Multiply.into(),
+            BlendMode::Screen.premultiplied(),
+            BlendMode::SourceOver.premultiplied(),
+        ] {
+            let mode: Blend = mode;
+            // The same opacity for every channel matches the single opacity
+            for opacity in [0.0, 0.3, 1.0] {
+                assert_eq!(
+                    mode.blend_channels(bg, color, [opacity; 4]),
+                    mode.blend(bg, color, opacity),
+                    "{mode:?} {opacity}"
+                );
+            }
+            // Channels with no opacity are left unchanged
+            let px = mode.blend_channels(bg, color, [0.0, 1.0, 0.0, 0.0]);
+            assert_eq!([px[0], px[2], px[3]], [200, 50, 255], "{mode:?}");
+            assert_eq!(px[1], mode.blend(bg, color, 1.0)[1], "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn channel_rect() {
+        let mut image = image::RgbaImage::from_pixel(4, 4, Rgba([200, 100, 50, 255]));
+        let rect = crate::shapes::Rect::new((1, 1), 2, 2);
+        crate::ops::blend_rect_channels(
+            &mut image,
+            rect,
+            [0.5, 0.0, 1.0, 0.0],
+            Rgba([0, 0, 0, 255]),
+            BlendMode::SourceOver,
+        );
+        assert_eq!(image.get_pixel(0, 0).0, [200, 100, 50, 255]);
+        let px = image.get_pixel(2, 2).0;
+        assert!((99..=100).contains(&px[0]), "{px:?}");
+        assert_eq!([px[1], px[2], px[3]], [100, 0, 255]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Opacity must be between")]
+    fn channel_opacity_checked() {
+        let _ = BlendMode::SourceOver.blend_channels(RED, BLUE, [0.5, 0.5, 1.5, 0.5]);
+    }
+
+    #[test]
+    fn alpha_policies() {
+        let bg = Rgba([200, 100, 50, 128]);
+        let color = Rgba([0, 0, 255, 64]);
+        let blended = BlendMode::SourceOver.blend(bg, color, 0.5);
+
+        let keep = BlendMode::SourceOver.with_alpha_policy(AlphaPolicy::KeepDestinationAlpha);
+        let px = keep.blend(bg, color, 0.5);
+        assert_eq!(px[3], 128);
+        assert!(px[2] > bg[2] && px[0] < bg[0], "{px:?}");
+
+        let replace = BlendMode::SourceOver.with_alpha_policy(AlphaPolicy::ReplaceWithSource);
+        assert_eq!(replace.blend(bg, color, 0.5)[3], 64);
+        assert_eq!(
+            Blend::from(BlendMode::SourceOver)
+                .with_alpha_policy(AlphaPolicy::BlendAlpha)
+                .blend(bg, color, 0.5),
+            blended
+        );
+
+        // Opaque backgrounds stay opaque
+        let opaque = Rgba([200, 100, 50, 255]);
+        for opacity in [0.1, 0.5, 0.9] {
+            assert_eq!(keep.blend(opaque, color, opacity)[3], 255);
+        }
+    }
+
+    #[test]
+    fn premultiplied_alpha_policies() {
+        let mode = BlendMode::SourceOver
+            .premultiplied()
+            .with_alpha_policy(AlphaPolicy::KeepDestinationAlpha);
+        let px = mode.blend(Rgba([100, 0, 0, 128]), BLUE, 1.0);
+        assert_eq!(px, Rgba([0, 0, 128, 128]));
+
+        // Colors never exceed the new alpha
+        let mode = BlendMode::SourceOver
+            .premultiplied()
+            .with_alpha_policy(AlphaPolicy::ReplaceWithSource);
+        let px = mode.blend(RED, Rgba([0, 0, 128, 128]), 1.0);
+        assert_eq!(px, Rgba([64, 0, 64, 128]));
+        assert_eq!(mode.blend(CLEAR, CLEAR, 1.0), CLEAR);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::Pt;
+use image::GenericImageView;
+
+/// Offsets to the 8 neighbors of a pixel, clockwise on screen starting from
+/// the left (west) neighbor.
+const NEIGHBORS: [(i32, i32); 8] = [
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+
+/// Traces the outlines of the regions in an image, using Moore-neighbor
+/// tracing.
+///
+/// A pixel is part of a region if `inside` returns true for it, so regions can
+/// be picked by color or taken from a mask (e.g. a
+/// [`GrayImage`](image::GrayImage) from [`rasterize_mask`](super::rasterize_mask)).
+/// Diagonal neighbors are part of the same region.
+///
+/// One outline is returned for each region, in the order their topmost
+/// (then leftmost) pixels appear in the image.  Each outline lists the pixels
+/// on the edge of the region, clockwise on screen, starting from that pixel.
+/// Pixels on thin parts of a region are visited once from each side, and holes
+/// inside a region are not traced.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::ops::trace_outline;
+/// use freehand::shapes::{rectangle_filled, Polygon};
+/// use freehand::Pt;
+///
+/// let red = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::new(20, 20);
+/// rectangle_filled(&mut image, (5, 5), 4, 3, red);
+///
+/// let outlines = trace_outline(&image, |px| px == red);
+/// assert_eq!(outlines.len(), 1);
+/// assert_eq!(outlines[0][..4], [Pt::new(5, 5), Pt::new(6, 5), Pt::new(7, 5), Pt::new(7, 6)]);
+///
+/// // Stroke the outline in another color
+/// Polygon::new(outlines[0].clone()).draw(&mut image, Rgba([0, 0, 0, 255]));
+/// ```
+pub fn trace_outline<I, F>(image: &I, mut inside: F) -> Vec<Vec<Pt<i32>>>
+where
+    I: GenericImageView,
+    F: FnMut(I::Pixel) -> bool,
+{
+    check_img_i32!(image);
+
+    let (width, height) = image.dimensions();
+    let (w, h) = (width as usize, height as usize);
+    let region: Vec<bool> = image.pixels().map(|(_, _, px)| inside(px)).collect();
+    // safe because of earlier check on image bounds (check_img_i32)
+    #[allow(clippy::cast_possible_wrap)]
+    let is_inside = |x: i32, y: i32| {
+        x >= 0
+            && y >= 0
+            && x < width as i32
+            && y < height as i32
+            && region[y as usize * w + x as usize]
+    };
+
+    let mut seen = vec![false; w * h];
+    let mut outlines = Vec::new();
+    for i in 0..w * h {
+        if region[i] && !seen[i] {
+            // safe because of earlier check on image bounds (check_img_i32)
+            #[allow(clippy::cast_possible_wrap)]
+            let start = Pt::new((i % w) as i32, (i / w) as i32);
+            // Each edge pixel can be visited at most once from each side
+            outlines.push(trace(start, is_inside, 8 * w * h));
+            mark_region(start, is_inside, &mut seen, w);
+        }
+    }
+    outlines
+}
+
+/// Follows the edge of a region clockwise from its topmost, leftmost pixel
+/// until it is about to repeat its first step.
+///
+/// Stopping when the first step repeats, rather than on returning to the
+/// first pixel, keeps regions that pass through that pixel more than once
+/// (like a diagonal line) from being cut short.
+fn trace(start: Pt<i32>, inside: impl Fn(i32, i32) -> bool, max_len: usize) -> Vec<Pt<i32>> {
+    let mut outline = vec![start];
+    // The first pixel's left neighbor is never part of the region
+    let (mut pt, mut back) = (start, 0);
+    let mut first = None;
+
+    while outline.len() <= max_len {
+        let next = (1..=8).map(|k| (back + k) % 8).find(|&d| {
+            let (dx, dy) = NEIGHBORS[d];
+            inside(pt.x() + dx, pt.y() + dy)
+        });
+        let d = match next {
+            Some(d) => d,
+            // A single isolated pixel
+            None => break,
+        };
+
+        let (dx, dy) = NEIGHBORS[d];
+        pt = Pt::new(pt.x() + dx, pt.y() + dy);
+        // The last neighbor checked before this one is outside of the region;
+        // the search around the new pixel continues from it
+        back = if d % 2 == 0 { (d + 6) % 8 } else { (d + 5) % 8 };
+
+        match first {
+            None => first = Some((pt, back)),
+            Some(first) if first == (pt, back) => break,
+            Some(_) => {}
+        }
+        outline.push(pt);
+    }
+    // The first pixel is reached again just before the first step repeats
+    if outline.len() > 1 && outline.last() == Some(&start) {
+        outline.pop();
+    }
+    outline
+}
+
+/// Marks every pixel connected to `start` (including diagonally) as seen.
+fn mark_region(start: Pt<i32>, inside: impl Fn(i32, i32) -> bool, seen: &mut [bool], w: usize) {
+    let index = |pt: Pt<i32>| pt.y() as usize * w + pt.x() as usize;
+    let mut stack = vec![start];
+    seen[index(start)] = true;
+
+    while let Some(pt) = stack.pop() {
+        for (dx, dy) in NEIGHBORS {
+            let next = Pt::new(pt.x() + dx, pt.y() + dy);
+            if inside(next.x(), next.y()) && !seen[index(next)] {
+                seen[index(next)] = true;
+                stack.push(next);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    fn mask(rows: &[&str]) -> GrayImage {
+        let mut mask = GrayImage::new(rows[0].len() as u32, rows.len() as u32);
+        for (y, row) in (0..).zip(rows) {
+            for (x, c) in (0..).zip(row.chars()) {
+                if c == '#' {
+                    mask.put_pixel(x, y, Luma([255]));
+                }
+            }
+        }
+        mask
+    }
+
+    fn pts(points: &[(i32, i32)]) -> Vec<Pt<i32>> {
+        points.iter().map(|&p| p.into()).collect()
+    }
+
+    #[test]
+    fn square_outline() {
+        let square = mask(&["....", ".##.", ".##.", "...."]);
+        let outlines = trace_outline(&square, |px| px.0[0] > 0);
+        assert_eq!(outlines, vec![pts(&[(1, 1), (2, 1), (2, 2), (1, 2)])]);
+    }
+
+    #[test]
+    fn separate_regions() {
+        let regions = mask(&["#...#", ".....", "..###", "#.###"]);
+        let outlines = trace_outline(&regions, |px| px.0[0] > 0);
+        assert_eq!(
+            outlines,
+            vec![
+                pts(&[(0, 0)]),
+                pts(&[(4, 0)]),
+                pts(&[(2, 2), (3, 2), (4, 2), (4, 3), (3, 3), (2, 3)]),
+                pts(&[(0, 3)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn thin_parts_visited_from_both_sides() {
+        // A diagonal line and a region with a hole
+        let line = mask(&["#....", ".#...", "..#..", "....."]);
+        let outlines = trace_outline(&line, |px| px.0[0] > 0);
+        assert_eq!(outlines, vec![pts(&[(0, 0), (1, 1), (2, 2), (1, 1)])]);
+
+        let ring = mask(&["###", "#.#", "###"]);
+        let outlines = trace_outline(&ring, |px| px.0[0] > 0);
+        assert_eq!(
+            outlines,
+            vec![pts(&[
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1)
+            ])]
+        );
+    }
+}
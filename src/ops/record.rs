@@ -0,0 +1,218 @@
+use image::{GenericImage, GenericImageView, Pixel};
+
+/// An image wrapper that saves the previous value of every pixel drawn to it,
+/// so the drawing can be undone later.  Created with [`Recorder::new`].
+///
+/// Because this implements [`image::GenericImage`], it can be passed to any
+/// drawing function.  Once drawing is done, [`Recorder::finish`] returns a
+/// [`Patch`] that puts the saved pixels back.
+///
+/// See also: [`Draw::record`](crate::Draw::record)
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::Recorder;
+/// use image::{Rgba, RgbaImage};
+///
+/// let mut image = RgbaImage::from_pixel(100, 100, Rgba([255, 255, 255, 255]));
+/// let original = image.clone();
+///
+/// let mut recorder = Recorder::new(&mut image);
+/// freehand::lines::line(&mut recorder, (10, 10), (90, 40), Rgba([255, 0, 0, 255]));
+/// freehand::conics::circle(&mut recorder, 30, (50, 50), Rgba([0, 0, 255, 255]));
+/// let patch = recorder.finish();
+///
+/// patch.revert(&mut image);
+/// assert_eq!(image, original);
+/// ```
+pub struct Recorder<'i, I>
+where
+    I: GenericImage,
+{
+    image: &'i mut I,
+    /// The row, column, and previous value of each pixel written, in order
+    changes: Vec<(u32, u32, I::Pixel)>,
+}
+
+impl<'i, I> Recorder<'i, I>
+where
+    I: GenericImage,
+{
+    /// Wraps an image, recording the pixels drawn to it from now on.
+    pub fn new(image: &'i mut I) -> Self {
+        Self {
+            image,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Stops recording and returns a patch holding the value each recorded
+    /// pixel had before it was first drawn to.
+    #[must_use]
+    pub fn finish(self) -> Patch<I::Pixel> {
+        Patch::new(self.changes)
+    }
+
+    /// Saves the current value of a pixel before it is changed.
+    fn save(&mut self, x: u32, y: u32) {
+        self.changes.push((y, x, self.image.get_pixel(x, y)));
+    }
+}
+
+impl<I> GenericImageView for Recorder<'_, I>
+where
+    I: GenericImage,
+{
+    type Pixel = I::Pixel;
+
+    fn dimensions(&self) -> (u32, u32) {
+        self.image.dimensions()
+    }
+
+    #[allow(deprecated)]
+    fn bounds(&self) -> (u32, u32, u32, u32) {
+        self.image.bounds()
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Self::Pixel {
+        self.image.get_pixel(x, y)
+    }
+}
+
+impl<I> GenericImage for Recorder<'_, I>
+where
+    I: GenericImage,
+{
+    /// Returns the underlying pixel.  The pixel is saved as if it was drawn
+    /// to, since it may be changed through the reference.
+    #[allow(deprecated)]
+    fn get_pixel_mut(&mut self, x: u32, y: u32) -> &mut Self::Pixel {
+        self.save(x, y);
+        self.image.get_pixel_mut(x, y)
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        self.save(x, y);
+        self.image.put_pixel(x, y, pixel);
+    }
+
+    #[allow(deprecated)]
+    fn blend_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        self.save(x, y);
+        self.image.blend_pixel(x, y, pixel);
+    }
+}
+
+/// The previous values of the pixels changed while drawing, stored as runs
+/// of neighboring pixels on each row.  Created by [`Recorder::finish`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Patch<P>
+where
+    P: Pixel,
+{
+    spans: Vec<PatchSpan<P>>,
+}
+
+/// A run of saved pixels starting at `(x, y)` and going right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PatchSpan<P> {
+    x: u32,
+    y: u32,
+    pixels: Vec<P>,
+}
+
+impl<P> Patch<P>
+where
+    P: Pixel,
+{
+    /// Groups the recorded pixels into runs, keeping the first value saved
+    /// for each pixel (its value from before any drawing).
+    fn new(mut changes: Vec<(u32, u32, P)>) -> Self {
+        // A stable sort keeps the earliest value first for each pixel
+        changes.sort_by_key(|&(y, x, _)| (y, x));
+        changes.dedup_by_key(|&mut (y, x, _)| (y, x));
+
+        let mut spans: Vec<PatchSpan<P>> = Vec::new();
+        for (y, x, px) in changes {
+            match spans.last_mut() {
+                Some(span) if span.y == y && span.x as usize + span.pixels.len() == x as usize => {
+                    span.pixels.push(px);
+                }
+                _ => spans.push(PatchSpan {
+                    x,
+                    y,
+                    pixels: vec![px],
+                }),
+            }
+        }
+        Self { spans }
+    }
+
+    /// Returns the number of pixels saved in the patch.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.spans.iter().map(|span| span.pixels.len()).sum()
+    }
+
+    /// Returns true if no pixels were drawn while recording.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the number of runs of neighboring pixels the patch is stored as.
+    #[must_use]
+    pub fn span_count(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Puts the saved pixels back into an image, undoing everything drawn
+    /// while recording.  Pixels outside of the image are skipped.
+    pub fn revert<I>(&self, image: &mut I)
+    where
+        I: GenericImage<Pixel = P>,
+    {
+        let (width, height) = image.dimensions();
+        for span in self.spans.iter().filter(|span| span.y < height) {
+            for (x, px) in (span.x..width).zip(&span.pixels) {
+                image.put_pixel(x, span.y, *px);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn keeps_first_value_in_spans() {
+        let white = Rgba([255, 255, 255, 255]);
+        let mut image = image::RgbaImage::from_pixel(20, 20, white);
+        image.put_pixel(5, 5, Rgba([0, 255, 0, 255]));
+        let original = image.clone();
+
+        let mut recorder = Recorder::new(&mut image);
+        crate::lines::line(&mut recorder, (2, 5), (10, 5), Rgba([255, 0, 0, 255]));
+        crate::lines::line(&mut recorder, (5, 2), (5, 8), Rgba([0, 0, 255, 255]));
+        let patch = recorder.finish();
+
+        // One run for the horizontal line, and one pixel on each other row
+        assert_eq!(patch.len(), 9 + 6);
+        assert_eq!(patch.span_count(), 7);
+        assert_eq!(*image.get_pixel(5, 5), Rgba([0, 0, 255, 255]));
+
+        patch.revert(&mut image);
+        assert_eq!(image, original);
+    }
+
+    #[test]
+    fn nothing_drawn() {
+        let mut image = crate::test::img::blank((10, 10));
+        let patch = Recorder::new(&mut image).finish();
+        assert!(patch.is_empty());
+        assert_eq!(patch.len(), 0);
+    }
+}
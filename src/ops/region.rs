@@ -0,0 +1,257 @@
+use super::blend::for_each_in_rect;
+use crate::shapes::Rect;
+
+/// Tints the pixels inside a rectangle by multiplying them with `color`.
+///
+/// `strength` ranges from `0.0` (no change) to `1.0` (fully multiplied by
+/// `color`), and the alpha channel of each pixel is left unchanged.  This is
+/// useful for highlighting an area of an existing image, e.g. an annotated
+/// screenshot.  Each row is modified directly on the image's underlying buffer.
+///
+/// # Panics
+///
+/// Panics if strength is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::tint_region;
+/// use freehand::shapes::Rect;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(10, 10, Rgba([200, 200, 200, 255]));
+/// tint_region(&mut image, Rect::new((0, 0), 5, 5), Rgba([255, 255, 0, 255]), 0.5);
+/// assert_eq!(*image.get_pixel(2, 2), Rgba([200, 200, 100, 255]));
+/// assert_eq!(*image.get_pixel(5, 5), Rgba([200, 200, 200, 255]));
+/// ```
+///
+/// See also: [`crate::Draw::tint_region`](crate::Draw::tint_region)
+pub fn tint_region(
+    image: &mut image::RgbaImage,
+    rect: Rect,
+    color: image::Rgba<u8>,
+    strength: f32,
+) {
+    assert!(
+        (0.0..=1.0).contains(&strength),
+        "Strength must be between 0.0 and 1.0.  strength={strength}"
+    );
+
+    // The amount each channel is scaled by
+    let scale =
+        [color[0], color[1], color[2]].map(|c| 1.0 - strength + strength * f32::from(c) / 255.0);
+
+    for_each_in_rect(image, rect, |px| {
+        for (c, scale) in px.iter_mut().zip(scale) {
+            *c = (f32::from(*c) * scale).round() as u8;
+        }
+    });
+}
+
+/// Inverts the RGB channels of the pixels inside a rectangle, leaving the
+/// alpha channel unchanged.
+///
+/// Useful for quickly marking a selection or the differences between two
+/// images.  Inverting the same region twice restores the original pixels.
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::invert_region;
+/// use freehand::shapes::Rect;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(10, 10, Rgba([255, 100, 0, 200]));
+/// invert_region(&mut image, Rect::new((0, 0), 5, 5));
+/// assert_eq!(*image.get_pixel(2, 2), Rgba([0, 155, 255, 200]));
+/// assert_eq!(*image.get_pixel(5, 5), Rgba([255, 100, 0, 200]));
+/// ```
+///
+/// See also: [`crate::Draw::invert_region`](crate::Draw::invert_region)
+pub fn invert_region(image: &mut image::RgbaImage, rect: Rect) {
+    for_each_in_rect(image, rect, |px| {
+        for c in &mut px[..3] {
+            *c = !*c;
+        }
+    });
+}
+
+/// Blurs the pixels inside a rectangle using a box blur, where each pixel
+/// becomes the average of the pixels within `radius` pixels of it.
+///
+/// Only pixels inside the rectangle are sampled, with the pixels along its
+/// edges repeated as needed.  The blur is done in two passes (horizontal then
+/// vertical), so the cost does not depend on the radius.  Colors are weighted
+/// by their alpha so transparent pixels do not darken their neighbors.
+///
+/// Useful for softening shadows or de-emphasizing the background behind an
+/// annotation.
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::box_blur_region;
+/// use freehand::shapes::Rect;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(10, 10, Rgba([255, 255, 255, 255]));
+/// image.put_pixel(5, 5, Rgba([0, 0, 0, 255]));
+///
+/// box_blur_region(&mut image, Rect::new((0, 0), 10, 10), 1);
+/// assert_eq!(*image.get_pixel(4, 4), Rgba([227, 227, 227, 255]));
+/// ```
+///
+/// See also: [`crate::Draw::box_blur_region`](crate::Draw::box_blur_region)
+pub fn box_blur_region(image: &mut image::RgbaImage, rect: Rect, radius: u32) {
+    let area = rect.intersect(Rect::new((0, 0), image.height(), image.width()));
+    if radius == 0 || area.width == 0 || area.height == 0 {
+        return;
+    }
+    let (width, height) = (area.width as usize, area.height as usize);
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for_each_in_rect(image, area, |px| {
+        let a = f32::from(px[3]) / 255.0;
+        pixels.push([
+            f32::from(px[0]) * a,
+            f32::from(px[1]) * a,
+            f32::from(px[2]) * a,
+            a,
+        ]);
+    });
+
+    // Each pass transposes the pixels, so two passes restore the original layout
+    let pixels = blur_transpose(&pixels, width, height, radius as usize);
+    let pixels = blur_transpose(&pixels, height, width, radius as usize);
+
+    let mut pixels = pixels.into_iter();
+    for_each_in_rect(image, area, |px| {
+        if let Some([r, g, b, a]) = pixels.next() {
+            if a > 0.0 {
+                px[0] = (r / a).round() as u8;
+                px[1] = (g / a).round() as u8;
+                px[2] = (b / a).round() as u8;
+            }
+            px[3] = (a * 255.0).round() as u8;
+        }
+    });
+}
+
+/// Blurs each row of `src` (`width` by `height` pixels) horizontally, and
+/// returns the result transposed (`height` by `width` pixels).
+#[allow(clippy::cast_possible_wrap)] // sizes come from u32 image dimensions
+fn blur_transpose(src: &[[f32; 4]], width: usize, height: usize, radius: usize) -> Vec<[f32; 4]> {
+    // A larger radius would only repeat the edge pixels more
+    let radius = radius.min(width);
+    let scale = 1.0 / (radius * 2 + 1) as f32;
+    let mut out = vec![[0.0; 4]; width * height];
+
+    for (y, row) in src.chunks_exact(width).enumerate() {
+        let at = |i: isize| row[i.clamp(0, width as isize - 1) as usize];
+        let radius = radius as isize;
+
+        let mut sum = [0.0; 4];
+        for i in -radius..=radius {
+            add(&mut sum, at(i), 1.0);
+        }
+        for x in 0..width {
+            out[x * height + y] = sum.map(|c| c * scale);
+
+            let x = x as isize;
+            add(&mut sum, at(x + radius + 1), 1.0);
+            add(&mut sum, at(x - radius), -1.0);
+        }
+    }
+    out
+}
+
+/// Adds `px` multiplied by `sign` to `sum`.
+fn add(sum: &mut [f32; 4], px: [f32; 4], sign: f32) {
+    for (s, c) in sum.iter_mut().zip(px) {
+        *s += c * sign;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn tint_clips_and_keeps_alpha() {
+        let mut image = image::RgbaImage::from_pixel(10, 10, Rgba([100, 200, 50, 128]));
+        tint_region(
+            &mut image,
+            Rect::new((7, -3), 20, 20),
+            Rgba([0, 255, 255, 255]),
+            1.0,
+        );
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let expected = if x >= 7 {
+                Rgba([0, 200, 50, 128])
+            } else {
+                Rgba([100, 200, 50, 128])
+            };
+            assert_eq!(*px, expected, "({x}, {y})");
+        }
+
+        tint_region(
+            &mut image,
+            Rect::new((0, 0), 10, 10),
+            Rgba([0, 0, 0, 255]),
+            0.0,
+        );
+        assert_eq!(*image.get_pixel(0, 0), Rgba([100, 200, 50, 128]));
+    }
+
+    #[test]
+    fn invert_twice() {
+        let mut image = crate::test::img::blank((10, 10));
+        let original = image.clone();
+        let rect = Rect::new((-5, 3), 4, 30);
+
+        invert_region(&mut image, rect);
+        for (x, y, px) in image.enumerate_pixels() {
+            let inverted = (3..7).contains(&y);
+            assert_eq!(px.0 == [0, 0, 0, 255], inverted, "({x}, {y})");
+        }
+
+        invert_region(&mut image, rect);
+        assert_eq!(image, original);
+    }
+
+    #[test]
+    fn blur_spreads_within_region() {
+        let mut image = crate::test::img::blank((20, 20));
+        image.put_pixel(10, 10, Rgba([0, 0, 0, 255]));
+        image.put_pixel(2, 2, Rgba([0, 0, 0, 255]));
+        box_blur_region(&mut image, Rect::new((5, 5), 30, 30), 2);
+
+        // The pixel outside of the region is untouched
+        assert_eq!(*image.get_pixel(2, 2), Rgba([0, 0, 0, 255]));
+        assert_eq!(*image.get_pixel(4, 10), Rgba([255, 255, 255, 255]));
+
+        // A 5x5 box spreads the black pixel evenly over 25 pixels
+        let gray = Rgba([245, 245, 245, 255]);
+        for (x, y, px) in image.enumerate_pixels() {
+            if (8..=12).contains(&x) && (8..=12).contains(&y) {
+                assert_eq!(*px, gray, "({x}, {y})");
+            } else if (x, y) != (2, 2) {
+                assert_eq!(*px, Rgba([255, 255, 255, 255]), "({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn blur_ignores_transparent_color() {
+        let mut image = image::RgbaImage::from_pixel(4, 1, Rgba([255, 0, 0, 255]));
+        image.put_pixel(0, 0, Rgba([0, 0, 0, 0]));
+        box_blur_region(&mut image, Rect::new((0, 0), 1, 4), 1);
+
+        // The transparent pixel only contributes to the alpha channel
+        assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 85]));
+        assert_eq!(*image.get_pixel(1, 0), Rgba([255, 0, 0, 170]));
+        assert_eq!(*image.get_pixel(3, 0), Rgba([255, 0, 0, 255]));
+    }
+}
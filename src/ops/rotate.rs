@@ -0,0 +1,153 @@
+use crate::pt::Point;
+use crate::Angle;
+use image::RgbaImage;
+
+/// Blends a rotated copy of `src` onto `dst`, with the center of `src` placed
+/// at `center`.
+///
+/// Positive angles rotate counterclockwise, following the same
+/// [angle conventions](crate#note-on-angles) as the rest of the crate.  Pixels
+/// are sampled bilinearly, and the area outside of `src` is treated as
+/// transparent, so the edges of the rotated image are antialiased.  Each pixel
+/// is blended using `opacity` multiplied by its alpha, and the parts that fall
+/// outside of `dst` are skipped.
+///
+/// The center of an image with an even width or height lies between two
+/// pixels, so `center` needs a fractional part of `0.5` along that axis for
+/// an unrotated copy to line up exactly with the pixels of `dst`.
+///
+/// # Panics
+///
+/// Panics if opacity is not between 0.0 and 1.0
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::rotate_blit;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let sprite = RgbaImage::from_pixel(64, 32, Rgba([255, 0, 0, 255]));
+///
+/// rotate_blit(&mut image, &sprite, (200.0, 200.0), 30, 1.0);
+/// ```
+///
+/// See also: [`composite`](super::composite), [`Draw::rotate_blit`](crate::Draw::rotate_blit)
+///
+pub fn rotate_blit<C, A>(dst: &mut RgbaImage, src: &RgbaImage, center: C, angle: A, opacity: f32)
+where
+    C: Point<f64>,
+    A: Angle,
+{
+    check_opacity!(opacity);
+    if src.width() == 0 || src.height() == 0 || opacity == 0.0 {
+        return;
+    }
+
+    let (sin, cos) = angle.radians().sin_cos();
+    let (hw, hh) = (f64::from(src.width()) / 2.0, f64::from(src.height()) / 2.0);
+    let (cx, cy) = (center.x(), center.y());
+
+    // Half the size of the rotated image's bounding box, plus the antialiased edge
+    let ex = (hw * cos).abs() + (hh * sin).abs() + 1.0;
+    let ey = (hw * sin).abs() + (hh * cos).abs() + 1.0;
+    let x0 = (cx - ex).floor().max(0.0);
+    let y0 = (cy - ey).floor().max(0.0);
+    let x1 = (cx + ex).ceil().min(f64::from(dst.width()) - 1.0);
+    let y1 = (cy + ey).ceil().min(f64::from(dst.height()) - 1.0);
+    if x0 > x1 || y0 > y1 {
+        return;
+    }
+
+    for y in y0 as u32..=y1 as u32 {
+        let dy = f64::from(y) - cy;
+        for x in x0 as u32..=x1 as u32 {
+            let dx = f64::from(x) - cx;
+            // Rotate back into src, where pixel (0, 0) is centered on (0.5, 0.5)
+            let sx = dx.mul_add(cos, -dy * sin) + hw - 0.5;
+            let sy = dx.mul_add(sin, dy * cos) + hh - 0.5;
+            if sx <= -1.0
+                || sy <= -1.0
+                || sx >= f64::from(src.width())
+                || sy >= f64::from(src.height())
+            {
+                continue;
+            }
+
+            let color = crate::shapes::bilinear(src, sx as f32, sy as f32, false);
+            if color[3] != 0 {
+                let a = opacity * f32::from(color[3]) / 255.0;
+                super::blend_at(dst, x, y, a, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn sprite() -> RgbaImage {
+        RgbaImage::from_fn(6, 4, |x, y| Rgba([x as u8 * 40, y as u8 * 60, 0, 255]))
+    }
+
+    #[test]
+    fn unrotated_matches_composite() {
+        let src = sprite();
+        let mut expected = crate::test::img::blank((20, 20));
+        crate::ops::composite(
+            &mut expected,
+            &src,
+            (3, 5),
+            0.5,
+            crate::ops::BlendMode::SourceOver,
+        );
+
+        let mut image = crate::test::img::blank((20, 20));
+        // The upper left pixel's center is at 2.5 pixels left of the sprite's center
+        rotate_blit(&mut image, &src, (5.5, 6.5), 0, 0.5);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn quarter_turns() {
+        let src = sprite();
+        let mut image = crate::test::img::blank((20, 20));
+        rotate_blit(&mut image, &src, (10.5, 10.5), 90, 1.0);
+
+        // Rotated counterclockwise, the right side of src is now at the top
+        for (x, y, px) in src.enumerate_pixels() {
+            let (dx, dy) = (9 + y, 13 - x);
+            assert_eq!(image.get_pixel(dx, dy), px, "({x}, {y})");
+        }
+        let changed = image
+            .pixels()
+            .filter(|px| px.0 != [255, 255, 255, 255])
+            .count();
+        assert_eq!(changed, 24);
+    }
+
+    #[test]
+    fn antialiased_edges() -> Result<(), image::ImageError> {
+        let src = RgbaImage::from_pixel(40, 20, Rgba([255, 0, 0, 255]));
+        let mut image = crate::test::img::blank((100, 100));
+        rotate_blit(&mut image, &src, (50.0, 50.0), 30, 1.0);
+        rotate_blit(&mut image, &src, (-5.0, 95.0), -45, 1.0);
+
+        assert_eq!(image.get_pixel(50, 50).0, [255, 0, 0, 255]);
+        assert!(image.pixels().any(|px| px[1] > 10 && px[1] < 245));
+        assert_eq!(image.get_pixel(50, 20).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(2, 93).0, [255, 0, 0, 255]);
+
+        // The covered area matches the size of src
+        let mut image = crate::test::img::blank((100, 100));
+        rotate_blit(&mut image, &src, (50.0, 50.0), 30, 1.0);
+        let area: f32 = image
+            .pixels()
+            .map(|px| f32::from(255 - px[1]) / 255.0)
+            .sum();
+        assert!((area - 800.0).abs() < 8.0, "{area}");
+        image.save("images/rotate_blit.png")
+    }
+}
@@ -0,0 +1,355 @@
+// These types are exported publicly in a different module - keep the module prefix
+#![allow(clippy::module_name_repetitions)]
+
+use image::{GenericImage, GenericImageView, GrayImage, Luma};
+
+/// How a [`StencilWriter`] changes the stencil values under the shapes drawn
+/// to it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StencilOp {
+    /// Replaces the value
+    Set(u8),
+    /// Adds one to the value, stopping at 255
+    Increment,
+    /// Subtracts one from the value, stopping at 0
+    Decrement,
+}
+
+/// Which pixels a [`Stenciled`] image lets through, by comparing the stencil
+/// value at each pixel.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StencilTest {
+    /// Draws where the stencil value equals the given value
+    Equal(u8),
+    /// Draws where the stencil value does not equal the given value
+    NotEqual(u8),
+}
+
+impl StencilTest {
+    /// Returns true if a pixel with the stencil value `value` passes the test.
+    #[must_use]
+    pub fn passes(self, value: u8) -> bool {
+        match self {
+            Self::Equal(v) => value == v,
+            Self::NotEqual(v) => value != v,
+        }
+    }
+}
+
+/// A buffer of one value per pixel used to restrict where shapes are drawn.
+///
+/// Shapes are first drawn into the stencil with a [`StencilWriter`], which
+/// changes the values under them instead of drawing any colors.  Later
+/// shapes are drawn to an image through a [`Stenciled`] wrapper, which only
+/// draws the pixels whose stencil value passes a [`StencilTest`].  Both
+/// wrappers implement [`image::GenericImage`], so any drawing function can be
+/// used with them.
+///
+/// This allows drawing unions, differences, and intersections of shapes
+/// without working out the combined shape first.
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::{Stencil, StencilOp, StencilTest};
+/// use freehand::conics::pie_slice_filled;
+/// use freehand::shapes::rectangle_filled;
+/// use image::{Luma, Rgba, RgbaImage};
+///
+/// let mut image = RgbaImage::new(100, 100);
+/// let mut stencil = Stencil::new(100, 100);
+///
+/// // Mark a pie slice, then cut a rectangle out of it
+/// pie_slice_filled(&mut stencil.writer(StencilOp::Set(1)), 0, 90, 40, (50, 50), Luma([0]));
+/// rectangle_filled(&mut stencil.writer(StencilOp::Set(0)), (60, 30), 10, 10, Luma([0]));
+///
+/// // Fill the whole image, but only where the stencil is 1
+/// let red = Rgba([255, 0, 0, 255]);
+/// rectangle_filled(&mut stencil.test(&mut image, StencilTest::Equal(1)), (0, 0), 100, 100, red);
+///
+/// assert_eq!(*image.get_pixel(60, 40), red);
+/// assert_eq!(*image.get_pixel(65, 35), Rgba([0, 0, 0, 0]));
+/// assert_eq!(*image.get_pixel(40, 60), Rgba([0, 0, 0, 0]));
+/// ```
+///
+/// See also: [`Draw::with_stencil`](crate::Draw::with_stencil)
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stencil {
+    values: GrayImage,
+}
+
+impl Stencil {
+    /// Creates a stencil with every value set to 0.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            values: GrayImage::new(width, height),
+        }
+    }
+
+    /// Returns the width and height of the stencil.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.values.dimensions()
+    }
+
+    /// Returns the stencil value at a pixel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside of the stencil
+    #[must_use]
+    pub fn value(&self, x: u32, y: u32) -> u8 {
+        self.values.get_pixel(x, y)[0]
+    }
+
+    /// Sets every value back to 0.
+    pub fn clear(&mut self) {
+        self.values.fill(0);
+    }
+
+    /// Returns the stencil values as a grayscale image, which can be used as a
+    /// mask (e.g. with [`fill_masked`](super::fill_masked)).
+    #[must_use]
+    pub fn as_image(&self) -> &GrayImage {
+        &self.values
+    }
+
+    /// Wraps the stencil so that shapes drawn to it change the values under
+    /// them using `op`.  The colors given to the drawing functions are ignored.
+    ///
+    /// Each pixel is changed at most once by the same writer, so shapes that
+    /// draw some pixels twice are only counted once by
+    /// [`StencilOp::Increment`].
+    pub fn writer(&mut self, op: StencilOp) -> StencilWriter<'_> {
+        let (width, height) = self.dimensions();
+        StencilWriter {
+            stencil: self,
+            op,
+            touched: vec![false; width as usize * height as usize],
+        }
+    }
+
+    /// Wraps an image so that only the pixels whose stencil value passes
+    /// `test` are drawn.  Pixels outside of the stencil are never drawn.
+    pub fn test<'s, 'i, I>(&'s self, image: &'i mut I, test: StencilTest) -> Stenciled<'s, 'i, I>
+    where
+        I: GenericImage,
+    {
+        Stenciled {
+            stencil: self,
+            image,
+            test,
+        }
+    }
+}
+
+/// A [`Stencil`] wrapper that changes the stencil values under every pixel
+/// drawn to it.  Created with [`Stencil::writer`].
+pub struct StencilWriter<'s> {
+    stencil: &'s mut Stencil,
+    op: StencilOp,
+    touched: Vec<bool>,
+}
+
+impl GenericImageView for StencilWriter<'_> {
+    type Pixel = Luma<u8>;
+
+    fn dimensions(&self) -> (u32, u32) {
+        self.stencil.dimensions()
+    }
+
+    #[allow(deprecated)]
+    fn bounds(&self) -> (u32, u32, u32, u32) {
+        self.stencil.values.bounds()
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Self::Pixel {
+        *self.stencil.values.get_pixel(x, y)
+    }
+}
+
+impl GenericImage for StencilWriter<'_> {
+    /// Returns the underlying stencil value.  Writing through the reference
+    /// bypasses the [`StencilOp`].
+    fn get_pixel_mut(&mut self, x: u32, y: u32) -> &mut Self::Pixel {
+        self.stencil.values.get_pixel_mut(x, y)
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, _: Self::Pixel) {
+        let i = y as usize * self.stencil.values.width() as usize + x as usize;
+        if std::mem::replace(&mut self.touched[i], true) {
+            return;
+        }
+
+        let value = &mut self.stencil.values.get_pixel_mut(x, y)[0];
+        *value = match self.op {
+            StencilOp::Set(v) => v,
+            StencilOp::Increment => value.saturating_add(1),
+            StencilOp::Decrement => value.saturating_sub(1),
+        };
+    }
+
+    /// Same as [`put_pixel`](Self::put_pixel), as the colors are ignored.
+    fn blend_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        self.put_pixel(x, y, pixel);
+    }
+}
+
+/// An image wrapper that only draws the pixels whose [`Stencil`] value passes
+/// a [`StencilTest`].  Created with [`Stencil::test`].
+pub struct Stenciled<'s, 'i, I>
+where
+    I: GenericImage,
+{
+    stencil: &'s Stencil,
+    image: &'i mut I,
+    test: StencilTest,
+}
+
+impl<I> Stenciled<'_, '_, I>
+where
+    I: GenericImage,
+{
+    /// Returns true if the pixel is inside the stencil and passes the test.
+    fn passes(&self, x: u32, y: u32) -> bool {
+        let (width, height) = self.stencil.dimensions();
+        x < width && y < height && self.test.passes(self.stencil.value(x, y))
+    }
+}
+
+impl<I> GenericImageView for Stenciled<'_, '_, I>
+where
+    I: GenericImage,
+{
+    type Pixel = I::Pixel;
+
+    fn dimensions(&self) -> (u32, u32) {
+        self.image.dimensions()
+    }
+
+    #[allow(deprecated)]
+    fn bounds(&self) -> (u32, u32, u32, u32) {
+        self.image.bounds()
+    }
+
+    fn get_pixel(&self, x: u32, y: u32) -> Self::Pixel {
+        self.image.get_pixel(x, y)
+    }
+}
+
+impl<I> GenericImage for Stenciled<'_, '_, I>
+where
+    I: GenericImage,
+{
+    /// Returns the underlying pixel.  Writing through the reference bypasses
+    /// the stencil test.
+    #[allow(deprecated)]
+    fn get_pixel_mut(&mut self, x: u32, y: u32) -> &mut Self::Pixel {
+        self.image.get_pixel_mut(x, y)
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        if self.passes(x, y) {
+            self.image.put_pixel(x, y, pixel);
+        }
+    }
+
+    #[allow(deprecated)]
+    fn blend_pixel(&mut self, x: u32, y: u32, pixel: Self::Pixel) {
+        if self.passes(x, y) {
+            self.image.blend_pixel(x, y, pixel);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shapes::rectangle_filled;
+    use image::Rgba;
+
+    #[test]
+    fn increment_counts_overlaps_once_per_writer() {
+        let mut stencil = Stencil::new(10, 10);
+        rectangle_filled(
+            &mut stencil.writer(StencilOp::Increment),
+            (0, 0),
+            4,
+            4,
+            Luma([0]),
+        );
+        {
+            // Overlapping shapes drawn with the same writer only count once
+            let mut writer = stencil.writer(StencilOp::Increment);
+            rectangle_filled(&mut writer, (2, 2), 4, 4, Luma([0]));
+            rectangle_filled(&mut writer, (2, 2), 2, 2, Luma([0]));
+        }
+
+        assert_eq!(stencil.value(0, 0), 1);
+        assert_eq!(stencil.value(3, 3), 2);
+        assert_eq!(stencil.value(5, 5), 1);
+        assert_eq!(stencil.value(6, 6), 0);
+
+        rectangle_filled(
+            &mut stencil.writer(StencilOp::Decrement),
+            (0, 0),
+            10,
+            10,
+            Luma([0]),
+        );
+        assert_eq!(stencil.value(3, 3), 1);
+        assert_eq!(stencil.value(6, 6), 0);
+    }
+
+    #[test]
+    fn intersection_and_difference() {
+        let red = Rgba([255, 0, 0, 255]);
+        let mut stencil = Stencil::new(10, 10);
+        rectangle_filled(
+            &mut stencil.writer(StencilOp::Increment),
+            (0, 0),
+            6,
+            6,
+            Luma([0]),
+        );
+        rectangle_filled(
+            &mut stencil.writer(StencilOp::Increment),
+            (3, 3),
+            6,
+            6,
+            Luma([0]),
+        );
+
+        let mut both = image::RgbaImage::new(12, 12);
+        rectangle_filled(
+            &mut stencil.test(&mut both, StencilTest::Equal(2)),
+            (0, 0),
+            12,
+            12,
+            red,
+        );
+        for (x, y, px) in both.enumerate_pixels() {
+            let inside = (3..6).contains(&x) && (3..6).contains(&y);
+            assert_eq!(*px == red, inside, "({x}, {y})");
+        }
+
+        let mut either = image::RgbaImage::new(12, 12);
+        rectangle_filled(
+            &mut stencil.test(&mut either, StencilTest::NotEqual(0)),
+            (0, 0),
+            12,
+            12,
+            red,
+        );
+        // Pixels outside of the stencil are never drawn
+        assert_eq!(*either.get_pixel(10, 10), Rgba([0, 0, 0, 0]));
+        assert_eq!(
+            either.pixels().filter(|&&px| px == red).count(),
+            36 + 36 - 9
+        );
+    }
+}
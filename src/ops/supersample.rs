@@ -0,0 +1,200 @@
+use super::{AlphaMode, Blend};
+use image::RgbaImage;
+
+/// Bytes per pixel
+const PX: usize = std::mem::size_of::<image::Rgba<u8>>();
+
+/// The number of samples taken along each axis of a pixel by [`supersample`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Supersample {
+    /// 2x2 samples per pixel
+    X2,
+    /// 4x4 samples per pixel.  This is the default.
+    X4,
+}
+
+impl Supersample {
+    /// Returns how many times larger the temporary image is along each axis.
+    #[must_use]
+    pub fn factor(self) -> u32 {
+        match self {
+            Self::X2 => 2,
+            Self::X4 => 4,
+        }
+    }
+}
+
+impl Default for Supersample {
+    fn default() -> Self {
+        Self::X4
+    }
+}
+
+/// Antialiases shapes that do not have an antialiased variant by drawing them
+/// at a higher resolution and scaling the result down into `image`.
+///
+/// `draw` is given a transparent image [`Supersample::factor`] times larger
+/// than `image` along each axis, so every coordinate and size passed to the
+/// drawing functions inside it must be multiplied by the factor.  The pixel
+/// at `(x, y)` in `image` is made from the samples from `x * factor` to
+/// `(x + 1) * factor - 1` along each axis.  Each block of samples is averaged
+/// (a box filter) and the result is blended onto `image` using `mode`.
+///
+/// This is slower and uses more memory than the dedicated antialiased
+/// functions (the temporary image holds `factor²` times as many pixels), but
+/// works with every shape.
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::{supersample, BlendMode, Supersample};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::from_pixel(100, 100, Rgba([255, 255, 255, 255]));
+///
+/// supersample(&mut image, Supersample::X4, BlendMode::SourceOver, |big| {
+///     // A triangle with corners at (10, 10), (90, 20), and (40, 90)
+///     let triangle = freehand::shapes::Polygon::new([(40, 40), (360, 80), (160, 360)]);
+///     triangle.draw_filled(big, Rgba([255, 0, 0, 255]));
+/// });
+/// ```
+///
+/// See also: [`Draw::supersample`](crate::Draw::supersample)
+///
+pub fn supersample<F>(image: &mut RgbaImage, samples: Supersample, mode: impl Into<Blend>, draw: F)
+where
+    F: FnOnce(&mut RgbaImage),
+{
+    let factor = samples.factor();
+    let mut big = RgbaImage::new(image.width() * factor, image.height() * factor);
+    draw(&mut big);
+
+    let mode = mode.into();
+    if let Some((x, y, small)) = downsample(&big, factor, mode.alpha) {
+        super::composite(image, &small, (x, y), 1.0, mode);
+    }
+}
+
+/// Averages each `factor` by `factor` block of `big`, only keeping the area
+/// that was drawn to.  Returns the upper left corner of the area, in the
+/// scaled down coordinates, along with the scaled down image.
+#[allow(clippy::cast_possible_wrap)]
+fn downsample(big: &RgbaImage, factor: u32, alpha: AlphaMode) -> Option<(i32, i32, RgbaImage)> {
+    // Find the blocks that were drawn to
+    let (mut x0, mut y0, mut x1, mut y1) = (u32::MAX, u32::MAX, 0, 0);
+    for (x, y, px) in big.enumerate_pixels() {
+        if px[3] != 0 {
+            x0 = x0.min(x / factor);
+            y0 = y0.min(y / factor);
+            x1 = x1.max(x / factor);
+            y1 = y1.max(y / factor);
+        }
+    }
+    if x0 > x1 {
+        return None;
+    }
+
+    let samples = factor * factor;
+    let mut small = RgbaImage::new(x1 - x0 + 1, y1 - y0 + 1);
+    let buf: &[u8] = big;
+
+    for (sx, sy, out) in small.enumerate_pixels_mut() {
+        let mut sum = [0u32; 4];
+        for row in 0..factor {
+            let i =
+                crate::rgba_array_index(big.width(), (x0 + sx) * factor, (y0 + sy) * factor + row);
+            for px in buf[i..i + factor as usize * PX].chunks_exact(PX) {
+                let a = u32::from(px[3]);
+                // Straight colors are weighted by how opaque each sample is
+                let w = match alpha {
+                    AlphaMode::Straight => a,
+                    AlphaMode::Premultiplied => 1,
+                };
+                for (s, c) in sum.iter_mut().zip(&px[..3]) {
+                    *s += u32::from(*c) * w;
+                }
+                sum[3] += a;
+            }
+        }
+
+        let div = match alpha {
+            AlphaMode::Straight => sum[3],
+            AlphaMode::Premultiplied => samples,
+        };
+        if sum[3] != 0 {
+            for (c, s) in out.0.iter_mut().zip(&sum[..3]) {
+                *c = ((s + div / 2) / div) as u8;
+            }
+            out[3] = ((sum[3] + samples / 2) / samples) as u8;
+        }
+    }
+    Some((x0 as i32, y0 as i32, small))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::BlendMode;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn aligned_rectangle() {
+        let mut image = crate::test::img::blank((20, 20));
+        supersample(&mut image, Supersample::X4, BlendMode::SourceOver, |big| {
+            crate::shapes::rectangle_filled(big, (8, 12), 20, 16, RED);
+        });
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let inside = (2..6).contains(&x) && (3..8).contains(&y);
+            assert_eq!(px.0 == RED.0, inside, "({x}, {y})");
+            if !inside {
+                assert_eq!(px.0, [255, 255, 255, 255], "({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn partial_coverage() {
+        let mut image = crate::test::img::blank((20, 20));
+        supersample(&mut image, Supersample::X2, BlendMode::SourceOver, |big| {
+            // Covers the right half of pixel 2 and the top half of row 3
+            crate::shapes::rectangle_filled(big, (5, 0), 7, 6, Rgba([0, 0, 0, 255]));
+        });
+
+        assert_eq!(image.get_pixel(3, 1).0, [0, 0, 0, 255]);
+        assert!((126..=128).contains(&image.get_pixel(2, 1)[0]));
+        assert!((126..=128).contains(&image.get_pixel(3, 3)[0]));
+        assert!((190..=192).contains(&image.get_pixel(2, 3)[0]));
+        assert_eq!(image.get_pixel(1, 1).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn supersampled_polygon() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((100, 100));
+        supersample(&mut image, Supersample::X4, BlendMode::SourceOver, |big| {
+            let poly = crate::shapes::Polygon::new([(40, 40), (360, 80), (160, 360)]);
+            poly.draw_filled(big, RED);
+        });
+
+        // The covered area matches the triangle's area of 3050 pixels
+        let area: f64 = image
+            .pixels()
+            .map(|px| f64::from(255 - px[1]) / 255.0)
+            .sum();
+        assert!((area - 3050.0).abs() < 5.0, "{area}");
+        assert!(image.pixels().any(|px| px[1] > 0 && px[1] < 255));
+
+        image.save("images/supersample_polygon.png")
+    }
+
+    #[test]
+    fn nothing_drawn() {
+        let mut image = crate::test::img::blank((10, 10));
+        let before = image.clone();
+        supersample(&mut image, Supersample::X2, BlendMode::SourceOver, |_| {});
+        assert_eq!(image, before);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::pt::Point;
+use crate::shapes::{triangle_textured, Sampling};
+use image::RgbaImage;
+
+/// Draws `src` stretched onto a quadrilateral in `dst`.
+///
+/// `corners` gives where the upper left, upper right, lower right, and lower
+/// left corners of `src` are placed, in that order.  The quadrilateral is
+/// split into two [textured triangles](crate::shapes::triangle_textured)
+/// along the diagonal from the upper left to the lower right corner, so any
+/// parallelogram gives an exact affine warp (scaling, rotation, and skewing),
+/// while other shapes bend slightly along the diagonal.
+///
+/// The pixels of `src` are blended using their alpha.  An unwarped copy of
+/// an image at `(x, y)` uses the corners `(x, y)`, `(x + width, y)`,
+/// `(x + width, y + height)`, and `(x, y + height)`.
+///
+/// # Example
+///
+/// ```
+/// use freehand::ops::warp_blit;
+/// use freehand::shapes::Sampling;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let sprite = RgbaImage::from_pixel(32, 32, Rgba([255, 0, 0, 255]));
+///
+/// // Skew the sprite to the right
+/// warp_blit(&mut image, &sprite, [(120, 100), (220, 100), (180, 200), (80, 200)], Sampling::Bilinear);
+/// ```
+///
+/// See also: [`Draw::warp_blit`](crate::Draw::warp_blit)
+///
+pub fn warp_blit<P>(dst: &mut RgbaImage, src: &RgbaImage, corners: [P; 4], sampling: Sampling)
+where
+    P: Point<i32>,
+{
+    let [tl, tr, br, bl] = corners;
+    let upper = [(tl, (0.0, 0.0)), (tr, (1.0, 0.0)), (br, (1.0, 1.0))];
+    let lower = [(tl, (0.0, 0.0)), (br, (1.0, 1.0)), (bl, (0.0, 1.0))];
+    triangle_textured(dst, half_pixel(upper), src, sampling);
+    triangle_textured(dst, half_pixel(lower), src, sampling);
+}
+
+/// Moves the texture coordinates of a triangle by half a pixel, so the centers
+/// of the drawn pixels line up with the centers of the texture's pixels
+/// instead of their corners.  Otherwise an unwarped copy would sample exactly
+/// between pixels of the texture.
+fn half_pixel<P>(vertices: [(P, (f32, f32)); 3]) -> [(P, (f32, f32)); 3]
+where
+    P: Point<i32>,
+{
+    let [(a, uv_a), (b, uv_b), (c, uv_c)] = vertices;
+    let diff = |p: &P| {
+        (
+            f64::from(p.x()) - f64::from(a.x()),
+            f64::from(p.y()) - f64::from(a.y()),
+        )
+    };
+    let (e1x, e1y) = diff(&b);
+    let (e2x, e2y) = diff(&c);
+    let det = e1x * e2y - e1y * e2x;
+    if det == 0.0 {
+        return vertices;
+    }
+
+    // How far along each edge half a pixel down and to the right is
+    let along_b = (0.5 * e2y - 0.5 * e2x) / det;
+    let along_c = (0.5 * e1x - 0.5 * e1y) / det;
+    let du = (f64::from(uv_b.0 - uv_a.0) * along_b + f64::from(uv_c.0 - uv_a.0) * along_c) as f32;
+    let dv = (f64::from(uv_b.1 - uv_a.1) * along_b + f64::from(uv_c.1 - uv_a.1) * along_c) as f32;
+    vertices.map(|(pt, (u, v))| (pt, (u + du, v + dv)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn sprite() -> RgbaImage {
+        RgbaImage::from_fn(6, 4, |x, y| {
+            Rgba([x as u8 * 40, y as u8 * 60, 0, 100 + x as u8 * 20])
+        })
+    }
+
+    #[test]
+    fn unwarped_matches_composite() {
+        let src = sprite();
+        let mut expected = crate::test::img::blank((20, 20));
+        crate::ops::composite(
+            &mut expected,
+            &src,
+            (3, 5),
+            1.0,
+            crate::ops::BlendMode::SourceOver,
+        );
+        let corners = [(3, 5), (9, 5), (9, 9), (3, 9)];
+        for sampling in [Sampling::Nearest, Sampling::Bilinear] {
+            let mut image = crate::test::img::blank((20, 20));
+            warp_blit(&mut image, &src, corners, sampling);
+            assert_eq!(image, expected, "{sampling:?}");
+        }
+    }
+
+    #[test]
+    fn scaled() {
+        let src = sprite();
+        let mut image = crate::test::img::blank((20, 20));
+        // Twice the size
+        warp_blit(
+            &mut image,
+            &src,
+            [(2, 1), (14, 1), (14, 9), (2, 9)],
+            Sampling::Nearest,
+        );
+
+        let mut expected = crate::test::img::blank((20, 20));
+        for (x, y, px) in src.enumerate_pixels() {
+            let (dx, dy) = (2 + x * 2, 1 + y * 2);
+            for (ox, oy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                crate::ops::blend_at(
+                    &mut expected,
+                    dx + ox,
+                    dy + oy,
+                    f32::from(px[3]) / 255.0,
+                    *px,
+                );
+            }
+        }
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn skewed() -> Result<(), image::ImageError> {
+        let src = RgbaImage::from_fn(16, 16, |x, y| {
+            if (x / 4 + y / 4) % 2 == 0 {
+                Rgba([0, 0, 0, 255])
+            } else {
+                Rgba([255, 0, 0, 255])
+            }
+        });
+        let mut image = crate::test::img::blank((100, 100));
+        warp_blit(
+            &mut image,
+            &src,
+            [(30, 10), (90, 20), (70, 90), (10, 80)],
+            Sampling::Bilinear,
+        );
+
+        // Inside the quad is covered, the corners of the image are not
+        assert_eq!(image.get_pixel(50, 50)[1], 0);
+        assert_eq!(image.get_pixel(2, 2).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(97, 97).0, [255, 255, 255, 255]);
+        image.save("images/warp_blit.png")
+    }
+
+    #[test]
+    fn huge_corners() {
+        let (min, max) = (i32::MIN, i32::MAX);
+        // Half a pixel is a tiny part of the texture
+        let [(_, (u, v)), _, _] = half_pixel([
+            ((min, min), (0.0, 0.0)),
+            ((max, min), (1.0, 0.0)),
+            ((max, max), (1.0, 1.0)),
+        ]);
+        assert!(u > 0.0 && u < 1e-6, "{u}");
+        assert!(v > 0.0 && v < 1e-6, "{v}");
+    }
+}
@@ -0,0 +1,127 @@
+//! Named color constants and ready made palettes.
+//!
+//! The constants are opaque [`Rgba<u8>`] colors (except for [`TRANSPARENT`]),
+//! so they can be passed straight to any drawing function.  The palettes
+//! contain colors that are easy to tell apart, which is useful for the series
+//! in a chart; [`cycle`] repeats a palette forever when there are more series
+//! than colors.
+//!
+//! ```
+//! use freehand::palette::{self, BLUE, RED};
+//! use image::RgbaImage;
+//!
+//! let mut image = RgbaImage::new(400, 400);
+//! freehand::new(&mut image)
+//!     .line((0, 0), (399, 399), RED)
+//!     .line((0, 399), (399, 0), BLUE);
+//!
+//! // One color per line, reusing the palette after its last color
+//! for (i, color) in (0..20).zip(palette::tableau()) {
+//!     freehand::new(&mut image).line((i * 20, 0), (i * 20, 100), color);
+//! }
+//! ```
+//!
+//! See also: [`color::palette`](crate::color::palette) for generating evenly
+//! spaced hues.
+
+use image::Rgba;
+
+/// A fully transparent black.
+pub const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+/// `#000000`
+pub const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+/// `#ffffff`
+pub const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+/// `#808080`
+pub const GRAY: Rgba<u8> = Rgba([128, 128, 128, 255]);
+/// `#ff0000`
+pub const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+/// `#00ff00`
+pub const GREEN: Rgba<u8> = Rgba([0, 255, 0, 255]);
+/// `#0000ff`
+pub const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+/// `#ffff00`
+pub const YELLOW: Rgba<u8> = Rgba([255, 255, 0, 255]);
+/// `#00ffff`
+pub const CYAN: Rgba<u8> = Rgba([0, 255, 255, 255]);
+/// `#ff00ff`
+pub const MAGENTA: Rgba<u8> = Rgba([255, 0, 255, 255]);
+/// `#ffa500`
+pub const ORANGE: Rgba<u8> = Rgba([255, 165, 0, 255]);
+/// `#800080`
+pub const PURPLE: Rgba<u8> = Rgba([128, 0, 128, 255]);
+
+/// The ten colors of the classic Tableau palette, which is also the default
+/// color cycle in matplotlib.
+pub const TABLEAU_10: [Rgba<u8>; 10] = [
+    Rgba([31, 119, 180, 255]),  // blue
+    Rgba([255, 127, 14, 255]),  // orange
+    Rgba([44, 160, 44, 255]),   // green
+    Rgba([214, 39, 40, 255]),   // red
+    Rgba([148, 103, 189, 255]), // purple
+    Rgba([140, 86, 75, 255]),   // brown
+    Rgba([227, 119, 194, 255]), // pink
+    Rgba([127, 127, 127, 255]), // gray
+    Rgba([188, 189, 34, 255]),  // olive
+    Rgba([23, 190, 207, 255]),  // cyan
+];
+
+/// The eight colors of the Okabe-Ito palette, chosen to remain distinct for
+/// people with color vision deficiencies.
+pub const OKABE_ITO: [Rgba<u8>; 8] = [
+    Rgba([0, 0, 0, 255]),       // black
+    Rgba([230, 159, 0, 255]),   // orange
+    Rgba([86, 180, 233, 255]),  // sky blue
+    Rgba([0, 158, 115, 255]),   // bluish green
+    Rgba([240, 228, 66, 255]),  // yellow
+    Rgba([0, 114, 178, 255]),   // blue
+    Rgba([213, 94, 0, 255]),    // vermillion
+    Rgba([204, 121, 167, 255]), // reddish purple
+];
+
+/// Returns an endless iterator over `colors`, starting over after the last
+/// color.  Empty slices give an empty iterator.
+///
+/// ```
+/// use freehand::palette::{cycle, RED, BLUE};
+///
+/// let colors: Vec<_> = cycle(&[RED, BLUE]).take(3).collect();
+/// assert_eq!(colors, vec![RED, BLUE, RED]);
+/// ```
+pub fn cycle(colors: &[Rgba<u8>]) -> impl Iterator<Item = Rgba<u8>> + Clone + '_ {
+    colors.iter().copied().cycle()
+}
+
+/// Returns an endless iterator over the [`TABLEAU_10`] colors.
+pub fn tableau() -> impl Iterator<Item = Rgba<u8>> + Clone {
+    cycle(&TABLEAU_10)
+}
+
+/// Returns an endless iterator over the [`OKABE_ITO`] colors.
+pub fn okabe_ito() -> impl Iterator<Item = Rgba<u8>> + Clone {
+    cycle(&OKABE_ITO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_repeat() {
+        let colors: Vec<_> = tableau().take(12).collect();
+        assert_eq!(colors[..10], TABLEAU_10);
+        assert_eq!(colors[10..], TABLEAU_10[..2]);
+        assert_eq!(okabe_ito().nth(8), Some(OKABE_ITO[0]));
+        assert_eq!(cycle(&[]).next(), None);
+    }
+
+    #[test]
+    fn palettes_are_distinct() {
+        for colors in [&TABLEAU_10[..], &OKABE_ITO[..]] {
+            for (i, a) in colors.iter().enumerate() {
+                assert_eq!(a[3], 255);
+                assert!(colors[i + 1..].iter().all(|b| a != b), "{a:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Standard test patterns.
+//!
+//! Each function fills a provided image with a reference pattern drawn using
+//! the crate's own primitives.  They are useful for checking an image
+//! pipeline (scaling, color conversion, compression) by comparing its output
+//! against the original pattern.
+//!
+//! ```
+//! use freehand::patterns;
+//! use image::RgbaImage;
+//!
+//! let mut image = RgbaImage::new(640, 480);
+//! patterns::color_bars(&mut image);
+//! ```
+
+use crate::palette::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, WHITE, YELLOW};
+use crate::pt::Point;
+use crate::{color, conics, lines, shapes, text, Degrees};
+use image::{Rgba, RgbaImage};
+
+/// The colors used by [`color_bars`], from left to right.
+pub const COLOR_BARS: [Rgba<u8>; 8] = [WHITE, YELLOW, CYAN, GREEN, MAGENTA, RED, BLUE, BLACK];
+
+/// Fills the image with vertical bars of each of the [`COLOR_BARS`] colors.
+///
+/// The bars are as close to the same width as possible, with any extra
+/// pixels given to the leftmost bars.
+pub fn color_bars(image: &mut RgbaImage) {
+    let (width, height) = image.dimensions();
+    let count = COLOR_BARS.len() as u32;
+    let mut x = 0;
+    for (i, color) in COLOR_BARS.into_iter().enumerate() {
+        let w = width / count + u32::from((i as u32) < width % count);
+        #[allow(clippy::cast_possible_wrap)]
+        shapes::rectangle_filled(image, (x as i32, 0), height, w, color);
+        x += w;
+    }
+}
+
+/// Fills the image with four horizontal bands of gradients going from black
+/// on the left to white, red, green, and blue on the right.
+///
+/// The gradients use every value from 0 to 255 when the image is at least
+/// 256 pixels wide, which makes banding from reduced color depth easy to see.
+pub fn gradient_ramps(image: &mut RgbaImage) {
+    let (width, height) = image.dimensions();
+    let ends = [WHITE, RED, GREEN, BLUE];
+    let mut y = 0;
+    for (i, end) in ends.into_iter().enumerate() {
+        let h = height / 4 + u32::from((i as u32) < height % 4);
+        if h == 0 {
+            continue;
+        }
+        for x in 0..width {
+            let t = if width > 1 {
+                x as f32 / (width - 1) as f32
+            } else {
+                0.0
+            };
+            #[allow(clippy::cast_possible_wrap)]
+            lines::vertical_line(
+                image,
+                (x as i32, y as i32),
+                (y + h - 1) as i32,
+                color::lerp(BLACK, end, t),
+            );
+        }
+        y += h;
+    }
+}
+
+/// Fills the image with white and draws grid lines every `spacing` pixels,
+/// labeling each line along the top and left edges with its coordinate.
+///
+/// # Panics
+///
+/// Panics if `spacing` is 0
+pub fn grid(image: &mut RgbaImage, spacing: u32, color: Rgba<u8>) {
+    assert!(spacing > 0, "Grid spacing must be greater than 0");
+    fill(image, WHITE);
+    #[allow(clippy::cast_possible_wrap)]
+    let (width, height) = (image.width() as i32, image.height() as i32);
+    let step = spacing as usize;
+
+    for x in (0..width).step_by(step) {
+        lines::vertical_line(image, (x, 0), height - 1, color);
+        if x > 0 {
+            text::draw_text(image, (x + 2, 2), &x.to_string(), 1, color);
+        }
+    }
+    for y in (0..height).step_by(step) {
+        lines::horizontal_line(image, (0, y), width - 1, color);
+        if y > 0 {
+            text::draw_text(image, (2, y + 2), &y.to_string(), 1, color);
+        }
+    }
+}
+
+/// Fills the image with white and draws a star of `spokes` black wedges
+/// around `center` (also known as a Siemens star).
+///
+/// The wedges narrow towards the center, so the point where they blur
+/// together shows the resolution of a scaled or compressed image.
+pub fn radial_sweep<C>(image: &mut RgbaImage, center: C, spokes: u32)
+where
+    C: Point<i32>,
+{
+    fill(image, WHITE);
+    if spokes == 0 {
+        return;
+    }
+    let center = center.pt();
+    // Reaches the corners of the image from any center inside of it
+    #[allow(clippy::cast_possible_wrap)]
+    let radius = (image.width() + image.height()) as i32;
+
+    let wedge = 360.0 / f64::from(spokes);
+    for i in 0..spokes {
+        let start = f64::from(i) * wedge;
+        let end = start + wedge / 2.0;
+        conics::pie_slice_filled(image, Degrees(start), Degrees(end), radius, center, BLACK);
+    }
+}
+
+/// Sets every pixel of the image to `color`.
+fn fill(image: &mut RgbaImage, color: Rgba<u8>) {
+    for px in image.pixels_mut() {
+        *px = color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars() {
+        let mut image = RgbaImage::new(19, 4);
+        color_bars(&mut image);
+        // 3 pixels extra, given to the first three bars
+        let expected = [0, 3, 6, 9, 11, 13, 15, 17];
+        for (x, color) in expected.into_iter().zip(COLOR_BARS) {
+            assert_eq!(*image.get_pixel(x, 3), color, "{x}");
+        }
+        assert_eq!(*image.get_pixel(18, 0), BLACK);
+    }
+
+    #[test]
+    fn ramps() {
+        let mut image = RgbaImage::new(256, 8);
+        gradient_ramps(&mut image);
+        for x in 0..256 {
+            let v = x as u8;
+            assert_eq!(image.get_pixel(x, 0).0, [v, v, v, 255]);
+            assert_eq!(image.get_pixel(x, 3).0, [v, 0, 0, 255]);
+            assert_eq!(image.get_pixel(x, 5).0, [0, v, 0, 255]);
+            assert_eq!(image.get_pixel(x, 7).0, [0, 0, v, 255]);
+        }
+
+        // Smaller than the number of bands
+        let mut image = RgbaImage::new(1, 2);
+        gradient_ramps(&mut image);
+        assert_eq!(image.as_raw(), &[0, 0, 0, 255, 0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn patterns() -> Result<(), image::ImageError> {
+        let mut image = RgbaImage::new(400, 400);
+        grid(&mut image, 50, BLACK);
+        assert_eq!(*image.get_pixel(100, 399), BLACK);
+        assert_eq!(*image.get_pixel(399, 150), BLACK);
+        assert_eq!(*image.get_pixel(75, 75), WHITE);
+        image.save("images/pattern_grid.png")?;
+
+        let mut image = RgbaImage::new(400, 400);
+        radial_sweep(&mut image, (200, 200), 16);
+        let dark = image.pixels().filter(|px| **px == BLACK).count();
+        assert!((dark as f64 / 160_000.0 - 0.5).abs() < 0.02, "{dark}");
+        // The first wedge is above and to the right of the center
+        assert_eq!(*image.get_pixel(300, 190), BLACK);
+        assert_eq!(*image.get_pixel(300, 210), WHITE);
+        image.save("images/pattern_radial_sweep.png")
+    }
+}
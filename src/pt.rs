@@ -17,10 +17,27 @@
 /// Represents x, y coordinates for a type.  Allows functions to be generic over
 /// types that represent x, y coordinates.
 ///
-/// The two ways a Point can be represented are:
+/// A Point can be represented as any of the following, or a reference to one:
 /// - tuples: `(12, 34)`
+/// - arrays: `[12, 34]`
 /// - [`Pt`s](crate::Pt): `Pt::new(12, 34)`
 ///
+/// Implementations for references allow borrowed points to be used directly:
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::path;
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// let points = vec![[0, 0], [399, 0], [399, 399]];
+/// path(&mut image, points.iter(), Rgba([255, 0, 0, 255]));
+///
+/// let points = vec![freehand::Pt::new(0, 0), freehand::Pt::new(0, 399)];
+/// path(&mut image, &points, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// Points that own their coordinates can also replace them with [`PointMut`].
+///
 /// See also: [Pt](crate::Pt)
 ///
 pub trait Point<T>
@@ -44,12 +61,6 @@ where
     /// Return the y coordinate
     fn y(&self) -> T;
 
-    /// Replace the x coordinate
-    fn set_x(&mut self, x: T);
-
-    /// Replace the y coordinate
-    fn set_y(&mut self, y: T);
-
     /// Creates a new [`Pt`] by switching the x and y coordinates
     fn transpose(&self) -> Pt<T> {
         Pt::new(self.y(), self.x())
@@ -70,6 +81,42 @@ where
     {
         Pt::new(self.x(), self.y() + y)
     }
+
+    /// Replace the x coordinate
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use freehand::Point;
+    ///
+    /// let mut pt = (12, 34);
+    /// pt.set_x(56);
+    /// assert_eq!(pt, (56, 34));
+    /// ```
+    #[deprecated(note = "use `PointMut::set_x` instead")]
+    fn set_x(&mut self, x: T)
+    where
+        Self: PointMut<T>,
+    {
+        PointMut::set_x(self, x);
+    }
+
+    /// Replace the y coordinate
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use freehand::Point;
+    ///
+    /// let mut pt = (12, 34);
+    /// pt.set_y(78);
+    /// assert_eq!(pt, (12, 78));
+    /// ```
+    #[deprecated(note = "use `PointMut::set_y` instead")]
+    fn set_y(&mut self, y: T)
+    where
+        Self: PointMut<T>,
+    {
+        PointMut::set_y(self, y);
+    }
 }
 
 impl<T> Point<T> for (T, T)
@@ -83,13 +130,44 @@ where
     fn y(&self) -> T {
         self.1
     }
+}
 
-    fn set_x(&mut self, x: T) {
-        self.0 = x;
+impl<T> Point<T> for &(T, T)
+where
+    T: Copy,
+{
+    fn x(&self) -> T {
+        self.0
     }
 
-    fn set_y(&mut self, y: T) {
-        self.1 = y;
+    fn y(&self) -> T {
+        self.1
+    }
+}
+
+impl<T> Point<T> for [T; 2]
+where
+    T: Copy,
+{
+    fn x(&self) -> T {
+        self[0]
+    }
+
+    fn y(&self) -> T {
+        self[1]
+    }
+}
+
+impl<T> Point<T> for &[T; 2]
+where
+    T: Copy,
+{
+    fn x(&self) -> T {
+        self[0]
+    }
+
+    fn y(&self) -> T {
+        self[1]
     }
 }
 
@@ -108,7 +186,79 @@ where
     fn y(&self) -> T {
         self.y()
     }
+}
+
+impl<T> Point<T> for &Pt<T>
+where
+    T: Copy,
+{
+    fn pt(&self) -> Pt<T> {
+        **self
+    }
+
+    fn x(&self) -> T {
+        self.x
+    }
+
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+/// A [`Point`] that owns its coordinates, allowing them to be replaced.
+///
+/// This is implemented for tuples, arrays, and [`Pt`s](crate::Pt), but not for
+/// references to them.
+///
+/// ```
+/// use freehand::PointMut;
+///
+/// let mut pt = (12, 34);
+/// pt.set_x(56);
+/// pt.set_y(78);
+/// assert_eq!(pt, (56, 78));
+/// ```
+pub trait PointMut<T>: Point<T>
+where
+    T: Copy,
+{
+    /// Replace the x coordinate
+    fn set_x(&mut self, x: T);
+
+    /// Replace the y coordinate
+    fn set_y(&mut self, y: T);
+}
+
+impl<T> PointMut<T> for (T, T)
+where
+    T: Copy,
+{
+    fn set_x(&mut self, x: T) {
+        self.0 = x;
+    }
+
+    fn set_y(&mut self, y: T) {
+        self.1 = y;
+    }
+}
 
+impl<T> PointMut<T> for [T; 2]
+where
+    T: Copy,
+{
+    fn set_x(&mut self, x: T) {
+        self[0] = x;
+    }
+
+    fn set_y(&mut self, y: T) {
+        self[1] = y;
+    }
+}
+
+impl<T> PointMut<T> for Pt<T>
+where
+    T: Copy,
+{
     fn set_x(&mut self, x: T) {
         self.x = x;
     }
@@ -352,6 +502,96 @@ impl Pt<f64> {
         Self { x, y }
     }
 
+    /// Returns the distance to another point.
+    ///
+    /// ```
+    /// use freehand::Pt;
+    ///
+    /// assert_eq!(Pt::new(1.0, 1.0).distance(Pt::new(4.0, 5.0)), 5.0);
+    /// ```
+    #[must_use]
+    pub fn distance(&self, other: Self) -> f64 {
+        (other.x - self.x).hypot(other.y - self.y)
+    }
+
+    /// Linearly interpolates between this point (`t = 0.0`) and another point
+    /// (`t = 1.0`).
+    ///
+    /// ```
+    /// use freehand::Pt;
+    ///
+    /// assert_eq!(Pt::new(0.0, 10.0).lerp(Pt::new(10.0, 20.0), 0.25), Pt::new(2.5, 12.5));
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: Self, t: f64) -> Self {
+        Self {
+            x: t.mul_add(other.x - self.x, self.x),
+            y: t.mul_add(other.y - self.y, self.y),
+        }
+    }
+
+    /// Returns the angle of a line from this point to another point, in radians.
+    ///
+    /// Angles follow the same convention as the arc functions: 0 points right,
+    /// and angles increase counter-clockwise (towards the top of the image).
+    /// The result is in the range `0..2*PI`.
+    ///
+    /// ```
+    /// use freehand::Pt;
+    ///
+    /// let angle = Pt::new(10.0, 10.0).angle_to(Pt::new(10.0, 0.0));
+    /// assert_eq!(angle, std::f64::consts::FRAC_PI_2);
+    /// ```
+    #[must_use]
+    pub fn angle_to(&self, other: Self) -> f64 {
+        crate::angle::point_angle(*self, other)
+    }
+
+    /// Rotates the point around a center point.  Positive angles rotate
+    /// counter-clockwise.
+    ///
+    /// Floating-point angles will be treated as radians while integer types will
+    /// be treated as degrees.
+    ///
+    /// ```
+    /// use freehand::Pt;
+    ///
+    /// let pt = Pt::new(20.0, 10.0).rotate_around(Pt::new(10.0, 10.0), 90);
+    /// assert!(pt.distance(Pt::new(10.0, 0.0)) < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn rotate_around<A>(&self, center: Self, angle: A) -> Self
+    where
+        A: crate::Angle,
+    {
+        let (sin, cos) = angle.radians().sin_cos();
+        let (dx, dy) = (self.x - center.x, self.y - center.y);
+        Self {
+            x: center.x + dx * cos + dy * sin,
+            y: center.y - dx * sin + dy * cos,
+        }
+    }
+
+    /// Converts the point to polar coordinates around a center point, returning
+    /// `(radius, angle)` with the angle in radians.
+    ///
+    /// See [`Pt::from_radian`] to convert back.
+    ///
+    /// ```
+    /// use freehand::Pt;
+    ///
+    /// let center = Pt::new(10.0, 10.0);
+    /// let (radius, angle) = Pt::new(10.0, 15.0).to_polar(center);
+    /// assert_eq!(radius, 5.0);
+    ///
+    /// let pt = Pt::from_radian(angle, radius, center);
+    /// assert!(pt.distance(Pt::new(10.0, 15.0)) < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn to_polar(&self, center: Self) -> (f64, f64) {
+        (center.distance(*self), center.angle_to(*self))
+    }
+
     /// Round and cast to a `Pt<i32>`.
     #[must_use]
     pub fn i32(&self) -> Pt<i32> {
@@ -416,7 +656,7 @@ impl Pt<u32> {
     ///
     /// Panics if the values cannot fit into an i32
     pub const fn i32(&self) -> Pt<i32> {
-        assert!(self.x <= std::i32::MAX as u32 && self.y <= std::i32::MAX as u32);
+        assert!(self.x <= i32::MAX as u32 && self.y <= i32::MAX as u32);
         Pt {
             x: self.x as i32,
             y: self.y as i32,
@@ -473,10 +713,10 @@ impl From<Pt<i32>> for Pt<f64> {
 }
 
 impl TryFrom<Pt<u32>> for Pt<i32> {
-    type Error = &'static str;
+    type Error = crate::Error;
 
     fn try_from(pt: Pt<u32>) -> Result<Self, Self::Error> {
-        if pt.x <= std::i32::MAX as u32 && pt.y <= std::i32::MAX as u32 {
+        if i32::try_from(pt.x).is_ok() && i32::try_from(pt.y).is_ok() {
             // safe because of the if check
             #[allow(clippy::cast_possible_wrap)]
             Ok(Self {
@@ -484,7 +724,7 @@ impl TryFrom<Pt<u32>> for Pt<i32> {
                 y: pt.y as i32,
             })
         } else {
-            Err("bounds exceeds i32::MAX")
+            Err(crate::Error::OutOfBounds)
         }
     }
 }
@@ -569,12 +809,67 @@ where
     }
 }
 
+impl<T> std::ops::Neg for Pt<T>
+where
+    T: std::ops::Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> std::ops::AddAssign for Pt<T>
+where
+    T: std::ops::AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T> std::ops::SubAssign for Pt<T>
+where
+    T: std::ops::SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Scales both coordinates
+impl std::ops::Mul<f64> for Pt<f64> {
+    type Output = Self;
+    fn mul(self, value: f64) -> Self {
+        Self {
+            x: self.x * value,
+            y: self.y * value,
+        }
+    }
+}
+
+/// Scales both coordinates
+impl std::ops::Mul<i32> for Pt<i32> {
+    type Output = Self;
+    fn mul(self, value: i32) -> Self {
+        Self {
+            x: self.x * value,
+            y: self.y * value,
+        }
+    }
+}
+
 impl std::convert::TryFrom<Pt<i32>> for Pt<u32> {
-    type Error = &'static str;
+    type Error = crate::Error;
 
     fn try_from(pt: Pt<i32>) -> Result<Self, Self::Error> {
         if pt.x().is_negative() || pt.y().is_negative() {
-            Err("Negative i32 values cannot be converted to u32")
+            Err(crate::Error::OutOfBounds)
         } else {
             Ok(Pt::new(pt.x() as u32, pt.y() as u32))
         }
@@ -585,7 +880,7 @@ impl std::convert::TryFrom<Pt<i32>> for Pt<u32> {
 //     type Error = &'static str;
 
 //     fn try_from(pt: Pt<u32>) -> Result<Self, Self::Error> {
-//         if pt.x() > std::i32::MAX as u32 || pt.y() > std::i32::MAX as u32 {
+//         if pt.x() > i32::MAX as u32 || pt.y() > i32::MAX as u32 {
 //             Err("")
 //         } else {
 //             Ok(Pt::new(pt.x() as u32, pt.y() as u32))
@@ -0,0 +1,580 @@
+//! Antialiased filling of arbitrary polygons and paths
+//!
+//! The rasterizer walks the image one row at a time, keeping a table of the
+//! edges that cross the current row.  Each edge adds the exact area it covers
+//! to an accumulation buffer, which is summed from left to right to find the
+//! coverage of every pixel.  Unlike supersampling this gives exact coverage
+//! for pixels crossed by a single edge, with no extra memory per pixel.
+//!
+//! ```
+//! use freehand::raster::{fill_path, FillRule};
+//! use image::{RgbaImage, Rgba};
+//!
+//! let mut image = RgbaImage::from_pixel(100, 100, Rgba([255, 255, 255, 255]));
+//! let star = [(50.0, 5.0), (79.0, 95.0), (2.0, 39.0), (98.0, 39.0), (21.0, 95.0)];
+//!
+//! fill_path(&mut image, star, FillRule::NonZero, Rgba([255, 0, 0, 255]));
+//! ```
+
+use crate::pt::Point;
+use image::{Rgba, RgbaImage};
+
+/// Coverage below this is treated as empty, as it would not change the image.
+const MIN_COVERAGE: f32 = 1.0 / 512.0;
+
+/// Decides which areas of overlapping or self-intersecting shapes are filled.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FillRule {
+    /// Areas are filled if the outlines wind around them at least once,
+    /// counting clockwise and counterclockwise outlines separately.  A hole
+    /// must be drawn in the opposite direction of its outer shape.  This is
+    /// the default.
+    NonZero,
+    /// Areas are filled if they are inside an odd number of outlines,
+    /// regardless of direction.
+    EvenOdd,
+}
+
+impl Default for FillRule {
+    fn default() -> Self {
+        Self::NonZero
+    }
+}
+
+impl FillRule {
+    /// Converts an accumulated winding into pixel coverage.
+    fn coverage(self, winding: f32) -> f32 {
+        match self {
+            Self::NonZero => winding.abs().min(1.0),
+            Self::EvenOdd => {
+                let w = winding.abs() % 2.0;
+                if w > 1.0 {
+                    2.0 - w
+                } else {
+                    w
+                }
+            }
+        }
+    }
+}
+
+/// Fills a closed path with antialiased edges.
+///
+/// The last point is automatically connected back to the first one.  As with
+/// the other drawing functions, integer coordinates are the centers of pixels.
+/// Points that are not finite are skipped.
+///
+/// # Example
+///
+/// ```
+/// use freehand::raster::{fill_path, FillRule};
+/// use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// fill_path(&mut image, [(10.0, 10.0), (390.5, 40.0), (200.0, 390.0)], FillRule::NonZero, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`fill_contours`], [`Polygon::draw_filled_antialiased`](crate::shapes::Polygon::draw_filled_antialiased)
+///
+pub fn fill_path<P, It>(image: &mut RgbaImage, points: It, rule: FillRule, color: Rgba<u8>)
+where
+    P: Point<f64>,
+    It: IntoIterator<Item = P>,
+{
+    Rasterizer::new().fill_path(image, points, rule, color);
+}
+
+/// Fills a shape made from several closed contours, such as a shape with
+/// holes, with antialiased edges.
+///
+/// The contours are filled together so the `rule` applies to where they
+/// overlap each other.
+///
+/// # Example
+///
+/// ```
+/// use freehand::raster::{fill_contours, FillRule};
+/// use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// // A square with a square hole
+/// let outer = vec![(10.0, 10.0), (390.0, 10.0), (390.0, 390.0), (10.0, 390.0)];
+/// let inner = vec![(100.0, 100.0), (300.0, 100.0), (300.0, 300.0), (100.0, 300.0)];
+/// fill_contours(&mut image, [outer, inner], FillRule::EvenOdd, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`fill_path`]
+///
+pub fn fill_contours<P, C, It>(image: &mut RgbaImage, contours: It, rule: FillRule, color: Rgba<u8>)
+where
+    P: Point<f64>,
+    C: IntoIterator<Item = P>,
+    It: IntoIterator<Item = C>,
+{
+    Rasterizer::new().fill_contours(image, contours, rule, color);
+}
+
+/// Fills many shapes while reusing the same buffers.
+///
+/// The free functions ([`fill_path`], [`fill_contours`]) allocate their edge
+/// lists and row buffers on every call.  A `Rasterizer` keeps them between
+/// calls instead, which avoids the allocations when drawing thousands of
+/// shapes (e.g. once per frame).  The output is identical.
+///
+/// # Example
+///
+/// ```
+/// use freehand::raster::{FillRule, Rasterizer};
+/// use freehand::shapes::Polygon;
+/// use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// let mut raster = Rasterizer::new();
+/// for i in 0..100 {
+///     let x = f64::from(i % 10) * 40.0;
+///     let y = f64::from(i / 10) * 40.0;
+///     raster.fill_path(&mut image, [(x, y), (x + 30.0, y + 5.0), (x + 15.0, y + 35.0)], FillRule::NonZero, Rgba([255, 0, 0, 255]));
+/// }
+/// raster.fill_polygon(&mut image, &Polygon::new([(0, 0), (399, 0), (200, 399)]), Rgba([0, 0, 255, 128]));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Rasterizer {
+    edges: Vec<Edge>,
+    active: Vec<Edge>,
+    /// Coverage accumulated for the current row, always left zeroed between rows
+    acc: Vec<f32>,
+}
+
+impl Rasterizer {
+    /// Creates a new rasterizer.  Its buffers are allocated by the first shape
+    /// drawn and grow as needed.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fills a closed path with antialiased edges.
+    ///
+    /// See [`fill_path`]
+    pub fn fill_path<P, It>(
+        &mut self,
+        image: &mut RgbaImage,
+        points: It,
+        rule: FillRule,
+        color: Rgba<u8>,
+    ) where
+        P: Point<f64>,
+        It: IntoIterator<Item = P>,
+    {
+        self.fill_contours(image, [points], rule, color);
+    }
+
+    /// Fills a shape made from several closed contours with antialiased edges.
+    ///
+    /// See [`fill_contours`]
+    pub fn fill_contours<P, C, It>(
+        &mut self,
+        image: &mut RgbaImage,
+        contours: It,
+        rule: FillRule,
+        color: Rgba<u8>,
+    ) where
+        P: Point<f64>,
+        C: IntoIterator<Item = P>,
+        It: IntoIterator<Item = C>,
+    {
+        for contour in contours {
+            add_contour(&mut self.edges, contour);
+        }
+        self.fill(image, rule, color);
+        self.edges.clear();
+        self.active.clear();
+    }
+
+    /// Fills a [`Polygon`](crate::shapes::Polygon) using the even-odd rule.
+    ///
+    /// See [`Polygon::draw_filled_antialiased`](crate::shapes::Polygon::draw_filled_antialiased)
+    pub fn fill_polygon(
+        &mut self,
+        image: &mut RgbaImage,
+        polygon: &crate::shapes::Polygon,
+        color: Rgba<u8>,
+    ) {
+        let points = polygon
+            .points()
+            .iter()
+            .map(|p| (f64::from(p.x()), f64::from(p.y())));
+        self.fill_path(image, points, FillRule::EvenOdd, color);
+    }
+
+    /// Fills the area enclosed by the edges, sorting them by their top.
+    fn fill(&mut self, image: &mut RgbaImage, rule: FillRule, color: Rgba<u8>) {
+        let Self { edges, active, acc } = self;
+        let (width, height) = image.dimensions();
+        if edges.is_empty() || width == 0 || height == 0 {
+            return;
+        }
+        edges.sort_by(|a, b| {
+            a.top
+                .partial_cmp(&b.top)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let top = edges[0].top.floor().max(0.0);
+        let bottom = edges
+            .iter()
+            .fold(f64::MIN, |b, e| b.max(e.bottom))
+            .ceil()
+            .min(f64::from(height));
+        if top >= bottom {
+            return;
+        }
+
+        // Room for edges touching the right side of the image
+        acc.resize(width as usize + 2, 0.0);
+        let mut next = 0;
+
+        for y in top as u32..bottom as u32 {
+            let (row_top, row_bottom) = (f64::from(y), f64::from(y) + 1.0);
+            while next < edges.len() && edges[next].top < row_bottom {
+                active.push(edges[next]);
+                next += 1;
+            }
+            active.retain(|e| e.bottom > row_top);
+
+            // Range of the accumulation buffer that was touched
+            let (mut lo, mut hi) = (usize::MAX, 0);
+            for e in &*active {
+                let y0 = e.top.max(row_top);
+                let y1 = e.bottom.min(row_bottom);
+                if y1 > y0 {
+                    let x0 = e.x + (y0 - e.top) * e.dxdy;
+                    let x1 = e.x + (y1 - e.top) * e.dxdy;
+                    let d = (y1 - y0) as f32 * e.dir;
+                    if let Some((a, b)) = accumulate(acc, width, x0, x1, d) {
+                        lo = lo.min(a);
+                        hi = hi.max(b);
+                    }
+                }
+            }
+            if lo > hi {
+                continue;
+            }
+
+            let mut winding = 0.0;
+            for (x, a) in acc.iter().enumerate().take(width as usize).skip(lo) {
+                winding += a;
+                // Past the last edge the winding stays the same
+                if x > hi && winding.abs() < MIN_COVERAGE {
+                    break;
+                }
+                let coverage = rule.coverage(winding);
+                if coverage >= MIN_COVERAGE {
+                    crate::ops::blend_at(image, x as u32, y, coverage, color);
+                }
+            }
+            for a in &mut acc[lo..=hi] {
+                *a = 0.0;
+            }
+        }
+    }
+}
+
+/// A non-horizontal edge, with pixel `i` covering `i..i + 1` along each axis.
+#[derive(Copy, Clone, Debug)]
+struct Edge {
+    top: f64,
+    bottom: f64,
+    /// The x coordinate at `top`
+    x: f64,
+    dxdy: f64,
+    /// `1.0` for edges going down, `-1.0` for edges going up
+    dir: f32,
+}
+
+/// Adds the edges of a closed contour.
+fn add_contour<P, C>(edges: &mut Vec<Edge>, contour: C)
+where
+    P: Point<f64>,
+    C: IntoIterator<Item = P>,
+{
+    let mut points = contour
+        .into_iter()
+        .map(|p| (p.x() + 0.5, p.y() + 0.5))
+        .filter(|(x, y)| x.is_finite() && y.is_finite());
+
+    let first = match points.next() {
+        Some(p) => p,
+        None => return,
+    };
+    let mut a = first;
+    for b in points.chain(std::iter::once(first)) {
+        let (top, bottom, dir) = if a.1 < b.1 { (a, b, 1.0) } else { (b, a, -1.0) };
+        // Horizontal edges do not change the coverage
+        if top.1 < bottom.1 {
+            edges.push(Edge {
+                top: top.1,
+                bottom: bottom.1,
+                x: top.0,
+                dxdy: (bottom.0 - top.0) / (bottom.1 - top.1),
+                dir,
+            });
+        }
+        a = b;
+    }
+}
+
+/// Adds the signed area to the right of a line segment within one row, where
+/// `d` is the height of the segment (negative for upward segments).
+///
+/// The parts of the segment left of the image are moved onto its left side,
+/// while the parts to the right are ignored.  Returns the range of `acc` that
+/// was changed.
+#[allow(clippy::many_single_char_names)]
+fn accumulate(acc: &mut [f32], width: u32, x0: f64, x1: f64, d: f32) -> Option<(usize, usize)> {
+    let w = f64::from(width);
+    let (min, max) = if x0 < x1 { (x0, x1) } else { (x1, x0) };
+    if min >= w {
+        return None;
+    }
+    if max <= 0.0 {
+        acc[0] += d;
+        return Some((0, 0));
+    }
+
+    // How much of the segment lies past each side of the image
+    let span = max - min;
+    let left = if min < 0.0 { -min / span } else { 0.0 };
+    let right = if max > w { (max - w) / span } else { 0.0 };
+    let mut lo = usize::MAX;
+    if left > 0.0 {
+        acc[0] += d * left as f32;
+        lo = 0;
+    }
+    let d = d * (1.0 - left - right) as f32;
+    let x0 = x0.clamp(0.0, w) as f32;
+    let x1 = x1.clamp(0.0, w) as f32;
+
+    let (a, b) = if x0 < x1 { (x0, x1) } else { (x1, x0) };
+    let i = a.floor() as usize;
+    let j = b.ceil() as usize;
+    if j <= i + 1 {
+        // Stays within a single pixel
+        let mid = 0.5 * (x0 + x1) - i as f32;
+        acc[i] += d - d * mid;
+        acc[i + 1] += d * mid;
+        return Some((lo.min(i), i + 1));
+    }
+
+    let s = (b - a).recip();
+    let af = a - i as f32;
+    let first = 0.5 * s * (1.0 - af) * (1.0 - af);
+    let bf = b - j as f32 + 1.0;
+    let last = 0.5 * s * bf * bf;
+    acc[i] += d * first;
+    if j == i + 2 {
+        acc[i + 1] += d * (1.0 - first - last);
+    } else {
+        let second = s * (1.5 - af);
+        acc[i + 1] += d * (second - first);
+        for c in &mut acc[i + 2..j - 1] {
+            *c += d * s;
+        }
+        let before_last = second + (j - i - 3) as f32 * s;
+        acc[j - 1] += d * (1.0 - before_last - last);
+    }
+    acc[j] += d * last;
+    Some((lo.min(i), j))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+    /// Total coverage of black drawn onto a white image.
+    fn area(image: &RgbaImage) -> f64 {
+        image
+            .pixels()
+            .map(|px| f64::from(255 - px[0]) / 255.0)
+            .sum()
+    }
+
+    #[test]
+    fn aligned_square() {
+        let mut image = crate::test::img::blank((20, 20));
+        fill_path(
+            &mut image,
+            [(2.5, 3.5), (7.5, 3.5), (7.5, 9.5), (2.5, 9.5)],
+            FillRule::NonZero,
+            BLACK,
+        );
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let inside = (3..8).contains(&x) && (4..10).contains(&y);
+            let expected = if inside {
+                BLACK
+            } else {
+                Rgba([255, 255, 255, 255])
+            };
+            assert_eq!(*px, expected, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn exact_area() {
+        // Right triangle with an area of 50 pixels and a shallow triangle of 210
+        let mut image = crate::test::img::blank((50, 50));
+        fill_path(
+            &mut image,
+            [(2.3, 2.1), (12.3, 2.1), (2.3, 12.1)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        assert!((area(&image) - 50.0).abs() < 0.5, "{}", area(&image));
+
+        let mut image = crate::test::img::blank((50, 50));
+        fill_path(
+            &mut image,
+            [(1.0, 20.0), (43.0, 30.0), (1.0, 30.0)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        assert!((area(&image) - 210.0).abs() < 0.5, "{}", area(&image));
+
+        // Half of a pixel
+        let mut image = crate::test::img::blank((5, 5));
+        fill_path(
+            &mut image,
+            [(1.5, 1.5), (2.5, 1.5), (1.5, 2.5)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        assert!((126..=128).contains(&image.get_pixel(2, 2)[0]));
+    }
+
+    #[test]
+    fn fill_rules() {
+        // Two overlapping squares drawn in the same direction
+        let a = vec![(2.0, 2.0), (12.0, 2.0), (12.0, 12.0), (2.0, 12.0)];
+        let b = vec![(7.0, 7.0), (17.0, 7.0), (17.0, 17.0), (7.0, 17.0)];
+
+        let mut image = crate::test::img::blank((20, 20));
+        fill_contours(&mut image, [a.clone(), b.clone()], FillRule::NonZero, BLACK);
+        assert_eq!(*image.get_pixel(10, 10), BLACK);
+
+        let mut image = crate::test::img::blank((20, 20));
+        fill_contours(&mut image, [a.clone(), b], FillRule::EvenOdd, BLACK);
+        assert_eq!(image.get_pixel(10, 10).0, [255, 255, 255, 255]);
+        assert_eq!(*image.get_pixel(4, 4), BLACK);
+        assert_eq!(*image.get_pixel(15, 15), BLACK);
+
+        // A hole drawn in the opposite direction
+        let hole = vec![(5.0, 5.0), (5.0, 9.0), (9.0, 9.0), (9.0, 5.0)];
+        let mut image = crate::test::img::blank((20, 20));
+        fill_contours(&mut image, [a, hole], FillRule::NonZero, BLACK);
+        assert_eq!(image.get_pixel(7, 7).0, [255, 255, 255, 255]);
+        assert_eq!(*image.get_pixel(3, 3), BLACK);
+    }
+
+    #[test]
+    fn clipping() {
+        // Extends past every side of the image
+        let mut image = crate::test::img::blank((20, 20));
+        fill_path(
+            &mut image,
+            [(-30.0, -25.0), (60.0, -10.0), (40.0, 70.0), (-20.0, 50.0)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        assert!(image.pixels().all(|px| *px == BLACK));
+
+        // Only the left edge is inside the image
+        let mut image = crate::test::img::blank((20, 20));
+        fill_path(
+            &mut image,
+            [(10.0, -5.0), (100.0, -5.0), (100.0, 50.0), (10.0, 50.0)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        for (x, _, px) in image.enumerate_pixels() {
+            assert_eq!(px[0] == 0, x > 10, "{x}");
+        }
+
+        // Entirely outside, or not finite
+        let mut image = crate::test::img::blank((20, 20));
+        fill_path(
+            &mut image,
+            [(30.0, 5.0), (40.0, 5.0), (35.0, 10.0)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        fill_path(
+            &mut image,
+            [(5.0, -30.0), (10.0, -30.0), (8.0, -20.0)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        fill_path(
+            &mut image,
+            [(f64::NAN, 5.0), (5.0, 5.0)],
+            FillRule::NonZero,
+            BLACK,
+        );
+        assert!(image.pixels().all(|px| px.0 == [255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn reused_rasterizer() {
+        let mut raster = Rasterizer::new();
+        let shapes = [
+            vec![(2.3, 2.1), (30.0, 8.0), (12.0, 25.5)],
+            vec![(-5.0, 4.0), (8.0, -2.0), (9.5, 9.5)],
+            vec![(10.0, 1.0), (14.0, 1.0)],
+        ];
+
+        // Different image sizes make the row buffer grow and shrink
+        for size in [(40, 30), (10, 10), (60, 5)] {
+            let mut expected = crate::test::img::blank(size);
+            let mut image = expected.clone();
+            for shape in &shapes {
+                fill_path(&mut expected, shape.clone(), FillRule::EvenOdd, BLACK);
+                raster.fill_path(&mut image, shape.clone(), FillRule::EvenOdd, BLACK);
+            }
+            assert_eq!(image, expected);
+        }
+
+        let poly = crate::shapes::Polygon::new([(5, 5), (40, 8), (30, 35), (18, 20), (3, 30)]);
+        let mut expected = crate::test::img::blank((50, 50));
+        let mut image = expected.clone();
+        poly.draw_filled_antialiased(&mut expected, BLACK);
+        raster.fill_polygon(&mut image, &poly, BLACK);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn star() -> Result<(), image::ImageError> {
+        let star = [
+            (50.0, 5.0),
+            (79.0, 95.0),
+            (2.0, 39.0),
+            (98.0, 39.0),
+            (21.0, 95.0),
+        ];
+        let mut image = crate::test::img::blank((200, 100));
+        fill_path(&mut image, star, FillRule::NonZero, Rgba([255, 0, 0, 255]));
+        let shifted = star.map(|(x, y)| (x + 100.0, y));
+        fill_path(
+            &mut image,
+            shifted,
+            FillRule::EvenOdd,
+            Rgba([255, 0, 0, 255]),
+        );
+
+        assert_eq!(image.get_pixel(50, 50).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(150, 50).0, [255, 255, 255, 255]);
+        image.save("images/raster_star.png")
+    }
+}
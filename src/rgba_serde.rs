@@ -0,0 +1,52 @@
+//! Serializes [`Rgba`] colors, which don't implement serde's traits, as their
+//! array of channels.  Used with `#[serde(with = "crate::rgba_serde")]` on
+//! fields holding a color or an optional color.
+
+use image::Rgba;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A color, or an optional color, that is stored as channels.
+pub(crate) trait Channels: Sized {
+    type Array: Serialize + DeserializeOwned;
+
+    fn to_array(&self) -> Self::Array;
+    fn from_array(array: Self::Array) -> Self;
+}
+
+impl Channels for Rgba<u8> {
+    type Array = [u8; 4];
+
+    fn to_array(&self) -> Self::Array {
+        self.0
+    }
+
+    fn from_array(array: Self::Array) -> Self {
+        Rgba(array)
+    }
+}
+
+impl Channels for Option<Rgba<u8>> {
+    type Array = Option<[u8; 4]>;
+
+    fn to_array(&self) -> Self::Array {
+        self.map(|c| c.0)
+    }
+
+    fn from_array(array: Self::Array) -> Self {
+        array.map(Rgba)
+    }
+}
+
+pub(crate) fn serialize<T: Channels, S: Serializer>(
+    color: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    color.to_array().serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, T: Channels, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::Array::deserialize(deserializer).map(T::from_array)
+}
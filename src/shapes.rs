@@ -1,5 +1,21 @@
 //! Various drawing functions for shapes
 
+mod ellipse;
+mod frame;
+mod iso;
+mod needle;
+mod polygon;
 mod rect;
+mod rounded;
+mod triangle;
 
-pub use rect::{rectangle, rectangle_alpha, rectangle_filled, rectangle_filled_alpha};
+pub use ellipse::{ellipse, rotated_ellipse, Ellipse};
+pub use frame::{rounded_frame, RoundedFrame};
+pub use iso::{diamond, diamond_filled, iso_grid, Diamond, IsoGrid};
+pub use needle::{needle, Needle};
+pub use polygon::Polygon;
+pub(crate) use rect::far_edge;
+pub use rect::{rectangle, rectangle_alpha, rectangle_filled, rectangle_filled_alpha, Rect};
+pub use rounded::{rounded_rectangle_antialiased, rounded_rectangle_filled_antialiased};
+pub(crate) use triangle::bilinear;
+pub use triangle::{triangle_textured, Sampling};
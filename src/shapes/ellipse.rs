@@ -0,0 +1,783 @@
+use super::Rect;
+use crate::pt::Point;
+use crate::{Angle, Pt};
+use image::GenericImage;
+use std::f64::consts::PI;
+
+/// An ellipse with whole number semi-axes, optionally rotated.
+///
+/// `a` is the distance from the center to the left and right ends of the
+/// ellipse and `b` is the distance to the top and bottom, before it is
+/// rotated.
+///
+/// Axis-aligned ellipses are drawn with the midpoint ellipse algorithm: one
+/// quadrant is calculated and mirrored into the other three, so the outline
+/// is symmetric and each pixel is visited once.  An ellipse with equal
+/// semi-axes draws the same pixels as [`circle`](crate::conics::circle).
+///
+/// Rotated ellipses are drawn one row at a time, solving the ellipse's
+/// equation for where its outline enters and leaves each row.  Rotations by
+/// a whole number of quarter turns still use the midpoint algorithm.
+///
+/// ```
+/// use freehand::shapes::Ellipse;
+/// use freehand::Pt;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(200, 200);
+///
+/// let ellipse = Ellipse::new((100, 100), 80, 40);
+/// ellipse.draw(&mut image, Rgba([255, 0, 0, 255]));
+///
+/// let points = ellipse.points();
+/// assert!(points.contains(&Pt::new(180, 100)));
+/// assert!(points.contains(&Pt::new(100, 60)));
+///
+/// // The same ellipse tilted up by 30°
+/// let tilted = ellipse.with_rotation(30);
+/// tilted.draw(&mut image, Rgba([0, 0, 255, 255]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ellipse {
+    center: Pt<i32>,
+    a: u32,
+    b: u32,
+    /// Counter-clockwise rotation in radians, in the range `0..PI`
+    rotation: f64,
+}
+
+impl Ellipse {
+    /// Creates an ellipse around `center` with a horizontal semi-axis of `a`
+    /// pixels and a vertical semi-axis of `b` pixels.
+    pub fn new<P>(center: P, a: u32, b: u32) -> Self
+    where
+        P: Point<i32>,
+    {
+        Self {
+            center: center.pt(),
+            a,
+            b,
+            rotation: 0.0,
+        }
+    }
+
+    /// Rotates the ellipse counter-clockwise around its center.
+    ///
+    /// Angles follow the [angle conventions](crate#note-on-angles) used by the
+    /// rest of the crate: integers are degrees and floating-point numbers are
+    /// radians.  Angles that aren't finite, such as `f64::NAN`, leave the
+    /// ellipse unrotated.
+    ///
+    /// ```
+    /// use freehand::shapes::Ellipse;
+    /// use freehand::Pt;
+    ///
+    /// // A quarter turn swaps the semi-axes
+    /// let ellipse = Ellipse::new((0, 0), 20, 10);
+    /// assert_eq!(
+    ///     ellipse.with_rotation(90).points().len(),
+    ///     Ellipse::new((0, 0), 10, 20).points().len()
+    /// );
+    ///
+    /// // Rotated 45°, the end of the long axis is up and to the right
+    /// assert!(ellipse.with_rotation(45).points().contains(&Pt::new(14, -14)));
+    /// ```
+    #[must_use]
+    pub fn with_rotation<A>(mut self, angle: A) -> Self
+    where
+        A: Angle,
+    {
+        if !angle.radians().is_finite() {
+            self.rotation = 0.0;
+            return self;
+        }
+        // Keep whole quarter turns exact so they can use the midpoint algorithm
+        let quarters = angle.f64() / (2.0 * angle.octant_size());
+        self.rotation = if quarters.fract() == 0.0 {
+            if quarters.rem_euclid(2.0) == 0.0 {
+                0.0
+            } else {
+                crate::QUAD
+            }
+        } else {
+            angle.radians().rem_euclid(PI)
+        };
+        self
+    }
+
+    /// Returns the counter-clockwise rotation of the ellipse in radians.
+    ///
+    /// Turning an ellipse halfway around leaves it unchanged, so the rotation
+    /// is always in the range `0..PI`.
+    #[must_use]
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    /// Returns the center of the ellipse.
+    #[must_use]
+    pub fn center(&self) -> Pt<i32> {
+        self.center
+    }
+
+    /// Returns the `(horizontal, vertical)` semi-axes of the ellipse.
+    #[must_use]
+    pub fn semi_axes(&self) -> (u32, u32) {
+        (self.a, self.b)
+    }
+
+    /// Returns a rectangle covering every pixel of the ellipse.
+    ///
+    /// ```
+    /// use freehand::shapes::{Ellipse, Rect};
+    ///
+    /// let ellipse = Ellipse::new((100, 100), 80, 40);
+    /// assert_eq!(ellipse.bounding_box(), Rect::new((20, 60), 81, 161));
+    /// assert_eq!(ellipse.with_rotation(90).bounding_box(), Rect::new((60, 20), 161, 81));
+    /// ```
+    #[must_use]
+    pub fn bounding_box(&self) -> Rect {
+        let (cx, cy) = (i64::from(self.center.x()), i64::from(self.center.y()));
+        let (a, b) = self.extents();
+        let (left, top) = (saturate(cx - a), saturate(cy - b));
+        let size = |lo: i32, hi: i64| {
+            (i64::from(saturate(hi)) - i64::from(lo) + 1).min(i64::from(u32::MAX)) as u32
+        };
+        Rect::new((left, top), size(top, cy + b), size(left, cx + a))
+    }
+
+    /// The horizontal and vertical distances from the center to the furthest
+    /// pixels of the ellipse.
+    fn extents(&self) -> (i64, i64) {
+        let (a, b) = (i64::from(self.a), i64::from(self.b));
+        if self.rotation == 0.0 {
+            return (a, b);
+        } else if self.rotation == crate::QUAD {
+            return (b, a);
+        }
+        let (x, y) = Conic::new(self.a, self.b, self.rotation).extents();
+        // Rows are drawn if any of the outline passes through them, and
+        // columns are chosen inside of the outline's reach in each row
+        (x.ceil() as i64, (y + 0.5).floor() as i64)
+    }
+
+    /// Calls a closure for every point of the ellipse.
+    ///
+    /// Points are not clipped and are not in order around the ellipse.
+    /// Axis-aligned ellipses are visited one quadrant step at a time, and
+    /// rotated ellipses one row at a time.
+    pub fn for_each<F>(&self, f: F)
+    where
+        F: FnMut(Pt<i32>),
+    {
+        self.visit(None, f);
+    }
+
+    /// Returns every point of the ellipse.  See [`Ellipse::for_each`].
+    #[must_use]
+    pub fn points(&self) -> Vec<Pt<i32>> {
+        let mut points = Vec::new();
+        self.for_each(|pt| points.push(pt));
+        points
+    }
+
+    /// Draws the outline of the ellipse.  See [`ellipse`].
+    ///
+    /// Only the rows and columns of the ellipse that are inside the image are
+    /// calculated.
+    pub fn draw<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let (width, height) = image.dimensions();
+        if !self.bounding_box().is_visible_in(width, height) {
+            return;
+        }
+        self.visit(Some((width, height)), |pt| {
+            if let Ok(pt) = Pt::<u32>::try_from(pt) {
+                if pt.x() < width && pt.y() < height {
+                    image.put_pixel(pt.x(), pt.y(), color);
+                }
+            }
+        });
+    }
+
+    /// Calls a closure for the points of the ellipse, skipping the rows and
+    /// columns that are outside of an image of the given size.  Points close
+    /// to the image may still be visited.
+    fn visit<F>(&self, size: Option<(u32, u32)>, mut f: F)
+    where
+        F: FnMut(Pt<i32>),
+    {
+        let window = size.map_or(Window::ALL, |(w, h)| Window::new(self.center, w, h));
+        let (cx, cy) = (i64::from(self.center.x()), i64::from(self.center.y()));
+        let mut visit = |dx: i64, dy: i64| f(Pt::new(saturate(cx + dx), saturate(cy + dy)));
+        let mirror = |x: i64, y: i64| {
+            // Points on an axis are shared by two quadrants
+            let xs: &[i64] = if x == 0 { &[0] } else { &[x, -x] };
+            let ys: &[i64] = if y == 0 { &[0] } else { &[y, -y] };
+            for &dx in xs {
+                for &dy in ys {
+                    visit(dx, dy);
+                }
+            }
+        };
+
+        let (a, b) = (i64::from(self.a), i64::from(self.b));
+        if self.rotation == 0.0 {
+            quadrant(a, b, window, mirror);
+        } else if self.rotation == crate::QUAD {
+            quadrant(b, a, window, mirror);
+        } else if a == 0 || b == 0 {
+            // A flat ellipse is a line through the center
+            let (len, angle) = if a == 0 {
+                (b as f64, self.rotation + crate::QUAD)
+            } else {
+                (a as f64, self.rotation)
+            };
+            let (cos, sin) = (angle.cos(), -angle.sin());
+            // Shorten lines that leave the range of an i32, which no image
+            // reaches, instead of saturating the ends and bending the line
+            let room = |c: i64| (i64::from(i32::MAX) - c).min(c - i64::from(i32::MIN)) as f64;
+            let len = len.min(room(cx) / cos.abs()).min(room(cy) / sin.abs());
+            let (ex, ey) = ((len * cos).round() as i64, (len * sin).round() as i64);
+            let offset = |sign: i64| Pt::new(saturate(cx + ex * sign), saturate(cy + ey * sign));
+            let (start, end) = (offset(-1), offset(1));
+            match size {
+                None => crate::lines::line_for_each(start, end, f),
+                Some((width, height)) => {
+                    let image = Rect::new((0, 0), height, width);
+                    if let Some((_, iter)) = crate::lines::clipped_iter(start, end, image) {
+                        iter.for_each(f);
+                    }
+                }
+            }
+        } else {
+            Conic::new(self.a, self.b, self.rotation).scan(window, visit);
+        }
+    }
+}
+
+/// The offsets from the center of an ellipse that are inside of an image,
+/// horizontally and vertically.
+#[derive(Copy, Clone, Debug)]
+struct Window {
+    x: (i64, i64),
+    y: (i64, i64),
+}
+
+impl Window {
+    /// Every offset, for visiting the whole ellipse
+    const ALL: Self = Self {
+        x: (i64::MIN, i64::MAX),
+        y: (i64::MIN, i64::MAX),
+    };
+
+    fn new(center: Pt<i32>, width: u32, height: u32) -> Self {
+        let (cx, cy) = (i64::from(center.x()), i64::from(center.y()));
+        Self {
+            x: (-cx, i64::from(width) - 1 - cx),
+            y: (-cy, i64::from(height) - 1 - cy),
+        }
+    }
+}
+
+/// The smallest and largest distances from 0 to the offsets in `range`.
+fn distances(range: (i64, i64)) -> (i64, i64) {
+    let (lo, hi) = range;
+    let near = if lo > 0 {
+        lo
+    } else if hi < 0 {
+        hi.saturating_neg()
+    } else {
+        0
+    };
+    (near, lo.saturating_abs().max(hi.saturating_abs()))
+}
+
+/// Calls a closure for the points of the quadrant with positive `x` and `y`
+/// of an ellipse centered on the origin, using the midpoint algorithm.  Only
+/// the points in rows and columns that are mirrored into the window are
+/// calculated.
+///
+/// The quadrant is split where the slope of the ellipse passes -1.  The part
+/// above is stepped along `x` from the top, `(0, b)`, and the part below is
+/// the same calculation with the axes swapped, stepped along `y` from the
+/// side.  Both halves use the same decision parameter as the circle's
+/// octants, so an ellipse with equal semi-axes is the same as a circle.
+fn quadrant<F>(a: i64, b: i64, window: Window, mut f: F)
+where
+    F: FnMut(i64, i64),
+{
+    let (cols, rows) = (distances(window.x), distances(window.y));
+    // A flat ellipse is a line, which the halves below would leave gaps in
+    if a == 0 || b == 0 {
+        for x in cols.0..=cols.1.min(a) {
+            for y in rows.0..=rows.1.min(b) {
+                f(x, y);
+            }
+        }
+        return;
+    }
+
+    half(a, b, false, intersect(cols, reaching(a, b, rows)), &mut f);
+    // Like the circle's even octants, the side half includes a point exactly
+    // on the split
+    let steps = intersect(rows, reaching(b, a, cols));
+    half(b, a, true, steps, |y, x| f(x, y));
+}
+
+/// Calls a closure for the points of an ellipse from `(0, b)` to where its
+/// slope passes -1, for the columns in `steps`.
+///
+/// Each step along `x` checks whether the midpoint below the next pixel is
+/// inside the ellipse, in which case `y` stays the same.  The decision
+/// parameter is scaled by 4 to keep it a whole number.
+///
+/// Each column's `y` is the largest where `b²x² + a²(y² - y) <= a²b²`, so the
+/// first column in `steps` is found directly instead of stepping to it.
+fn half<F>(a: i64, b: i64, inclusive: bool, steps: (i64, i64), mut visit: F)
+where
+    F: FnMut(i64, i64),
+{
+    let (first, last) = steps;
+    if first > last || first > a {
+        return;
+    }
+    // Wide enough to hold the products of very large semi-axes
+    let (a2, b2) = (i128::from(a) * i128::from(a), i128::from(b) * i128::from(b));
+    // How far a point is outside of the ellipse.  The terms can be larger than
+    // an i128, but the result is small near the ellipse, so wrapping is exact.
+    let outside = |x: i128, y: i128| {
+        b2.wrapping_mul(x * x)
+            .wrapping_add(a2 * (y * y - y))
+            .wrapping_sub(a2.wrapping_mul(b2))
+    };
+
+    let col = i128::from(first.max(0));
+    let rem = (i128::from(a) - col) * (i128::from(a) + col);
+    let mut row = (b as f64 * (rem as f64).sqrt() / a as f64 + 0.5) as i128;
+    // Correct any rounding error from the floating-point square root
+    while row > 0 && outside(col, row) > 0 {
+        row -= 1;
+    }
+    while outside(col, row + 1) <= 0 {
+        row += 1;
+    }
+
+    let (mut x, mut y) = (col as i64, row as i64);
+    // The change in the ellipse's equation for a step along x and along y
+    let (mut dx, mut dy) = (2 * b2 * i128::from(x), 2 * a2 * i128::from(y));
+    let mut err = 4 * outside(i128::from(x) + 1, i128::from(y));
+
+    while (dx < dy || (inclusive && dx == dy)) && x <= last {
+        visit(x, y);
+        x += 1;
+        dx += 2 * b2;
+        if err > 0 {
+            y -= 1;
+            dy -= 2 * a2;
+            err += 4 * (dx - dy + b2);
+        } else {
+            err += 4 * (dx + b2);
+        }
+    }
+}
+
+/// Returns a range of columns of the top half of an ellipse that includes
+/// every column whose `y` is within `range`.  The range is a little wider
+/// than needed so the result is never short because of rounding.
+fn reaching(a: i64, b: i64, range: (i64, i64)) -> (i64, i64) {
+    let column = |y: i64| {
+        let y = i128::from(y.clamp(0, b));
+        let (a, b) = (a as f64, i128::from(b));
+        a * (((b - y) * (b + y)) as f64).sqrt() / b as f64
+    };
+    let first = column(range.1.saturating_add(1)).floor() as i64 - 1;
+    let last = column(range.0.saturating_sub(1)).ceil() as i64 + 1;
+    (first.max(0), last)
+}
+
+/// Returns the overlap of two ranges, which is empty if `first > last`.
+fn intersect(a: (i64, i64), b: (i64, i64)) -> (i64, i64) {
+    (a.0.max(b.0), a.1.min(b.1))
+}
+
+/// The equation of a rotated ellipse centered on the origin,
+/// `A x² + B x y + C y² = 1`, with `y` pointing down.
+struct Conic {
+    a: f64,
+    b: f64,
+    c: f64,
+    /// `1 / (a² b²)` for the ellipse's semi-axes `a` and `b`
+    inv_ab: f64,
+}
+
+impl Conic {
+    fn new(a: u32, b: u32, rotation: f64) -> Self {
+        let (sin, cos) = rotation.sin_cos();
+        let (ia, ib) = (1.0 / f64::from(a).powi(2), 1.0 / f64::from(b).powi(2));
+        Self {
+            a: cos * cos * ia + sin * sin * ib,
+            b: 2.0 * sin * cos * (ib - ia),
+            c: sin * sin * ia + cos * cos * ib,
+            inv_ab: ia * ib,
+        }
+    }
+
+    /// The furthest the ellipse reaches from its center horizontally and
+    /// vertically.
+    fn extents(&self) -> (f64, f64) {
+        ((self.c / self.inv_ab).sqrt(), (self.a / self.inv_ab).sqrt())
+    }
+
+    /// The left and right sides of the ellipse in row `y`.  Rows past the
+    /// top or bottom give the point where the sides meet.
+    fn sides(&self, y: f64) -> (f64, f64) {
+        let half = (self.a - y * y * self.inv_ab).max(0.0).sqrt() / self.a;
+        let mid = -self.b * y / (2.0 * self.a);
+        (mid - half, mid + half)
+    }
+
+    /// Calls a closure for the points of the ellipse one row at a time, from
+    /// the top down.  Only the rows and columns in the window are visited.
+    ///
+    /// Each row gets the pixels whose centers lie between the furthest left
+    /// and right reaches of each side of the outline while it crosses that
+    /// row, so neighboring rows meet without gaps.
+    fn scan<F>(&self, window: Window, mut f: F)
+    where
+        F: FnMut(i64, i64),
+    {
+        let (ex, ey) = self.extents();
+        // The rows where the sides reach furthest left and right
+        let turn = self.b * ex / (2.0 * self.c);
+        let rows = (ey + 0.5).floor() as i64;
+        let mut row = |y: i64, x0: i64, x1: i64| {
+            (x0.max(window.x.0)..=x1.min(window.x.1)).for_each(|x| f(x, y));
+        };
+
+        for y in (-rows).max(window.y.0)..=rows.min(window.y.1) {
+            let (y0, y1) = ((y as f64 - 0.5).max(-ey), (y as f64 + 0.5).min(ey));
+            if y0 > y1 {
+                continue;
+            }
+            let (l0, r0) = self.sides(y0);
+            let (l1, r1) = self.sides(y1);
+            let (lc, rc) = self.sides((y as f64).clamp(-ey, ey));
+            let reach = |ends: (f64, f64), extreme: f64, at: f64| {
+                let (mut lo, mut hi) = (ends.0.min(ends.1), ends.0.max(ends.1));
+                if (y0..=y1).contains(&at) {
+                    lo = lo.min(extreme);
+                    hi = hi.max(extreme);
+                }
+                (lo, hi)
+            };
+            let left = span(reach((l0, l1), -ex, turn), lc);
+            let right = span(reach((r0, r1), ex, -turn), rc);
+
+            // The sides meet at the top and bottom, so those rows are one span
+            let tip = y0 <= -ey || y1 >= ey;
+            if tip || left.1 + 1 >= right.0 {
+                row(y, left.0, left.1.max(right.1));
+            } else {
+                row(y, left.0, left.1);
+                row(y, right.0, right.1);
+            }
+        }
+    }
+}
+
+/// The pixels whose centers are in `lo..hi`, or the pixel at `center` if
+/// there are none.
+fn span(reach: (f64, f64), center: f64) -> (i64, i64) {
+    let (lo, hi) = (reach.0.ceil() as i64, reach.1.ceil() as i64 - 1);
+    if lo > hi {
+        let x = center.round() as i64;
+        (x, x)
+    } else {
+        (lo, hi)
+    }
+}
+
+/// Clamps a coordinate into the range of an `i32`.
+fn saturate(v: i64) -> i32 {
+    #[allow(clippy::cast_possible_truncation)]
+    let v = v.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+    v
+}
+
+/// Draws the outline of an axis-aligned ellipse with a horizontal semi-axis of
+/// `a` pixels and a vertical semi-axis of `b` pixels.
+///
+/// Only the part of the ellipse inside the image is drawn.  See [`Ellipse`].
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::ellipse;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// ellipse(&mut image, (200, 200), 180, 90, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::ellipse`](crate::Draw::ellipse)
+///
+pub fn ellipse<I, P>(image: &mut I, center: P, a: u32, b: u32, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    Ellipse::new(center, a, b).draw(image, color);
+}
+
+/// Draws the outline of an ellipse rotated counter-clockwise by `angle`
+/// around its center.  See [`ellipse`] and [`Ellipse::with_rotation`].
+///
+/// Angles that aren't finite draw the ellipse without rotating it.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::rotated_ellipse;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// // An orbit tilted by 20°
+/// rotated_ellipse(&mut image, (200, 200), 180, 60, 20, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::rotated_ellipse`](crate::Draw::rotated_ellipse)
+///
+pub fn rotated_ellipse<A, I, P>(image: &mut I, center: P, a: u32, b: u32, angle: A, color: I::Pixel)
+where
+    A: Angle,
+    I: GenericImage,
+    P: Point<i32>,
+{
+    Ellipse::new(center, a, b)
+        .with_rotation(angle)
+        .draw(image, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+    use std::collections::HashSet;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn ellipse_points() {
+        let sizes = (1..30).flat_map(|a| (1..30).map(move |b| (a, b)));
+        for (a, b) in sizes.chain([(400, 7), (2, 300), (1000, 999)]) {
+            let points = Ellipse::new((0, 0), a, b).points();
+            let set: HashSet<_> = points.iter().copied().collect();
+            assert_eq!(set.len(), points.len(), "{a}x{b} repeats points");
+
+            let (a, b) = (f64::from(a), f64::from(b));
+            for pt in &points {
+                // Symmetric, and close to the ellipse
+                assert!(set.contains(&Pt::new(-pt.x(), pt.y())));
+                assert!(set.contains(&Pt::new(pt.x(), -pt.y())));
+                let (px, py) = (f64::from(pt.x()), f64::from(pt.y()));
+                let r = (px / a).hypot(py / b);
+                let scale = 1.0 / a.min(b);
+                assert!((r - 1.0).abs() <= scale, "{a}x{b} {pt:?}");
+
+                // Each point has a neighbor on either side
+                let neighbors = set
+                    .iter()
+                    .filter(|p| *p != pt)
+                    .filter(|p| (p.x() - pt.x()).abs() <= 1 && (p.y() - pt.y()).abs() <= 1)
+                    .count();
+                assert!(neighbors >= 2, "{a}x{b} {pt:?}");
+            }
+            for end in [(a, 0.0), (0.0, b)] {
+                let end = Pt::new(end.0 as i32, end.1 as i32);
+                assert!(set.contains(&end), "{a}x{b} {end:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn equal_axes_match_circle() {
+        for r in [1, 2, 4, 10, 37, 150] {
+            let mut circle = crate::test::img::blank((400, 400));
+            crate::conics::circle(&mut circle, r, (200, 200), RED);
+            let mut image = crate::test::img::blank((400, 400));
+            ellipse(&mut image, (200, 200), r as u32, r as u32, RED);
+            assert_eq!(circle, image, "r={r}");
+        }
+    }
+
+    #[test]
+    fn flat_and_clipped() {
+        assert_eq!(Ellipse::new((5, 5), 0, 0).points(), [Pt::new(5, 5)]);
+        assert_eq!(Ellipse::new((5, 5), 2, 0).points().len(), 5);
+        assert_eq!(Ellipse::new((5, 5), 0, 3).points().len(), 7);
+
+        let mut image = crate::test::img::blank((20, 20));
+        ellipse(&mut image, (-10, 10), 15, 5, RED);
+        assert_eq!(*image.get_pixel(5, 10), RED);
+        assert_eq!(
+            Ellipse::new((0, 0), u32::MAX, 1).bounding_box(),
+            Rect::new((i32::MIN, -1), 3, u32::MAX)
+        );
+    }
+
+    #[test]
+    fn skipping_matches_stepping() {
+        for (a, b) in [(1, 1), (5, 2), (30, 12), (12, 30), (400, 7), (1000, 999)] {
+            let mut all = Vec::new();
+            half(a, b, false, (0, i64::MAX), |x, y| all.push((x, y)));
+            for (i, &(x, _)) in all.iter().enumerate() {
+                let mut rest = Vec::new();
+                half(a, b, false, (x, i64::MAX), |x, y| rest.push((x, y)));
+                assert_eq!(rest, all[i..], "{a}x{b} from x={x}");
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_ellipses_match_unclipped() {
+        let shapes = [
+            (60, 25, 0.0),
+            (25, 60, 90.0),
+            (70, 70, 0.0),
+            (60, 25, 30.0),
+            (50, 0, 20.0),
+        ];
+        for (a, b, deg) in shapes {
+            let shape = |x, y| Ellipse::new((x, y), a, b).with_rotation(crate::Degrees(deg));
+            let mut full = crate::test::img::blank((240, 240));
+            shape(120, 120).draw(&mut full, RED);
+            for (x, y) in [(0, 0), (130, 10), (60, 140), (150, 150), (0, 100)] {
+                let mut image = crate::test::img::blank((40, 40));
+                shape(120 - x, 120 - y).draw(&mut image, RED);
+                let view = image::imageops::crop_imm(&full, x as u32, y as u32, 40, 40);
+                assert_eq!(image, view.to_image(), "{a}x{b} at {deg}° from ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn huge_axes() {
+        let max = u32::MAX;
+        let row = |image: &RgbaImage, y| (0..20).all(|x| *image.get_pixel(x, y) == RED);
+
+        let mut image = crate::test::img::blank((20, 20));
+        ellipse(&mut image, (10, 10), max, 5, RED);
+        assert!(row(&image, 5) && row(&image, 15));
+        assert_eq!(image.pixels().filter(|&&p| p == RED).count(), 40);
+
+        let mut image = crate::test::img::blank((20, 20));
+        Ellipse::new((10, 10), 5, max)
+            .with_rotation(90)
+            .draw(&mut image, RED);
+        assert!(row(&image, 5) && row(&image, 15));
+
+        let mut image = crate::test::img::blank((20, 20));
+        Ellipse::new((10, 10), max, 0)
+            .with_rotation(crate::Degrees(30.0))
+            .draw(&mut image, RED);
+        assert_eq!(*image.get_pixel(10, 10), RED);
+
+        // The outline is far outside of the image
+        for deg in [0.0, 30.0] {
+            let mut image = crate::test::img::blank((20, 20));
+            Ellipse::new((10, 10), max, max)
+                .with_rotation(crate::Degrees(deg))
+                .draw(&mut image, RED);
+            assert!(image.pixels().all(|&p| p != RED));
+        }
+
+        // Only the rows and columns in the image are calculated
+        let mut image = crate::test::img::blank((20, 20));
+        Ellipse::new((10, 10), max, max / 3)
+            .with_rotation(crate::Degrees(30.0))
+            .draw(&mut image, RED);
+    }
+
+    #[test]
+    fn rotated_points() {
+        for (a, b) in [(1, 1), (3, 1), (8, 3), (20, 20), (40, 7), (100, 60)] {
+            for deg in [1.0, 10.0, 30.0, 45.0, 60.0, 89.0, 120.0, 170.0] {
+                let ellipse = Ellipse::new((0, 0), a, b).with_rotation(crate::Degrees(deg));
+                let points = ellipse.points();
+                let set: HashSet<_> = points.iter().copied().collect();
+                assert_eq!(set.len(), points.len(), "{a}x{b} at {deg}° repeats points");
+
+                // Samples along the outline
+                let t = deg.to_radians();
+                let outline: Vec<(f64, f64)> = (0..2000)
+                    .map(|i| {
+                        let s = f64::from(i) * std::f64::consts::PI / 1000.0;
+                        let (u, v) = (f64::from(a) * s.cos(), f64::from(b) * s.sin());
+                        (u * t.cos() - v * t.sin(), -(u * t.sin() + v * t.cos()))
+                    })
+                    .collect();
+                let bounds = ellipse.bounding_box();
+                for pt in &points {
+                    let (px, py) = (f64::from(pt.x()), f64::from(pt.y()));
+                    let d = outline
+                        .iter()
+                        .map(|&(x, y)| (x - px).hypot(y - py))
+                        .fold(f64::INFINITY, f64::min);
+                    assert!(d < 0.75, "{a}x{b} at {deg}° {pt:?} is {d} away");
+                    assert!(bounds.contains(*pt), "{a}x{b} at {deg}° {pt:?}");
+
+                    let neighbors = set
+                        .iter()
+                        .filter(|p| *p != pt)
+                        .filter(|p| (p.x() - pt.x()).abs() <= 1 && (p.y() - pt.y()).abs() <= 1)
+                        .count();
+                    assert!(neighbors >= 2, "{a}x{b} at {deg}° {pt:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn quarter_turns_use_midpoint() {
+        let ellipse = Ellipse::new((50, 50), 30, 12);
+        let sorted = |e: Ellipse| {
+            let mut points = e.points();
+            points.sort_by_key(|p| (p.x(), p.y()));
+            points
+        };
+        assert_eq!(sorted(ellipse.with_rotation(180)), sorted(ellipse));
+        assert_eq!(sorted(ellipse.with_rotation(-360)), sorted(ellipse));
+        assert_eq!(
+            sorted(ellipse.with_rotation(std::f64::consts::FRAC_PI_2)),
+            sorted(Ellipse::new((50, 50), 12, 30))
+        );
+        assert!((ellipse.with_rotation(270).rotation() - crate::QUAD).abs() < f64::EPSILON);
+
+        // Flat rotated ellipses are lines
+        let line = Ellipse::new((0, 0), 10, 0).with_rotation(45).points();
+        assert_eq!(line.len(), 15);
+        assert!(line.contains(&Pt::new(7, -7)) && line.contains(&Pt::new(-7, 7)));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn non_finite_rotations() {
+        let mut expected = crate::test::img::blank((60, 60));
+        ellipse(&mut expected, (30, 30), 20, 10, RED);
+        for angle in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Ellipse::new((0, 0), 20, 10).with_rotation(angle).rotation(),
+                0.0
+            );
+            let mut image = crate::test::img::blank((60, 60));
+            rotated_ellipse(&mut image, (30, 30), 20, 10, angle, RED);
+            assert_eq!(image, expected, "{angle}");
+        }
+        let mut image = crate::test::img::blank((60, 60));
+        rotated_ellipse(&mut image, (30, 30), 20, 10, f32::NAN, RED);
+        assert_eq!(image, expected);
+    }
+}
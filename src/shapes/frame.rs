@@ -0,0 +1,295 @@
+use super::{far_edge, Rect};
+use crate::pt::Point;
+use image::GenericImage;
+
+/// A ring between two rounded rectangles sharing the same center: a thick
+/// border with rounded corners.  This is the rectangular version of
+/// [`Annulus`](crate::conics::Annulus).
+///
+/// The frame covers every pixel of `rect` except for an inner rectangle inset
+/// by `thickness` on each side.  By default the inner corners are rounded by
+/// `radius - thickness`, which keeps the border the same thickness around the
+/// corners; [`RoundedFrame::with_inner_radius`] rounds them by a different
+/// amount.  Each radius is limited to half of the shorter side of its
+/// rectangle.
+///
+/// ```
+/// use freehand::shapes::{Rect, RoundedFrame};
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(200, 200);
+///
+/// let frame = RoundedFrame::new(Rect::new((10, 10), 100, 180), 6, 16);
+/// frame.draw(&mut image, Rgba([255, 0, 0, 255]));
+///
+/// assert!(frame.contains((100, 12)));
+/// assert!(!frame.contains((100, 60)));
+/// // Outside of the rounded corner
+/// assert!(!frame.contains((10, 10)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RoundedFrame {
+    rect: Rect,
+    thickness: u32,
+    outer_radius: u32,
+    inner_radius: u32,
+}
+
+impl RoundedFrame {
+    /// Creates a frame covering `rect` with a border `thickness` pixels wide
+    /// and outer corners rounded by `radius`.
+    #[must_use]
+    pub fn new(rect: Rect, thickness: u32, radius: u32) -> Self {
+        Self {
+            rect,
+            thickness,
+            outer_radius: radius,
+            inner_radius: radius.saturating_sub(thickness),
+        }
+    }
+
+    /// Rounds the corners of the inner rectangle by `radius` instead of
+    /// following the outer corners.
+    #[must_use]
+    pub fn with_inner_radius(self, radius: u32) -> Self {
+        Self {
+            inner_radius: radius,
+            ..self
+        }
+    }
+
+    /// Returns the rectangle covered by the outside of the frame.
+    #[must_use]
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns true if a point lies on the frame.
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: Point<i32>,
+    {
+        let (x, y) = (f64::from(pt.x()), f64::from(pt.y()));
+        let (outer, inner) = self.shapes();
+        let inside = |shape: &Rounded| shape.extent(y).map_or(false, |e| (x - shape.cx).abs() <= e);
+        inside(&outer) && !inner.as_ref().map_or(false, inside)
+    }
+
+    /// Calls a closure with each row of the frame as a span of pixels:
+    /// `f(y, x1, x2)` covers `x1..=x2` on row `y`.
+    ///
+    /// Rows crossing the hole in the middle have a span on each side of it,
+    /// while the other rows have a single span.  Spans are not clipped.
+    pub fn for_each_span<F>(&self, mut f: F)
+    where
+        F: FnMut(i32, i32, i32),
+    {
+        if self.rect.width == 0 || self.rect.height == 0 {
+            return;
+        }
+        let y1 = far_edge(self.rect.y, self.rect.height);
+        self.spans(self.rect.y, y1, &mut f);
+    }
+
+    /// Draws the frame.
+    ///
+    /// The rectangle may lie partly outside of the image, including negative
+    /// coordinates; only the visible rows are visited.
+    pub fn draw<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        if !self.rect.is_visible_in(image.width(), image.height()) {
+            return;
+        }
+        #[allow(clippy::cast_possible_wrap)]
+        let max_y = image.height().min(i32::MAX as u32) as i32 - 1;
+        let y0 = self.rect.y.max(0);
+        let y1 = far_edge(self.rect.y, self.rect.height).min(max_y);
+        self.spans(y0, y1, &mut |y, x1, x2| {
+            crate::lines::horizontal_line(image, (x1, y), x2, color);
+        });
+    }
+
+    /// Finds the spans of the rows from `y0` to `y1`.
+    fn spans(&self, y0: i32, y1: i32, f: &mut dyn FnMut(i32, i32, i32)) {
+        let (outer, inner) = self.shapes();
+        for y in y0..=y1 {
+            let fy = f64::from(y);
+            let e = match outer.extent(fy) {
+                Some(e) => e,
+                None => continue,
+            };
+            let (x1, x2) = ((outer.cx - e).ceil() as i32, (outer.cx + e).floor() as i32);
+            if x1 > x2 {
+                continue;
+            }
+
+            // The pixels inside of the inner rectangle on this row
+            let hole = inner
+                .as_ref()
+                .and_then(|inner| Some((inner.cx, inner.extent(fy)?)))
+                .map(|(cx, e)| ((cx - e).ceil() as i32, (cx + e).floor() as i32))
+                .filter(|(a, b)| a <= b);
+            match hole {
+                Some((a, b)) => {
+                    if x1 < a {
+                        f(y, x1, a - 1);
+                    }
+                    if b < x2 {
+                        f(y, b + 1, x2);
+                    }
+                }
+                None => f(y, x1, x2),
+            }
+        }
+    }
+
+    /// Returns the outer and inner rounded rectangles.  There is no inner
+    /// rectangle if the border fills the whole frame.
+    fn shapes(&self) -> (Rounded, Option<Rounded>) {
+        let rect = self.rect;
+        let cx = f64::from(rect.x) - 0.5 + f64::from(rect.width) / 2.0;
+        let cy = f64::from(rect.y) - 0.5 + f64::from(rect.height) / 2.0;
+        let hw = f64::from(rect.width) / 2.0;
+        let hh = f64::from(rect.height) / 2.0;
+        let t = f64::from(self.thickness);
+
+        let outer = Rounded::new(cx, cy, hw, hh, self.outer_radius);
+        let inner =
+            (hw > t && hh > t).then(|| Rounded::new(cx, cy, hw - t, hh - t, self.inner_radius));
+        (outer, inner)
+    }
+}
+
+/// A rounded rectangle described by its center, half of its size, and the
+/// radius of its corners.  Pixels are inside if their centers are.
+struct Rounded {
+    cx: f64,
+    cy: f64,
+    hw: f64,
+    hh: f64,
+    r: f64,
+}
+
+impl Rounded {
+    fn new(cx: f64, cy: f64, hw: f64, hh: f64, radius: u32) -> Self {
+        let r = f64::from(radius).min(hw).min(hh);
+        Self { cx, cy, hw, hh, r }
+    }
+
+    /// How far the rectangle reaches to either side of its center on row `y`,
+    /// or `None` if the row misses it.
+    fn extent(&self, y: f64) -> Option<f64> {
+        let dy = (y - self.cy).abs();
+        if dy > self.hh {
+            return None;
+        }
+        // How far into the rounded corners the row is
+        let q = dy - (self.hh - self.r);
+        if q <= 0.0 {
+            Some(self.hw)
+        } else {
+            Some(self.hw - self.r + (self.r * self.r - q * q).sqrt())
+        }
+    }
+}
+
+/// Draws a thick border with rounded corners: the ring between a rounded
+/// rectangle and a second one inset by `thickness` on each side.  The
+/// specified point represents the upper left corner of the outside of the
+/// frame.
+///
+/// The inner corners are rounded by `radius - thickness` so the border keeps
+/// the same thickness around them.  Use [`RoundedFrame`] to round them by a
+/// different amount.  A radius of 0 draws a plain rectangular frame.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::rounded_frame;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// // A 10px wide border around a button
+/// rounded_frame(&mut image, (50, 150), 100, 300, 10, 24, Rgba([40, 90, 200, 255]));
+/// ```
+///
+/// See also: [`Draw::rounded_frame`](crate::Draw::rounded_frame)
+///
+pub fn rounded_frame<I, P>(
+    image: &mut I,
+    pt: P,
+    height: u32,
+    width: u32,
+    thickness: u32,
+    radius: u32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    RoundedFrame::new(Rect::new(pt, height, width), thickness, radius).draw(image, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn square_corners_match_rectangles() {
+        let white = Rgba([255, 255, 255, 255]);
+        let mut expected = crate::test::img::blank((30, 30));
+        crate::shapes::rectangle_filled(&mut expected, (3, -2), 20, 25, RED);
+        crate::shapes::rectangle_filled(&mut expected, (7, 2), 12, 17, white);
+
+        let mut image = crate::test::img::blank((30, 30));
+        rounded_frame(&mut image, (3, -2), 20, 25, 4, 0, RED);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn rounded_corners() {
+        let frame = RoundedFrame::new(Rect::new((0, 0), 40, 60), 5, 12);
+        let mut spans = Vec::new();
+        frame.for_each_span(|y, x1, x2| spans.push((y, x1, x2)));
+
+        // The corner rows are narrower, and the middle rows have two spans
+        assert!(spans[0].1 > 0 && spans[0].2 < 59);
+        let middle: Vec<_> = spans.iter().filter(|s| s.0 == 20).collect();
+        assert_eq!(middle, [&(20, 0, 4), &(20, 55, 59)]);
+
+        let mut image = crate::test::img::blank((60, 40));
+        frame.draw(&mut image, RED);
+        for y in 0..40 {
+            for x in 0..60 {
+                let drawn = *image.get_pixel(x as u32, y as u32) == RED;
+                assert_eq!(drawn, frame.contains((x, y)), "({x}, {y})");
+            }
+        }
+        assert_eq!(
+            spans.iter().map(|s| s.2 - s.1 + 1).sum::<i32>() as usize,
+            image.pixels().filter(|&&px| px == RED).count()
+        );
+    }
+
+    #[test]
+    fn inner_radius_and_solid_frames() {
+        // Rounded inner corners leave the border thicker at each corner
+        let round = RoundedFrame::new(Rect::new((0, 0), 40, 40), 5, 12);
+        let square = round.with_inner_radius(0);
+        assert!(round.contains((6, 6)));
+        assert!(!square.contains((6, 6)));
+        assert!(!square.contains((5, 5)));
+        assert!(square.contains((4, 6)));
+        assert!(!square.contains((1, 1)));
+
+        // A border as thick as half the frame has no hole
+        let solid = RoundedFrame::new(Rect::new((0, 0), 10, 10), 5, 0);
+        assert!((0..10).all(|x| solid.contains((x, 5))));
+    }
+}
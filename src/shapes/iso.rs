@@ -0,0 +1,451 @@
+use super::Rect;
+use crate::pt::Point;
+use crate::Pt;
+use image::GenericImage;
+
+/// A diamond: a rhombus whose corners point straight up, down, left, and
+/// right.  Diamonds are the tiles of an [`IsoGrid`].
+///
+/// Its edges are drawn as straight lines between the corners, so the edges of
+/// a diamond twice as wide as it is tall step evenly, two pixels across for
+/// each pixel down, as in isometric pixel art.  The filled diamond covers
+/// exactly the pixels inside of and on its outline.
+///
+/// ```
+/// use freehand::shapes::Diamond;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(100, 100);
+///
+/// let tile = Diamond::new((50, 50), (16, 8));
+/// tile.draw_filled(&mut image, Rgba([90, 160, 60, 255]));
+/// tile.draw(&mut image, Rgba([40, 80, 30, 255]));
+///
+/// assert!(tile.contains((60, 50)));
+/// assert!(!tile.contains((60, 56)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Diamond {
+    center: Pt<i32>,
+    radii: (u32, u32),
+}
+
+impl Diamond {
+    /// Creates a diamond around `center`.  The radii are the `(horizontal,
+    /// vertical)` distances from the center to the corners.
+    pub fn new<P>(center: P, radii: (u32, u32)) -> Self
+    where
+        P: Point<i32>,
+    {
+        Self {
+            center: center.pt(),
+            radii,
+        }
+    }
+
+    /// Returns the center of the diamond.
+    #[must_use]
+    pub fn center(&self) -> Pt<i32> {
+        self.center
+    }
+
+    /// Returns the `(horizontal, vertical)` radii of the diamond.
+    #[must_use]
+    pub fn radii(&self) -> (u32, u32) {
+        self.radii
+    }
+
+    /// Returns the top, right, bottom, and left corners, in that order.
+    #[must_use]
+    pub fn corners(&self) -> [Pt<i32>; 4] {
+        let (cx, cy) = (i64::from(self.center.x()), i64::from(self.center.y()));
+        let (rx, ry) = (i64::from(self.radii.0), i64::from(self.radii.1));
+        let pt = |x: i64, y: i64| Pt::new(saturate(x), saturate(y));
+        [
+            pt(cx, cy - ry),
+            pt(cx + rx, cy),
+            pt(cx, cy + ry),
+            pt(cx - rx, cy),
+        ]
+    }
+
+    /// Returns true if a point lies inside of the diamond or on its edges.
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: Point<i32>,
+    {
+        let dx = (i64::from(pt.x()) - i64::from(self.center.x())).abs();
+        let dy = (i64::from(pt.y()) - i64::from(self.center.y())).abs();
+        let (rx, ry) = (i64::from(self.radii.0), i64::from(self.radii.1));
+        dx <= rx && dy <= ry && dx * ry + dy * rx <= rx * ry
+    }
+
+    /// Draws the outline of the diamond.  See [`diamond`].
+    pub fn draw<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let corners = self.corners();
+        for (i, &a) in corners.iter().enumerate() {
+            crate::lines::line(image, a, corners[(i + 1) % 4], color);
+        }
+    }
+
+    /// Draws the diamond filled.  See [`diamond_filled`].
+    pub fn draw_filled<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let corners = self.corners();
+        let (top, bottom) = (corners[0].y(), corners[2].y());
+        if image.width() == 0 || image.height() == 0 {
+            return;
+        }
+
+        // Only the rows inside of the image are filled
+        #[allow(clippy::cast_possible_wrap)]
+        let last_row = (image.height() - 1).min(i32::MAX as u32) as i32;
+        // The left and right ends of each row are taken from the outline so
+        // the fill lines up with it
+        for y in top.max(0)..=bottom.min(last_row) {
+            let span = (0..4)
+                .filter_map(|i| crate::lines::row_span(corners[i], corners[(i + 1) % 4], y))
+                .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)));
+            if let Some((x1, x2)) = span {
+                crate::lines::horizontal_line(image, (x1, y), x2, color);
+            }
+        }
+    }
+}
+
+/// Clamps a coordinate into the range of an `i32`.
+fn saturate(v: i64) -> i32 {
+    #[allow(clippy::cast_possible_truncation)]
+    let v = v.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+    v
+}
+
+/// An isometric grid of [`Diamond`] tiles, converting between `(i, j)` tile
+/// coordinates and pixel coordinates.
+///
+/// Tile `(0, 0)` is centered on the grid's origin.  Moving along `i` goes down
+/// and to the right, and moving along `j` goes down and to the left, so tiles
+/// with the same `i + j` are on the same row.
+///
+/// ```
+/// use freehand::shapes::IsoGrid;
+/// use freehand::Pt;
+///
+/// // 32x16 tiles with the first one centered at (200, 20)
+/// let grid = IsoGrid::new((200, 20), 32, 16);
+/// assert_eq!(grid.to_pixel(1, 0), Pt::new(216, 28));
+/// assert_eq!(grid.to_pixel(2, 3), Pt::new(184, 60));
+/// assert_eq!(grid.from_pixel((186, 58)), (2, 3));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IsoGrid {
+    origin: Pt<i32>,
+    /// Half of the width and height of each tile
+    half: (i32, i32),
+}
+
+impl IsoGrid {
+    /// Creates a grid of tiles `tile_width` pixels wide and `tile_height`
+    /// pixels tall (from corner to corner), with tile `(0, 0)` centered on
+    /// `origin`.
+    ///
+    /// Tile sizes are rounded down to an even number of pixels so every
+    /// corner lands on a pixel, with a smallest size of 2.  Tiles twice as
+    /// wide as they are tall give the common 2:1 pixel art look.
+    pub fn new<P>(origin: P, tile_width: u32, tile_height: u32) -> Self
+    where
+        P: Point<i32>,
+    {
+        // safe because the size is limited to half of the largest i32
+        #[allow(clippy::cast_possible_wrap)]
+        let half = |size: u32| (size / 2).clamp(1, i32::MAX as u32 / 2) as i32;
+        Self {
+            origin: origin.pt(),
+            half: (half(tile_width), half(tile_height)),
+        }
+    }
+
+    /// Returns the pixel at the center of tile `(i, j)`.
+    #[must_use]
+    pub fn to_pixel(&self, i: i32, j: i32) -> Pt<i32> {
+        let (i, j) = (i64::from(i), i64::from(j));
+        let (hw, hh) = (i64::from(self.half.0), i64::from(self.half.1));
+        Pt::new(
+            saturate(i64::from(self.origin.x()) + (i - j) * hw),
+            saturate(i64::from(self.origin.y()) + (i + j) * hh),
+        )
+    }
+
+    /// Returns the `(i, j)` coordinates of the tile containing a pixel.
+    /// Pixels on the edge between two tiles belong to the tile further down.
+    #[must_use]
+    pub fn from_pixel<P>(&self, pt: P) -> (i32, i32)
+    where
+        P: Point<i32>,
+    {
+        let (i, j) = self.tile_coords(Pt::new(pt.x(), pt.y()));
+        (
+            saturate((i + 0.5).floor() as i64),
+            saturate((j + 0.5).floor() as i64),
+        )
+    }
+
+    /// Returns the tile at `(i, j)`.
+    #[must_use]
+    pub fn tile(&self, i: i32, j: i32) -> Diamond {
+        Diamond::new(
+            self.to_pixel(i, j),
+            (self.half.0 as u32, self.half.1 as u32),
+        )
+    }
+
+    /// Draws the edges of every tile overlapping `rect`, clipped to `rect`.
+    /// See [`iso_grid`].
+    pub fn draw<I>(&self, image: &mut I, rect: Rect, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let area = rect.intersect(Rect::new((0, 0), image.height(), image.width()));
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        // The range of tile coordinates covered by the area
+        let (x1, y1) = (
+            super::far_edge(area.x, area.width),
+            super::far_edge(area.y, area.height),
+        );
+        let corners = [(area.x, area.y), (x1, area.y), (area.x, y1), (x1, y1)]
+            .map(|(x, y)| self.tile_coords(Pt::new(x, y)));
+        let range = |f: fn(&(f64, f64)) -> f64| {
+            let lo = corners.iter().map(f).fold(f64::INFINITY, f64::min);
+            let hi = corners.iter().map(f).fold(f64::NEG_INFINITY, f64::max);
+            (saturate(lo.floor() as i64), saturate(hi.ceil() as i64 + 1))
+        };
+        let (i0, i1) = range(|c| c.0);
+        let (j0, j1) = range(|c| c.1);
+
+        let mut plot = |a: Pt<i32>, b: Pt<i32>| {
+            crate::lines::line_for_each(a, b, |pt| {
+                if area.contains(pt) {
+                    // safe because the area was clipped to the image
+                    #[allow(clippy::cast_sign_loss)]
+                    image.put_pixel(pt.x() as u32, pt.y() as u32, color);
+                }
+            });
+        };
+        // Edges where i or j is halfway between two tiles, drawn whole so the
+        // steps of each line are the same no matter where it is clipped
+        for i in i0..=i1 {
+            plot(self.corner(i, j0), self.corner(i, j1));
+        }
+        for j in j0..=j1 {
+            plot(self.corner(i0, j), self.corner(i1, j));
+        }
+    }
+
+    /// Returns the corner between tiles `(i - 1, j - 1)`, `(i - 1, j)`,
+    /// `(i, j - 1)`, and `(i, j)`: the top corner of tile `(i, j)`.
+    fn corner(&self, i: i32, j: i32) -> Pt<i32> {
+        let center = self.to_pixel(i, j);
+        Pt::new(
+            center.x(),
+            saturate(i64::from(center.y()) - i64::from(self.half.1)),
+        )
+    }
+
+    /// Converts a pixel to fractional tile coordinates, where tile centers
+    /// are whole numbers.
+    fn tile_coords(&self, pt: Pt<i32>) -> (f64, f64) {
+        let u = (f64::from(pt.x()) - f64::from(self.origin.x())) / f64::from(self.half.0);
+        let v = (f64::from(pt.y()) - f64::from(self.origin.y())) / f64::from(self.half.1);
+        ((u + v) / 2.0, (v - u) / 2.0)
+    }
+}
+
+/// Draws the outline of a diamond: a rhombus around `center` with corners
+/// `radii.0` pixels to the left and right and `radii.1` pixels above and
+/// below.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::diamond;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// // An isometric tile
+/// diamond(&mut image, (200, 200), (64, 32), Rgba([0, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::diamond`](crate::Draw::diamond)
+///
+pub fn diamond<I, P>(image: &mut I, center: P, radii: (u32, u32), color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    Diamond::new(center, radii).draw(image, color);
+}
+
+/// Draws a filled diamond.  See [`diamond`].
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::diamond_filled;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// diamond_filled(&mut image, (200, 200), (64, 32), Rgba([90, 160, 60, 255]));
+/// ```
+///
+/// See also: [`Draw::diamond_filled`](crate::Draw::diamond_filled)
+///
+pub fn diamond_filled<I, P>(image: &mut I, center: P, radii: (u32, u32), color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    Diamond::new(center, radii).draw_filled(image, color);
+}
+
+/// Draws the lines of an isometric grid across `rect`.
+///
+/// The lines are the edges of the grid's tiles, clipped to `rect`.  Lines are
+/// always stepped as if they were drawn in full, so grids drawn over
+/// neighboring areas line up.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::{iso_grid, IsoGrid, Rect};
+///
+/// let mut image = RgbaImage::from_pixel(400, 300, Rgba([255, 255, 255, 255]));
+/// let grid = IsoGrid::new((200, 0), 32, 16);
+/// iso_grid(&mut image, Rect::new((0, 0), 300, 400), grid, Rgba([180, 180, 180, 255]));
+///
+/// // Highlight the tile under a point
+/// let (i, j) = grid.from_pixel((250, 120));
+/// grid.tile(i, j).draw_filled(&mut image, Rgba([255, 200, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::iso_grid`](crate::Draw::iso_grid)
+///
+pub fn iso_grid<I>(image: &mut I, rect: Rect, grid: IsoGrid, color: I::Pixel)
+where
+    I: GenericImage,
+{
+    grid.draw(image, rect, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn diamond_fill_matches_outline() {
+        let tile = Diamond::new((20, 12), (16, 8));
+        let mut outline = crate::test::img::blank((41, 25));
+        tile.draw(&mut outline, RED);
+        let mut filled = crate::test::img::blank((41, 25));
+        tile.draw_filled(&mut filled, RED);
+
+        // The fill covers the outline and everything inside of it
+        for (x, y, px) in filled.enumerate_pixels() {
+            let on_outline = *outline.get_pixel(x, y) == RED;
+            let (x, y) = (i32::try_from(x).unwrap(), i32::try_from(y).unwrap());
+            assert_eq!(
+                *px == RED,
+                on_outline || tile.contains((x, y)),
+                "({x}, {y})"
+            );
+        }
+        // The edges step 2 across for each pixel down
+        let row = |y| -> Vec<u32> {
+            (0..41)
+                .filter(|&x| *outline.get_pixel(x, y) == RED)
+                .collect()
+        };
+        assert_eq!(row(5), [18, 19, 22, 23]);
+        assert_eq!(row(6), [16, 17, 24, 25]);
+        assert_eq!(row(19), [18, 19, 22, 23]);
+    }
+
+    #[test]
+    fn clipped_diamonds_match_unclipped() {
+        for (center, radii) in [((5, 3), (16, 8)), ((38, 20), (9, 13)), ((-4, 10), (20, 3))] {
+            let tile = Diamond::new(center, radii);
+            let mut image = crate::test::img::blank((40, 24));
+            tile.draw_filled(&mut image, RED);
+
+            // The same diamond drawn on a larger image, then cropped
+            let moved = Diamond::new((center.0 + 30, center.1 + 30), radii);
+            let mut large = crate::test::img::blank((100, 84));
+            moved.draw_filled(&mut large, RED);
+            let cropped = image::imageops::crop_imm(&large, 30, 30, 40, 24).to_image();
+            assert_eq!(image, cropped, "{center:?} {radii:?}");
+        }
+    }
+
+    #[test]
+    fn huge_diamonds() {
+        let mut image = crate::test::img::blank((20, 20));
+        Diamond::new((10, 10), (u32::MAX, u32::MAX)).draw_filled(&mut image, RED);
+        assert!(image.pixels().all(|px| *px == RED));
+
+        let mut image = crate::test::img::blank((20, 20));
+        let tile = Diamond::new((10, 10), (1_000_000_000, 1_000_000_000));
+        tile.draw_filled(&mut image, RED);
+        assert!(image.pixels().all(|px| *px == RED));
+    }
+
+    #[test]
+    fn grid_coordinates() {
+        let grid = IsoGrid::new((100, 50), 20, 10);
+        for (i, j) in [(0, 0), (3, -2), (-5, 7), (4, 4)] {
+            let center = grid.to_pixel(i, j);
+            assert_eq!(grid.from_pixel(center), (i, j));
+            // Points just inside each corner
+            let tile = grid.tile(i, j);
+            let [top, right, bottom, left] = tile.corners();
+            assert_eq!(grid.from_pixel((top.x(), top.y() + 1)), (i, j));
+            assert_eq!(grid.from_pixel((right.x() - 1, right.y())), (i, j));
+            assert_eq!(grid.from_pixel((bottom.x(), bottom.y() - 1)), (i, j));
+            assert_eq!(grid.from_pixel((left.x() + 1, left.y())), (i, j));
+        }
+        // Odd sizes are rounded down
+        assert_eq!(IsoGrid::new((0, 0), 21, 11), IsoGrid::new((0, 0), 20, 10));
+    }
+
+    #[test]
+    fn grid_lines_follow_tiles() {
+        let grid = IsoGrid::new((33, 7), 16, 8);
+        let mut image = crate::test::img::blank((60, 40));
+        grid.draw(&mut image, Rect::new((5, 5), 30, 50), RED);
+
+        // Each tile's outline is part of the grid, inside of the rect
+        let mut tiles = crate::test::img::blank((60, 40));
+        for i in -8..12 {
+            for j in -8..12 {
+                grid.tile(i, j).draw(&mut tiles, RED);
+            }
+        }
+        for (x, y, px) in image.enumerate_pixels() {
+            let inside = (5..55).contains(&x) && (5..35).contains(&y);
+            let expected = inside && *tiles.get_pixel(x, y) == RED;
+            assert_eq!(*px == RED, expected, "({x}, {y})");
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::frame::Frame;
+use crate::pt::Point;
+use crate::raster::{fill_contours, FillRule};
+use crate::{Angle, Pt};
+use image::{Rgba, RgbaImage};
+
+/// The needle of a gauge or dial: a thin shape pointing away from a pivot,
+/// drawn by [`needle`].
+///
+/// The needle runs `length` pixels from the pivot to its tip, tapering from
+/// `width` at the back to `tip_width` at the tip.  It can also extend `tail`
+/// pixels behind the pivot, have rounded ends, and sit on a disc covering the
+/// pivot.
+///
+/// ```
+/// use freehand::shapes::Needle;
+///
+/// // A pointed needle with a short tail and a disc over the pivot
+/// let pointer = Needle {
+///     tail: 15.0,
+///     pivot_radius: 8.0,
+///     ..Needle::new(150.0, 6.0)
+/// };
+///
+/// // A rounded needle of even width
+/// let capsule = Needle::capsule(120.0, 5.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Needle {
+    /// The distance from the pivot to the tip in pixels
+    pub length: f32,
+    /// The width of the back of the needle in pixels
+    pub width: f32,
+    /// The width of the tip in pixels, or 0 for a sharp point
+    pub tip_width: f32,
+    /// How far the needle extends behind the pivot in pixels
+    pub tail: f32,
+    /// Whether both ends are rounded off with half circles
+    pub rounded: bool,
+    /// The radius of a disc drawn over the pivot, or 0 for none
+    pub pivot_radius: f32,
+}
+
+impl Needle {
+    /// Creates a needle that tapers from `width` at the pivot to a sharp
+    /// point, with no tail or pivot disc.
+    #[must_use]
+    pub fn new(length: f32, width: f32) -> Self {
+        Self {
+            length,
+            width,
+            tip_width: 0.0,
+            tail: 0.0,
+            rounded: false,
+            pivot_radius: 0.0,
+        }
+    }
+
+    /// Creates a needle that stays `width` pixels wide all the way to its tip,
+    /// with rounded ends.
+    #[must_use]
+    pub fn capsule(length: f32, width: f32) -> Self {
+        Self {
+            tip_width: width,
+            rounded: true,
+            ..Self::new(length, width)
+        }
+    }
+
+    /// Returns the outline of the needle pointing at `angle` from `pivot`,
+    /// not including the pivot disc.
+    ///
+    /// Angles follow the [angle conventions](crate#note-on-angles) used by the
+    /// rest of the crate, so 0° points right and 90° points up.
+    ///
+    /// ```
+    /// use freehand::shapes::Needle;
+    ///
+    /// let points = Needle::new(50.0, 10.0).outline((100, 100), 90);
+    /// // Straight up from the pivot
+    /// assert!((points[1].x() - 100.0).abs() < 1e-9);
+    /// assert!((points[1].y() - 50.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn outline<A, C, T>(&self, pivot: C, angle: A) -> Vec<Pt<f64>>
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let len = f64::from(self.length.max(0.0));
+        let tail = -f64::from(self.tail.max(0.0));
+        let hw = f64::from(self.width.max(0.0)) / 2.0;
+        let tw = f64::from(self.tip_width.max(0.0)) / 2.0;
+
+        // Points are found along the needle (u) and across it (v), then turned
+        // to face the angle.  The outline goes forward along one side and back
+        // along the other, so it always winds the same way as `disc`.
+        let mut local = vec![(tail, hw)];
+        if self.rounded {
+            local.extend(half_circle(len, tw, 90.0));
+            local.extend(half_circle(tail, hw, -90.0));
+        } else {
+            local.push((len, tw));
+            if tw > 0.0 {
+                local.push((len, -tw));
+            }
+            local.push((tail, -hw));
+        }
+        map(&frame(pivot, angle), &local)
+    }
+
+    /// Draws the needle pointing at `angle` from `pivot`, antialiased.  See
+    /// [`needle`].
+    pub fn draw<A, C, T>(&self, image: &mut RgbaImage, pivot: C, angle: A, color: Rgba<u8>)
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let mut contours = vec![self.outline(pivot, angle)];
+        let r = f64::from(self.pivot_radius.max(0.0));
+        if r > 0.0 {
+            let mut disc = half_circle(0.0, r, 90.0);
+            disc.extend(half_circle(0.0, r, -90.0));
+            contours.push(map(&frame(pivot, angle), &disc));
+        }
+        // The disc and needle wind the same way, so they are filled as one shape
+        fill_contours(image, contours, FillRule::NonZero, color);
+    }
+}
+
+/// Points along half of a circle centered `u` pixels along the needle,
+/// starting at `from` degrees and going clockwise (measured from the
+/// direction of the needle).
+fn half_circle(u: f64, r: f64, from: f64) -> Vec<(f64, f64)> {
+    if r == 0.0 {
+        return vec![(u, 0.0)];
+    }
+    let steps = (r * std::f64::consts::PI).ceil().clamp(4.0, 256.0) as u32;
+    (0..=steps)
+        .map(|i| {
+            let a = (from - 180.0 * f64::from(i) / f64::from(steps)).to_radians();
+            (r.mul_add(a.cos(), u), r * a.sin())
+        })
+        .collect()
+}
+
+/// Returns the frame for points measured along the needle from its pivot,
+/// and across it to the left.
+fn frame<A, C, T>(pivot: C, angle: A) -> Frame
+where
+    A: Angle,
+    C: Point<T>,
+    T: Into<f64> + Copy,
+{
+    let a = angle.radians();
+    Frame {
+        origin: Pt::new(pivot.x().into(), pivot.y().into()),
+        len: 1.0,
+        // Image coordinates have y going down
+        tangent: Pt::new(a.cos(), -a.sin()),
+    }
+}
+
+/// Turns points measured along and across the needle into image coordinates.
+fn map(frame: &Frame, points: &[(f64, f64)]) -> Vec<Pt<f64>> {
+    points.iter().map(|&(u, v)| frame.point(u, v)).collect()
+}
+
+/// Draws the antialiased needle of a gauge or dial pointing at `angle` from
+/// `pivot`.
+///
+/// Combine it with [`annulus`](crate::conics::annulus) or
+/// [`radial_ticks`](crate::lines::radial_ticks) to draw the face of a dial or
+/// speedometer.  The needle and its pivot disc are filled together, so the
+/// parts that overlap are only blended once.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::lines::{radial_ticks, Ticks};
+/// use freehand::shapes::{needle, Needle};
+/// use freehand::Degrees;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// let black = Rgba([0, 0, 0, 255]);
+///
+/// // A speedometer face sweeping 270° clockwise from 225°, pointing at 60%
+/// radial_ticks(&mut image, -45, 225, 180, (200, 200), Ticks::new(11, 15), black);
+/// let pointer = Needle {
+///     pivot_radius: 10.0,
+///     ..Needle::new(160.0, 8.0)
+/// };
+/// let angle = Degrees(225.0 - 0.6 * 270.0);
+/// needle(&mut image, (200, 200), angle, pointer, Rgba([200, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::needle`](crate::Draw::needle)
+///
+pub fn needle<A, C, T>(image: &mut RgbaImage, pivot: C, angle: A, needle: Needle, color: Rgba<u8>)
+where
+    A: Angle,
+    C: Point<T>,
+    T: Into<f64> + Copy,
+{
+    needle.draw(image, pivot, angle, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    #[test]
+    fn points_along_angle() {
+        let mut image = crate::test::img::blank((100, 100));
+        needle(&mut image, (50, 50), 0, Needle::new(40.0, 8.0), RED);
+
+        // Solid near the pivot, thinner towards the tip
+        assert_eq!(*image.get_pixel(55, 50), RED);
+        assert_eq!(*image.get_pixel(55, 52), RED);
+        assert_eq!(*image.get_pixel(85, 52), WHITE);
+        assert_eq!(*image.get_pixel(80, 50), RED);
+        // Nothing behind the pivot or past the tip
+        assert_eq!(*image.get_pixel(45, 50), WHITE);
+        assert_eq!(*image.get_pixel(92, 50), WHITE);
+
+        // 90° points up
+        let mut image = crate::test::img::blank((100, 100));
+        needle(
+            &mut image,
+            (50.0, 50.0),
+            90,
+            Needle::capsule(30.0, 4.0),
+            RED,
+        );
+        assert_eq!(*image.get_pixel(50, 25), RED);
+        assert_eq!(*image.get_pixel(50, 75), WHITE);
+    }
+
+    #[test]
+    fn tail_and_pivot_blend_once() {
+        let color = Rgba([255, 0, 0, 128]);
+        let pointer = Needle {
+            tail: 10.0,
+            pivot_radius: 6.0,
+            ..Needle::new(30.0, 4.0)
+        };
+        let mut image = crate::test::img::blank((100, 100));
+        needle(&mut image, (50, 50), 0, pointer, color);
+
+        // Where the needle and the disc overlap is the same as the disc alone
+        assert_eq!(image.get_pixel(50, 50), image.get_pixel(50, 54));
+        assert_ne!(*image.get_pixel(50, 54), WHITE);
+        // The tail sticks out past the disc
+        assert_ne!(*image.get_pixel(42, 50), WHITE);
+        assert_eq!(*image.get_pixel(38, 50), WHITE);
+    }
+
+    #[test]
+    fn outline_winds_with_disc() {
+        let area = |pts: &[Pt<f64>]| {
+            pts.iter()
+                .zip(pts.iter().cycle().skip(1))
+                .map(|(a, b)| a.x() * b.y() - b.x() * a.y())
+                .sum::<f64>()
+        };
+        let frame = frame((0, 0), 30);
+        let mut disc = half_circle(0.0, 5.0, 90.0);
+        disc.extend(half_circle(0.0, 5.0, -90.0));
+        let disc = area(&map(&frame, &disc));
+        for shape in [Needle::new(40.0, 8.0), Needle::capsule(40.0, 8.0)] {
+            let needle = area(&shape.outline((0, 0), 30));
+            assert!(needle * disc > 0.0, "{shape:?}");
+        }
+    }
+}
@@ -0,0 +1,599 @@
+use crate::geo::segment_distance;
+use crate::pt::Point;
+use crate::Pt;
+use image::{GenericImage, Rgba, RgbaImage};
+
+/// How far from an edge, in pixels, a point can be while still being considered
+/// part of the polygon (half the diagonal of a pixel).
+const EDGE_TOLERANCE: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// A closed polygon made from a list of vertices.
+///
+/// The last vertex is automatically connected back to the first one.
+///
+/// ```
+/// use freehand::shapes::Polygon;
+///
+/// let triangle = Polygon::new([(10, 10), (30, 10), (20, 30)]);
+/// assert!(triangle.contains((20, 15)));
+/// assert!(!triangle.contains((10, 30)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Polygon {
+    points: Vec<Pt<i32>>,
+}
+
+impl Polygon {
+    /// Creates a new polygon from a list of vertices.
+    pub fn new<P, It>(points: It) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        Self {
+            points: points.into_iter().map(|p| p.pt()).collect(),
+        }
+    }
+
+    /// Returns the vertices of the polygon.
+    #[must_use]
+    pub fn points(&self) -> &[Pt<i32>] {
+        &self.points
+    }
+
+    /// Returns true if a point lies inside the polygon or on its outline.
+    ///
+    /// The interior is determined using the even-odd rule, so areas where a
+    /// self-intersecting polygon overlaps itself an even number of times are
+    /// considered outside.
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: Point<i32>,
+    {
+        let p = Pt::new(f64::from(pt.x()), f64::from(pt.y()));
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            if segment_distance(p, a, b) <= EDGE_TOLERANCE {
+                return true;
+            }
+            // Cast a ray to the right and count the edges it crosses
+            if (a.y() > p.y()) != (b.y() > p.y()) {
+                let x = a.x() + (p.y() - a.y()) / (b.y() - a.y()) * (b.x() - a.x());
+                if p.x() < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Draws the outline of the polygon.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        self.draw_with_stats(image, color);
+    }
+
+    /// Draws the outline of the polygon and returns how many pixels were drawn
+    /// and clipped.
+    ///
+    /// Pixels shared by two edges are counted for each edge.
+    pub fn draw_with_stats<I>(&self, image: &mut I, color: I::Pixel) -> crate::DrawStats
+    where
+        I: GenericImage,
+    {
+        let mut stats = crate::DrawStats::default();
+        let (width, height) = image.dimensions();
+        let bounds = crate::shapes::Rect::new((0, 0), height, width);
+        let first = self.points.first().copied();
+        let mut points = self.points.iter().copied().chain(first);
+
+        if let Some(mut a) = points.next() {
+            for b in points {
+                // Only the visible part of each edge is stepped through
+                let dx = (i64::from(b.x()) - i64::from(a.x())).unsigned_abs();
+                let dy = (i64::from(b.y()) - i64::from(a.y())).unsigned_abs();
+                let mut edge = crate::DrawStats::new(0, dx.max(dy) + 1);
+                if let Some((_, iter)) = crate::lines::clipped_iter(a, b, bounds) {
+                    for pt in iter {
+                        image.put_pixel(pt.x() as u32, pt.y() as u32, color);
+                        edge.drawn += 1;
+                        edge.clipped -= 1;
+                    }
+                }
+                stats += edge;
+                a = b;
+            }
+        }
+        stats
+    }
+
+    /// Draws the polygon filled in using the even-odd rule.
+    ///
+    /// A pixel is filled when its center lies inside the polygon.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw_filled(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw_filled<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let (width, height) = image.dimensions();
+        let mut crossings = Vec::new();
+
+        for y in self.rows(height) {
+            self.crossings(f64::from(y), &mut crossings);
+            for span in crossings.chunks_exact(2) {
+                // Pixels with their centers in `span[0]..span[1]`
+                let x0 = span[0].ceil().max(0.0);
+                let x1 = span[1].ceil().min(f64::from(width));
+                for x in x0 as u32..x1.max(x0) as u32 {
+                    image.put_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// Draws the polygon filled in using the even-odd rule, with antialiased
+    /// edges.
+    ///
+    /// Pixels on the boundary are blended by how much of their area is
+    /// covered by the polygon.  To fill many polygons without allocating each
+    /// time use [`Rasterizer::fill_polygon`](crate::raster::Rasterizer::fill_polygon).
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw_filled_antialiased(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See also: [`Draw::polygon_filled_antialiased`](crate::Draw::polygon_filled_antialiased)
+    ///
+    pub fn draw_filled_antialiased(&self, image: &mut RgbaImage, color: Rgba<u8>) {
+        crate::raster::Rasterizer::new().fill_polygon(image, self, color);
+    }
+
+    /// Returns the outline of the polygon with each corner replaced by an arc
+    /// of the given radius, as a list of points to connect with straight
+    /// lines.
+    ///
+    /// Each arc meets the edges on both sides at a tangent.  Where two corners
+    /// are too close together for the full radius, both arcs are made smaller
+    /// so they meet in the middle of the edge between them.  Corners where
+    /// the edges continue in a straight line are left as they are.
+    ///
+    /// The points can be passed to [`fill_path`](crate::raster::fill_path) to
+    /// fill the rounded polygon with antialiased edges.
+    ///
+    /// ```
+    /// use freehand::shapes::Polygon;
+    ///
+    /// let square = Polygon::new([(0, 0), (40, 0), (40, 40), (0, 40)]);
+    /// let rounded = square.rounded_points(10.0);
+    ///
+    /// // The corner itself is cut off
+    /// assert!(rounded.iter().all(|pt| pt.x() + pt.y() > 5.0));
+    /// ```
+    #[must_use]
+    pub fn rounded_points(&self, radius: f64) -> Vec<Pt<f64>> {
+        let n = self.points.len();
+        let mut out = Vec::with_capacity(n * 4);
+        for i in 0..n {
+            let prev = to_f64(self.points[(i + n - 1) % n]);
+            let corner = to_f64(self.points[i]);
+            let next = to_f64(self.points[(i + 1) % n]);
+            match crate::conics::corner_arc(prev, corner, next, radius) {
+                Some(arc) => out.extend(arc.points()),
+                None => out.push(corner),
+            }
+        }
+        out
+    }
+
+    /// Draws the outline of the polygon with rounded corners.  See
+    /// [`rounded_points`](Self::rounded_points).
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw_rounded(&mut image, 30.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See also: [`Draw::rounded_polygon`](crate::Draw::rounded_polygon)
+    ///
+    pub fn draw_rounded<I>(&self, image: &mut I, radius: f64, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        self.rounded_polygon(radius).draw(image, color);
+    }
+
+    /// Draws the polygon with rounded corners, filled in using the even-odd
+    /// rule.  See [`rounded_points`](Self::rounded_points).
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw_rounded_filled(&mut image, 30.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw_rounded_filled<I>(&self, image: &mut I, radius: f64, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        self.rounded_polygon(radius).draw_filled(image, color);
+    }
+
+    /// Draws the polygon with rounded corners, filled in using the even-odd
+    /// rule with antialiased edges.  See [`rounded_points`](Self::rounded_points).
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw_rounded_filled_antialiased(&mut image, 30.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See also: [`Draw::rounded_polygon_filled_antialiased`](crate::Draw::rounded_polygon_filled_antialiased)
+    ///
+    pub fn draw_rounded_filled_antialiased(
+        &self,
+        image: &mut RgbaImage,
+        radius: f64,
+        color: Rgba<u8>,
+    ) {
+        crate::raster::fill_path(
+            image,
+            self.rounded_points(radius),
+            crate::raster::FillRule::EvenOdd,
+            color,
+        );
+    }
+
+    /// Returns the vertices of the polygon moved outwards by `distance`, or
+    /// inwards if `distance` is negative.
+    ///
+    /// Every edge is moved the same distance along its normal, and the moved
+    /// edges are extended until they meet again.  Sharp corners that would
+    /// stick out more than four times the distance are cut off with a short
+    /// extra edge (a bevel), so those corners produce two points.  Works the
+    /// same for vertices listed in either direction.
+    ///
+    /// This is a simple offset: insetting by more than the polygon's
+    /// thickness turns it inside out instead of making it disappear, and the
+    /// corners of concave polygons may overlap.
+    ///
+    /// ```
+    /// use freehand::shapes::Polygon;
+    /// use freehand::Pt;
+    ///
+    /// let square = Polygon::new([(10, 10), (30, 10), (30, 30), (10, 30)]);
+    /// let margin = square.offset_points(5.0);
+    /// assert_eq!(margin[0], Pt::new(5.0, 5.0));
+    /// assert_eq!(margin[2], Pt::new(35.0, 35.0));
+    ///
+    /// let inset = square.offset_points(-5.0);
+    /// assert_eq!(inset[0], Pt::new(15.0, 15.0));
+    /// ```
+    #[must_use]
+    pub fn offset_points(&self, distance: f64) -> Vec<Pt<f64>> {
+        let mut points: Vec<Pt<f64>> = self.points.iter().map(|p| to_f64(*p)).collect();
+        points.dedup();
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() < 3 || distance == 0.0 {
+            return points;
+        }
+
+        // Positive area means the normals below point outwards
+        let area: f64 = self
+            .edges()
+            .map(|(a, b)| a.x() * b.y() - b.x() * a.y())
+            .sum();
+        let side = if area < 0.0 { -distance } else { distance };
+
+        let n = points.len();
+        let mut out = Vec::with_capacity(n);
+        for i in 0..n {
+            let (prev, pt, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            crate::frame::offset_corner(prev, pt, next, side, &mut out);
+        }
+        out
+    }
+
+    /// Returns a copy of the polygon moved outwards by `distance`, or
+    /// inwards if `distance` is negative, with the vertices rounded to the
+    /// nearest pixel.  See [`offset_points`](Self::offset_points).
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Polygon;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let triangle = Polygon::new([(10, 10), (390, 10), (200, 390)]);
+    /// triangle.draw_filled(&mut image, Rgba([255, 0, 0, 255]));
+    /// // A border inside of the edge
+    /// triangle.offset(-5.0).draw(&mut image, Rgba([0, 0, 0, 255]));
+    /// ```
+    #[must_use]
+    pub fn offset(&self, distance: f64) -> Self {
+        Self::from_f64(self.offset_points(distance))
+    }
+
+    /// Rounds points to the nearest pixel centers, skipping repeated points.
+    fn from_f64(points: Vec<Pt<f64>>) -> Self {
+        let mut points: Vec<Pt<i32>> = points
+            .into_iter()
+            .map(|pt| Pt::new(pt.x().round() as i32, pt.y().round() as i32))
+            .collect();
+        points.dedup();
+        Self { points }
+    }
+
+    /// The rounded outline snapped to pixel centers, skipping repeated points.
+    fn rounded_polygon(&self, radius: f64) -> Self {
+        Self::from_f64(self.rounded_points(radius))
+    }
+
+    /// Returns the rows of pixels inside the image that the polygon may cover.
+    fn rows(&self, height: u32) -> std::ops::Range<u32> {
+        let ys = self.points.iter().map(Pt::y);
+        match (ys.clone().min(), ys.max()) {
+            (Some(min), Some(max)) if max >= 0 && height > 0 => {
+                let min = min.max(0) as u32;
+                let max = (max as u32).min(height - 1);
+                min..(max + 1).max(min)
+            }
+            _ => 0..0,
+        }
+    }
+
+    /// Finds where the edges cross the horizontal line at `y`, sorted from
+    /// left to right.  Each pair of crossings is a span inside the polygon.
+    fn crossings(&self, y: f64, out: &mut Vec<f64>) {
+        out.clear();
+        for (a, b) in self.edges() {
+            if (a.y() > y) != (b.y() > y) {
+                out.push(a.x() + (y - a.y()) / (b.y() - a.y()) * (b.x() - a.x()));
+            }
+        }
+        out.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    /// Returns each edge of the polygon, including the closing edge.
+    fn edges(&self) -> impl Iterator<Item = (Pt<f64>, Pt<f64>)> + '_ {
+        let next = self.points.iter().cycle().skip(1);
+        self.points
+            .iter()
+            .zip(next)
+            .map(|(a, b)| (to_f64(*a), to_f64(*b)))
+    }
+}
+
+fn to_f64(pt: Pt<i32>) -> Pt<f64> {
+    Pt::new(f64::from(pt.x()), f64::from(pt.y()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn polygon_contains() {
+        let poly = Polygon::new([(5, 5), (40, 8), (30, 35), (18, 20), (3, 30)]);
+        let mut image = crate::test::img::blank((50, 50));
+        poly.draw(&mut image, image::Rgba([255, 0, 0, 255]));
+
+        for (x, y, px) in image.enumerate_pixels() {
+            if px.0 == [255, 0, 0, 255] {
+                assert!(poly.contains((x as i32, y as i32)), "({x}, {y})");
+            }
+        }
+
+        assert!(poly.contains((20, 10)));
+        assert!(poly.contains((28, 25)));
+        assert!(!poly.contains((18, 28)));
+        assert!(!poly.contains((45, 20)));
+        assert!(!poly.contains((-5, 10)));
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn polygon_filled() {
+        let poly = Polygon::new([(5, 5), (40, 8), (30, 35), (18, 20), (3, 30)]);
+        let mut image = crate::test::img::blank((50, 50));
+        poly.draw_filled(&mut image, image::Rgba([255, 0, 0, 255]));
+
+        for (x, y, px) in image.enumerate_pixels() {
+            if px.0 == [255, 0, 0, 255] {
+                assert!(poly.contains((x as i32, y as i32)), "({x}, {y})");
+            }
+        }
+        assert_eq!(image.get_pixel(20, 10).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(18, 28).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn polygon_filled_antialiased() {
+        // Right triangle with an area of 200 pixels
+        let poly = Polygon::new([(10, 10), (30, 10), (10, 30)]);
+        let mut image = crate::test::img::blank((40, 40));
+        poly.draw_filled_antialiased(&mut image, image::Rgba([0, 0, 0, 255]));
+
+        let area: f64 = image
+            .pixels()
+            .map(|px| f64::from(255 - px[0]) / 255.0)
+            .sum();
+        assert!((area - 200.0).abs() < 1.0, "{area}");
+
+        // Fully inside, on the diagonal edge, and outside
+        assert_eq!(image.get_pixel(15, 15).0, [0, 0, 0, 255]);
+        assert!((100..156).contains(&image.get_pixel(20, 20)[0]));
+        assert_eq!(image.get_pixel(25, 25).0, [255, 255, 255, 255]);
+        // Vertical edge cuts pixels in half
+        assert!((120..136).contains(&image.get_pixel(10, 20)[0]));
+    }
+
+    #[test]
+    fn polygon_filled_clips() {
+        let poly = Polygon::new([(-20, -20), (60, -10), (20, 80)]);
+        let mut image = crate::test::img::blank((40, 40));
+        poly.draw_filled_antialiased(&mut image, image::Rgba([0, 0, 0, 255]));
+        poly.draw_filled(&mut image, image::Rgba([0, 0, 0, 255]));
+        assert_eq!(image.get_pixel(0, 0).0, [0, 0, 0, 255]);
+
+        let mut image = crate::test::img::blank((40, 40));
+        Polygon::new([(50, 50), (60, 50), (55, 60)])
+            .draw_filled_antialiased(&mut image, image::Rgba([0, 0, 0, 255]));
+        assert!(image.pixels().all(|px| px.0 == [255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn polygon_contains_degenerate() {
+        assert!(!Polygon::new(Vec::<(i32, i32)>::new()).contains((0, 0)));
+
+        let dot = Polygon::new([(3, 3)]);
+        assert!(dot.contains((3, 3)));
+        assert!(!dot.contains((4, 3)));
+    }
+
+    #[test]
+    fn rounded_corners() -> Result<(), image::ImageError> {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let square = Polygon::new([(10, 10), (50, 10), (50, 50), (10, 50)]);
+
+        // A radius of 0 is the same as the original polygon
+        let mut expected = crate::test::img::blank((60, 60));
+        square.draw(&mut expected, red);
+        let mut image = crate::test::img::blank((60, 60));
+        square.draw_rounded(&mut image, 0.0, red);
+        assert_eq!(image, expected);
+
+        let mut image = crate::test::img::blank((60, 60));
+        square.draw_rounded(&mut image, 10.0, red);
+        // The straight parts of the edges are kept, the corners are cut off
+        assert_eq!(*image.get_pixel(30, 10), red);
+        assert_eq!(*image.get_pixel(10, 30), red);
+        assert_ne!(*image.get_pixel(10, 10), red);
+        assert_ne!(*image.get_pixel(50, 50), red);
+        // The arc passes through the point 45 degrees around each corner
+        assert_eq!(*image.get_pixel(13, 13), red);
+        assert_eq!(*image.get_pixel(47, 47), red);
+
+        // The filled version covers the same area as the outline, and its
+        // antialiased area is close to a square minus the rounded corners
+        let mut filled = crate::test::img::blank((60, 60));
+        square.draw_rounded_filled(&mut filled, 10.0, red);
+        assert_eq!(*filled.get_pixel(30, 30), red);
+        assert_ne!(*filled.get_pixel(10, 10), red);
+
+        let mut aa = crate::test::img::blank((60, 60));
+        square.draw_rounded_filled_antialiased(&mut aa, 10.0, image::Rgba([0, 0, 0, 255]));
+        let area: f64 = aa.pixels().map(|px| f64::from(255 - px[0]) / 255.0).sum();
+        let expected = 1600.0 - (400.0 - std::f64::consts::PI * 100.0);
+        assert!((area - expected).abs() < 4.0, "{area} {expected}");
+
+        image.save("images/rounded_polygon.png")
+    }
+
+    #[test]
+    fn rounded_radius_limited() {
+        // The short edge limits both of its corners, so the arcs meet in
+        // the middle of it
+        let poly = Polygon::new([(0, 0), (100, 0), (100, 10), (0, 10)]);
+        let points = poly.rounded_points(20.0);
+        assert!(points
+            .iter()
+            .any(|pt| (pt.x() - 100.0).abs() < 1e-9 && (pt.y() - 5.0).abs() < 1e-9));
+        for pt in points {
+            assert!(pt.x() >= 0.0 && pt.x() <= 100.0, "{pt:?}");
+            assert!(pt.y() >= 0.0 && pt.y() <= 10.0, "{pt:?}");
+        }
+
+        // Straight corners are left alone
+        let poly = Polygon::new([(0, 0), (10, 0), (20, 0), (10, 10)]);
+        assert!(poly.rounded_points(3.0).contains(&Pt::new(10.0, 0.0)));
+        assert!(Polygon::default().rounded_points(3.0).is_empty());
+    }
+
+    #[test]
+    fn offset_either_direction() {
+        let square = [(10, 10), (30, 10), (30, 30), (10, 30)];
+        let mut reversed = square;
+        reversed.reverse();
+
+        for points in [square, reversed] {
+            let poly = Polygon::new(points);
+            let outset = poly.offset(5.0);
+            let inset = poly.offset(-5.0);
+            for p in outset.points() {
+                assert!(
+                    [5, 35].contains(&p.x()) && [5, 35].contains(&p.y()),
+                    "{p:?}"
+                );
+            }
+            for p in inset.points() {
+                assert!(
+                    [15, 25].contains(&p.x()) && [15, 25].contains(&p.y()),
+                    "{p:?}"
+                );
+            }
+            assert_eq!(poly.offset(0.0), poly);
+        }
+    }
+
+    #[test]
+    fn offset_distance_and_bevels() {
+        // Every offset edge is the given distance from the original edge
+        let poly = Polygon::new([(0, 0), (40, 5), (35, 30), (20, 15), (5, 35)]);
+        let offset = poly.offset_points(3.0);
+        assert_eq!(offset.len(), 5);
+        for (i, (a, b)) in poly.edges().enumerate() {
+            let moved = offset[i];
+            let d = segment_distance(moved, a, b);
+            assert!(d >= 3.0 - 1e-9, "{i} {d}");
+        }
+
+        // A very sharp corner is cut off instead of extending far away
+        let spike = Polygon::new([(0, 0), (100, 5), (0, 10)]);
+        let offset = spike.offset_points(2.0);
+        assert_eq!(offset.len(), 4);
+        assert!(offset.iter().all(|p| p.x() < 110.0), "{offset:?}");
+
+        // Repeated and closing points are ignored
+        let poly = Polygon::new([(10, 10), (30, 10), (30, 10), (30, 30), (10, 30), (10, 10)]);
+        assert_eq!(poly.offset_points(1.0).len(), 4);
+        assert_eq!(Polygon::new([(1, 1), (2, 2)]).offset_points(1.0).len(), 2);
+    }
+}
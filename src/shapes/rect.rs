@@ -1,7 +1,139 @@
 use image::GenericImage;
 
+/// Returns the last coordinate covered by a span of `len` pixels starting at `start`.
+pub(crate) fn far_edge(start: i32, len: u32) -> i32 {
+    let end = i64::from(start) + i64::from(len) - 1;
+    #[allow(clippy::cast_possible_truncation)]
+    let end = end.min(i64::from(i32::MAX)) as i32;
+    end
+}
+
+/// An axis-aligned rectangle, described by its upper left corner and size.
+///
+/// ```
+/// use freehand::shapes::Rect;
+///
+/// let rect = Rect::new((10, 10), 20, 40);
+/// assert!(rect.contains((49, 29)));
+/// assert!(!rect.contains((50, 29)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Rect {
+    /// The x coordinate of the upper left corner
+    pub x: i32,
+    /// The y coordinate of the upper left corner
+    pub y: i32,
+    /// The width in pixels
+    pub width: u32,
+    /// The height in pixels
+    pub height: u32,
+}
+
+impl Rect {
+    /// Creates a new `Rect` with its upper left corner at `pt`.
+    ///
+    /// The `height` and `width` are given in the same order as the rectangle
+    /// drawing functions.
+    pub fn new<P>(pt: P, height: u32, width: u32) -> Self
+    where
+        P: crate::pt::Point<i32>,
+    {
+        Self {
+            x: pt.x(),
+            y: pt.y(),
+            width,
+            height,
+        }
+    }
+
+    /// Returns true if a point lies inside the rectangle (including its edges).
+    #[must_use]
+    pub fn contains<P>(&self, pt: P) -> bool
+    where
+        P: crate::pt::Point<i32>,
+    {
+        self.width != 0
+            && self.height != 0
+            && (self.x..=far_edge(self.x, self.width)).contains(&pt.x())
+            && (self.y..=far_edge(self.y, self.height)).contains(&pt.y())
+    }
+
+    /// Draws the outline of the rectangle.  See [`rectangle`].
+    pub fn draw<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        rectangle(image, (self.x, self.y), self.height, self.width, color);
+    }
+
+    /// Draws the outline of the rectangle and returns how many pixels were
+    /// drawn and clipped.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Rect;
+    /// # let mut image = RgbaImage::new(100, 100);
+    ///
+    /// let stats = Rect::new((-5, 10), 10, 10).draw_with_stats(&mut image, Rgba([255, 0, 0, 255]));
+    /// assert_eq!(stats.total(), 36);
+    /// assert_eq!(stats.drawn, 18);
+    /// ```
+    pub fn draw_with_stats<I>(&self, image: &mut I, color: I::Pixel) -> crate::DrawStats
+    where
+        I: GenericImage,
+    {
+        self.draw(image, color);
+
+        let (width, height) = image.dimensions();
+        let area = |r: Rect| u64::from(r.width) * u64::from(r.height);
+        let visible = |r: Rect| area(r.intersect(Rect::new((0, 0), height, width)));
+
+        let inner = Rect::new(
+            (self.x.saturating_add(1), self.y.saturating_add(1)),
+            self.height.saturating_sub(2),
+            self.width.saturating_sub(2),
+        );
+        let drawn = visible(*self) - visible(inner);
+        crate::DrawStats::new(drawn, area(*self) - area(inner) - drawn)
+    }
+
+    /// Returns the area covered by both rectangles.  The result has a width
+    /// and height of 0 if they do not overlap.
+    pub(crate) fn intersect(&self, other: Rect) -> Rect {
+        let x1 = self.x.max(other.x);
+        let y1 = self.y.max(other.y);
+        let x2 = far_edge(self.x, self.width).min(far_edge(other.x, other.width));
+        let y2 = far_edge(self.y, self.height).min(far_edge(other.y, other.height));
+
+        if self.width == 0 || self.height == 0 || other.width == 0 || other.height == 0 {
+            return Rect::new((x1, y1), 0, 0);
+        }
+        let len = |a: i32, b: i32| (i64::from(b) - i64::from(a) + 1).max(0) as u32;
+        Rect::new((x1, y1), len(y1, y2), len(x1, x2))
+    }
+
+    /// Returns true if any part of the rectangle is inside an image of the
+    /// given size.  Used to skip drawing shapes that are entirely off screen.
+    pub(crate) fn is_visible_in(&self, width: u32, height: u32) -> bool {
+        let visible = self.intersect(Rect::new((0, 0), height, width));
+        visible.width != 0 && visible.height != 0
+    }
+
+    /// Draws the rectangle filled.  See [`rectangle_filled`].
+    pub fn draw_filled<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        rectangle_filled(image, (self.x, self.y), self.height, self.width, color);
+    }
+}
+
 /// Draws a basic rectangle.
 ///
+/// The point may lie outside of the image, including negative coordinates; only the
+/// visible part of the rectangle is drawn.
+///
 /// # Example
 ///
 /// ```
@@ -19,21 +151,25 @@ use image::GenericImage;
 pub fn rectangle<I, P>(image: &mut I, pt: P, height: u32, width: u32, color: I::Pixel)
 where
     I: GenericImage,
-    P: crate::pt::Point<u32>,
+    P: crate::pt::Point<i32>,
 {
+    if width == 0 || height == 0 {
+        return;
+    }
+
     let x0 = pt.x();
-    let x1 = x0 + width - 1;
+    let x1 = far_edge(x0, width);
     let y0 = pt.y();
-    let y1 = y0 + height - 1;
+    let y1 = far_edge(y0, height);
 
     // Top
-    crate::lines::horizontal_line(image, crate::Pt::new(x0 + 1, y0), x1, color);
+    crate::lines::horizontal_line(image, crate::Pt::new(x0.saturating_add(1), y0), x1, color);
     // Bottom
-    crate::lines::horizontal_line(image, crate::Pt::new(x0, y1), x1 - 1, color);
+    crate::lines::horizontal_line(image, crate::Pt::new(x0, y1), x1.saturating_sub(1), color);
     // Left
-    crate::lines::vertical_line(image, crate::Pt::new(x0, y0), y1 - 1, color);
+    crate::lines::vertical_line(image, crate::Pt::new(x0, y0), y1.saturating_sub(1), color);
     // Right
-    crate::lines::vertical_line(image, crate::Pt::new(x1, y0 + 1), y1, color);
+    crate::lines::vertical_line(image, crate::Pt::new(x1, y0.saturating_add(1)), y1, color);
 }
 
 /// Draws a basic rectangle with the specified opacity.
@@ -60,26 +196,57 @@ pub fn rectangle_alpha<P>(
     opacity: f32,
     color: image::Rgba<u8>,
 ) where
-    P: crate::pt::Point<u32>,
+    P: crate::pt::Point<i32>,
 {
+    if width == 0 || height == 0 {
+        return;
+    }
+
     let x0 = pt.x();
-    let x1 = x0 + width - 1;
+    let x1 = far_edge(x0, width);
     let y0 = pt.y();
-    let y1 = y0 + height - 1;
+    let y1 = far_edge(y0, height);
 
     // Top
-    crate::lines::horizontal_line_alpha(image, crate::Pt::new(x0 + 1, y0), x1, opacity, color);
+    crate::lines::horizontal_line_alpha(
+        image,
+        crate::Pt::new(x0.saturating_add(1), y0),
+        x1,
+        opacity,
+        color,
+    );
     // Bottom
-    crate::lines::horizontal_line_alpha(image, crate::Pt::new(x0, y1), x1 - 1, opacity, color);
+    crate::lines::horizontal_line_alpha(
+        image,
+        crate::Pt::new(x0, y1),
+        x1.saturating_sub(1),
+        opacity,
+        color,
+    );
     // Left
-    crate::lines::vertical_line_alpha(image, crate::Pt::new(x0, y0), y1 - 1, opacity, color);
+    crate::lines::vertical_line_alpha(
+        image,
+        crate::Pt::new(x0, y0),
+        y1.saturating_sub(1),
+        opacity,
+        color,
+    );
     // Right
-    crate::lines::vertical_line_alpha(image, crate::Pt::new(x1, y0 + 1), y1, opacity, color);
+    crate::lines::vertical_line_alpha(
+        image,
+        crate::Pt::new(x1, y0.saturating_add(1)),
+        y1,
+        opacity,
+        color,
+    );
 }
 
 /// Draws a filled rectangle.  The specified point represents the upper left cordner
 /// of the rectangle, and will be drawn using the given `height` and `width`.
 ///
+/// The point may lie outside of the image, including negative coordinates; only the
+/// visible part of the rectangle is drawn.
+///
 /// # Example
 ///
 /// ```
@@ -98,11 +265,17 @@ pub fn rectangle_alpha<P>(
 pub fn rectangle_filled<I, P>(image: &mut I, pt: P, height: u32, width: u32, color: I::Pixel)
 where
     I: GenericImage,
-    P: crate::pt::Point<u32>,
+    P: crate::pt::Point<i32>,
 {
+    if width == 0 || height == 0 {
+        return;
+    }
+
     let x0 = pt.x();
-    let x1 = pt.x() + width - 1;
-    for y in pt.y()..pt.y() + height {
+    let x1 = far_edge(x0, width);
+    #[allow(clippy::cast_possible_wrap)]
+    let max_y = image.height() as i32 - 1;
+    for y in pt.y().max(0)..=far_edge(pt.y(), height).min(max_y) {
         crate::lines::horizontal_line(image, crate::Pt::new(x0, y), x1, color);
     }
 }
@@ -132,13 +305,9 @@ pub fn rectangle_filled_alpha<P>(
     opacity: f32,
     color: image::Rgba<u8>,
 ) where
-    P: crate::pt::Point<u32>,
+    P: crate::pt::Point<i32>,
 {
-    let x0 = pt.x();
-    let x1 = pt.x() + width - 1;
-    for y in pt.y()..pt.y() + height {
-        crate::lines::horizontal_line_alpha(image, crate::Pt::new(x0, y), x1, opacity, color);
-    }
+    crate::ops::blend_rect(image, Rect::new(pt, height, width), opacity, color);
 }
 
 #[cfg(test)]
@@ -166,6 +335,58 @@ mod tests {
                 (3, 3)
             ]
         );
+        test_pixels_changed!(
+            rectangle_negative,
+            rectangle((-1, -1), 3, 3),
+            4,
+            &*vec![(1, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn rect_contains() {
+        let rect = Rect::new((-2, 3), 4, 5);
+        let mut image = crate::test::img::blank((10, 10));
+        rect.draw_filled(&mut image, image::Rgba([255, 0, 0, 255]));
+
+        for (x, y, px) in image.enumerate_pixels() {
+            let pt = (x as i32, y as i32);
+            assert_eq!(rect.contains(pt), px.0 == [255, 0, 0, 255], "{pt:?}");
+        }
+        assert!(rect.contains((-2, 6)));
+        assert!(!rect.contains((-3, 6)));
+        assert!(!Rect::new((0, 0), 0, 5).contains((0, 0)));
+    }
+
+    #[test]
+    fn rect_draw_stats() {
+        let mut image = crate::test::img::blank((10, 10));
+        let color = image::Rgba([255, 0, 0, 255]);
+        for (x, y, w, h) in [
+            (-3, 2, 6, 4),
+            (5, 5, 10, 10),
+            (2, 2, 1, 5),
+            (-10, -10, 5, 5),
+            (0, 0, 2, 2),
+        ] {
+            let mut image = image.clone();
+            let stats = Rect::new((x, y), h, w).draw_with_stats(&mut image, color);
+            let drawn = image.pixels().filter(|p| **p == color).count() as u64;
+            assert_eq!(stats.drawn, drawn, "{x} {y} {w} {h}");
+            assert_eq!(
+                stats.total(),
+                if w < 3 || h < 3 {
+                    u64::from(w * h)
+                } else {
+                    u64::from(2 * (w + h) - 4)
+                }
+            );
+        }
+        assert_eq!(
+            Rect::new((0, 0), 0, 4).draw_with_stats(&mut image, color),
+            crate::DrawStats::default()
+        );
     }
 
     mod rectangle_alpha {
@@ -209,6 +430,12 @@ mod tests {
             4,
             &*vec![(1, 1), (1, 2), (2, 1), (2, 2)]
         );
+        test_pixels_changed!(
+            rectangle_filled_negative,
+            rectangle_filled((-2, -2), 4, 4),
+            4,
+            &*vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
     }
 
     mod rectangle_filled_alpha {
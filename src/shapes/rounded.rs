@@ -0,0 +1,189 @@
+use super::Rect;
+use crate::ops::blend_at;
+use crate::pt::Point;
+use image::{Rgba, RgbaImage};
+
+/// Draws the outline of a rectangle with rounded, antialiased corners.  The
+/// specified point represents the upper left corner of the rectangle.
+///
+/// The `radius` is measured to the outer edge of the corners and is limited
+/// to half of the shorter side.  The corners and straight edges use the same
+/// coverage calculation, so they join without seams; a radius of `0.0` draws
+/// the same pixels as [`rectangle`](super::rectangle).
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::rounded_rectangle_antialiased;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// rounded_rectangle_antialiased(&mut image, (10, 10), 200, 380, 20.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::rounded_rectangle_antialiased`](crate::Draw::rounded_rectangle_antialiased)
+///
+pub fn rounded_rectangle_antialiased<P>(
+    image: &mut RgbaImage,
+    pt: P,
+    height: u32,
+    width: u32,
+    radius: f32,
+    color: Rgba<u8>,
+) where
+    P: Point<i32>,
+{
+    // The outline is 1px wide and centered on the outer pixel centers
+    let inset = 0.5;
+    let radius = (f64::from(radius) - inset).max(0.0);
+    draw(
+        image,
+        Rect::new(pt, height, width),
+        inset,
+        radius,
+        color,
+        |d| 1.0 - d.abs(),
+    );
+}
+
+/// Draws a filled rectangle with rounded, antialiased corners.  The specified
+/// point represents the upper left corner of the rectangle.
+///
+/// The `radius` is limited to half of the shorter side.  A radius of `0.0`
+/// draws the same pixels as [`rectangle_filled`](super::rectangle_filled).
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::shapes::rounded_rectangle_filled_antialiased;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+/// rounded_rectangle_filled_antialiased(&mut image, (10, 10), 200, 380, 20.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::rounded_rectangle_filled_antialiased`](crate::Draw::rounded_rectangle_filled_antialiased)
+///
+pub fn rounded_rectangle_filled_antialiased<P>(
+    image: &mut RgbaImage,
+    pt: P,
+    height: u32,
+    width: u32,
+    radius: f32,
+    color: Rgba<u8>,
+) where
+    P: Point<i32>,
+{
+    let radius = f64::from(radius).max(0.0);
+    draw(
+        image,
+        Rect::new(pt, height, width),
+        0.0,
+        radius,
+        color,
+        |d| 0.5 - d,
+    );
+}
+
+/// Blends every pixel near a rounded rectangle using the coverage returned by
+/// `coverage`, which is given the signed distance from the pixel center to
+/// the edge of the rectangle (negative inside).
+///
+/// The edges of the rectangle are moved inward from the outer edges of its
+/// pixels by `inset`.
+fn draw<F>(image: &mut RgbaImage, rect: Rect, inset: f64, radius: f64, color: Rgba<u8>, coverage: F)
+where
+    F: Fn(f64) -> f64,
+{
+    if rect.width == 0 || rect.height == 0 {
+        return;
+    }
+
+    // Half the size of the rectangle and its center
+    let hw = f64::from(rect.width) / 2.0 - inset;
+    let hh = f64::from(rect.height) / 2.0 - inset;
+    let cx = f64::from(rect.x) - 0.5 + f64::from(rect.width) / 2.0;
+    let cy = f64::from(rect.y) - 0.5 + f64::from(rect.height) / 2.0;
+    let radius = radius.min(hw.max(0.0)).min(hh.max(0.0));
+
+    let distance = |x: f64, y: f64| {
+        let qx = (x - cx).abs() - (hw - radius);
+        let qy = (y - cy).abs() - (hh - radius);
+        qx.max(0.0).hypot(qy.max(0.0)) + qx.max(qy).min(0.0) - radius
+    };
+
+    let (w, h) = (f64::from(image.width()), f64::from(image.height()));
+    let x0 = (cx - hw - 1.0).floor().max(0.0);
+    let y0 = (cy - hh - 1.0).floor().max(0.0);
+    let x1 = (cx + hw + 1.0).ceil().min(w - 1.0);
+    let y1 = (cy + hh + 1.0).ceil().min(h - 1.0);
+    if x0 > x1 || y0 > y1 {
+        return;
+    }
+
+    for y in y0 as u32..=y1 as u32 {
+        for x in x0 as u32..=x1 as u32 {
+            let c = coverage(distance(f64::from(x), f64::from(y))).clamp(0.0, 1.0);
+            if c > 0.0 {
+                blend_at(image, x, y, c as f32, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn square_corners_match_rectangle() {
+        let mut aliased = crate::test::img::blank((30, 30));
+        let mut image = aliased.clone();
+        crate::shapes::rectangle(&mut aliased, (3, 4), 20, 15, RED);
+        rounded_rectangle_antialiased(&mut image, (3, 4), 20, 15, 0.0, RED);
+        assert_eq!(image, aliased);
+
+        let mut aliased = crate::test::img::blank((30, 30));
+        let mut image = aliased.clone();
+        crate::shapes::rectangle_filled(&mut aliased, (-3, 4), 20, 15, RED);
+        rounded_rectangle_filled_antialiased(&mut image, (-3, 4), 20, 15, 0.0, RED);
+        assert_eq!(image, aliased);
+    }
+
+    #[test]
+    fn rounded_corners() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((100, 100));
+        rounded_rectangle_filled_antialiased(&mut image, (10, 10), 40, 80, 12.0, RED);
+        rounded_rectangle_antialiased(&mut image, (10, 60), 30, 80, 12.0, RED);
+
+        // The corner pixel is cut off, while the edges stay solid
+        assert_eq!(image.get_pixel(10, 10).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(50, 10).0, RED.0);
+        assert_eq!(image.get_pixel(10, 30).0, RED.0);
+        // Partially covered pixels along the corner arc
+        assert!(image.pixels().any(|px| px[1] > 0 && px[1] < 255));
+
+        assert_eq!(image.get_pixel(50, 60).0, RED.0);
+        assert_eq!(image.get_pixel(50, 61).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(10, 60).0, [255, 255, 255, 255]);
+
+        image.save("images/rounded_rectangle_aa.png")
+    }
+
+    #[test]
+    fn radius_is_limited() {
+        // A radius larger than the rectangle gives a circle instead of spilling out
+        let mut image = crate::test::img::blank((30, 30));
+        rounded_rectangle_filled_antialiased(&mut image, (5, 5), 20, 20, 100.0, RED);
+        assert_eq!(image.get_pixel(15, 15).0, RED.0);
+        assert_eq!(image.get_pixel(15, 6).0, RED.0);
+        assert_eq!(image.get_pixel(5, 5).0, [255, 255, 255, 255]);
+        for (x, y, px) in image.enumerate_pixels() {
+            if !(4..26).contains(&x) || !(4..26).contains(&y) {
+                assert_eq!(px.0, [255, 255, 255, 255], "({x}, {y})");
+            }
+        }
+    }
+}
@@ -0,0 +1,298 @@
+#![allow(clippy::many_single_char_names)]
+
+use crate::pt::Point;
+use image::{Rgba, RgbaImage};
+
+/// How a texture is sampled between its pixels.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Sampling {
+    /// Uses the closest texture pixel.  Fast, but looks blocky when scaled up.
+    /// This is the default.
+    Nearest,
+    /// Blends the four closest texture pixels together.
+    Bilinear,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Self::Nearest
+    }
+}
+
+impl Sampling {
+    /// Samples `texture` at texture coordinates `u` and `v`, where `(0.0, 0.0)`
+    /// is the upper left corner of the texture and `(1.0, 1.0)` is its lower
+    /// right corner.  Coordinates outside the texture use its closest edge.
+    ///
+    /// The texture must not be empty.
+    pub(crate) fn sample(self, texture: &RgbaImage, u: f32, v: f32) -> Rgba<u8> {
+        let (w, h) = (texture.width() as f32, texture.height() as f32);
+        let (x, y) = (u * w, v * h);
+        match self {
+            Self::Nearest => {
+                let x = x.floor().clamp(0.0, w - 1.0) as u32;
+                let y = y.floor().clamp(0.0, h - 1.0) as u32;
+                *texture.get_pixel(x, y)
+            }
+            Self::Bilinear => bilinear(texture, x - 0.5, y - 0.5, true),
+        }
+    }
+}
+
+/// Blends the four texture pixels around `(x, y)`, where texture pixel
+/// `(0, 0)` is centered on `(0.0, 0.0)`.
+///
+/// Pixels outside of the texture repeat its closest edge when `clamp` is true,
+/// otherwise they are transparent, which gives antialiased edges.  The colors
+/// are weighted by their alpha so transparent pixels do not darken the edges
+/// of opaque areas.
+pub(crate) fn bilinear(texture: &RgbaImage, x: f32, y: f32, clamp: bool) -> Rgba<u8> {
+    let (max_x, max_y) = (texture.width() as f32 - 1.0, texture.height() as f32 - 1.0);
+    let (fx, fy) = (x.floor(), y.floor());
+    let (tx, ty) = (x - fx, y - fy);
+    let texel = |x: f32, y: f32| {
+        if clamp {
+            Some(texture.get_pixel(x.clamp(0.0, max_x) as u32, y.clamp(0.0, max_y) as u32))
+        } else if (0.0..=max_x).contains(&x) && (0.0..=max_y).contains(&y) {
+            Some(texture.get_pixel(x as u32, y as u32))
+        } else {
+            None
+        }
+    };
+
+    let mut sum = [0.0f32; 4];
+    for (px, weight) in [
+        (texel(fx, fy), (1.0 - tx) * (1.0 - ty)),
+        (texel(fx + 1.0, fy), tx * (1.0 - ty)),
+        (texel(fx, fy + 1.0), (1.0 - tx) * ty),
+        (texel(fx + 1.0, fy + 1.0), tx * ty),
+    ] {
+        let px = match px {
+            Some(px) => px,
+            None => continue,
+        };
+        let a = f32::from(px[3]) * weight;
+        for (s, c) in sum.iter_mut().zip(&px.0[..3]) {
+            *s += f32::from(*c) * a;
+        }
+        sum[3] += a;
+    }
+
+    if sum[3] <= 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+    let a = sum[3];
+    Rgba([
+        (sum[0] / a).round() as u8,
+        (sum[1] / a).round() as u8,
+        (sum[2] / a).round() as u8,
+        a.round() as u8,
+    ])
+}
+
+/// Fills a triangle with pixels from a texture.
+///
+/// Each vertex is given along with its texture coordinates `(u, v)`, where
+/// `(0.0, 0.0)` is the upper left corner of `texture` and `(1.0, 1.0)` is its
+/// lower right corner.  The texture coordinates are interpolated across the
+/// triangle, so the part of the texture between the three coordinates is
+/// stretched onto the triangle.  Texture coordinates outside of `0.0..=1.0`
+/// repeat the closest edge of the texture.
+///
+/// Pixels whose centers lie inside the triangle are drawn, blended using the
+/// alpha of the texture.  Pixels on an edge shared by two triangles are only
+/// drawn by one of them, so meshes of triangles do not blend any pixel twice.
+///
+/// # Example
+///
+/// ```
+/// use freehand::shapes::{triangle_textured, Sampling};
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let texture = RgbaImage::from_fn(8, 8, |x, y| {
+///     if (x + y) % 2 == 0 { Rgba([0, 0, 0, 255]) } else { Rgba([255, 255, 255, 255]) }
+/// });
+///
+/// let vertices = [((200, 10), (0.5, 0.0)), ((390, 390), (1.0, 1.0)), ((10, 390), (0.0, 1.0))];
+/// triangle_textured(&mut image, vertices, &texture, Sampling::Bilinear);
+/// ```
+///
+/// See also: [`Draw::triangle_textured`](crate::Draw::triangle_textured)
+///
+pub fn triangle_textured<P>(
+    image: &mut RgbaImage,
+    vertices: [(P, (f32, f32)); 3],
+    texture: &RgbaImage,
+    sampling: Sampling,
+) where
+    P: Point<i32>,
+{
+    if texture.width() == 0 || texture.height() == 0 {
+        return;
+    }
+    let mut v = vertices.map(|(p, uv)| ((i64::from(p.x()), i64::from(p.y())), uv));
+
+    let mut area = edge(v[0].0, v[1].0, v[2].0);
+    if area == 0 {
+        return;
+    }
+    if area < 0 {
+        v.swap(1, 2);
+        area = -area;
+    }
+    let [(a, uv_a), (b, uv_b), (c, uv_c)] = v;
+
+    let (width, height) = (i64::from(image.width()), i64::from(image.height()));
+    let x0 = a.0.min(b.0).min(c.0).max(0);
+    let y0 = a.1.min(b.1).min(c.1).max(0);
+    let x1 = a.0.max(b.0).max(c.0).min(width - 1);
+    let y1 = a.1.max(b.1).max(c.1).min(height - 1);
+
+    // Pixels exactly on an edge only belong to the triangle for top and left edges
+    let bias = |p: (i64, i64), q: (i64, i64)| {
+        let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+        i64::from(!(dy < 0 || (dy == 0 && dx > 0)))
+    };
+    let (bias_a, bias_b, bias_c) = (bias(b, c), bias(c, a), bias(a, b));
+    let area = area as f32;
+
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            // Weights of each vertex, scaled by the area
+            let wa = edge(b, c, (x, y));
+            let wb = edge(c, a, (x, y));
+            let wc = edge(a, b, (x, y));
+            if wa < bias_a || wb < bias_b || wc < bias_c {
+                continue;
+            }
+
+            let (wa, wb, wc) = (wa as f32 / area, wb as f32 / area, wc as f32 / area);
+            let u = wa * uv_a.0 + wb * uv_b.0 + wc * uv_c.0;
+            let v = wa * uv_a.1 + wb * uv_b.1 + wc * uv_c.1;
+            let color = sampling.sample(texture, u, v);
+            if color[3] != 0 {
+                let opacity = f32::from(color[3]) / 255.0;
+                crate::ops::blend_at(image, x as u32, y as u32, opacity, color);
+            }
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p`.  Positive when `p` is
+/// on the inside of the edge from `a` to `b` for triangles with a positive
+/// area.
+fn edge(a: (i64, i64), b: (i64, i64), p: (i64, i64)) -> i64 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> RgbaImage {
+        RgbaImage::from_fn(4, 4, |x, y| {
+            if (x + y) % 2 == 0 {
+                Rgba([0, 0, 0, 255])
+            } else {
+                Rgba([255, 255, 255, 255])
+            }
+        })
+    }
+
+    #[test]
+    fn shared_edges_drawn_once() {
+        // Two triangles making up a square, using a half transparent texture
+        let texture = RgbaImage::from_pixel(1, 1, Rgba([0, 0, 0, 128]));
+        let mut image = crate::test::img::blank((20, 20));
+        let (tl, tr, br, bl) = ((2, 3), (12, 3), (12, 15), (2, 15));
+        for tri in [[tl, tr, br], [tl, br, bl]] {
+            let vertices = tri.map(|p| (p, (0.0, 0.0)));
+            triangle_textured(&mut image, vertices, &texture, Sampling::Nearest);
+        }
+
+        let first = image.get_pixel(5, 5).0;
+        assert!(first[0] > 100 && first[0] < 150, "{first:?}");
+        for (x, y, px) in image.enumerate_pixels() {
+            // Top and left edges are included, bottom and right edges are not
+            let inside = (2..12).contains(&x) && (3..15).contains(&y);
+            let expected = if inside { first } else { [255, 255, 255, 255] };
+            assert_eq!(px.0, expected, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn texture_mapping() {
+        // Stretch each checkerboard square over 4x4 pixels
+        let texture = checkerboard();
+        let mut image = crate::test::img::blank((16, 16));
+        let quad = [
+            ((0, 0), (0.0, 0.0)),
+            ((16, 0), (1.0, 0.0)),
+            ((16, 16), (1.0, 1.0)),
+        ];
+        triangle_textured(&mut image, quad, &texture, Sampling::Nearest);
+        let quad = [
+            ((0, 0), (0.0, 0.0)),
+            ((16, 16), (1.0, 1.0)),
+            ((0, 16), (0.0, 1.0)),
+        ];
+        triangle_textured(&mut image, quad, &texture, Sampling::Nearest);
+
+        for (x, y, px) in image.enumerate_pixels() {
+            assert_eq!(*px, *texture.get_pixel(x / 4, y / 4), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn bilinear_sampling() {
+        let texture = RgbaImage::from_fn(2, 1, |x, _| Rgba([x as u8 * 255, 0, 0, 255]));
+        // Texel centers keep their colors, halfway between them mixes evenly
+        assert_eq!(
+            Sampling::Bilinear.sample(&texture, 0.25, 0.5).0,
+            [0, 0, 0, 255]
+        );
+        assert_eq!(
+            Sampling::Bilinear.sample(&texture, 0.5, 0.5).0,
+            [128, 0, 0, 255]
+        );
+        assert_eq!(
+            Sampling::Bilinear.sample(&texture, 2.0, -1.0).0,
+            [255, 0, 0, 255]
+        );
+        assert_eq!(
+            Sampling::Nearest.sample(&texture, 0.49, 0.5).0,
+            [0, 0, 0, 255]
+        );
+
+        // Transparent pixels do not darken their neighbors
+        let texture = RgbaImage::from_fn(2, 1, |x, _| Rgba([255, 255, 255, x as u8 * 255]));
+        assert_eq!(
+            Sampling::Bilinear.sample(&texture, 0.5, 0.5).0,
+            [255, 255, 255, 128]
+        );
+    }
+
+    #[test]
+    fn degenerate_and_clipped() {
+        let texture = checkerboard();
+        let mut image = crate::test::img::blank((10, 10));
+        let line = [
+            ((0, 0), (0.0, 0.0)),
+            ((5, 5), (1.0, 1.0)),
+            ((9, 9), (1.0, 0.0)),
+        ];
+        triangle_textured(&mut image, line, &texture, Sampling::Bilinear);
+        triangle_textured(&mut image, line, &RgbaImage::new(0, 0), Sampling::Bilinear);
+        assert!(image.pixels().all(|px| px.0 == [255, 255, 255, 255]));
+
+        let big = [
+            ((-50, -50), (0.0, 0.0)),
+            ((60, -50), (1.0, 0.0)),
+            ((-50, 60), (0.0, 1.0)),
+        ];
+        triangle_textured(&mut image, big, &texture, Sampling::Bilinear);
+        assert!(image.pixels().all(|px| px[3] == 255 && px[0] == px[1]));
+    }
+}
@@ -0,0 +1,74 @@
+//! Pixel counts reported by drawing methods.
+
+/// The number of pixels written to an image by a drawing method, and the
+/// number skipped because they fell outside of the image.
+///
+/// Returned by the `draw_with_stats()` methods, e.g.
+/// [`Arc::draw_with_stats`](crate::conics::Arc::draw_with_stats).  This is
+/// useful for detecting geometry that ended up entirely off screen, which
+/// would otherwise fail silently.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::Arc;
+///
+/// let mut image = RgbaImage::new(100, 100);
+///
+/// let stats = Arc::new(0, 90, 20, (500, 500)).draw_with_stats(&mut image, Rgba([255, 0, 0, 255]));
+/// assert_eq!(stats.drawn, 0);
+/// assert!(stats.is_offscreen());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DrawStats {
+    /// Pixels written to the image
+    pub drawn: u64,
+    /// Pixels skipped because they were outside of the image
+    pub clipped: u64,
+}
+
+impl DrawStats {
+    /// Creates a new `DrawStats`
+    #[must_use]
+    pub const fn new(drawn: u64, clipped: u64) -> Self {
+        Self { drawn, clipped }
+    }
+
+    /// The total number of pixels in the shape, including clipped pixels
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.drawn + self.clipped
+    }
+
+    /// Returns true if the shape had pixels but none of them were drawn
+    #[must_use]
+    pub const fn is_offscreen(&self) -> bool {
+        self.drawn == 0 && self.clipped != 0
+    }
+
+    /// Counts a single pixel as either drawn or clipped
+    pub(crate) fn record(&mut self, drawn: bool) {
+        if drawn {
+            self.drawn += 1;
+        } else {
+            self.clipped += 1;
+        }
+    }
+}
+
+impl std::ops::Add for DrawStats {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            drawn: self.drawn + other.drawn,
+            clipped: self.clipped + other.clipped,
+        }
+    }
+}
+
+impl std::ops::AddAssign for DrawStats {
+    fn add_assign(&mut self, other: Self) {
+        self.drawn += other.drawn;
+        self.clipped += other.clipped;
+    }
+}
@@ -48,6 +48,82 @@ where
     None
 }
 
+/// Checks that a bounding box is exactly the smallest rectangle covering every
+/// point.
+#[cfg(test)]
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn check_bounds(bounds: crate::shapes::Rect, points: &[crate::Pt<i32>], msg: &str) {
+    assert!(!points.is_empty(), "no points {msg}");
+    let x1 = points.iter().map(crate::Pt::x).min().unwrap();
+    let x2 = points.iter().map(crate::Pt::x).max().unwrap();
+    let y1 = points.iter().map(crate::Pt::y).min().unwrap();
+    let y2 = points.iter().map(crate::Pt::y).max().unwrap();
+
+    let bx2 = bounds.x + bounds.width as i32 - 1;
+    let by2 = bounds.y + bounds.height as i32 - 1;
+    assert_eq!((bounds.x, bounds.y, bx2, by2), (x1, y1, x2, y2), "{msg}");
+}
+
+/// Fails to compile if any of the public data types listed here is missing
+/// the serde derives.  New public types have to be added to the list.  Types
+/// that hold images, borrow them while drawing, or wrap closures (such as
+/// `Draw`, `Stencil`, and `BlendFn`) are left out, as are the fonts.
+#[cfg(all(test, feature = "serde"))]
+#[test]
+fn serde_public_types() {
+    fn check<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+
+    check::<crate::Pt<i32>>();
+    check::<crate::Pt<f64>>();
+    check::<crate::Degrees>();
+    check::<crate::Radians>();
+    check::<crate::Turns>();
+    check::<crate::Error>();
+    check::<crate::color::Hsv>();
+    check::<crate::color::Hsl>();
+    check::<crate::color::Colormap>();
+    check::<crate::DrawStats>();
+    check::<crate::ops::BlendMode>();
+    check::<crate::ops::AlphaPolicy>();
+    check::<crate::ops::AlphaMode>();
+    check::<crate::ops::Blend>();
+    check::<crate::lines::LineIter>();
+    check::<crate::lines::LineRuns>();
+    check::<crate::lines::Run>();
+    check::<crate::lines::LineCap>();
+    check::<crate::ops::Supersample>();
+    check::<crate::raster::FillRule>();
+    check::<crate::shapes::Sampling>();
+    check::<crate::conics::Arc>();
+    check::<crate::conics::ArcSet>();
+    check::<crate::conics::AntialiasedArc>();
+    check::<crate::conics::Annulus>();
+    check::<crate::conics::EllipticalAnnulus>();
+    check::<crate::conics::Coverage>();
+    check::<crate::shapes::Rect>();
+    check::<crate::shapes::Polygon>();
+    check::<crate::shapes::Ellipse>();
+    check::<crate::charts::Marker>();
+    check::<crate::charts::BarStyle<[u8; 4]>>();
+    check::<crate::charts::GridStyle>();
+    check::<crate::debug::GuidelineOptions>();
+    check::<crate::shapes::Needle>();
+    check::<crate::shapes::Diamond>();
+    check::<crate::shapes::IsoGrid>();
+    check::<crate::shapes::RoundedFrame>();
+    check::<crate::conics::Glow>();
+    check::<crate::conics::ArcStroke>();
+    check::<crate::conics::CircleAlgorithm>();
+    check::<crate::curves::CubicBezier>();
+    check::<crate::curves::Samples>();
+    check::<crate::lines::Ticks>();
+    check::<crate::lines::TickSide>();
+    check::<crate::charts::HeatmapStyle>();
+    check::<crate::ops::StencilOp>();
+    check::<crate::ops::StencilTest>();
+    check::<crate::ops::DiffStats>();
+}
+
 #[cfg(test)]
 // #[macro_export]
 macro_rules! test_pixels_changed {
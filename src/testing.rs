@@ -0,0 +1,224 @@
+//! Comparing rendered images against stored reference images.
+//!
+//! Requires the `testing` feature.
+//!
+//! [`compare`] checks two images pixel by pixel, allowing each channel to
+//! differ by a small tolerance (e.g. to allow for rounding differences
+//! between platforms), and produces a diff image highlighting the pixels that
+//! do not match.  [`assert_matches_file`] wraps this up for use in tests,
+//! comparing against a PNG stored alongside the tests.
+//!
+//! ```no_run
+//! use image::{Rgba, RgbaImage};
+//!
+//! let mut image = RgbaImage::new(400, 400);
+//! freehand::new(&mut image).circle(190, (200, 200), Rgba([255, 0, 0, 255]));
+//!
+//! // Set FREEHAND_UPDATE_REFERENCES=1 to create or update the reference image
+//! freehand::testing::assert_matches_file(&image, "tests/references/circle.png", 0);
+//! ```
+
+use image::{Rgba, RgbaImage};
+use std::path::Path;
+
+/// Setting this environment variable makes [`assert_matches_file`] save the
+/// image as the new reference instead of comparing against it.
+pub const UPDATE_VAR: &str = "FREEHAND_UPDATE_REFERENCES";
+
+/// The color used for mismatched pixels in [`Comparison::diff`].
+const MISMATCH: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// The result of comparing two images with [`compare`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// The number of pixels that differ by more than the tolerance, including
+    /// pixels that are only in one of the images when their sizes differ
+    pub mismatched: u64,
+    /// The largest difference found in any single channel
+    pub max_difference: u8,
+    /// An image the size of both images combined, with mismatched pixels shown
+    /// in red over a faded copy of the expected image
+    pub diff: RgbaImage,
+}
+
+impl Comparison {
+    /// Returns true if every pixel was within the tolerance.
+    #[must_use]
+    pub fn matches(&self) -> bool {
+        self.mismatched == 0
+    }
+}
+
+/// Compares `actual` against `expected`, allowing each channel of a pixel to
+/// differ by up to `tolerance`.
+///
+/// Images with different sizes are compared where they overlap, and every
+/// pixel outside of the overlap counts as a mismatch.
+///
+/// ```
+/// use freehand::testing::compare;
+/// use image::{Rgba, RgbaImage};
+///
+/// let expected = RgbaImage::from_pixel(10, 10, Rgba([100, 100, 100, 255]));
+/// let mut actual = expected.clone();
+/// actual.put_pixel(3, 4, Rgba([102, 100, 100, 255]));
+///
+/// assert!(compare(&actual, &expected, 2).matches());
+/// assert_eq!(compare(&actual, &expected, 1).mismatched, 1);
+/// ```
+#[must_use]
+pub fn compare(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> Comparison {
+    let width = actual.width().max(expected.width());
+    let height = actual.height().max(expected.height());
+    let mut diff = RgbaImage::from_pixel(width, height, MISMATCH);
+    let mut mismatched = u64::from(width) * u64::from(height);
+    let mut max_difference = 0;
+
+    let overlap = (
+        actual.width().min(expected.width()),
+        actual.height().min(expected.height()),
+    );
+    for y in 0..overlap.1 {
+        for x in 0..overlap.0 {
+            let (a, e) = (actual.get_pixel(x, y), expected.get_pixel(x, y));
+            let difference =
+                a.0.iter()
+                    .zip(e.0)
+                    .map(|(a, e)| a.abs_diff(e))
+                    .max()
+                    .unwrap_or(0);
+            max_difference = max_difference.max(difference);
+
+            if difference <= tolerance {
+                mismatched -= 1;
+                diff.put_pixel(x, y, faded(*e));
+            }
+        }
+    }
+
+    Comparison {
+        mismatched,
+        max_difference,
+        diff,
+    }
+}
+
+/// Compares `actual` against the reference image stored at `path`.  See
+/// [`compare`].
+///
+/// # Errors
+///
+/// Returns an error if the reference image could not be loaded.
+pub fn compare_to_file<P>(
+    actual: &RgbaImage,
+    path: P,
+    tolerance: u8,
+) -> Result<Comparison, image::ImageError>
+where
+    P: AsRef<Path>,
+{
+    let expected = image::open(path)?.into_rgba8();
+    Ok(compare(actual, &expected, tolerance))
+}
+
+/// Asserts that `actual` matches the reference image stored at `path`,
+/// allowing each channel to differ by up to `tolerance`.
+///
+/// When the images do not match, the diff image is saved next to the
+/// reference with `.diff.png` appended to its name, and the actual image with
+/// `.actual.png` appended.
+///
+/// If the [`UPDATE_VAR`] environment variable is set, `actual` is saved as
+/// the new reference (creating any missing directories) instead.
+///
+/// # Panics
+///
+/// Panics if the images do not match, or if the reference image could not be
+/// loaded or saved.
+pub fn assert_matches_file<P>(actual: &RgbaImage, path: P, tolerance: u8)
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    if std::env::var_os(UPDATE_VAR).is_some() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).unwrap_or_else(|e| panic!("{}: {e}", dir.display()));
+        }
+        actual
+            .save(path)
+            .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        return;
+    }
+
+    let comparison = compare_to_file(actual, path, tolerance).unwrap_or_else(|e| {
+        panic!(
+            "Could not load reference image {}: {e}.  Set {UPDATE_VAR}=1 to create it.",
+            path.display()
+        )
+    });
+    if !comparison.matches() {
+        let with_suffix = |suffix: &str| {
+            let mut name = path.as_os_str().to_owned();
+            name.push(suffix);
+            std::path::PathBuf::from(name)
+        };
+        let (diff, actual_path) = (with_suffix(".diff.png"), with_suffix(".actual.png"));
+        // The assertion below is more useful than an error saving these
+        let _ = comparison.diff.save(&diff);
+        let _ = actual.save(&actual_path);
+        panic!(
+            "Image does not match {}: {} pixels differ by more than {tolerance} (max difference {}).  See {}",
+            path.display(),
+            comparison.mismatched,
+            comparison.max_difference,
+            diff.display()
+        );
+    }
+}
+
+/// A washed out grayscale copy of a matching pixel, so mismatches stand out.
+fn faded(px: Rgba<u8>) -> Rgba<u8> {
+    let luma = (u16::from(px[0]) * 3 + u16::from(px[1]) * 6 + u16::from(px[2])) / 10;
+    let v = (255 - (255 - luma) / 4) as u8;
+    Rgba([v, v, v, 255])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerance_and_sizes() {
+        let expected = RgbaImage::from_pixel(4, 3, Rgba([10, 20, 30, 255]));
+        let mut actual = expected.clone();
+        actual.put_pixel(1, 1, Rgba([10, 25, 30, 250]));
+
+        let c = compare(&actual, &expected, 4);
+        assert_eq!((c.mismatched, c.max_difference), (1, 5));
+        assert_eq!(*c.diff.get_pixel(1, 1), MISMATCH);
+        assert_ne!(*c.diff.get_pixel(0, 0), MISMATCH);
+        assert!(compare(&actual, &expected, 5).matches());
+
+        // The extra column and row count as mismatches
+        let bigger = RgbaImage::from_pixel(5, 4, Rgba([10, 20, 30, 255]));
+        let c = compare(&bigger, &expected, 0);
+        assert_eq!(c.mismatched, 8);
+        assert_eq!(c.diff.dimensions(), (5, 4));
+        assert_eq!(*c.diff.get_pixel(4, 0), MISMATCH);
+    }
+
+    #[test]
+    fn reference_files() {
+        let path = Path::new("images/testing_reference.png");
+        let image = crate::test::img::blank((20, 20));
+        image.save(path).unwrap();
+        assert_matches_file(&image, path, 0);
+
+        let mut changed = image.clone();
+        changed.put_pixel(5, 5, Rgba([0, 0, 0, 255]));
+        let rst = std::panic::catch_unwind(|| assert_matches_file(&changed, path, 0));
+        assert!(rst.is_err());
+        let diff = image::open("images/testing_reference.png.diff.png").unwrap();
+        assert_eq!(diff.into_rgba8().get_pixel(5, 5), &MISMATCH);
+    }
+}
@@ -0,0 +1,239 @@
+//! Drawing text using bitmap fonts
+//!
+//! A small [5x7 pixel font](Font5x7) covering the printable ASCII characters is
+//! built in, so labels can be added to images without a font rendering
+//! library.  Other monospaced bitmap fonts can be used by implementing [`Font`],
+//! or loaded from BDF files with `BdfFont` when the `bdf` feature is enabled.
+
+mod arc;
+#[cfg(feature = "bdf")]
+mod bdf;
+mod font5x7;
+
+pub use arc::{draw_text_on_arc, draw_text_on_arc_with};
+#[cfg(feature = "bdf")]
+pub use bdf::BdfFont;
+pub use font5x7::Font5x7;
+
+use crate::pt::Point;
+use image::GenericImage;
+
+/// A monospaced bitmap font.
+pub trait Font {
+    /// The width and height of every glyph in pixels.  The width can be at
+    /// most 32 pixels.
+    fn glyph_size(&self) -> (u32, u32);
+
+    /// Returns a row of a glyph as a bit mask, where bit `n` is set if the
+    /// pixel in column `n` (counting from the left) should be drawn.  Rows are
+    /// counted from the top.
+    ///
+    /// Returns `None` if the font does not have a glyph for `c`.
+    fn glyph_row(&self, c: char, row: u32) -> Option<u32>;
+}
+
+/// Draws text using the built-in [`Font5x7`], with its upper left corner at
+/// `pt`.
+///
+/// Each pixel of the font is drawn as a `scale` by `scale` square, and there
+/// is a `scale` pixel gap between characters and between lines.  Newlines
+/// (`\n`) start a new line, and characters missing from the font are left
+/// blank.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// let mut image = RgbaImage::new(400, 100);
+///
+/// freehand::text::draw_text(&mut image, (10, 10), "Hello, world!", 2, Rgba([255, 255, 255, 255]));
+/// ```
+///
+/// See also: [`Draw::text`](crate::Draw::text)
+pub fn draw_text<I, P>(image: &mut I, pt: P, text: &str, scale: u32, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    draw_text_with(image, &Font5x7, pt, text, scale, color);
+}
+
+/// Draws text with the specified font.  See [`draw_text`].
+pub fn draw_text_with<I, F, P>(
+    image: &mut I,
+    font: &F,
+    pt: P,
+    text: &str,
+    scale: u32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    F: Font + ?Sized,
+    P: Point<i32>,
+{
+    let (width, height) = font.glyph_size();
+    let scale_i = i64::from(scale);
+    let (advance, line_height) = (
+        i64::from(width + 1) * scale_i,
+        i64::from(height + 1) * scale_i,
+    );
+    let (mut x, mut y) = (i64::from(pt.x()), i64::from(pt.y()));
+
+    for c in text.chars() {
+        if c == '\n' {
+            x = i64::from(pt.x());
+            y += line_height;
+            continue;
+        }
+        draw_glyph(image, font, c, (x, y), scale, color);
+        x += advance;
+    }
+}
+
+/// Returns the width and height, in pixels, of text drawn with the built-in
+/// font.  See [`draw_text`].
+///
+/// ```
+/// assert_eq!(freehand::text::text_size("Hi\nthere", 2), (58, 30));
+/// ```
+#[must_use]
+pub fn text_size(text: &str, scale: u32) -> (u32, u32) {
+    text_size_with(&Font5x7, text, scale)
+}
+
+/// Returns the width and height, in pixels, of text drawn with the specified
+/// font.  See [`draw_text_with`].
+///
+/// Sizes too large for a `u32` are clamped to `u32::MAX`.
+#[must_use]
+pub fn text_size_with<F>(font: &F, text: &str, scale: u32) -> (u32, u32)
+where
+    F: Font + ?Sized,
+{
+    let (width, height) = font.glyph_size();
+    let longest = text
+        .split('\n')
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0) as u64;
+    let lines = text.split('\n').count() as u64;
+    if longest == 0 && lines <= 1 {
+        return (0, 0);
+    }
+
+    // Lines and characters have a gap between them, but not after the last one
+    let size = |count: u64, glyph: u32| {
+        let size = count
+            .saturating_mul(u64::from(glyph) + 1)
+            .saturating_sub(1)
+            .saturating_mul(scale.into());
+        u32::try_from(size).unwrap_or(u32::MAX)
+    };
+    (size(longest, width), size(lines, height))
+}
+
+/// Draws a single glyph with its upper left corner at `pt`.
+fn draw_glyph<I, F>(image: &mut I, font: &F, c: char, pt: (i64, i64), scale: u32, color: I::Pixel)
+where
+    I: GenericImage,
+    F: Font + ?Sized,
+{
+    let (width, height) = font.glyph_size();
+    let scale_i = i64::from(scale);
+    let (img_w, img_h) = (i64::from(image.width()), i64::from(image.height()));
+
+    // Skip glyphs that are entirely outside of the image
+    if pt.0 >= img_w
+        || pt.1 >= img_h
+        || pt.0 + i64::from(width) * scale_i < 0
+        || pt.1 + i64::from(height) * scale_i < 0
+    {
+        return;
+    }
+
+    for row in 0..height {
+        let bits = match font.glyph_row(c, row) {
+            Some(bits) => bits,
+            None => return,
+        };
+        for col in (0..width.min(32)).filter(|col| bits >> col & 1 == 1) {
+            let x0 = pt.0 + i64::from(col) * scale_i;
+            let y0 = pt.1 + i64::from(row) * scale_i;
+            for y in y0.max(0)..(y0 + scale_i).min(img_h) {
+                for x in x0.max(0)..(x0 + scale_i).min(img_w) {
+                    image.put_pixel(x as u32, y as u32, color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn glyph_rows() {
+        // The top row and crossbar of 'A'
+        assert_eq!(Font5x7.glyph_row('A', 0), Some(0b01110));
+        assert_eq!(Font5x7.glyph_row('A', 3), Some(0b10001));
+        assert_eq!(Font5x7.glyph_row('A', 4), Some(0b11111));
+        assert_eq!(Font5x7.glyph_row(' ', 3), Some(0));
+        assert_eq!(Font5x7.glyph_row('é', 3), None);
+        assert_eq!(Font5x7.glyph_row('\t', 3), None);
+    }
+
+    #[test]
+    fn draw_scaled_text() {
+        let mut image = crate::test::img::blank((30, 40));
+        draw_text(&mut image, (1, 2), "I\n-", 2, RED);
+
+        // 'I' has a vertical bar in its middle column
+        for y in 2..16 {
+            assert_eq!(*image.get_pixel(5, y), RED, "(5, {y})");
+            assert_eq!(*image.get_pixel(6, y), RED, "(6, {y})");
+        }
+        // '-' is drawn on the second line, 16 pixels down
+        assert_eq!(*image.get_pixel(1, 18 + 6), RED);
+        assert_eq!(*image.get_pixel(10, 18 + 7), RED);
+        assert_eq!(*image.get_pixel(11, 18 + 7), Rgba([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn text_clips() {
+        let mut image = crate::test::img::blank((10, 10));
+        draw_text(&mut image, (-3, -3), "XX", 1, RED);
+        draw_text(&mut image, (i32::MAX - 1, i32::MAX - 1), "XX", 100, RED);
+        // Column 3 and row 4 of the first 'X'
+        assert_eq!(*image.get_pixel(0, 1), RED);
+    }
+
+    #[test]
+    fn measure() {
+        assert_eq!(text_size("", 1), (0, 0));
+        assert_eq!(text_size("a", 1), (5, 7));
+        assert_eq!(text_size("ab", 3), (33, 21));
+        assert_eq!(text_size("\n", 1), (0, 15));
+    }
+
+    #[test]
+    fn measure_huge() {
+        struct Huge;
+        impl Font for Huge {
+            fn glyph_size(&self) -> (u32, u32) {
+                (u32::MAX, u32::MAX)
+            }
+            fn glyph_row(&self, _: char, _: u32) -> Option<u32> {
+                None
+            }
+        }
+
+        // Sizes past the u32 range are clamped
+        assert_eq!(text_size("ab\ncd", u32::MAX), (u32::MAX, u32::MAX));
+        assert_eq!(text_size_with(&Huge, "ab", 1), (u32::MAX, u32::MAX));
+        assert_eq!(text_size_with(&Huge, "a", 1), (u32::MAX, u32::MAX));
+    }
+}
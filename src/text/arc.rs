@@ -0,0 +1,167 @@
+use super::{Font, Font5x7};
+use crate::conics::Arc;
+use image::GenericImage;
+
+/// Draws text along an arc using the built-in [`Font5x7`], rotating each
+/// glyph to follow the curve.
+///
+/// The text is centered on the middle of the arc (or its start angle for a
+/// complete circle), and each glyph is centered on the arc's line.  Text on
+/// the upper half of a circle runs clockwise with the tops of the glyphs
+/// facing out, while text on the lower half runs counter-clockwise with the
+/// tops facing in, so it reads left to right either way.
+///
+/// Each pixel of the font is `scale` pixels in size.  Newlines are ignored,
+/// and text longer than the arc continues past its ends.
+///
+/// # Example
+///
+/// ```
+/// use freehand::conics::Arc;
+/// use image::{RgbaImage, Rgba};
+///
+/// let mut image = RgbaImage::new(400, 400);
+/// let arc = Arc::new(20, 160, 150, (200, 200));
+///
+/// freehand::text::draw_text_on_arc(&mut image, "Speed (km/h)", &arc, 3, Rgba([255, 255, 255, 255]));
+/// ```
+pub fn draw_text_on_arc<I>(image: &mut I, text: &str, arc: &Arc, scale: u32, color: I::Pixel)
+where
+    I: GenericImage,
+{
+    draw_text_on_arc_with(image, &Font5x7, text, arc, scale, color);
+}
+
+/// Draws text along an arc with the specified font.  See [`draw_text_on_arc`].
+pub fn draw_text_on_arc_with<I, F>(
+    image: &mut I,
+    font: &F,
+    text: &str,
+    arc: &Arc,
+    scale: u32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    F: Font + ?Sized,
+{
+    let radius = f64::from(arc.radius());
+    let chars: Vec<char> = text.chars().filter(|c| *c != '\n').collect();
+    if chars.is_empty() || radius == 0.0 || scale == 0 {
+        return;
+    }
+
+    let (width, height) = font.glyph_size();
+    let scale = f64::from(scale);
+    let (cell_w, cell_h) = (f64::from(width) * scale, f64::from(height) * scale);
+
+    // The angle taken up by each character, including the gap after it
+    let step = (cell_w + scale) / radius;
+    let total = step * chars.len() as f64 - scale / radius;
+    let mid = if crate::angle::is_full_sweep(arc.start_angle(), arc.end_angle()) {
+        arc.start_angle()
+    } else {
+        arc.start_angle() + arc.sweep() / 2.0
+    };
+    // Run clockwise on the top half of the circle so the text is upright
+    let dir = if mid.sin() >= 0.0 { -1.0 } else { 1.0 };
+    let first = mid - dir * (total - step + scale / radius) / 2.0;
+
+    let center = arc.center();
+    let (cx, cy) = (f64::from(center.x()), f64::from(center.y()));
+    // Distance from a glyph's center to its farthest corner
+    let reach = cell_w.hypot(cell_h) / 2.0;
+    let (img_w, img_h) = (f64::from(image.width()), f64::from(image.height()));
+
+    for (i, &c) in chars.iter().enumerate() {
+        let angle = (i as f64).mul_add(dir * step, first);
+        let (sin, cos) = angle.sin_cos();
+        let (gx, gy) = (radius.mul_add(cos, cx), (-radius).mul_add(sin, cy));
+        // Unit vectors in image coordinates for the glyph's x axis (the
+        // direction of the text) and y axis (from the top to the bottom)
+        let along = (dir * -sin, dir * -cos);
+        let down = (dir * cos, -dir * sin);
+
+        let x0 = (gx - reach).floor().max(0.0);
+        let y0 = (gy - reach).floor().max(0.0);
+        let x1 = (gx + reach).ceil().min(img_w - 1.0);
+        let y1 = (gy + reach).ceil().min(img_h - 1.0);
+        if x0 > x1 || y0 > y1 {
+            continue;
+        }
+
+        // Map each pixel near the glyph back onto the glyph to avoid gaps.
+        // Like the arc's points, pixel coordinates refer to pixel centers.
+        for y in y0 as u32..=y1 as u32 {
+            for x in x0 as u32..=x1 as u32 {
+                let (vx, vy) = (f64::from(x) - gx, f64::from(y) - gy);
+                let lx = vx.mul_add(along.0, vy * along.1) + cell_w / 2.0;
+                let ly = vx.mul_add(down.0, vy * down.1) + cell_h / 2.0;
+                if lx < 0.0 || ly < 0.0 || lx >= cell_w || ly >= cell_h {
+                    continue;
+                }
+
+                let (col, row) = ((lx / scale) as u32, (ly / scale) as u32);
+                let set = font
+                    .glyph_row(c, row)
+                    .map_or(false, |bits| col < 32 && bits >> col & 1 == 1);
+                if set {
+                    image.put_pixel(x, y, color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    /// Returns the bounds of every red pixel as `(x0, y0, x1, y1)`
+    fn red_bounds(image: &image::RgbaImage) -> (u32, u32, u32, u32) {
+        image
+            .enumerate_pixels()
+            .filter(|(_, _, px)| **px == RED)
+            .fold((u32::MAX, u32::MAX, 0, 0), |b, (x, y, _)| {
+                (b.0.min(x), b.1.min(y), b.2.max(x), b.3.max(y))
+            })
+    }
+
+    #[test]
+    fn text_on_top_of_arc() {
+        let mut image = crate::test::img::blank((100, 100));
+        // A single upright 'I' centered at the top of the circle
+        draw_text_on_arc(&mut image, "I", &Arc::new(45, 135, 40, (50, 50)), 1, RED);
+        assert_eq!(red_bounds(&image), (49, 7, 51, 13));
+        // The middle column of 'I' is solid
+        for y in 7..=13 {
+            assert_eq!(*image.get_pixel(50, y), RED, "(50, {y})");
+        }
+    }
+
+    #[test]
+    fn text_on_bottom_of_arc() {
+        let mut top = crate::test::img::blank((100, 100));
+        let mut bottom = crate::test::img::blank((100, 100));
+        draw_text_on_arc(&mut top, "L", &Arc::new(45, 135, 40, (50, 50)), 1, RED);
+        draw_text_on_arc(&mut bottom, "L", &Arc::new(225, 315, 40, (50, 50)), 1, RED);
+
+        // Both are upright, so the bottom glyph is the top glyph moved down
+        for (x, y, px) in top.enumerate_pixels() {
+            assert_eq!(*px, *bottom.get_pixel(x, y + 80), "({x}, {y})");
+            if y + 80 >= 99 {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn text_on_arc_clips() {
+        let mut image = crate::test::img::blank((20, 20));
+        draw_text_on_arc(&mut image, "Hello", &Arc::new(0, 180, 15, (0, 20)), 2, RED);
+        draw_text_on_arc(&mut image, "Hello", &Arc::new(0, 180, 0, (10, 10)), 2, RED);
+        assert!(red_bounds(&image).0 < 20);
+    }
+}
@@ -0,0 +1,301 @@
+use super::Font;
+use crate::Error;
+use std::collections::HashMap;
+
+/// A monospaced bitmap font loaded from the
+/// [BDF](https://en.wikipedia.org/wiki/Glyph_Bitmap_Distribution_Format) text
+/// format.  Requires the `bdf` feature.
+///
+/// Every glyph is placed inside the font's bounding box, so glyphs line up on
+/// a shared baseline.  Glyphs wider than 32 pixels are not supported.
+///
+/// # Example
+///
+/// ```
+/// use freehand::text::{draw_text_with, BdfFont, Font};
+/// use image::{RgbaImage, Rgba};
+///
+/// let data = "STARTFONT 2.1
+/// FONTBOUNDINGBOX 3 3 0 0
+/// CHARS 1
+/// STARTCHAR plus
+/// ENCODING 43
+/// BBX 3 3 0 0
+/// BITMAP
+/// 40
+/// E0
+/// 40
+/// ENDCHAR
+/// ENDFONT
+/// ";
+///
+/// let font = BdfFont::parse(data).unwrap();
+/// assert_eq!(font.glyph_row('+', 1), Some(0b111));
+///
+/// let mut image = RgbaImage::new(20, 20);
+/// draw_text_with(&mut image, &font, (0, 0), "++", 2, Rgba([255, 0, 0, 255]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BdfFont {
+    width: u32,
+    height: u32,
+    glyphs: HashMap<char, Glyph>,
+}
+
+/// The rows of a glyph inside the font's bounding box.  Only the rows from
+/// the glyph's bitmap are stored, starting `top` rows from the top; the rest
+/// are blank.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Glyph {
+    top: u32,
+    rows: Vec<u32>,
+}
+
+/// The bounding box of a font or glyph: width, height, x offset, and y offset
+type Bbx = (u32, u32, i32, i32);
+
+impl BdfFont {
+    /// Parses a font from the contents of a BDF file.
+    ///
+    /// Glyphs without a Unicode encoding are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFont`] with the line number of the first line
+    /// that could not be understood.
+    pub fn parse(data: &str) -> Result<Self, Error> {
+        let mut font_bbx: Option<Bbx> = None;
+        let mut glyphs = HashMap::new();
+        let mut lines = data.lines().enumerate().map(|(i, l)| (i + 1, l.trim()));
+        // Every row of the font's bounding box is covered by a row of some
+        // glyph's bitmap, each on its own line
+        let max_height = data.lines().count();
+
+        while let Some((n, line)) = lines.next() {
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("FONTBOUNDINGBOX") => {
+                    let bbx = parse_bbx(words).ok_or(Error::InvalidFont(n))?;
+                    if bbx.0 > 32 || bbx.1 as usize > max_height {
+                        return Err(Error::InvalidFont(n));
+                    }
+                    font_bbx = Some(bbx);
+                }
+                Some("STARTCHAR") => {
+                    let font_bbx = font_bbx.ok_or(Error::InvalidFont(n))?;
+                    if let Some((c, glyph)) = parse_char(&mut lines, font_bbx)? {
+                        glyphs.insert(c, glyph);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (width, height, _, _) = font_bbx.ok_or(Error::InvalidFont(0))?;
+        Ok(Self {
+            width,
+            height,
+            glyphs,
+        })
+    }
+}
+
+impl std::str::FromStr for BdfFont {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Font for BdfFont {
+    fn glyph_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn glyph_row(&self, c: char, row: u32) -> Option<u32> {
+        let glyph = self.glyphs.get(&c)?;
+        if row >= self.height {
+            return None;
+        }
+        let bits = row
+            .checked_sub(glyph.top)
+            .and_then(|r| glyph.rows.get(r as usize));
+        Some(bits.copied().unwrap_or(0))
+    }
+}
+
+fn parse_bbx<'a>(mut words: impl Iterator<Item = &'a str>) -> Option<Bbx> {
+    Some((
+        words.next()?.parse().ok()?,
+        words.next()?.parse().ok()?,
+        words.next()?.parse().ok()?,
+        words.next()?.parse().ok()?,
+    ))
+}
+
+/// Parses the lines after `STARTCHAR` through `ENDCHAR`, and returns the
+/// glyph's rows positioned inside the font's bounding box.
+///
+/// Only the rows of the glyph's bitmap that are inside of the font's bounding
+/// box are kept, so a huge bounding box doesn't allocate anything.
+fn parse_char<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    font: Bbx,
+) -> Result<Option<(char, Glyph)>, Error> {
+    let (font_w, font_h, font_x, font_y) = font;
+    let mut encoding = None;
+    let mut bbx = None;
+    let mut glyph: Option<Glyph> = None;
+    let mut row = 0;
+    let mut in_bitmap = false;
+
+    for (n, line) in lines {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("ENDCHAR") => {
+                let glyph = glyph.unwrap_or_default();
+                return Ok(encoding.and_then(char::from_u32).map(|c| (c, glyph)));
+            }
+            Some("ENCODING") => {
+                let code: i64 = words
+                    .next()
+                    .and_then(|w| w.parse().ok())
+                    .ok_or(Error::InvalidFont(n))?;
+                encoding = u32::try_from(code).ok();
+            }
+            Some("BBX") => bbx = Some(parse_bbx(words).ok_or(Error::InvalidFont(n))?),
+            Some("BITMAP") => in_bitmap = true,
+            Some(hex) if in_bitmap => {
+                let (w, h, x, y) = bbx.ok_or(Error::InvalidFont(n))?;
+                let bits = u64::from_str_radix(hex, 16).map_err(|_| Error::InvalidFont(n))?;
+                // A glyph without any columns has no bitmap rows
+                if w == 0 || hex.len() > 16 || w > hex.len() as u32 * 4 {
+                    return Err(Error::InvalidFont(n));
+                }
+                // Rows are padded to whole bytes with the leftmost pixel in the highest bit
+                let bits = bits >> (hex.len() as u32 * 4 - w);
+
+                // Distance from the top of the font's bounding box to this row
+                let top =
+                    i64::from(font_y) + i64::from(font_h) - (i64::from(y) + i64::from(h)) + row;
+                let left = i64::from(x) - i64::from(font_x);
+                if (0..i64::from(font_h)).contains(&top) {
+                    let mut pixels = 0;
+                    for col in (0..w).filter(|col| bits >> (w - 1 - col) & 1 == 1) {
+                        let col = left + i64::from(col);
+                        if (0..i64::from(font_w)).contains(&col) {
+                            pixels |= 1 << col;
+                        }
+                    }
+                    // Rows inside of the bounding box are consecutive
+                    glyph
+                        .get_or_insert_with(|| Glyph {
+                            top: top as u32,
+                            rows: Vec::new(),
+                        })
+                        .rows
+                        .push(pixels);
+                }
+                row += 1;
+            }
+            _ => {}
+        }
+    }
+    Err(Error::InvalidFont(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FONT: &str = "STARTFONT 2.1
+FONT -test-
+SIZE 4 75 75
+FONTBOUNDINGBOX 4 5 0 -1
+CHARS 3
+STARTCHAR A
+ENCODING 65
+SWIDTH 500 0
+DWIDTH 5 0
+BBX 3 4 0 0
+BITMAP
+40
+A0
+E0
+A0
+ENDCHAR
+STARTCHAR comma
+ENCODING 44
+BBX 1 2 1 -1
+BITMAP
+80
+80
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn parse_bdf() {
+        let font = BdfFont::parse(FONT).unwrap();
+        assert_eq!(font.glyph_size(), (4, 5));
+        assert_eq!(font.glyphs.len(), 2);
+
+        let rows = |c| {
+            (0..5)
+                .map(|r| font.glyph_row(c, r).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rows('A'), vec![0b010, 0b101, 0b111, 0b101, 0]);
+        // The comma sits on the baseline and extends below it
+        assert_eq!(rows(','), vec![0, 0, 0, 0b10, 0b10]);
+        assert_eq!(font.glyph_row('B', 0), None);
+    }
+
+    #[test]
+    fn parse_bdf_errors() {
+        assert_eq!(BdfFont::parse(""), Err(Error::InvalidFont(0)));
+        assert_eq!(
+            BdfFont::parse("STARTCHAR A\nENDCHAR"),
+            Err(Error::InvalidFont(1))
+        );
+        assert_eq!(
+            BdfFont::parse("FONTBOUNDINGBOX 4 5 0\n"),
+            Err(Error::InvalidFont(1))
+        );
+        let bad_hex = FONT.replace("E0", "ZZ");
+        assert_eq!(BdfFont::parse(&bad_hex), Err(Error::InvalidFont(14)));
+    }
+
+    #[test]
+    fn parse_bdf_malformed() {
+        // A bitmap row for a glyph without any columns
+        let empty = FONT.replace(
+            "BBX 1 1 0 0\nBITMAP\n80",
+            "BBX 0 1 0 0\nBITMAP\nFFFFFFFFFFFFFFFF",
+        );
+        assert_eq!(BdfFont::parse(&empty), Err(Error::InvalidFont(28)));
+
+        // A bounding box taller than the glyphs could fill
+        let huge = FONT.replace(
+            "FONTBOUNDINGBOX 4 5 0 -1",
+            "FONTBOUNDINGBOX 1 4000000000 0 0",
+        );
+        assert_eq!(BdfFont::parse(&huge), Err(Error::InvalidFont(4)));
+
+        // Glyphs only keep the rows of their bitmaps
+        let tall = FONT.replace("FONTBOUNDINGBOX 4 5 0 -1", "FONTBOUNDINGBOX 4 20 0 -1");
+        let font = BdfFont::parse(&tall).unwrap();
+        assert_eq!(font.glyphs[&'A'].rows.len(), 4);
+        assert_eq!(font.glyph_row('A', 0), Some(0));
+        assert_eq!(font.glyph_row('A', 15), Some(0b010));
+        assert_eq!(font.glyph_row('A', 19), Some(0));
+        assert_eq!(font.glyph_row('A', 20), None);
+    }
+}
@@ -1,15 +1,21 @@
 use crate::pt::Pt;
 
+/// Translates a point from octant 7 into the given octant around the center.
+///
+/// The center is added with saturating math: points of huge circles that fall
+/// beyond the `i32` range are pinned to its edges, far outside any image,
+/// instead of overflowing.
 pub(crate) fn iter_to_real(x: i32, y: i32, oct: u8, c: Pt<i32>) -> Pt<i32> {
-    match oct {
-        1 => (y + c.x(), -x + c.y()).into(),
-        2 => (x + c.x(), -y + c.y()).into(),
-        3 => (-x + c.x(), -y + c.y()).into(),
-        4 => (-y + c.x(), -x + c.y()).into(),
-        5 => (-y + c.x(), x + c.y()).into(),
-        6 => (-x + c.x(), y + c.y()).into(),
-        // 7 => (x + c.x(), y + c.y()).into(),
-        8 => (y + c.x(), x + c.y()).into(),
-        _ => (x + c.x(), y + c.y()).into(),
-    }
+    let (x, y) = match oct {
+        1 => (y, -x),
+        2 => (x, -y),
+        3 => (-x, -y),
+        4 => (-y, -x),
+        5 => (-y, x),
+        6 => (-x, y),
+        // 7 => (x, y),
+        8 => (y, x),
+        _ => (x, y),
+    };
+    Pt::new(c.x().saturating_add(x), c.y().saturating_add(y))
 }
@@ -14,10 +14,11 @@ use image::Rgba;
 #[test]
 fn basic_drawing() -> Result<(), image::ImageError> {
     logger(log::LevelFilter::Warn);
-    let height = 400;
-    let width = 400;
+    let height: i32 = 400;
+    let width: i32 = 400;
 
-    let mut image = image::RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]));
+    let mut image =
+        image::RgbaImage::from_pixel(width as u32, height as u32, Rgba([255, 255, 255, 255]));
 
     freehand::lines::vertical_line(
         &mut image,